@@ -19,10 +19,11 @@ use hydradx_traits::router::AssetPair;
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use hydradx_traits::stableswap::AssetAmount;
+use hydradx_traits::OraclePeriod;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
 use pallet_broadcast::types::*;
-use pallet_dca::types::{Order, Schedule};
+use pallet_dca::types::{Order, PriceCondition, Schedule};
 use pallet_omnipool::types::Tradability;
 use pallet_stableswap::MAX_ASSETS_IN_POOL;
 use primitives::{AssetId, Balance};
@@ -361,6 +362,7 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				price_condition: None,
 				slippage: Some(Permill::from_percent(5)),
 				order: Order::Buy {
 					asset_in: HDX,
@@ -1459,6 +1461,7 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				price_condition: None,
 				slippage: Some(Permill::from_percent(1)),
 				order: Order::Sell {
 					asset_in: HDX,
@@ -1513,6 +1516,53 @@ mod omnipool {
 		});
 	}
 
+	#[test]
+	fn sell_schedule_execution_should_be_skipped_when_price_condition_is_not_met() {
+		TestNet::reset();
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let alice_init_hdx_balance = 5000 * UNITS;
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				ALICE.into(),
+				alice_init_hdx_balance,
+			));
+
+			let dca_budget = 1100 * UNITS;
+			let amount_to_sell = 100 * UNITS;
+			let mut schedule1 =
+				schedule_fake_with_sell_order(ALICE, PoolType::Omnipool, dca_budget, HDX, DAI, amount_to_sell);
+			// HDX is never sold for 1000 DAI
+			schedule1.price_condition = Some(PriceCondition {
+				limit_price: FixedU128::from(1000),
+				oracle_period: OraclePeriod::Short,
+			});
+			create_schedule(ALICE, schedule1);
+
+			assert_reserved_balance!(&ALICE.into(), HDX, dca_budget);
+
+			//Act
+			set_relaychain_block_number(12);
+
+			//Assert
+			let schedule_id = 0;
+			let fee = Currencies::free_balance(HDX, &Treasury::account_id()) - TREASURY_ACCOUNT_INIT_BALANCE;
+			assert!(fee > 0, "Treasury got rugged");
+
+			assert_balance!(ALICE.into(), HDX, alice_init_hdx_balance - dca_budget);
+			assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+			assert_reserved_balance!(&ALICE.into(), HDX, dca_budget - fee);
+
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_eq!(count_dca_event!(pallet_dca::Event::ExecutionSkipped { .. }), 1);
+			assert_eq!(count_failed_trade_events(), 0);
+
+			let next_execution_block = 12 + 5;
+			assert!(DCA::schedule_ids_per_block(next_execution_block).contains(&schedule_id));
+		});
+	}
+
 	#[test]
 	fn sell_schedule_execution_should_work_when_hub_asset_is_sold() {
 		TestNet::reset();
@@ -2372,6 +2422,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -2650,6 +2701,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: stable_asset_1,
@@ -2958,6 +3010,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Buy {
 						asset_in: HDX,
@@ -3139,6 +3192,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(70)),
 					order: Order::Buy {
 						asset_in: stable_asset_1,
@@ -3199,6 +3253,7 @@ mod xyk {
 				1000 * UNITS,
 				DAI,
 				2000 * UNITS,
+				None,
 			));
 
 			//For populating oracle
@@ -3392,6 +3447,7 @@ mod all_pools {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(15)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -3448,6 +3504,7 @@ fn create_xyk_pool(asset_a: AssetId, amount_a: Balance, asset_b: AssetId, amount
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
@@ -3538,6 +3595,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Buy {
 						asset_in: HDX,
@@ -3652,6 +3710,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -3736,6 +3795,7 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				price_condition: None,
 				slippage: Some(Permill::from_percent(10)),
 				order: Order::Sell {
 					asset_in: DOT,
@@ -3883,6 +3943,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					price_condition: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: stable_asset_1,
@@ -4003,6 +4064,7 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				price_condition: None,
 				slippage: Some(Permill::from_percent(10)),
 				order: Order::Sell {
 					asset_in: DOT,
@@ -4100,6 +4162,7 @@ fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amou
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
@@ -4140,6 +4203,7 @@ fn schedule_fake_with_buy_order_with_route(
 		total_amount: budget,
 		max_retries: None,
 		stability_threshold: None,
+		price_condition: None,
 		slippage: Some(Permill::from_percent(10)),
 		order: Order::Buy {
 			asset_in,
@@ -4187,6 +4251,7 @@ fn schedule_fake_with_sell_order_with_route(
 		total_amount,
 		max_retries: None,
 		stability_threshold: None,
+		price_condition: None,
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Sell {
 			asset_in,
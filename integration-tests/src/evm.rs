@@ -1363,6 +1363,7 @@ mod chainlink_precompile {
 				100 * UNITS,
 				DOT,
 				200 * UNITS,
+				None,
 			));
 
 			assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), XYK_SOURCE, (HDX, DOT)));
@@ -1436,6 +1437,7 @@ mod chainlink_precompile {
 				100 * UNITS,
 				DAI,
 				200 * UNITS,
+				None,
 			));
 
 			assert_ok!(hydradx_runtime::XYK::create_pool(
@@ -1444,6 +1446,7 @@ mod chainlink_precompile {
 				100 * UNITS,
 				DOT,
 				300 * UNITS,
+				None,
 			));
 
 			assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), XYK_SOURCE, (HDX, DAI)));
@@ -2339,5 +2342,6 @@ fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amou
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
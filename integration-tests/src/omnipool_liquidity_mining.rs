@@ -218,6 +218,61 @@ fn deposit_shares_should_work_when_yield_farm_exists() {
 	});
 }
 
+#[test]
+fn split_and_merge_positions_should_fail_when_position_is_deposited_in_farm() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		//Arrange
+		init_omnipool();
+
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(100);
+		set_relaychain_block_number(100);
+		create_global_farm(None, None);
+
+		set_relaychain_block_number(200);
+		create_yield_farm(global_farm_id, ETH);
+
+		set_relaychain_block_number(300);
+
+		assert_ok!(hydradx_runtime::Currencies::update_balance(
+			hydradx_runtime::RuntimeOrigin::root(),
+			CHARLIE.into(),
+			ETH,
+			10_000 * UNITS as i128,
+		));
+
+		let position_id = omnipool_add_liquidity(CHARLIE.into(), ETH, 1_000 * UNITS);
+		let other_position_id = omnipool_add_liquidity(CHARLIE.into(), ETH, 1_000 * UNITS);
+
+		set_relaychain_block_number(400);
+		assert_ok!(hydradx_runtime::OmnipoolLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			global_farm_id,
+			yield_farm_id,
+			position_id
+		));
+
+		//Act & assert
+		assert_noop!(
+			Omnipool::split_position(RuntimeOrigin::signed(CHARLIE.into()), position_id, 100 * UNITS),
+			pallet_omnipool::Error::<Runtime>::Forbidden
+		);
+		assert_noop!(
+			Omnipool::merge_positions(RuntimeOrigin::signed(CHARLIE.into()), other_position_id, position_id),
+			pallet_omnipool::Error::<Runtime>::Forbidden
+		);
+		assert_noop!(
+			Omnipool::merge_positions(RuntimeOrigin::signed(CHARLIE.into()), position_id, other_position_id),
+			pallet_omnipool::Error::<Runtime>::Forbidden
+		);
+	});
+}
+
 #[test]
 fn redeposit_shares_multiple_times_should_work_when_shares_already_deposited() {
 	TestNet::reset();
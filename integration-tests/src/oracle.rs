@@ -191,6 +191,7 @@ fn xyk_trades_with_insufficient_asset_are_not_tracked_by_oracle() {
 			100 * UNITS,
 			INSUFFICIENT_ASSET,
 			100 * UNITS,
+			None,
 		));
 
 		assert_ok!(hydradx_runtime::XYK::buy(
@@ -242,6 +243,7 @@ fn xyk_trades_with_insufficient_asset_are_tracked_by_oracle_when_asset_is_whitel
 			100 * UNITS,
 			INSUFFICIENT_ASSET,
 			100 * UNITS,
+			None,
 		));
 
 		assert_ok!(EmaOracle::add_oracle(
@@ -929,6 +929,7 @@ pub fn create_xyk_pool(asset_a: u32, amount_a: u128, asset_b: u32, amount_b: u12
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
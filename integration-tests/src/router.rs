@@ -1318,6 +1318,7 @@ mod omnipool_router_tests {
 					100000 * UNITS,
 					altcoin,
 					100000 * UNITS,
+					None,
 				));
 
 				let trades = vec![Trade {
@@ -1379,6 +1380,7 @@ mod omnipool_router_tests {
 					100000 * UNITS,
 					altcoin,
 					100000 * UNITS,
+					None,
 				));
 
 				let trades = vec![Trade {
@@ -1439,6 +1441,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					DOT,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1447,6 +1450,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1455,6 +1459,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					ETH,
 					10000 * UNITS,
+					None,
 				));
 
 				let trades = vec![
@@ -1565,6 +1570,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset2,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1573,6 +1579,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset3,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1581,6 +1588,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset4,
 					10000 * UNITS,
+					None,
 				));
 
 				let trades = vec![
@@ -1666,6 +1674,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset2,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1674,6 +1683,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					ETH,
 					10000 * UNITS,
+					None,
 				));
 
 				let trades = vec![
@@ -1766,6 +1776,7 @@ mod omnipool_router_tests {
 					100000 * UNITS,
 					insufficient_asset_2,
 					100000 * UNITS,
+					None,
 				));
 
 				let trades = vec![Trade {
@@ -1868,6 +1879,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset2,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1876,6 +1888,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset3,
 					10000 * UNITS,
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -1884,6 +1897,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					insufficient_asset4,
 					10000 * UNITS,
+					None,
 				));
 
 				let trades = vec![
@@ -1960,6 +1974,7 @@ mod omnipool_router_tests {
 					100000 * UNITS,
 					shitcoin,
 					100000 * UNITS,
+					None,
 				));
 
 				init_omnipool();
@@ -2028,6 +2043,7 @@ mod omnipool_router_tests {
 					10000 * UNITS,
 					HDX,
 					10000 * UNITS,
+					None,
 				));
 
 				init_omnipool();
@@ -2152,6 +2168,7 @@ mod omnipool_router_tests {
 					100000 * UNITS,
 					shitcoin,
 					100000 * UNITS,
+					None,
 				));
 
 				let trades = vec![Trade {
@@ -5664,6 +5681,7 @@ pub fn create_lbp_pool(accumulated_asset: u32, distributed_asset: u32) {
 		None,
 		None,
 		None,
+		None,
 	));
 }
 
@@ -5686,6 +5704,7 @@ pub fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		100 * UNITS,
 		asset_b,
 		50 * UNITS,
+		None,
 	));
 }
 
@@ -5709,6 +5728,7 @@ fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amou
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
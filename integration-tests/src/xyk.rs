@@ -2,9 +2,11 @@
 
 use crate::polkadot_test_net::*;
 
-use hydradx_runtime::{DustRemovalWhitelist, RuntimeOrigin, LBP, XYK};
+use hydradx_runtime::{Currencies, DustRemovalWhitelist, RuntimeEvent, RuntimeOrigin, Staking, System, LBP, XYK};
 use hydradx_traits::AMM;
+use orml_traits::MultiCurrency;
 use pallet_xyk::types::AssetPair;
+use sp_runtime::Permill;
 use xcm_emulator::TestExt;
 
 use frame_support::{assert_noop, assert_ok, traits::Contains};
@@ -32,6 +34,7 @@ fn pair_account_should_be_added_into_whitelist_when_pool_is_created() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 
 		//assert
@@ -54,6 +57,7 @@ fn pair_account_should_be_removed_from_whitelist_when_pool_was_destroyed() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 		assert!(DustRemovalWhitelist::contains(&pair_account(asset_a, asset_b)));
 
@@ -85,6 +89,7 @@ fn pool_should_be_created_when_it_was_destroyed_previously() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 		assert_ok!(XYK::remove_liquidity(
 			RuntimeOrigin::signed(ALICE.into()),
@@ -100,6 +105,7 @@ fn pool_should_be_created_when_it_was_destroyed_previously() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 	});
 }
@@ -119,6 +125,7 @@ fn share_asset_id_should_be_offset() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 
 		let share_token = XYK::get_share_token(AssetPair {
@@ -145,6 +152,7 @@ fn creating_xyk_pool_should_fail_when_asset_is_pool_share_asset() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 
 		let share_token = XYK::get_share_token(AssetPair {
@@ -159,6 +167,7 @@ fn creating_xyk_pool_should_fail_when_asset_is_pool_share_asset() {
 				100 * UNITS,
 				asset_b,
 				200 * UNITS,
+				None,
 			),
 			pallet_xyk::Error::<hydradx_runtime::Runtime>::CannotCreatePool
 		);
@@ -169,6 +178,7 @@ fn creating_xyk_pool_should_fail_when_asset_is_pool_share_asset() {
 				100 * UNITS,
 				share_token,
 				200 * UNITS,
+				None,
 			),
 			pallet_xyk::Error::<hydradx_runtime::Runtime>::CannotCreatePool
 		);
@@ -214,6 +224,7 @@ fn creating_xyk_pool_should_fail_when_lbp_pool_is_running() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// running LBP
@@ -227,8 +238,116 @@ fn creating_xyk_pool_should_fail_when_lbp_pool_is_running() {
 				100 * UNITS,
 				asset_b,
 				200 * UNITS,
+				None,
 			),
 			pallet_xyk::Error::<hydradx_runtime::Runtime>::CannotCreatePool
 		);
 	});
 }
+
+#[test]
+fn pool_should_be_created_with_fee_tier_added_by_governance() {
+	TestNet::reset();
+
+	let asset_a = 1;
+	let asset_b = 2;
+	let fee_tier = (1, 100);
+
+	Hydra::execute_with(|| {
+		//arrange
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), fee_tier));
+
+		//act
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			100 * UNITS,
+			asset_b,
+			200 * UNITS,
+			Some(fee_tier),
+		));
+
+		//assert
+		assert_eq!(XYK::get_fee(&pair_account(asset_a, asset_b)), fee_tier);
+		assert_eq!(
+			XYK::pool_details_of_assets(asset_a, asset_b).map(|pool| pool.fee),
+			Some(fee_tier)
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_tier_is_not_allowed() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		assert_noop!(
+			XYK::create_pool(
+				RuntimeOrigin::signed(ALICE.into()),
+				1,
+				100 * UNITS,
+				2,
+				200 * UNITS,
+				Some((1, 100)),
+			),
+			pallet_xyk::Error::<hydradx_runtime::Runtime>::FeeTierNotAllowed
+		);
+	});
+}
+
+#[test]
+fn sell_should_distribute_protocol_fee_when_protocol_fee_is_set() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//arrange
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			100 * UNITS,
+			DAI,
+			200 * UNITS,
+			None,
+		));
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(50)));
+
+		let pool = pair_account(HDX, DAI);
+		let treasury = hydradx_runtime::Treasury::account_id();
+		let staking_pot = Staking::pot_account_id();
+
+		let pool_hdx_before = Currencies::free_balance(HDX, &pool);
+		let bob_hdx_before = Currencies::free_balance(HDX, &AccountId::from(BOB));
+		let protocol_hdx_before =
+			Currencies::free_balance(HDX, &treasury) + Currencies::free_balance(HDX, &staking_pot);
+
+		//act
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			DAI,
+			HDX,
+			10 * UNITS,
+			0,
+			false,
+		));
+
+		//assert
+		let fee = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::XYK(pallet_xyk::Event::SellExecuted { fee_amount, .. }) => Some(fee_amount),
+				_ => None,
+			})
+			.unwrap();
+		let protocol_fee = Permill::from_percent(50).mul_floor(fee);
+		assert!(protocol_fee > 0);
+
+		let received = Currencies::free_balance(HDX, &AccountId::from(BOB)) - bob_hdx_before;
+		let protocol_hdx_after = Currencies::free_balance(HDX, &treasury) + Currencies::free_balance(HDX, &staking_pot);
+
+		assert_eq!(protocol_hdx_after - protocol_hdx_before, protocol_fee);
+		assert_eq!(
+			Currencies::free_balance(HDX, &pool),
+			pool_hdx_before - received - protocol_fee
+		);
+	});
+}
@@ -1254,6 +1254,7 @@ fn create_xyk_pool(asset_a: u32, amount_a: u128, asset_b: u32, amount_b: u128) -
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 
 	share_id
@@ -0,0 +1,526 @@
+use crate::concentrated_liquidity::types::SwapStep;
+use crate::support::rational::{round_u512_to_rational, Rounding};
+use crate::types::Balance;
+use num_traits::Zero;
+use primitive_types::{U256, U512};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+
+/// Minimum tick. Price at a tick is `1.0001^tick`.
+pub const MIN_TICK: i32 = -887_272;
+/// Maximum tick.
+pub const MAX_TICK: i32 = 887_272;
+
+/// Square root price at `MIN_TICK` (Q64.96).
+pub const MIN_SQRT_PRICE: U256 = U256([4_295_128_739, 0, 0, 0]);
+/// Square root price at `MAX_TICK` (Q64.96).
+pub const MAX_SQRT_PRICE: U256 = U256([0x5d95_1d52_6398_8d26, 0xefd1_fc6a_5064_8849, 0xfffd_8963, 0]);
+
+/// 2^96 - fixed point unit of square root price.
+pub const Q96: U256 = U256([0, 1 << 32, 0, 0]);
+/// 2^128 - fixed point unit of fee growth.
+pub const Q128: U256 = U256([0, 0, 1, 0]);
+
+const FEE_DENOMINATOR: u32 = 1_000_000;
+
+// `2^128 / sqrt(1.0001^(2^i))` for i in 1..20, used to calculate square root price at a tick.
+const TICK_RATIOS: [(u32, u128); 19] = [
+	(0x2, 0xfff97272373d413259a46990580e213a),
+	(0x4, 0xfff2e50f5f656932ef12357cf3c7fdcc),
+	(0x8, 0xffe5caca7e10e4e61c3624eaa0941cd0),
+	(0x10, 0xffcb9843d60f6159c9db58835c926644),
+	(0x20, 0xff973b41fa98c081472e6896dfb254c0),
+	(0x40, 0xff2ea16466c96a3843ec78b326b52861),
+	(0x80, 0xfe5dee046a99a2a811c461f1969c3053),
+	(0x100, 0xfcbe86c7900a88aedcffc83b479aa3a4),
+	(0x200, 0xf987a7253ac413176f2b074cf7815e54),
+	(0x400, 0xf3392b0822b70005940c7a398e4b70f3),
+	(0x800, 0xe7159475a2c29b7443b29c7fa6e889d9),
+	(0x1000, 0xd097f3bdfd2022b8845ad8f792aa5825),
+	(0x2000, 0xa9f746462d870fdf8a65dc1f90e061e5),
+	(0x4000, 0x70d869a156d2a1b890bb3df62baf32f7),
+	(0x8000, 0x31be135f97d08fd981231505542fcfa6),
+	(0x10000, 0x9aa508b5b7a84e1c677de54f3e99bc9),
+	(0x20000, 0x5d6af8dedb81196699c329225ee604),
+	(0x40000, 0x2216e584f5fa1ea926041bedfe98),
+	(0x80000, 0x48a170391f7dc42444e8fa2),
+];
+
+/// Calculate square root price (Q64.96) at given tick.
+pub fn sqrt_price_at_tick(tick: i32) -> Option<U256> {
+	let abs_tick = tick.unsigned_abs();
+	if abs_tick > MAX_TICK.unsigned_abs() {
+		return None;
+	}
+
+	let mut ratio = if abs_tick & 0x1 != 0 {
+		U256::from(0xfffcb933bd6fad37aa2d162d1a594001u128)
+	} else {
+		Q128
+	};
+	for (bit, tick_ratio) in TICK_RATIOS.iter() {
+		if abs_tick & bit != 0 {
+			ratio = ratio.checked_mul(U256::from(*tick_ratio))? >> 128;
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX / ratio;
+	}
+
+	// Q128.128 to Q64.96, rounded up
+	let (sqrt_price, remainder) = ratio.div_mod(U256::one() << 32);
+	if remainder.is_zero() {
+		Some(sqrt_price)
+	} else {
+		sqrt_price.checked_add(U256::one())
+	}
+}
+
+/// Calculate the greatest tick whose square root price is less than or equal to given square root price.
+pub fn tick_at_sqrt_price(sqrt_price: U256) -> Option<i32> {
+	if sqrt_price < MIN_SQRT_PRICE || sqrt_price >= MAX_SQRT_PRICE {
+		return None;
+	}
+
+	let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+	while low < high {
+		let mid = low + (high - low + 1) / 2;
+		if sqrt_price_at_tick(mid)? <= sqrt_price {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+	Some(low)
+}
+
+/// Convert price of asset 0 denominated in asset 1 to square root price (Q64.96).
+pub fn sqrt_price_from_price(price: FixedU128) -> Option<U256> {
+	let price_x192 = (U512::from(price.into_inner()) << 192) / U512::from(FixedU128::DIV);
+	let sqrt_price = U256::try_from(price_x192.integer_sqrt()).ok()?;
+
+	if sqrt_price < MIN_SQRT_PRICE || sqrt_price >= MAX_SQRT_PRICE {
+		return None;
+	}
+	Some(sqrt_price)
+}
+
+/// Convert square root price (Q64.96) to price of asset 0 denominated in asset 1.
+///
+/// Returns price as rational number (n, d).
+pub fn price_from_sqrt_price(sqrt_price: U256) -> (Balance, Balance) {
+	let n = sqrt_price.full_mul(sqrt_price);
+	let d = U512::one() << 192;
+	round_u512_to_rational((n, d), Rounding::Nearest)
+}
+
+/// Calculate amount of asset 0 between two square root prices for given liquidity.
+pub fn amount0_delta(sqrt_price_a: U256, sqrt_price_b: U256, liquidity: u128, round_up: bool) -> Option<U256> {
+	let (lower, upper) = sort(sqrt_price_a, sqrt_price_b);
+	if lower.is_zero() {
+		return None;
+	}
+
+	let numerator1 = U256::from(liquidity) << 96;
+	let numerator2 = upper - lower;
+
+	if round_up {
+		div_rounding_up(mul_div_rounding_up(numerator1, numerator2, upper)?, lower)
+	} else {
+		Some(mul_div(numerator1, numerator2, upper)? / lower)
+	}
+}
+
+/// Calculate amount of asset 1 between two square root prices for given liquidity.
+pub fn amount1_delta(sqrt_price_a: U256, sqrt_price_b: U256, liquidity: u128, round_up: bool) -> Option<U256> {
+	let (lower, upper) = sort(sqrt_price_a, sqrt_price_b);
+
+	if round_up {
+		mul_div_rounding_up(U256::from(liquidity), upper - lower, Q96)
+	} else {
+		mul_div(U256::from(liquidity), upper - lower, Q96)
+	}
+}
+
+/// Calculate next square root price given an amount of asset in.
+///
+/// `zero_for_one` - asset 0 is sold for asset 1.
+pub fn next_sqrt_price_from_input(
+	sqrt_price: U256,
+	liquidity: u128,
+	amount_in: U256,
+	zero_for_one: bool,
+) -> Option<U256> {
+	if sqrt_price.is_zero() || liquidity.is_zero() {
+		return None;
+	}
+
+	if zero_for_one {
+		next_sqrt_price_from_amount0_rounding_up(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount1_rounding_down(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculate next square root price given an amount of asset out.
+///
+/// `zero_for_one` - asset 0 is sold for asset 1.
+pub fn next_sqrt_price_from_output(
+	sqrt_price: U256,
+	liquidity: u128,
+	amount_out: U256,
+	zero_for_one: bool,
+) -> Option<U256> {
+	if sqrt_price.is_zero() || liquidity.is_zero() {
+		return None;
+	}
+
+	if zero_for_one {
+		next_sqrt_price_from_amount1_rounding_down(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount0_rounding_up(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+fn next_sqrt_price_from_amount0_rounding_up(
+	sqrt_price: U256,
+	liquidity: u128,
+	amount: U256,
+	add: bool,
+) -> Option<U256> {
+	if amount.is_zero() {
+		return Some(sqrt_price);
+	}
+
+	let numerator = U256::from(liquidity) << 96;
+	let product = amount.full_mul(sqrt_price);
+	let denominator = if add {
+		U512::from(numerator).checked_add(product)?
+	} else {
+		U512::from(numerator).checked_sub(product)?
+	};
+	if denominator.is_zero() {
+		return None;
+	}
+
+	let (result, remainder) = numerator.full_mul(sqrt_price).div_mod(denominator);
+	let result = U256::try_from(result).ok()?;
+	if remainder.is_zero() {
+		Some(result)
+	} else {
+		result.checked_add(U256::one())
+	}
+}
+
+fn next_sqrt_price_from_amount1_rounding_down(
+	sqrt_price: U256,
+	liquidity: u128,
+	amount: U256,
+	add: bool,
+) -> Option<U256> {
+	let amount = amount.checked_mul(Q96)?;
+	if add {
+		let quotient = amount / U256::from(liquidity);
+		sqrt_price.checked_add(quotient)
+	} else {
+		let quotient = div_rounding_up(amount, U256::from(liquidity))?;
+		if sqrt_price <= quotient {
+			return None;
+		}
+		Some(sqrt_price - quotient)
+	}
+}
+
+/// Calculate result of a swap within a single price range where liquidity is constant.
+///
+/// Price moves from `sqrt_price_current` towards `sqrt_price_target` until `amount_remaining` is used up
+/// or the target price is reached. Direction of the swap is given by the relation of the two prices.
+///
+/// `exact_in` - `amount_remaining` is amount in (fee included), otherwise it is amount out.
+///
+/// Fee is charged in asset in.
+pub fn compute_swap_step(
+	sqrt_price_current: U256,
+	sqrt_price_target: U256,
+	liquidity: u128,
+	amount_remaining: Balance,
+	exact_in: bool,
+	fee: Permill,
+) -> Option<SwapStep> {
+	let zero_for_one = sqrt_price_current >= sqrt_price_target;
+	let fee_pips = U256::from(fee.deconstruct());
+	let fee_denominator = U256::from(FEE_DENOMINATOR);
+	let fee_complement = fee_denominator.checked_sub(fee_pips).filter(|v| !v.is_zero())?;
+	let amount_remaining = U256::from(amount_remaining);
+
+	let mut amount_in = U256::zero();
+	let mut amount_out = U256::zero();
+
+	let sqrt_price_next = if exact_in {
+		let amount_remaining_less_fee = mul_div(amount_remaining, fee_complement, fee_denominator)?;
+		amount_in = if zero_for_one {
+			amount0_delta(sqrt_price_target, sqrt_price_current, liquidity, true)?
+		} else {
+			amount1_delta(sqrt_price_current, sqrt_price_target, liquidity, true)?
+		};
+		if amount_remaining_less_fee >= amount_in {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_input(sqrt_price_current, liquidity, amount_remaining_less_fee, zero_for_one)?
+		}
+	} else {
+		amount_out = if zero_for_one {
+			amount1_delta(sqrt_price_target, sqrt_price_current, liquidity, false)?
+		} else {
+			amount0_delta(sqrt_price_current, sqrt_price_target, liquidity, false)?
+		};
+		if amount_remaining >= amount_out {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining, zero_for_one)?
+		}
+	};
+
+	let target_reached = sqrt_price_next == sqrt_price_target;
+
+	if zero_for_one {
+		if !target_reached || !exact_in {
+			amount_in = amount0_delta(sqrt_price_next, sqrt_price_current, liquidity, true)?;
+		}
+		if !target_reached || exact_in {
+			amount_out = amount1_delta(sqrt_price_next, sqrt_price_current, liquidity, false)?;
+		}
+	} else {
+		if !target_reached || !exact_in {
+			amount_in = amount1_delta(sqrt_price_current, sqrt_price_next, liquidity, true)?;
+		}
+		if !target_reached || exact_in {
+			amount_out = amount0_delta(sqrt_price_current, sqrt_price_next, liquidity, false)?;
+		}
+	}
+
+	if !exact_in && amount_out > amount_remaining {
+		amount_out = amount_remaining;
+	}
+
+	let fee_amount = if exact_in && !target_reached {
+		// remainder of amount in is taken as fee
+		amount_remaining.checked_sub(amount_in)?
+	} else {
+		mul_div_rounding_up(amount_in, fee_pips, fee_complement)?
+	};
+
+	Some(SwapStep {
+		sqrt_price_next,
+		amount_in: Balance::try_from(amount_in).ok()?,
+		amount_out: Balance::try_from(amount_out).ok()?,
+		fee_amount: Balance::try_from(fee_amount).ok()?,
+	})
+}
+
+/// Calculate maximum liquidity which can be provided in given price range with given amounts.
+pub fn liquidity_for_amounts(
+	sqrt_price: U256,
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	amount0: Balance,
+	amount1: Balance,
+) -> Option<u128> {
+	let (lower, upper) = sort(sqrt_price_a, sqrt_price_b);
+	if lower == upper {
+		return None;
+	}
+
+	let liquidity = if sqrt_price <= lower {
+		liquidity_for_amount0(lower, upper, amount0)?
+	} else if sqrt_price < upper {
+		let liquidity0 = liquidity_for_amount0(sqrt_price, upper, amount0)?;
+		let liquidity1 = liquidity_for_amount1(lower, sqrt_price, amount1)?;
+		liquidity0.min(liquidity1)
+	} else {
+		liquidity_for_amount1(lower, upper, amount1)?
+	};
+
+	u128::try_from(liquidity).ok()
+}
+
+/// Calculate amounts of both assets which correspond to given liquidity in given price range.
+///
+/// Amounts are rounded up when liquidity is provided and rounded down when liquidity is removed.
+pub fn amounts_for_liquidity(
+	sqrt_price: U256,
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	liquidity: u128,
+	round_up: bool,
+) -> Option<(Balance, Balance)> {
+	let (lower, upper) = sort(sqrt_price_a, sqrt_price_b);
+
+	let (amount0, amount1) = if sqrt_price <= lower {
+		(amount0_delta(lower, upper, liquidity, round_up)?, U256::zero())
+	} else if sqrt_price < upper {
+		(
+			amount0_delta(sqrt_price, upper, liquidity, round_up)?,
+			amount1_delta(lower, sqrt_price, liquidity, round_up)?,
+		)
+	} else {
+		(U256::zero(), amount1_delta(lower, upper, liquidity, round_up)?)
+	};
+
+	Some((Balance::try_from(amount0).ok()?, Balance::try_from(amount1).ok()?))
+}
+
+/// Apply signed liquidity delta to liquidity.
+pub fn add_liquidity_delta(liquidity: u128, delta: i128) -> Option<u128> {
+	if delta < 0 {
+		liquidity.checked_sub(delta.unsigned_abs())
+	} else {
+		liquidity.checked_add(delta.unsigned_abs())
+	}
+}
+
+/// Calculate increase of fee growth per unit of liquidity (Q128.128).
+pub fn fee_growth_delta(fee_amount: Balance, liquidity: u128) -> Option<U256> {
+	if liquidity.is_zero() {
+		return Some(U256::zero());
+	}
+	mul_div(U256::from(fee_amount), Q128, U256::from(liquidity))
+}
+
+/// Calculate fee growth inside of a price range.
+///
+/// Fee growth values are allowed to overflow, only differences between them are meaningful.
+pub fn fee_growth_inside(
+	tick_lower: i32,
+	tick_upper: i32,
+	tick_current: i32,
+	fee_growth_global: U256,
+	fee_growth_outside_lower: U256,
+	fee_growth_outside_upper: U256,
+) -> U256 {
+	let fee_growth_below = if tick_current >= tick_lower {
+		fee_growth_outside_lower
+	} else {
+		fee_growth_global.overflowing_sub(fee_growth_outside_lower).0
+	};
+	let fee_growth_above = if tick_current < tick_upper {
+		fee_growth_outside_upper
+	} else {
+		fee_growth_global.overflowing_sub(fee_growth_outside_upper).0
+	};
+
+	fee_growth_global
+		.overflowing_sub(fee_growth_below)
+		.0
+		.overflowing_sub(fee_growth_above)
+		.0
+}
+
+/// Calculate fees earned by liquidity since last fee growth inside checkpoint.
+pub fn fees_earned(fee_growth_inside: U256, fee_growth_inside_last: U256, liquidity: u128) -> Option<Balance> {
+	let delta = fee_growth_inside.overflowing_sub(fee_growth_inside_last).0;
+	Balance::try_from(mul_div(delta, U256::from(liquidity), Q128)?).ok()
+}
+
+/// Position of a compressed tick (tick divided by tick spacing) in the tick bitmap - (word, bit).
+pub fn tick_bitmap_position(compressed_tick: i32) -> (i16, u8) {
+	((compressed_tick >> 8) as i16, (compressed_tick & 0xff) as u8)
+}
+
+/// Find next initialized tick contained in the same bitmap word as given tick.
+///
+/// `lte` - search for the next tick to the left (less than or equal to the given tick).
+///
+/// Returns next tick and whether it is initialized. If there is no initialized tick in the word,
+/// the last tick of the word in the search direction is returned.
+pub fn next_initialized_tick_within_one_word<F: Fn(i16) -> U256>(
+	word_at: F,
+	tick: i32,
+	tick_spacing: i32,
+	lte: bool,
+) -> (i32, bool) {
+	let compressed = tick.div_euclid(tick_spacing);
+
+	if lte {
+		let (word_pos, bit_pos) = tick_bitmap_position(compressed);
+		let mask = if bit_pos == u8::MAX {
+			U256::MAX
+		} else {
+			(U256::one() << (bit_pos as usize + 1)) - 1
+		};
+		let masked = word_at(word_pos) & mask;
+
+		if masked.is_zero() {
+			((compressed - bit_pos as i32) * tick_spacing, false)
+		} else {
+			let most_significant_bit = 255 - masked.leading_zeros() as i32;
+			(
+				(compressed - (bit_pos as i32 - most_significant_bit)) * tick_spacing,
+				true,
+			)
+		}
+	} else {
+		let (word_pos, bit_pos) = tick_bitmap_position(compressed + 1);
+		let mask = !((U256::one() << bit_pos as usize) - 1);
+		let masked = word_at(word_pos) & mask;
+
+		if masked.is_zero() {
+			((compressed + 1 + (u8::MAX - bit_pos) as i32) * tick_spacing, false)
+		} else {
+			let least_significant_bit = masked.trailing_zeros() as i32;
+			(
+				(compressed + 1 + (least_significant_bit - bit_pos as i32)) * tick_spacing,
+				true,
+			)
+		}
+	}
+}
+
+fn liquidity_for_amount0(lower: U256, upper: U256, amount0: Balance) -> Option<U256> {
+	let intermediate = mul_div(lower, upper, Q96)?;
+	mul_div(U256::from(amount0), intermediate, upper - lower)
+}
+
+fn liquidity_for_amount1(lower: U256, upper: U256, amount1: Balance) -> Option<U256> {
+	mul_div(U256::from(amount1), Q96, upper - lower)
+}
+
+fn sort(a: U256, b: U256) -> (U256, U256) {
+	if a > b {
+		(b, a)
+	} else {
+		(a, b)
+	}
+}
+
+fn mul_div(a: U256, b: U256, denominator: U256) -> Option<U256> {
+	if denominator.is_zero() {
+		return None;
+	}
+	U256::try_from(a.full_mul(b) / U512::from(denominator)).ok()
+}
+
+fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> Option<U256> {
+	if denominator.is_zero() {
+		return None;
+	}
+	let (result, remainder) = a.full_mul(b).div_mod(U512::from(denominator));
+	let result = U256::try_from(result).ok()?;
+	if remainder.is_zero() {
+		Some(result)
+	} else {
+		result.checked_add(U256::one())
+	}
+}
+
+fn div_rounding_up(a: U256, b: U256) -> Option<U256> {
+	if b.is_zero() {
+		return None;
+	}
+	let (result, remainder) = a.div_mod(b);
+	if remainder.is_zero() {
+		Some(result)
+	} else {
+		result.checked_add(U256::one())
+	}
+}
@@ -0,0 +1,8 @@
+mod math;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+
+pub use math::*;
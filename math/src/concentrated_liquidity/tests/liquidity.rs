@@ -0,0 +1,163 @@
+use super::*;
+use crate::concentrated_liquidity::*;
+
+#[test]
+fn amount0_delta_should_round_correctly() {
+	let lower = encode_sqrt_price(1, 1);
+	let upper = encode_sqrt_price(121, 100);
+
+	assert_eq!(
+		amount0_delta(lower, upper, ONE, true),
+		Some(U256::from(90_909_090_909_090_910u128))
+	);
+	assert_eq!(
+		amount0_delta(lower, upper, ONE, false),
+		Some(U256::from(90_909_090_909_090_909u128))
+	);
+}
+
+#[test]
+fn amount1_delta_should_round_correctly() {
+	let lower = encode_sqrt_price(1, 1);
+	let upper = encode_sqrt_price(121, 100);
+
+	assert_eq!(
+		amount1_delta(lower, upper, ONE, true),
+		Some(U256::from(100_000_000_000_000_000u128))
+	);
+	assert_eq!(
+		amount1_delta(lower, upper, ONE, false),
+		Some(U256::from(99_999_999_999_999_999u128))
+	);
+}
+
+#[test]
+fn amount_deltas_should_be_zero_when_liquidity_is_zero() {
+	let lower = encode_sqrt_price(1, 1);
+	let upper = encode_sqrt_price(2, 1);
+
+	assert_eq!(amount0_delta(lower, upper, 0, true), Some(U256::zero()));
+	assert_eq!(amount1_delta(lower, upper, 0, true), Some(U256::zero()));
+}
+
+#[test]
+fn liquidity_for_amounts_should_use_only_asset0_when_price_is_below_range() {
+	let sqrt_price = encode_sqrt_price(1, 1);
+	let lower = encode_sqrt_price(2, 1);
+	let upper = encode_sqrt_price(4, 1);
+
+	let liquidity = liquidity_for_amounts(sqrt_price, lower, upper, 100 * ONE, 0).unwrap();
+	assert!(liquidity > 0);
+	assert_eq!(
+		liquidity_for_amounts(sqrt_price, lower, upper, 100 * ONE, 200 * ONE),
+		Some(liquidity)
+	);
+
+	let (amount0, amount1) = amounts_for_liquidity(sqrt_price, lower, upper, liquidity, true).unwrap();
+	assert!(amount0 <= 100 * ONE);
+	assert!(100 * ONE - amount0 <= 1);
+	assert_eq!(amount1, 0);
+}
+
+#[test]
+fn liquidity_for_amounts_should_use_only_asset1_when_price_is_above_range() {
+	let sqrt_price = encode_sqrt_price(8, 1);
+	let lower = encode_sqrt_price(2, 1);
+	let upper = encode_sqrt_price(4, 1);
+
+	let liquidity = liquidity_for_amounts(sqrt_price, lower, upper, 0, 100 * ONE).unwrap();
+	assert!(liquidity > 0);
+
+	let (amount0, amount1) = amounts_for_liquidity(sqrt_price, lower, upper, liquidity, true).unwrap();
+	assert_eq!(amount0, 0);
+	assert!(amount1 <= 100 * ONE);
+	assert!(100 * ONE - amount1 <= 1);
+}
+
+#[test]
+fn liquidity_for_amounts_should_be_limited_by_scarcer_asset_when_price_is_in_range() {
+	let sqrt_price = encode_sqrt_price(1, 1);
+	let lower = encode_sqrt_price(1, 2);
+	let upper = encode_sqrt_price(2, 1);
+
+	let balanced = liquidity_for_amounts(sqrt_price, lower, upper, 100 * ONE, 100 * ONE).unwrap();
+	let less_asset1 = liquidity_for_amounts(sqrt_price, lower, upper, 100 * ONE, 50 * ONE).unwrap();
+	assert!(less_asset1 < balanced);
+
+	// symmetric range around current price requires same amounts of both assets
+	let (amount0, amount1) = amounts_for_liquidity(sqrt_price, lower, upper, balanced, true).unwrap();
+	assert!(100 * ONE - amount0 <= 1);
+	assert!(100 * ONE - amount1 <= 1);
+}
+
+#[test]
+fn amounts_for_liquidity_should_round_up_when_providing_liquidity() {
+	let sqrt_price = encode_sqrt_price(3, 2);
+	let lower = encode_sqrt_price(1, 2);
+	let upper = encode_sqrt_price(2, 1);
+	let liquidity = 1_234_567_890_123_456_789u128;
+
+	let (up0, up1) = amounts_for_liquidity(sqrt_price, lower, upper, liquidity, true).unwrap();
+	let (down0, down1) = amounts_for_liquidity(sqrt_price, lower, upper, liquidity, false).unwrap();
+
+	assert_eq!(up0 - down0, 1);
+	assert_eq!(up1 - down1, 1);
+}
+
+#[test]
+fn liquidity_for_amounts_should_fail_when_range_is_empty() {
+	let sqrt_price = encode_sqrt_price(1, 1);
+	assert_eq!(
+		liquidity_for_amounts(sqrt_price, sqrt_price, sqrt_price, ONE, ONE),
+		None
+	);
+}
+
+#[test]
+fn add_liquidity_delta_should_work() {
+	assert_eq!(add_liquidity_delta(100, 50), Some(150));
+	assert_eq!(add_liquidity_delta(100, -50), Some(50));
+	assert_eq!(add_liquidity_delta(100, -101), None);
+	assert_eq!(add_liquidity_delta(u128::MAX, 1), None);
+}
+
+#[test]
+fn fee_growth_inside_should_include_only_fees_in_range() {
+	let global = U256::from(1_000u128);
+
+	// current tick in range, no fees accrued outside
+	assert_eq!(
+		fee_growth_inside(-10, 10, 0, global, U256::zero(), U256::zero()),
+		global
+	);
+
+	// part of fees were accrued below and above the range
+	assert_eq!(
+		fee_growth_inside(-10, 10, 0, global, U256::from(100u128), U256::from(200u128)),
+		U256::from(700u128)
+	);
+
+	// current tick above the range - upper tick outside value is on the other side
+	assert_eq!(
+		fee_growth_inside(-10, 10, 20, global, U256::from(100u128), U256::from(800u128)),
+		U256::from(700u128)
+	);
+}
+
+#[test]
+fn fee_growth_inside_should_handle_overflow() {
+	let global = U256::from(10u128);
+	let lower_outside = U256::MAX - 9;
+
+	let inside = fee_growth_inside(-10, 10, 0, global, lower_outside, U256::zero());
+	assert_eq!(inside, U256::from(20u128));
+}
+
+#[test]
+fn fees_earned_should_be_proportional_to_liquidity() {
+	let growth = fee_growth_delta(1_000 * ONE, 4 * ONE).unwrap();
+
+	assert_eq!(fees_earned(growth, U256::zero(), 4 * ONE), Some(1_000 * ONE));
+	assert_eq!(fees_earned(growth, U256::zero(), ONE), Some(250 * ONE));
+	assert_eq!(fees_earned(growth, growth, ONE), Some(0));
+}
@@ -0,0 +1,19 @@
+mod liquidity;
+mod swap;
+mod ticks;
+
+use crate::concentrated_liquidity::Q96;
+use primitive_types::{U256, U512};
+
+pub(crate) const ONE: u128 = 1_000_000_000_000_000_000;
+
+// Square root price (Q64.96) of `n / d`, rounded down.
+pub(crate) fn encode_sqrt_price(n: u128, d: u128) -> U256 {
+	let price_x192 = (U512::from(n) << 192) / U512::from(d);
+	U256::try_from(price_x192.integer_sqrt()).unwrap()
+}
+
+#[test]
+fn encode_sqrt_price_should_return_q96_when_price_is_one() {
+	assert_eq!(encode_sqrt_price(1, 1), Q96);
+}
@@ -0,0 +1,159 @@
+use super::*;
+use crate::concentrated_liquidity::*;
+use sp_arithmetic::Permill;
+
+#[test]
+fn exact_in_swap_should_stop_at_target_price_when_amount_is_sufficient() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(101, 100);
+	let liquidity = 2 * ONE;
+	let amount = ONE;
+	let fee = Permill::from_parts(600);
+
+	let step = compute_swap_step(current, target, liquidity, amount, true, fee).unwrap();
+
+	assert_eq!(step.sqrt_price_next, target);
+	assert_eq!(
+		U256::from(step.amount_in),
+		amount1_delta(current, target, liquidity, true).unwrap()
+	);
+	assert_eq!(
+		U256::from(step.amount_out),
+		amount0_delta(current, target, liquidity, false).unwrap()
+	);
+	assert!(step.amount_in + step.fee_amount < amount);
+}
+
+#[test]
+fn exact_out_swap_should_stop_at_target_price_when_amount_is_sufficient() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(101, 100);
+	let liquidity = 2 * ONE;
+	let amount = ONE;
+	let fee = Permill::from_parts(600);
+
+	let step = compute_swap_step(current, target, liquidity, amount, false, fee).unwrap();
+
+	assert_eq!(step.sqrt_price_next, target);
+	assert_eq!(
+		U256::from(step.amount_out),
+		amount0_delta(current, target, liquidity, false).unwrap()
+	);
+	assert!(step.amount_out < amount);
+}
+
+#[test]
+fn exact_in_swap_should_use_whole_amount_when_target_is_not_reached() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(1_000, 100);
+	let liquidity = 2 * ONE;
+	let amount = ONE;
+	let fee = Permill::from_parts(600);
+
+	let step = compute_swap_step(current, target, liquidity, amount, true, fee).unwrap();
+
+	assert!(step.sqrt_price_next < target);
+	assert!(step.sqrt_price_next > current);
+	assert_eq!(step.amount_in + step.fee_amount, amount);
+	assert!(step.amount_out < amount);
+}
+
+#[test]
+fn exact_out_swap_should_return_whole_amount_when_target_is_not_reached() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(10_000, 100);
+	let liquidity = 2 * ONE;
+	let amount = ONE;
+	let fee = Permill::from_parts(600);
+
+	let step = compute_swap_step(current, target, liquidity, amount, false, fee).unwrap();
+
+	assert!(step.sqrt_price_next < target);
+	assert_eq!(step.amount_out, amount);
+	assert!(step.amount_in > amount);
+}
+
+#[test]
+fn swap_should_charge_fee_on_amount_in() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(1, 2);
+	let liquidity = 1_000 * ONE;
+	let fee = Permill::from_percent(1);
+
+	let step = compute_swap_step(current, target, liquidity, ONE, false, fee).unwrap();
+
+	// fee is 1% of total amount paid
+	let total = step.amount_in + step.fee_amount;
+	assert!(total / 100 <= step.fee_amount);
+	assert!(step.fee_amount - total / 100 <= 1);
+}
+
+#[test]
+fn swap_should_move_price_down_when_selling_asset0() {
+	let current = encode_sqrt_price(1, 1);
+	let target = MIN_SQRT_PRICE;
+	let liquidity = 1_000 * ONE;
+
+	let step = compute_swap_step(current, target, liquidity, ONE, true, Permill::zero()).unwrap();
+
+	assert!(step.sqrt_price_next < current);
+	assert_eq!(step.amount_in, ONE);
+	assert_eq!(step.fee_amount, 0);
+	// price impact of selling 0.1% of liquidity
+	assert!(step.amount_out < ONE);
+	assert!(step.amount_out > ONE - ONE / 500);
+}
+
+#[test]
+fn swap_should_reach_target_when_liquidity_is_zero() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(2, 1);
+
+	let step = compute_swap_step(current, target, 0, ONE, true, Permill::from_parts(3_000)).unwrap();
+
+	assert_eq!(step.sqrt_price_next, target);
+	assert_eq!(step.amount_in, 0);
+	assert_eq!(step.amount_out, 0);
+	assert_eq!(step.fee_amount, 0);
+}
+
+#[test]
+fn swap_should_fail_when_fee_is_hundred_percent() {
+	let current = encode_sqrt_price(1, 1);
+	let target = encode_sqrt_price(2, 1);
+
+	assert_eq!(compute_swap_step(current, target, ONE, ONE, true, Permill::one()), None);
+}
+
+#[test]
+fn next_sqrt_price_from_input_should_fail_when_liquidity_is_zero() {
+	assert_eq!(next_sqrt_price_from_input(Q96, 0, U256::from(ONE), true), None);
+}
+
+#[test]
+fn next_sqrt_price_from_output_should_fail_when_output_exceeds_reserve() {
+	// with 1 unit of liquidity at price 1, there is only 1 unit of asset 1 below the current price
+	assert_eq!(next_sqrt_price_from_output(Q96, 1, U256::from(2u128), true), None);
+}
+
+#[test]
+fn next_sqrt_price_from_input_should_return_same_price_when_amount_is_zero() {
+	assert_eq!(next_sqrt_price_from_input(Q96, ONE, U256::zero(), true), Some(Q96));
+	assert_eq!(next_sqrt_price_from_input(Q96, ONE, U256::zero(), false), Some(Q96));
+}
+
+#[test]
+fn next_sqrt_price_from_input_should_be_consistent_with_amount_deltas() {
+	let liquidity = 10 * ONE;
+	let amount_in = U256::from(ONE);
+
+	let next = next_sqrt_price_from_input(Q96, liquidity, amount_in, false).unwrap();
+	let delta = amount1_delta(Q96, next, liquidity, true).unwrap();
+	assert!(delta <= amount_in);
+	assert!(amount_in - delta <= U256::one());
+
+	let next = next_sqrt_price_from_input(Q96, liquidity, amount_in, true).unwrap();
+	let delta = amount0_delta(next, Q96, liquidity, true).unwrap();
+	assert!(delta >= amount_in);
+	assert!(delta - amount_in <= U256::one());
+}
@@ -0,0 +1,164 @@
+use crate::concentrated_liquidity::*;
+use primitive_types::U256;
+use std::collections::BTreeMap;
+
+#[test]
+fn sqrt_price_at_tick_should_return_q96_when_tick_is_zero() {
+	assert_eq!(sqrt_price_at_tick(0), Some(Q96));
+}
+
+#[test]
+fn sqrt_price_at_tick_should_return_bounds_at_min_and_max_tick() {
+	assert_eq!(sqrt_price_at_tick(MIN_TICK), Some(MIN_SQRT_PRICE));
+	assert_eq!(sqrt_price_at_tick(MAX_TICK), Some(MAX_SQRT_PRICE));
+}
+
+#[test]
+fn sqrt_price_at_tick_should_fail_when_tick_is_out_of_bounds() {
+	assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), None);
+	assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), None);
+}
+
+#[test]
+fn sqrt_price_at_tick_should_be_close_to_exact_value() {
+	for tick in [-500_000, -50_000, -5_000, -50, -1, 1, 50, 5_000, 50_000, 500_000] {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		let expected = (f64::from(tick) * 0.0001f64.ln_1p() / 2.0).exp() * 2f64.powi(96);
+		let actual = sqrt_price.to_string().parse::<f64>().unwrap();
+
+		let relative_error = ((actual - expected) / expected).abs();
+		assert!(relative_error < 1e-12, "tick {tick}: relative error {relative_error}");
+	}
+}
+
+#[test]
+fn sqrt_price_at_tick_should_be_monotonic() {
+	let mut previous = sqrt_price_at_tick(-1_000).unwrap();
+	for tick in -999..=1_000 {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		assert!(sqrt_price > previous);
+		previous = sqrt_price;
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_return_bounds_at_min_and_max_price() {
+	assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE), Some(MIN_TICK));
+	assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE - 1), Some(MAX_TICK - 1));
+}
+
+#[test]
+fn tick_at_sqrt_price_should_fail_when_price_is_out_of_bounds() {
+	assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE - 1), None);
+	assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE), None);
+}
+
+#[test]
+fn tick_at_sqrt_price_should_be_inverse_of_sqrt_price_at_tick() {
+	for tick in [
+		MIN_TICK + 1,
+		-500_000,
+		-50_000,
+		-5_000,
+		-50,
+		-1,
+		0,
+		1,
+		50,
+		5_000,
+		50_000,
+		500_000,
+		MAX_TICK - 1,
+	] {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		assert_eq!(tick_at_sqrt_price(sqrt_price), Some(tick));
+		assert_eq!(tick_at_sqrt_price(sqrt_price - 1), Some(tick - 1));
+		assert_eq!(tick_at_sqrt_price(sqrt_price + 1), Some(tick));
+	}
+}
+
+#[test]
+fn sqrt_price_from_price_should_be_inverse_of_price_from_sqrt_price() {
+	use sp_arithmetic::FixedU128;
+
+	let sqrt_price = sqrt_price_from_price(FixedU128::from_rational(5, 2)).unwrap();
+	let (n, d) = price_from_sqrt_price(sqrt_price);
+	let price = FixedU128::from_rational(n, d);
+
+	assert!(price.into_inner().abs_diff(FixedU128::from_rational(5, 2).into_inner()) <= 1);
+}
+
+#[test]
+fn sqrt_price_from_price_should_fail_when_price_is_zero() {
+	assert_eq!(sqrt_price_from_price(sp_arithmetic::FixedU128::from(0)), None);
+}
+
+#[test]
+fn tick_bitmap_position_should_return_word_and_bit() {
+	assert_eq!(tick_bitmap_position(0), (0, 0));
+	assert_eq!(tick_bitmap_position(255), (0, 255));
+	assert_eq!(tick_bitmap_position(256), (1, 0));
+	assert_eq!(tick_bitmap_position(-1), (-1, 255));
+	assert_eq!(tick_bitmap_position(-256), (-1, 0));
+	assert_eq!(tick_bitmap_position(-257), (-2, 255));
+}
+
+fn bitmap(ticks: &[i32], tick_spacing: i32) -> BTreeMap<i16, U256> {
+	let mut words = BTreeMap::new();
+	for tick in ticks {
+		let (word, bit) = tick_bitmap_position(tick / tick_spacing);
+		let value = words.entry(word).or_insert_with(U256::zero);
+		*value |= U256::one() << bit as usize;
+	}
+	words
+}
+
+fn next_tick(words: &BTreeMap<i16, U256>, tick: i32, tick_spacing: i32, lte: bool) -> (i32, bool) {
+	next_initialized_tick_within_one_word(
+		|word| words.get(&word).copied().unwrap_or_default(),
+		tick,
+		tick_spacing,
+		lte,
+	)
+}
+
+const INITIALIZED_TICKS: [i32; 9] = [-200, -55, -4, 70, 78, 84, 139, 240, 535];
+
+#[test]
+fn next_initialized_tick_to_the_right_should_work() {
+	let words = bitmap(&INITIALIZED_TICKS, 1);
+
+	assert_eq!(next_tick(&words, 78, 1, false), (84, true));
+	assert_eq!(next_tick(&words, 77, 1, false), (78, true));
+	assert_eq!(next_tick(&words, -56, 1, false), (-55, true));
+	assert_eq!(next_tick(&words, -55, 1, false), (-4, true));
+	assert_eq!(next_tick(&words, -257, 1, false), (-200, true));
+	assert_eq!(next_tick(&words, 255, 1, false), (511, false));
+	assert_eq!(next_tick(&words, 383, 1, false), (511, false));
+}
+
+#[test]
+fn next_initialized_tick_to_the_left_should_work() {
+	let words = bitmap(&INITIALIZED_TICKS, 1);
+
+	assert_eq!(next_tick(&words, 78, 1, true), (78, true));
+	assert_eq!(next_tick(&words, 79, 1, true), (78, true));
+	assert_eq!(next_tick(&words, 72, 1, true), (70, true));
+	assert_eq!(next_tick(&words, -1, 1, true), (-4, true));
+	assert_eq!(next_tick(&words, 258, 1, true), (256, false));
+	assert_eq!(next_tick(&words, 256, 1, true), (256, false));
+	assert_eq!(next_tick(&words, -257, 1, true), (-512, false));
+	assert_eq!(next_tick(&words, 1023, 1, true), (768, false));
+}
+
+#[test]
+fn next_initialized_tick_should_respect_tick_spacing() {
+	let words = bitmap(&[-600, -60, 120, 600], 60);
+
+	assert_eq!(next_tick(&words, 0, 60, false), (120, true));
+	assert_eq!(next_tick(&words, 119, 60, false), (120, true));
+	assert_eq!(next_tick(&words, 120, 60, false), (600, true));
+	assert_eq!(next_tick(&words, 0, 60, true), (0, false));
+	assert_eq!(next_tick(&words, -1, 60, true), (-60, true));
+	assert_eq!(next_tick(&words, -61, 60, true), (-600, true));
+}
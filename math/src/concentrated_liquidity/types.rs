@@ -0,0 +1,15 @@
+use crate::types::Balance;
+use primitive_types::U256;
+
+/// Result of a swap within a single price range of constant liquidity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapStep {
+	/// Square root price after the step (Q64.96).
+	pub sqrt_price_next: U256,
+	/// Amount of asset in consumed by the step, fee excluded.
+	pub amount_in: Balance,
+	/// Amount of asset out produced by the step.
+	pub amount_out: Balance,
+	/// Fee charged in asset in.
+	pub fee_amount: Balance,
+}
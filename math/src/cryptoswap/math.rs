@@ -0,0 +1,591 @@
+use crate::cryptoswap::types::{AssetReserve, CurveParams};
+use crate::to_u256;
+use crate::types::Balance;
+use num_traits::{One, Zero};
+use primitive_types::U256;
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_arithmetic::per_things::Rounding as PTRounding;
+use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::{FixedPointNumber, FixedU128, PerThing, Permill};
+
+/// Multiplier of the amplification parameter (same as in Curve v2).
+pub const A_MULTIPLIER: u128 = 10_000;
+
+/// Minimum amplification - `N^N * A_MULTIPLIER / 10`.
+pub const MIN_AMPLIFICATION: u128 = 4 * A_MULTIPLIER / 10;
+/// Maximum amplification - `N^N * A_MULTIPLIER * 100_000`.
+pub const MAX_AMPLIFICATION: u128 = 4 * A_MULTIPLIER * 100_000;
+
+/// Minimum gamma (18 decimals).
+pub const MIN_GAMMA: u128 = 10_000_000_000;
+/// Maximum gamma (18 decimals).
+pub const MAX_GAMMA: u128 = 20_000_000_000_000_000;
+
+/// Maximum number of iterations of Newton's method used to calculate D and Y.
+pub const MAX_ITERATIONS: u16 = 255;
+
+// Precision to convert reserves and amounts to.
+const TARGET_PRECISION: u8 = 18;
+
+const PRECISION: u128 = 1_000_000_000_000_000_000;
+
+#[derive(Clone, Copy)]
+enum Rounding {
+	Down,
+	Up,
+}
+
+/// Calculate invariant D of the pool.
+///
+/// Returns D in 18 decimals.
+pub fn calculate_d(reserves: &[AssetReserve; 2], params: CurveParams, price_scale: FixedU128) -> Option<Balance> {
+	let xp = normalize_reserves(reserves, price_scale)?;
+	let d = calculate_d_internal(params, &xp)?;
+	Balance::try_from(d).ok()
+}
+
+/// Calculate virtual price of a share - value of the pool as if it was a constant product pool at price scale
+/// (`xcp = sqrt(D / 2 * D / (2 * price_scale))`) per share.
+///
+/// Virtual price grows with fees collected by the pool and it drops when price scale moves towards the balanced state
+/// of the pool, which is how loss of a re-peg is measured.
+pub fn calculate_virtual_price(
+	reserves: &[AssetReserve; 2],
+	params: CurveParams,
+	price_scale: FixedU128,
+	share_issuance: Balance,
+) -> Option<FixedU128> {
+	let xp = normalize_reserves(reserves, price_scale)?;
+	let d = calculate_d_internal(params, &xp)?;
+
+	let two = U256::from(2u8);
+	let x0 = d.checked_div(two)?;
+	let x1 = d
+		.checked_mul(to_u256!(PRECISION))?
+		.checked_div(to_u256!(price_scale.into_inner()).checked_mul(two)?)?;
+	let xcp = x0.checked_mul(x1)?.integer_sqrt();
+
+	let virtual_price = xcp
+		.checked_mul(to_u256!(PRECISION))?
+		.checked_div(to_u256!(share_issuance))?;
+	Some(FixedU128::from_inner(u128::try_from(virtual_price).ok()?))
+}
+
+/// Curve v2 condition of price scale update - virtual price after the update has to be above the initial one
+/// and it has to keep at least half of the profit.
+///
+/// Both `virtual_price` and `xcp_profit` are relative to the initial liquidity of the pool (`1` means no profit).
+pub fn is_price_scale_update_allowed(virtual_price: FixedU128, xcp_profit: FixedU128) -> bool {
+	let one = FixedU128::one();
+	let half_profit = xcp_profit
+		.saturating_sub(one)
+		.saturating_mul(FixedU128::from_rational(1, 2));
+	virtual_price > one && virtual_price.saturating_sub(one) > half_profit
+}
+
+/// Calculate amount to be received from the pool given the amount to be sent to the pool.
+///
+/// Returns (amount_out, fee). Fee is paid in asset out and it is already subtracted from amount_out.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_out_given_in(
+	reserves: &[AssetReserve; 2],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	params: CurveParams,
+	price_scale: FixedU128,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	if idx_in == idx_out || idx_in > 1 || idx_out > 1 {
+		return None;
+	}
+
+	let mut xp = normalize_reserves(reserves, price_scale)?;
+	let d = calculate_d_internal(params, &xp)?;
+
+	let dx = to_internal(
+		amount_in,
+		reserves[idx_in].decimals,
+		idx_in,
+		price_scale,
+		Rounding::Down,
+	)?;
+	xp[idx_in] = xp[idx_in].checked_add(dx)?;
+
+	let y = calculate_y_internal(params, &xp, d, idx_out)?;
+	let dy = xp[idx_out].checked_sub(y)?.checked_sub(U256::one())?;
+
+	let amount_out = from_internal(dy, reserves[idx_out].decimals, idx_out, price_scale, Rounding::Down)?;
+	let fee_amount = fee.mul_ceil(amount_out);
+
+	Some((amount_out.checked_sub(fee_amount)?, fee_amount))
+}
+
+/// Calculate amount to be sent to the pool given the amount to be received from the pool.
+///
+/// Returns (amount_in, fee). Fee is paid in asset out - pool gives out `amount_out + fee` worth of the invariant.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_in_given_out(
+	reserves: &[AssetReserve; 2],
+	idx_in: usize,
+	idx_out: usize,
+	amount_out: Balance,
+	params: CurveParams,
+	price_scale: FixedU128,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	if idx_in == idx_out || idx_in > 1 || idx_out > 1 {
+		return None;
+	}
+
+	let fee_amount = calculate_fee_for_net_amount(amount_out, fee)?;
+	let gross_amount_out = amount_out.checked_add(fee_amount)?;
+	if gross_amount_out >= reserves[idx_out].amount {
+		return None;
+	}
+
+	let mut xp = normalize_reserves(reserves, price_scale)?;
+	let d = calculate_d_internal(params, &xp)?;
+
+	let dy = to_internal(
+		gross_amount_out,
+		reserves[idx_out].decimals,
+		idx_out,
+		price_scale,
+		Rounding::Up,
+	)?;
+	xp[idx_out] = xp[idx_out].checked_sub(dy)?;
+
+	let x = calculate_y_internal(params, &xp, d, idx_in)?;
+	let dx = x.checked_sub(xp[idx_in])?.checked_add(U256::one())?;
+
+	let amount_in = from_internal(dx, reserves[idx_in].decimals, idx_in, price_scale, Rounding::Up)?;
+
+	Some((amount_in, fee_amount))
+}
+
+/// Calculate amount of shares to be given to LP after LP provided liquidity.
+///
+/// Initial liquidity receives D shares. Subsequent liquidity receives shares proportional to the increase of D,
+/// reduced by a fee which scales with the imbalance of added amounts (Curve v2 style).
+///
+/// Returns (shares, fee) where fee is amount of shares not minted due to the imbalance fee.
+pub fn calculate_shares(
+	initial_reserves: &[AssetReserve; 2],
+	updated_reserves: &[AssetReserve; 2],
+	params: CurveParams,
+	price_scale: FixedU128,
+	share_issuance: Balance,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	let updated_xp = normalize_reserves(updated_reserves, price_scale)?;
+	let updated_d = calculate_d_internal(params, &updated_xp)?;
+
+	if share_issuance.is_zero() {
+		return Some((Balance::try_from(updated_d).ok()?, Balance::zero()));
+	}
+
+	let initial_xp = normalize_reserves(initial_reserves, price_scale)?;
+	let initial_d = calculate_d_internal(params, &initial_xp)?;
+	if updated_d <= initial_d {
+		return None;
+	}
+
+	let issuance = to_u256!(share_issuance);
+	let shares = issuance
+		.checked_mul(updated_d.checked_sub(initial_d)?)?
+		.checked_div(initial_d)?;
+
+	// fee * N / (4 * (N - 1)) * sum(|added_i - avg|) / sum(added_i), which is fee / 2 * |a0 - a1| / (a0 + a1) for 2 assets
+	let added_0 = updated_xp[0].checked_sub(initial_xp[0])?;
+	let added_1 = updated_xp[1].checked_sub(initial_xp[1])?;
+	let added_sum = added_0.checked_add(added_1)?;
+	let added_diff = abs_diff(added_0, added_1);
+	let fee_shares = div_rounding(
+		shares
+			.checked_mul(to_u256!(fee.deconstruct()))?
+			.checked_mul(added_diff)?,
+		to_u256!(Permill::ACCURACY)
+			.checked_mul(U256::from(2u8))?
+			.checked_mul(added_sum)?,
+		Rounding::Up,
+	)?;
+
+	let shares = Balance::try_from(shares.checked_sub(fee_shares.min(shares))?).ok()?;
+	let fee_shares = Balance::try_from(fee_shares).ok()?;
+
+	Some((shares, fee_shares))
+}
+
+/// Calculate amount of an asset received for removing `share_amount` of shares proportionally.
+pub fn calculate_liquidity_out(reserve: Balance, share_amount: Balance, share_issuance: Balance) -> Option<Balance> {
+	if share_amount > share_issuance || share_issuance.is_zero() {
+		return None;
+	}
+	multiply_by_rational_with_rounding(reserve, share_amount, share_issuance, PTRounding::Down)
+}
+
+/// Calculate spot price of asset 1 denominated in asset 0.
+///
+/// Price is derived from the invariant (not from a trade) and it is expressed in the smallest units of both assets.
+pub fn calculate_spot_price(
+	reserves: &[AssetReserve; 2],
+	params: CurveParams,
+	price_scale: FixedU128,
+) -> Option<FixedU128> {
+	let xp = normalize_reserves(reserves, price_scale)?;
+	let d = calculate_d_internal(params, &xp)?;
+	let p = calculate_internal_price(params, &xp, d)?;
+
+	let (d0, d1) = (reserves[0].decimals as u32, reserves[1].decimals as u32);
+	let price = p
+		.checked_mul(to_u256!(price_scale.into_inner()))?
+		.checked_mul(U256::from(10u8).checked_pow(U256::from(d0))?)?
+		.checked_div(to_u256!(PRECISION).checked_mul(U256::from(10u8).checked_pow(U256::from(d1))?)?)?;
+
+	Some(FixedU128::from_inner(u128::try_from(price).ok()?))
+}
+
+/// Move price scale towards target price by at most `max_update` of the current price scale per block.
+pub fn calculate_price_scale(
+	current: FixedU128,
+	target: FixedU128,
+	max_update: Permill,
+	block_diff: u128,
+) -> FixedU128 {
+	let max_delta = current
+		.saturating_mul(FixedU128::from(max_update))
+		.saturating_mul(FixedU128::saturating_from_integer(block_diff));
+
+	if target >= current {
+		current.saturating_add(max_delta).min(target)
+	} else {
+		current.saturating_sub(max_delta).max(target)
+	}
+}
+
+/// Convert price of asset 1 denominated in asset 0 expressed in the smallest units of both assets
+/// to price of whole units, which is the unit of price scale.
+pub fn calculate_price_scale_from_price(price: (Balance, Balance), decimals: [u8; 2]) -> Option<FixedU128> {
+	let (n, d) = to_u256!(price.0, price.1);
+	let inner = n
+		.checked_mul(to_u256!(PRECISION))?
+		.checked_mul(pow10(decimals[1])?)?
+		.checked_div(d.checked_mul(pow10(decimals[0])?)?)?;
+
+	let price_scale = FixedU128::from_inner(u128::try_from(inner).ok()?);
+	if price_scale.is_zero() {
+		return None;
+	}
+	Some(price_scale)
+}
+
+// Fee amount which needs to be added to net amount so that net amount is the amount after fee.
+fn calculate_fee_for_net_amount(amount: Balance, fee: Permill) -> Option<Balance> {
+	let fee = fee.deconstruct() as u128;
+	let accuracy = Permill::ACCURACY as u128;
+	if fee >= accuracy {
+		return None;
+	}
+	multiply_by_rational_with_rounding(amount, fee, accuracy - fee, PTRounding::Up)
+}
+
+fn normalize_reserves(reserves: &[AssetReserve; 2], price_scale: FixedU128) -> Option<[U256; 2]> {
+	Some([
+		to_internal(reserves[0].amount, reserves[0].decimals, 0, price_scale, Rounding::Down)?,
+		to_internal(reserves[1].amount, reserves[1].decimals, 1, price_scale, Rounding::Down)?,
+	])
+}
+
+// Converts amount to 18 decimals. Amount of the second asset is converted to the value in the first asset.
+fn to_internal(amount: Balance, decimals: u8, idx: usize, price_scale: FixedU128, rounding: Rounding) -> Option<U256> {
+	let amount = if decimals <= TARGET_PRECISION {
+		to_u256!(amount).checked_mul(pow10(TARGET_PRECISION - decimals)?)?
+	} else {
+		div_rounding(to_u256!(amount), pow10(decimals - TARGET_PRECISION)?, rounding)?
+	};
+	if idx == 0 {
+		Some(amount)
+	} else {
+		div_rounding(
+			amount.checked_mul(to_u256!(price_scale.into_inner()))?,
+			to_u256!(PRECISION),
+			rounding,
+		)
+	}
+}
+
+fn from_internal(
+	amount: U256,
+	decimals: u8,
+	idx: usize,
+	price_scale: FixedU128,
+	rounding: Rounding,
+) -> Option<Balance> {
+	let amount = if idx == 0 {
+		amount
+	} else {
+		div_rounding(
+			amount.checked_mul(to_u256!(PRECISION))?,
+			to_u256!(price_scale.into_inner()),
+			rounding,
+		)?
+	};
+	let amount = if decimals <= TARGET_PRECISION {
+		div_rounding(amount, pow10(TARGET_PRECISION - decimals)?, rounding)?
+	} else {
+		amount.checked_mul(pow10(decimals - TARGET_PRECISION)?)?
+	};
+	Balance::try_from(amount).ok()
+}
+
+// Newton's method to find D (Curve v2, 2 assets).
+fn calculate_d_internal(params: CurveParams, xp: &[U256; 2]) -> Option<U256> {
+	let one = to_u256!(PRECISION);
+	let two = U256::from(2u8);
+	let (ann, gamma) = to_u256!(params.amplification, params.gamma);
+	let a_multiplier = to_u256!(A_MULTIPLIER);
+
+	let (x0, x1) = if xp[0] >= xp[1] { (xp[0], xp[1]) } else { (xp[1], xp[0]) };
+	if x1.is_zero() || ann.is_zero() || gamma.is_zero() {
+		return None;
+	}
+
+	let s = x0.checked_add(x1)?;
+	let mut d = two.checked_mul(x0.checked_mul(x1)?.integer_sqrt())?;
+
+	for _ in 0..MAX_ITERATIONS {
+		let d_prev = d;
+
+		// K0 = 10**18 * N**2 * x0 * x1 / D**2
+		let k0 = one
+			.checked_mul(U256::from(4u8))?
+			.checked_mul(x0)?
+			.checked_div(d)?
+			.checked_mul(x1)?
+			.checked_div(d)?;
+		let g1k0 = abs_diff(gamma.checked_add(one)?, k0).checked_add(U256::one())?;
+
+		// D / (A * N**N) * g1k0**2 / gamma**2
+		let mul1 = one
+			.checked_mul(d)?
+			.checked_div(gamma)?
+			.checked_mul(g1k0)?
+			.checked_div(gamma)?
+			.checked_mul(g1k0)?
+			.checked_mul(a_multiplier)?
+			.checked_div(ann)?;
+		// 2 * N * K0 / g1k0
+		let mul2 = one
+			.checked_mul(two)?
+			.checked_mul(two)?
+			.checked_mul(k0)?
+			.checked_div(g1k0)?;
+
+		let neg_fprime = s
+			.checked_add(s.checked_mul(mul2)?.checked_div(one)?)?
+			.checked_add(mul1.checked_mul(two)?.checked_div(k0)?)?
+			.checked_sub(mul2.checked_mul(d)?.checked_div(one)?)?;
+
+		let d_plus = d.checked_mul(neg_fprime.checked_add(s)?)?.checked_div(neg_fprime)?;
+		let mut d_minus = d.checked_mul(d)?.checked_div(neg_fprime)?;
+		let correction = d
+			.checked_mul(mul1.checked_div(neg_fprime)?)?
+			.checked_div(one)?
+			.checked_mul(abs_diff(one, k0))?
+			.checked_div(k0)?;
+		if one > k0 {
+			d_minus = d_minus.checked_add(correction)?;
+		} else {
+			d_minus = d_minus.checked_sub(correction)?;
+		}
+
+		d = if d_plus > d_minus {
+			d_plus - d_minus
+		} else {
+			(d_minus - d_plus).checked_div(two)?
+		};
+
+		let diff = abs_diff(d, d_prev);
+		if diff.checked_mul(pow10(14)?)? < d.max(pow10(16)?) {
+			for x in [x0, x1] {
+				ensure_safe_fraction(x, d)?;
+			}
+			return Some(d);
+		}
+	}
+
+	None
+}
+
+// Newton's method to find reserve of asset `idx` given D and reserve of the other asset (Curve v2, 2 assets).
+fn calculate_y_internal(params: CurveParams, xp: &[U256; 2], d: U256, idx: usize) -> Option<U256> {
+	let one = to_u256!(PRECISION);
+	let two = U256::from(2u8);
+	let (ann, gamma) = to_u256!(params.amplification, params.gamma);
+	let a_multiplier = to_u256!(A_MULTIPLIER);
+
+	let x_j = xp[1 - idx];
+	if x_j.is_zero() || d.is_zero() {
+		return None;
+	}
+
+	let mut y = d.checked_mul(d)?.checked_div(x_j.checked_mul(U256::from(4u8))?)?;
+	let k0_i = one.checked_mul(two)?.checked_mul(x_j)?.checked_div(d)?;
+	if k0_i < pow10(16)?.checked_mul(two)? || k0_i > pow10(20)?.checked_mul(two)? {
+		return None;
+	}
+
+	let convergence_limit = x_j
+		.checked_div(pow10(14)?)?
+		.max(d.checked_div(pow10(14)?)?)
+		.max(U256::from(100u8));
+
+	for _ in 0..MAX_ITERATIONS {
+		let y_prev = y;
+
+		let k0 = k0_i.checked_mul(y)?.checked_mul(two)?.checked_div(d)?;
+		let s = x_j.checked_add(y)?;
+		let g1k0 = abs_diff(gamma.checked_add(one)?, k0).checked_add(U256::one())?;
+
+		// D / (A * N**N) * g1k0**2 / gamma**2
+		let mul1 = one
+			.checked_mul(d)?
+			.checked_div(gamma)?
+			.checked_mul(g1k0)?
+			.checked_div(gamma)?
+			.checked_mul(g1k0)?
+			.checked_mul(a_multiplier)?
+			.checked_div(ann)?;
+		// 1 + 2 * K0 / g1k0
+		let mul2 = one.checked_add(one.checked_mul(two)?.checked_mul(k0)?.checked_div(g1k0)?)?;
+
+		let mut yfprime = one
+			.checked_mul(y)?
+			.checked_add(s.checked_mul(mul2)?)?
+			.checked_add(mul1)?;
+		let dyfprime = d.checked_mul(mul2)?;
+		if yfprime < dyfprime {
+			y = y_prev.checked_div(two)?;
+			continue;
+		}
+		yfprime -= dyfprime;
+
+		let fprime = yfprime.checked_div(y)?;
+		let mut y_minus = mul1.checked_div(fprime)?;
+		let y_plus = yfprime
+			.checked_add(one.checked_mul(d)?)?
+			.checked_div(fprime)?
+			.checked_add(y_minus.checked_mul(one)?.checked_div(k0)?)?;
+		y_minus = y_minus.checked_add(one.checked_mul(s)?.checked_div(fprime)?)?;
+
+		y = if y_plus < y_minus {
+			y_prev.checked_div(two)?
+		} else {
+			y_plus - y_minus
+		};
+
+		let diff = abs_diff(y, y_prev);
+		if diff < convergence_limit.max(y.checked_div(pow10(14)?)?) {
+			ensure_safe_fraction(y, d)?;
+			return Some(y);
+		}
+	}
+
+	None
+}
+
+// Marginal price of asset 1 denominated in asset 0 in the normalized space, 18 decimals.
+fn calculate_internal_price(params: CurveParams, xp: &[U256; 2], d: U256) -> Option<U256> {
+	let one = to_u256!(PRECISION);
+	let one_36 = one.checked_mul(one)?;
+	let (ann, gamma) = to_u256!(params.amplification, params.gamma);
+
+	let k0 = U256::from(4u8)
+		.checked_mul(xp[0])?
+		.checked_mul(xp[1])?
+		.checked_div(d)?
+		.checked_mul(one_36)?
+		.checked_div(d)?;
+
+	// 36 decimals
+	let gk0 = U256::from(2u8)
+		.checked_mul(k0)?
+		.checked_mul(k0)?
+		.checked_div(one_36)?
+		.checked_mul(k0)?
+		.checked_div(one_36)?
+		.checked_add(gamma.checked_add(one)?.checked_pow(U256::from(2u8))?)?
+		.checked_sub(
+			k0.checked_mul(k0)?
+				.checked_div(one_36)?
+				.checked_mul(
+					U256::from(2u8)
+						.checked_mul(gamma)?
+						.checked_add(U256::from(3u8).checked_mul(one)?)?,
+				)?
+				.checked_div(one)?,
+		)?;
+
+	// N**N * A * gamma**2
+	let nnag2 = ann
+		.checked_mul(gamma.checked_pow(U256::from(2u8))?)?
+		.checked_div(to_u256!(A_MULTIPLIER))?;
+
+	let denominator = gk0.checked_add(
+		nnag2
+			.checked_mul(xp[0])?
+			.checked_div(d)?
+			.checked_mul(k0)?
+			.checked_div(one_36)?,
+	)?;
+	let numerator = gk0.checked_add(
+		nnag2
+			.checked_mul(xp[1])?
+			.checked_div(d)?
+			.checked_mul(k0)?
+			.checked_div(one_36)?,
+	)?;
+
+	xp[0]
+		.checked_mul(numerator)?
+		.checked_div(xp[1])?
+		.checked_mul(one)?
+		.checked_div(denominator)
+}
+
+// Reserve must be within 1% and 100x of D (same safety bounds as in Curve v2).
+fn ensure_safe_fraction(x: U256, d: U256) -> Option<()> {
+	let frac = x.checked_mul(to_u256!(PRECISION))?.checked_div(d)?;
+	if frac < pow10(16)? || frac > pow10(20)? {
+		return None;
+	}
+	Some(())
+}
+
+fn div_rounding(a: U256, b: U256, rounding: Rounding) -> Option<U256> {
+	match rounding {
+		Rounding::Down => a.checked_div(b),
+		Rounding::Up => {
+			let r = a.checked_div(b)?;
+			if (r.checked_mul(b)?) < a {
+				r.checked_add(U256::one())
+			} else {
+				Some(r)
+			}
+		}
+	}
+}
+
+fn pow10(exp: u8) -> Option<U256> {
+	U256::from(10u8).checked_pow(U256::from(exp))
+}
+
+#[inline]
+fn abs_diff(a: U256, b: U256) -> U256 {
+	if a >= b {
+		a - b
+	} else {
+		b - a
+	}
+}
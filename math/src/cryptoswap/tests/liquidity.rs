@@ -0,0 +1,149 @@
+use super::*;
+use crate::cryptoswap::*;
+use num_traits::One;
+use sp_arithmetic::{FixedU128, Permill};
+
+#[test]
+fn initial_shares_should_equal_d() {
+	let initial = reserves(0, 0);
+	let updated = reserves(2_000 * ONE, 1_000 * ONE);
+	let price_scale = FixedU128::from(2);
+
+	let result = calculate_shares(
+		&initial,
+		&updated,
+		default_params(),
+		price_scale,
+		0,
+		Permill::from_percent(1),
+	);
+
+	assert_eq!(
+		result,
+		Some((calculate_d(&updated, default_params(), price_scale).unwrap(), 0))
+	);
+}
+
+#[test]
+fn shares_should_be_proportional_when_liquidity_is_added_in_pool_ratio() {
+	let initial = reserves(2_000 * ONE, 1_000 * ONE);
+	let updated = reserves(3_000 * ONE, 1_500 * ONE);
+	let issuance = 4_000_000_000_000_000_000_000;
+
+	let result = calculate_shares(
+		&initial,
+		&updated,
+		default_params(),
+		FixedU128::from(2),
+		issuance,
+		Permill::from_percent(1),
+	);
+
+	assert_eq!(result, Some((issuance / 2, 0)));
+}
+
+#[test]
+fn imbalanced_liquidity_should_pay_fee() {
+	let initial = reserves(1_000 * ONE, 1_000 * ONE);
+	let updated = reserves(1_100 * ONE, 1_000 * ONE);
+	let issuance = 2_000_000_000_000_000_000_000;
+
+	let (shares_without_fee, _) = calculate_shares(
+		&initial,
+		&updated,
+		default_params(),
+		FixedU128::one(),
+		issuance,
+		Permill::zero(),
+	)
+	.unwrap();
+	let (shares, fee) = calculate_shares(
+		&initial,
+		&updated,
+		default_params(),
+		FixedU128::one(),
+		issuance,
+		Permill::from_percent(1),
+	)
+	.unwrap();
+
+	// single sided liquidity pays half of the fee
+	assert_eq!(fee, Permill::from_rational(5u32, 1_000u32).mul_ceil(shares_without_fee));
+	assert_eq!(shares + fee, shares_without_fee);
+}
+
+#[test]
+fn calculate_shares_should_fail_when_d_does_not_increase() {
+	let pool = reserves(1_000 * ONE, 1_000 * ONE);
+
+	assert!(calculate_shares(
+		&pool,
+		&pool,
+		default_params(),
+		FixedU128::one(),
+		2_000_000_000_000_000_000_000,
+		Permill::zero()
+	)
+	.is_none());
+}
+
+#[test]
+fn liquidity_out_should_be_proportional_to_shares() {
+	assert_eq!(calculate_liquidity_out(1_000 * ONE, 25, 100), Some(250 * ONE));
+	assert_eq!(calculate_liquidity_out(1_000 * ONE, 101, 100), None);
+	assert_eq!(calculate_liquidity_out(1_000 * ONE, 0, 0), None);
+}
+
+#[test]
+fn virtual_price_should_grow_when_pool_collects_fee() {
+	let initial = reserves(2_000_000 * ONE, 1_000_000 * ONE);
+	let price_scale = FixedU128::from(2);
+	let issuance = calculate_d(&initial, default_params(), price_scale).unwrap();
+
+	let amount_in = 10_000 * ONE;
+	let (amount_out, _) = calculate_out_given_in(
+		&initial,
+		1,
+		0,
+		amount_in,
+		default_params(),
+		price_scale,
+		Permill::from_rational(3u32, 1000u32),
+	)
+	.unwrap();
+	let updated = reserves(2_000_000 * ONE - amount_out, 1_000_000 * ONE + amount_in);
+
+	let initial_price = calculate_virtual_price(&initial, default_params(), price_scale, issuance).unwrap();
+	let updated_price = calculate_virtual_price(&updated, default_params(), price_scale, issuance).unwrap();
+	assert!(updated_price > initial_price);
+}
+
+#[test]
+fn virtual_price_should_drop_when_price_scale_moves_towards_balanced_state() {
+	let pool = reserves(1_800_000 * ONE, 1_100_000 * ONE);
+	let issuance = 4_000_000_000_000_000_000_000_000;
+
+	let current = calculate_virtual_price(&pool, default_params(), FixedU128::from(2), issuance).unwrap();
+	let repegged =
+		calculate_virtual_price(&pool, default_params(), FixedU128::from_rational(19, 10), issuance).unwrap();
+	assert!(repegged < current);
+}
+
+#[test]
+fn price_scale_update_should_be_allowed_only_when_half_of_profit_is_kept() {
+	let profit = FixedU128::from_rational(110, 100);
+
+	assert!(is_price_scale_update_allowed(
+		FixedU128::from_rational(106, 100),
+		profit
+	));
+	assert!(!is_price_scale_update_allowed(
+		FixedU128::from_rational(105, 100),
+		profit
+	));
+	assert!(!is_price_scale_update_allowed(FixedU128::one(), FixedU128::one()));
+	assert!(is_price_scale_update_allowed(
+		FixedU128::from_rational(10_001, 10_000),
+		FixedU128::one()
+	));
+}
@@ -0,0 +1,17 @@
+mod liquidity;
+mod prices;
+mod trades;
+
+use crate::cryptoswap::types::{AssetReserve, CurveParams};
+use crate::types::Balance;
+
+pub(crate) const ONE: Balance = 1_000_000_000_000;
+
+// Parameters of Curve v2 CRV/ETH-like pool
+pub(crate) fn default_params() -> CurveParams {
+	CurveParams::new(400_000, 145_000_000_000_000)
+}
+
+pub(crate) fn reserves(a: Balance, b: Balance) -> [AssetReserve; 2] {
+	[AssetReserve::new(a, 12), AssetReserve::new(b, 12)]
+}
@@ -0,0 +1,106 @@
+use super::*;
+use crate::cryptoswap::*;
+use num_traits::One;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+
+#[test]
+fn spot_price_should_be_one_when_pool_is_balanced() {
+	let price = calculate_spot_price(
+		&reserves(1_000_000 * ONE, 1_000_000 * ONE),
+		default_params(),
+		FixedU128::one(),
+	);
+	assert_eq!(price, Some(FixedU128::one()));
+}
+
+#[test]
+fn spot_price_should_be_price_scale_when_pool_is_balanced() {
+	let price = calculate_spot_price(
+		&reserves(2_000_000 * ONE, 1_000_000 * ONE),
+		default_params(),
+		FixedU128::from(2),
+	);
+	assert_eq!(price, Some(FixedU128::from(2)));
+}
+
+#[test]
+fn spot_price_should_correspond_to_small_trade() {
+	let pool = reserves(2_100_000 * ONE, 900_000 * ONE);
+	let price_scale = FixedU128::from(2);
+	let amount_in = ONE;
+
+	let spot_price = calculate_spot_price(&pool, default_params(), price_scale).unwrap();
+	let (amount_out, _) =
+		calculate_out_given_in(&pool, 1, 0, amount_in, default_params(), price_scale, Permill::zero()).unwrap();
+
+	let expected = spot_price.checked_mul_int(amount_in).unwrap();
+	let diff = expected.abs_diff(amount_out);
+	assert!(diff < expected / 100_000);
+}
+
+#[test]
+fn spot_price_should_account_for_decimals() {
+	let pool = [
+		AssetReserve::new(1_000_000_000_000, 6),
+		AssetReserve::new(1_000_000_000_000_000_000_000_000, 18),
+	];
+	let price = calculate_spot_price(&pool, default_params(), FixedU128::one());
+	assert_eq!(price, Some(FixedU128::from_rational(1, 1_000_000_000_000)));
+}
+
+#[test]
+fn price_scale_should_move_to_target_when_within_max_update() {
+	let current = FixedU128::from(2);
+	let target = FixedU128::from_rational(201, 100);
+
+	assert_eq!(
+		calculate_price_scale(current, target, Permill::from_percent(1), 1),
+		target
+	);
+}
+
+#[test]
+fn price_scale_should_move_by_max_update_per_block() {
+	let current = FixedU128::from(2);
+
+	assert_eq!(
+		calculate_price_scale(current, FixedU128::from(3), Permill::from_percent(1), 1),
+		FixedU128::from_rational(202, 100)
+	);
+	assert_eq!(
+		calculate_price_scale(current, FixedU128::from(3), Permill::from_percent(1), 5),
+		FixedU128::from_rational(210, 100)
+	);
+	assert_eq!(
+		calculate_price_scale(current, FixedU128::from(1), Permill::from_percent(1), 1),
+		FixedU128::from_rational(198, 100)
+	);
+}
+
+#[test]
+fn price_scale_should_not_change_when_no_block_passed() {
+	let current = FixedU128::from(2);
+
+	assert_eq!(
+		calculate_price_scale(current, FixedU128::from(3), Permill::from_percent(1), 0),
+		current
+	);
+}
+
+#[test]
+fn price_scale_from_price_should_account_for_decimals() {
+	assert_eq!(
+		calculate_price_scale_from_price((1_000_000, 1_000_000_000_000_000_000), [6, 18]),
+		Some(FixedU128::one())
+	);
+	assert_eq!(
+		calculate_price_scale_from_price((5, 2), [12, 12]),
+		Some(FixedU128::from_rational(5, 2))
+	);
+}
+
+#[test]
+fn price_scale_from_price_should_fail_when_price_is_zero() {
+	assert_eq!(calculate_price_scale_from_price((0, 1), [12, 12]), None);
+	assert_eq!(calculate_price_scale_from_price((1, 0), [12, 12]), None);
+}
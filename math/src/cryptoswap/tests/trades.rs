@@ -0,0 +1,176 @@
+use super::*;
+use crate::cryptoswap::*;
+use num_traits::One;
+use sp_arithmetic::{FixedU128, Permill};
+
+#[test]
+fn calculate_d_should_equal_sum_of_reserves_when_pool_is_balanced() {
+	let d = calculate_d(&reserves(1_000 * ONE, 1_000 * ONE), default_params(), FixedU128::one()).unwrap();
+	assert_eq!(d, 2_000_000_000_000_000_000_000);
+}
+
+#[test]
+fn calculate_d_should_account_for_price_scale() {
+	let d = calculate_d(
+		&reserves(2_000 * ONE, 1_000 * ONE),
+		default_params(),
+		FixedU128::from(2),
+	)
+	.unwrap();
+	assert_eq!(d, 4_000_000_000_000_000_000_000);
+}
+
+#[test]
+fn calculate_d_should_fail_when_reserve_is_zero() {
+	assert!(calculate_d(&reserves(1_000 * ONE, 0), default_params(), FixedU128::one()).is_none());
+}
+
+#[test]
+fn calculate_d_should_fail_when_pool_is_too_imbalanced() {
+	assert!(calculate_d(&reserves(1_000_000 * ONE, ONE), default_params(), FixedU128::one()).is_none());
+}
+
+#[test]
+fn calculate_out_given_in_should_give_better_price_than_constant_product() {
+	let (reserve_in, reserve_out) = (1_000_000 * ONE, 1_000_000 * ONE);
+	let amount_in = 100 * ONE;
+
+	let (amount_out, fee) = calculate_out_given_in(
+		&reserves(reserve_in, reserve_out),
+		0,
+		1,
+		amount_in,
+		default_params(),
+		FixedU128::one(),
+		Permill::zero(),
+	)
+	.unwrap();
+
+	let xyk_amount_out = reserve_out * amount_in / (reserve_in + amount_in);
+	assert_eq!(fee, 0);
+	assert!(amount_out < amount_in);
+	assert!(amount_out > xyk_amount_out);
+}
+
+#[test]
+fn calculate_out_given_in_should_subtract_fee_from_amount_out() {
+	let pool = reserves(1_000_000 * ONE, 1_000_000 * ONE);
+	let (amount_out_without_fee, _) = calculate_out_given_in(
+		&pool,
+		0,
+		1,
+		100 * ONE,
+		default_params(),
+		FixedU128::one(),
+		Permill::zero(),
+	)
+	.unwrap();
+
+	let fee = Permill::from_percent(1);
+	let (amount_out, fee_amount) =
+		calculate_out_given_in(&pool, 0, 1, 100 * ONE, default_params(), FixedU128::one(), fee).unwrap();
+
+	assert_eq!(fee_amount, fee.mul_ceil(amount_out_without_fee));
+	assert_eq!(amount_out + fee_amount, amount_out_without_fee);
+}
+
+#[test]
+fn calculate_out_given_in_should_use_price_scale() {
+	// asset 1 is worth 2 units of asset 0
+	let pool = reserves(2_000_000 * ONE, 1_000_000 * ONE);
+	let price_scale = FixedU128::from(2);
+
+	let (amount_out, _) =
+		calculate_out_given_in(&pool, 1, 0, 100 * ONE, default_params(), price_scale, Permill::zero()).unwrap();
+	assert!(amount_out < 200 * ONE);
+	assert!(amount_out > 199_990 * ONE / 1_000);
+
+	let (amount_out, _) =
+		calculate_out_given_in(&pool, 0, 1, 100 * ONE, default_params(), price_scale, Permill::zero()).unwrap();
+	assert!(amount_out < 50 * ONE);
+	assert!(amount_out > 49_990 * ONE / 1_000);
+}
+
+#[test]
+fn calculate_out_given_in_should_work_when_assets_have_different_decimals() {
+	let pool = [
+		AssetReserve::new(1_000_000_000_000, 6),
+		AssetReserve::new(1_000_000_000_000_000_000_000_000, 18),
+	];
+	let same_decimals = reserves(1_000_000 * ONE, 1_000_000 * ONE);
+
+	let (amount_out, _) = calculate_out_given_in(
+		&pool,
+		0,
+		1,
+		100_000_000,
+		default_params(),
+		FixedU128::one(),
+		Permill::zero(),
+	)
+	.unwrap();
+	let (expected, _) = calculate_out_given_in(
+		&same_decimals,
+		0,
+		1,
+		100 * ONE,
+		default_params(),
+		FixedU128::one(),
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert_eq!(amount_out / 1_000_000, expected);
+}
+
+#[test]
+fn calculate_out_given_in_should_fail_when_indexes_are_invalid() {
+	let pool = reserves(1_000_000 * ONE, 1_000_000 * ONE);
+	let calculate = |idx_in, idx_out| {
+		calculate_out_given_in(
+			&pool,
+			idx_in,
+			idx_out,
+			100 * ONE,
+			default_params(),
+			FixedU128::one(),
+			Permill::zero(),
+		)
+	};
+
+	assert!(calculate(0, 0).is_none());
+	assert!(calculate(0, 2).is_none());
+	assert!(calculate(2, 1).is_none());
+}
+
+#[test]
+fn calculate_in_given_out_should_be_inverse_of_calculate_out_given_in() {
+	let pool = reserves(2_000_000 * ONE, 1_000_000 * ONE);
+	let price_scale = FixedU128::from(2);
+	let fee = Permill::from_rational(3u32, 1_000u32);
+	let amount_out = 100 * ONE;
+
+	let (amount_in, fee_amount) =
+		calculate_in_given_out(&pool, 0, 1, amount_out, default_params(), price_scale, fee).unwrap();
+	assert!(fee_amount > 0);
+
+	let (received, _) = calculate_out_given_in(&pool, 0, 1, amount_in, default_params(), price_scale, fee).unwrap();
+	assert!(received >= amount_out);
+	assert!(received - amount_out < ONE / 1_000_000);
+}
+
+#[test]
+fn calculate_in_given_out_should_fail_when_amount_out_exceeds_reserve() {
+	let pool = reserves(1_000 * ONE, 1_000 * ONE);
+
+	assert!(calculate_in_given_out(
+		&pool,
+		0,
+		1,
+		1_000 * ONE,
+		default_params(),
+		FixedU128::one(),
+		Permill::zero()
+	)
+	.is_none());
+}
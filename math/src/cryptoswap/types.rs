@@ -0,0 +1,34 @@
+use crate::types::Balance;
+use num_traits::Zero;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetReserve {
+	pub amount: Balance,
+	pub decimals: u8,
+}
+
+impl AssetReserve {
+	pub fn new(amount: Balance, decimals: u8) -> Self {
+		Self { amount, decimals }
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.amount == Balance::zero()
+	}
+}
+
+/// Invariant parameters of a cryptoswap pool.
+///
+/// Both parameters use the same scale as the reference Curve v2 implementation:
+/// `amplification` is `A * N^N * A_MULTIPLIER` and `gamma` has 18 decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveParams {
+	pub amplification: u128,
+	pub gamma: u128,
+}
+
+impl CurveParams {
+	pub fn new(amplification: u128, gamma: u128) -> Self {
+		Self { amplification, gamma }
+	}
+}
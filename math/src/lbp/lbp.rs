@@ -4,7 +4,7 @@ use primitive_types::U256;
 use crate::types::{AssetId, Balance, LBPWeight};
 use crate::{
 	ensure, to_balance, to_lbp_weight, to_u256, MathError,
-	MathError::{Overflow, ZeroDuration, ZeroReserve},
+	MathError::{Overflow, ZeroDuration, ZeroReserve, ZeroWeight},
 };
 
 use core::convert::From;
@@ -12,7 +12,7 @@ use fixed::types::U32F96;
 use num_traits::{CheckedMul, CheckedSub, Zero};
 use sp_arithmetic;
 use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_arithmetic::{FixedPointNumber, FixedU128, Rounding};
+use sp_arithmetic::{FixedPointNumber, FixedU128, PerThing, Permill, Rounding};
 
 /// Calculating spot price given reserve of selling asset and reserve of buying asset.
 /// Formula : BUY_RESERVE * AMOUNT / SELL_RESERVE
@@ -175,6 +175,61 @@ pub fn calculate_in_given_out(
 	Ok(r.saturating_add(1))
 }
 
+/// Calculating amounts of the reserves which can be moved to a pool with equal weights of the assets
+/// without changing the spot price.
+/// Formula : AMOUNT_B = AMOUNT_A * RESERVE_B * WEIGHT_A / (RESERVE_A * WEIGHT_B)
+///
+/// - `reserve_a` - reserve amount of asset a
+/// - `reserve_b` - reserve amount of asset b
+/// - `weight_a` - pool weight of asset a
+/// - `weight_b` - pool weight of asset b
+/// - `fraction` - fraction of the reserve of lower value which is moved
+///
+/// Returns (amount_a, amount_b)
+pub fn calculate_graduation_amounts(
+	reserve_a: Balance,
+	reserve_b: Balance,
+	weight_a: LBPWeight,
+	weight_b: LBPWeight,
+	fraction: Permill,
+) -> Result<(Balance, Balance), MathError> {
+	ensure!(reserve_a != 0 && reserve_b != 0, ZeroReserve);
+	ensure!(weight_a != 0 && weight_b != 0, ZeroWeight);
+
+	// Value of the reserve of asset b denominated in asset a is RESERVE_A * WEIGHT_B / WEIGHT_A
+	if weight_b >= weight_a {
+		let amount_a = fraction.mul_floor(reserve_a);
+		let amount_b = convert_graduation_amount(amount_a, reserve_a, reserve_b, weight_a, weight_b)?;
+		Ok((amount_a, amount_b))
+	} else {
+		let amount_b = fraction.mul_floor(reserve_b);
+		let amount_a = convert_graduation_amount(amount_b, reserve_b, reserve_a, weight_b, weight_a)?;
+		Ok((amount_a, amount_b))
+	}
+}
+
+/// AMOUNT * TO_RESERVE * FROM_WEIGHT / (FROM_RESERVE * TO_WEIGHT)
+fn convert_graduation_amount(
+	amount: Balance,
+	from_reserve: Balance,
+	to_reserve: Balance,
+	from_weight: LBPWeight,
+	to_weight: LBPWeight,
+) -> Result<Balance, MathError> {
+	let (amount, from_reserve, to_reserve, from_weight, to_weight) =
+		to_u256!(amount, from_reserve, to_reserve, from_weight, to_weight);
+
+	let result = amount
+		.checked_mul(to_reserve)
+		.ok_or(Overflow)?
+		.checked_mul(from_weight)
+		.ok_or(Overflow)?
+		.checked_div(from_reserve.checked_mul(to_weight).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+
+	to_balance!(result)
+}
+
 /// Calculating weight at any given block in an interval using linear interpolation.
 ///
 /// - `start_x` - beginning of an interval
@@ -189,6 +244,195 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 	end_y: LBPWeight,
 	at: BlockNumber,
 ) -> Result<LBPWeight, MathError> {
+	let (d1, d2, dx) = interval_distances(start_x, end_x, at)?;
+
+	let (start_y, end_y, d1, d2) = to_u256!(start_y, end_y, d1, d2);
+
+	let left_part = start_y.checked_mul(d1).ok_or(Overflow)?;
+	let right_part = end_y.checked_mul(d2).ok_or(Overflow)?;
+	let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
+		.checked_div(dx.into())
+		.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Maximum decay rate supported by `calculate_exponential_weights`.
+pub const MAX_EXPONENTIAL_DECAY_RATE: u128 = 20;
+
+/// Calculating weight at any given block in an interval using exponential decay.
+/// Formula : END_Y + (START_Y - END_Y) * (e^(-RATE * t) - e^(-RATE)) / (1 - e^(-RATE))
+/// where `t` is the elapsed fraction of the interval.
+///
+/// The weight moves quickly at the beginning of the interval and slows down towards its end.
+/// Both end points are matched exactly.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `rate` - decay rate, must be non-zero and not greater than `MAX_EXPONENTIAL_DECAY_RATE`
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	rate: FixedU128,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (_, d2, dx) = interval_distances(start_x, end_x, at)?;
+
+	ensure!(!rate.is_zero(), DivisionByZero);
+	ensure!(rate <= FixedU128::from(MAX_EXPONENTIAL_DECAY_RATE), Overflow);
+
+	if start_y == end_y {
+		return Ok(end_y);
+	}
+
+	let rate = div_to_fixed(rate.into_inner(), FixedU128::DIV, Rounding::Down).ok_or(Overflow)?;
+	let elapsed = div_to_fixed(d2, dx.into(), Rounding::Down).ok_or(Overflow)?;
+
+	let decayed: U32F96 =
+		crate::transcendental::exp(rate.checked_mul(elapsed).ok_or(Overflow)?, true).map_err(|_| Overflow)?;
+	let decayed_end: U32F96 = crate::transcendental::exp(rate, true).map_err(|_| Overflow)?;
+
+	// remaining fraction of the weight change, 1 at the start and 0 at the end of the interval
+	let remaining = decayed
+		.checked_sub(decayed_end)
+		.ok_or(Overflow)?
+		.checked_div(U32F96::one().checked_sub(decayed_end).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+
+	let diff = start_y.abs_diff(end_y);
+	let remaining_diff = mul_to_balance(diff.into(), remaining, Rounding::Down).ok_or(Overflow)?;
+	let remaining_diff: LBPWeight = remaining_diff.try_into().map_err(|_| Overflow)?;
+
+	if start_y > end_y {
+		end_y.checked_add(remaining_diff).ok_or(Overflow)
+	} else {
+		end_y.checked_sub(remaining_diff).ok_or(Overflow)
+	}
+}
+
+/// Calculating weight at any given block in an interval using equally long steps.
+///
+/// The interval is split into `steps` parts. The weight is constant within each part and changes
+/// linearly from `start_y` in the first part to `end_y` in the last part.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `steps` - number of steps, must be at least 2
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_step_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	steps: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (_, d2, dx) = interval_distances(start_x, end_x, at)?;
+
+	ensure!(steps > 1, DivisionByZero);
+
+	let last_step = steps - 1;
+	let step = d2
+		.checked_mul(steps.into())
+		.ok_or(Overflow)?
+		.checked_div(dx.into())
+		.ok_or(Overflow)?
+		.min(last_step.into());
+
+	let (start_y, end_y, step, last_step) = to_u256!(start_y, end_y, step, last_step);
+
+	let left_part = start_y
+		.checked_mul(last_step.checked_sub(step).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+	let right_part = end_y.checked_mul(step).ok_or(Overflow)?;
+	let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
+		.checked_div(last_step)
+		.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Calculating weight at any given block in an interval using linear interpolation between
+/// the given points.
+///
+/// Each point is a pair of the elapsed fraction of the interval and the weight at that moment.
+/// `(0, start_y)` and `(1, end_y)` are implicit end points of the curve.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `points` - intermediate points, sorted by strictly increasing fraction within (0, 1)
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_piecewise_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	points: &[(Permill, LBPWeight)],
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (_, d2, dx) = interval_distances(start_x, end_x, at)?;
+
+	// positions are scaled by the Permill accuracy to keep the interpolation exact
+	let accuracy = U256::from(Permill::ACCURACY);
+	let dx = U256::from(dx);
+	let position = U256::from(d2).checked_mul(accuracy).ok_or(Overflow)?;
+
+	let curve = core::iter::once((U256::zero(), start_y))
+		.chain(
+			points
+				.iter()
+				.map(|(fraction, weight)| (dx.saturating_mul(fraction.deconstruct().into()), *weight)),
+		)
+		.chain(core::iter::once((dx.saturating_mul(accuracy), end_y)));
+
+	let mut previous: Option<(U256, LBPWeight)> = None;
+	for (x, y) in curve {
+		if x >= position {
+			let Some((prev_x, prev_y)) = previous else {
+				return Ok(y);
+			};
+
+			let segment = x.checked_sub(prev_x).ok_or(Overflow)?;
+			ensure!(!segment.is_zero(), DivisionByZero);
+
+			let (prev_y, y) = to_u256!(prev_y, y);
+			let left_part = prev_y
+				.checked_mul(x.checked_sub(position).ok_or(Overflow)?)
+				.ok_or(Overflow)?;
+			let right_part = y
+				.checked_mul(position.checked_sub(prev_x).ok_or(Overflow)?)
+				.ok_or(Overflow)?;
+			let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
+				.checked_div(segment)
+				.ok_or(Overflow)?;
+
+			return to_lbp_weight!(result);
+		}
+		if let Some((prev_x, _)) = previous {
+			ensure!(x > prev_x, DivisionByZero);
+		}
+		previous = Some((x, y));
+	}
+
+	// `at` is past the end of the interval
+	Err(Overflow)
+}
+
+/// Returns the distances of `at` from the end and the start of an interval and the length of the interval.
+fn interval_distances<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<(u128, u128, u32), MathError> {
 	let d1 = end_x.checked_sub(&at).ok_or(Overflow)?;
 	let d2 = at.checked_sub(&start_x).ok_or(Overflow)?;
 	let dx = end_x.checked_sub(&start_x).ok_or(Overflow)?;
@@ -200,15 +444,7 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 
 	ensure!(dx != 0, ZeroDuration);
 
-	let (start_y, end_y, d1, d2) = to_u256!(start_y, end_y, d1, d2);
-
-	let left_part = start_y.checked_mul(d1).ok_or(Overflow)?;
-	let right_part = end_y.checked_mul(d2).ok_or(Overflow)?;
-	let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
-		.checked_div(dx.into())
-		.ok_or(Overflow)?;
-
-	to_lbp_weight!(result)
+	Ok((d1, d2, dx))
 }
 
 /// Create a fixed point number based on two `u128` values. Divides the values and rounds according to `r`.
@@ -2,7 +2,9 @@
 use crate::lbp::lbp;
 
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
-use crate::MathError::{Overflow, ZeroDuration, ZeroReserve};
+use crate::MathError::{DivisionByZero, Overflow, ZeroDuration, ZeroReserve, ZeroWeight};
+use num_traits::Zero;
+use sp_arithmetic::{FixedU128, Permill};
 
 use std::vec;
 
@@ -36,6 +38,97 @@ fn spot_price_should_work() {
 	}
 }
 
+#[test]
+fn graduation_amounts_should_work() {
+	let cases: Vec<(
+		u128,
+		u128,
+		u32,
+		u32,
+		Permill,
+		Result<(u128, u128), crate::MathError>,
+		&str,
+	)> = vec![
+		(
+			1000,
+			2000,
+			500,
+			500,
+			Permill::from_percent(50),
+			Ok((500, 1000)),
+			"Equal weights",
+		),
+		(
+			1000,
+			2000,
+			20_000_000,
+			80_000_000,
+			Permill::from_percent(100),
+			Ok((1000, 500)),
+			"Reserve a is limiting",
+		),
+		(
+			1000,
+			2000,
+			80_000_000,
+			20_000_000,
+			Permill::from_percent(100),
+			Ok((250, 2000)),
+			"Reserve b is limiting",
+		),
+		(
+			1000,
+			2000,
+			80_000_000,
+			20_000_000,
+			Permill::zero(),
+			Ok((0, 0)),
+			"Zero fraction",
+		),
+		(
+			0,
+			2000,
+			500,
+			500,
+			Permill::from_percent(50),
+			Err(ZeroReserve),
+			"Zero reserve",
+		),
+		(
+			1000,
+			2000,
+			0,
+			500,
+			Permill::from_percent(50),
+			Err(ZeroWeight),
+			"Zero weight",
+		),
+		(
+			Balance::MAX,
+			1,
+			1,
+			LBPWeight::MAX,
+			Permill::from_percent(100),
+			Ok((Balance::MAX, 0)),
+			"Truncated result",
+		),
+	];
+
+	for case in cases {
+		let result = lbp::calculate_graduation_amounts(case.0, case.1, case.2, case.3, case.4);
+		assert_eq!(result, case.5, "{}", case.6);
+
+		// Spot price of the pool is preserved
+		if let Ok((amount_a, amount_b)) = result {
+			if amount_b > 0 {
+				let lbp_price = FixedU128::from_rational(case.0 * case.3 as u128, case.1 * case.2 as u128);
+				let price = FixedU128::from_rational(amount_a, amount_b);
+				assert_eq!(price, lbp_price, "{}", case.6);
+			}
+		}
+	}
+}
+
 #[test]
 fn out_given_in_should_work() {
 	let cases: Vec<(u128, u128, u32, u32, u128, Result<u128, crate::MathError>, &str)> = vec![
@@ -219,3 +312,392 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let rate = FixedU128::from(5);
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			rate,
+			100u32,
+			Ok(90_000_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			rate,
+			200u32,
+			Ok(10_000_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			rate,
+			100u32,
+			Ok(10_000_000),
+			"Initial increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			rate,
+			200u32,
+			Ok(90_000_000),
+			"Final increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			50_000_000u32,
+			50_000_000u32,
+			rate,
+			170u32,
+			Ok(50_000_000),
+			"Constant weight",
+		),
+		(
+			100u32,
+			100u32,
+			90_000_000u32,
+			10_000_000u32,
+			rate,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			rate,
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			FixedU128::zero(),
+			170u32,
+			Err(DivisionByZero),
+			"Zero rate",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			FixedU128::from(21),
+			170u32,
+			Err(Overflow),
+			"Rate too high",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn exponential_weights_should_be_accurate() {
+	let rates = [
+		FixedU128::from_rational(1, 10),
+		FixedU128::from_rational(3, 2),
+		FixedU128::from(5),
+		FixedU128::from(20),
+	];
+	let weights = [(90_000_000u32, 10_000_000u32), (10_000_000u32, 90_000_000u32)];
+
+	for rate in rates {
+		let k = rate.to_float();
+		for (start_y, end_y) in weights {
+			for at in (0u32..=1_000).step_by(37) {
+				let t = f64::from(at) / 1_000f64;
+				let remaining = ((-k * t).exp() - (-k).exp()) / (1f64 - (-k).exp());
+				let expected = f64::from(end_y) + (f64::from(start_y) - f64::from(end_y)) * remaining;
+
+				let weight = lbp::calculate_exponential_weights(0u32, 1_000u32, start_y, end_y, rate, at).unwrap();
+
+				assert!(
+					(f64::from(weight) - expected).abs() <= 1f64,
+					"rate: {k}, at: {at}, weight: {weight}, expected: {expected}"
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn step_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			100u32,
+			Ok(1_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			124u32,
+			Ok(1_000),
+			"First step",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			125u32,
+			Ok(1_333),
+			"Second step",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			170u32,
+			Ok(1_666),
+			"Third step",
+		),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4u32, 199u32, Ok(2_000), "Last step"),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			200u32,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			3u32,
+			150u32,
+			Ok(1_500),
+			"Decreasing weight",
+		),
+		(100u32, 200u32, 2_000u32, 1_000u32, 2u32, 149u32, Ok(2_000), "Two steps"),
+		(100u32, 200u32, 2_000u32, 1_000u32, 2u32, 150u32, Ok(1_000), "Two steps"),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1u32,
+			150u32,
+			Err(DivisionByZero),
+			"Single step",
+		),
+		(
+			100u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			3u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			3u32,
+			201u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_step_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn piecewise_linear_weights_should_work() {
+	let points = vec![
+		(Permill::from_percent(20), 5_000u32),
+		(Permill::from_percent(50), 2_000u32),
+	];
+	let duplicated = vec![
+		(Permill::from_percent(50), 5_000u32),
+		(Permill::from_percent(50), 2_000u32),
+	];
+	let unsorted = vec![
+		(Permill::from_percent(50), 5_000u32),
+		(Permill::from_percent(20), 2_000u32),
+	];
+
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			100u32,
+			Ok(10_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			110u32,
+			Ok(7_500),
+			"First segment",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			120u32,
+			Ok(5_000),
+			"First point",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			130u32,
+			Ok(4_000),
+			"Second segment",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			150u32,
+			Ok(2_000),
+			"Second point",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			175u32,
+			Ok(1_500),
+			"Last segment",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			200u32,
+			Ok(1_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			100u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&points,
+			201u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&duplicated,
+			170u32,
+			Err(DivisionByZero),
+			"Duplicated points",
+		),
+		(
+			100u32,
+			200u32,
+			10_000u32,
+			1_000u32,
+			&unsorted,
+			170u32,
+			Err(DivisionByZero),
+			"Unsorted points",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_piecewise_linear_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+
+	// without intermediate points the curve is linear
+	for at in (100u32..=200).step_by(7) {
+		assert_eq!(
+			lbp::calculate_piecewise_linear_weights(100u32, 200u32, 10_000u32, 1_000u32, &[], at),
+			lbp::calculate_linear_weights(100u32, 200u32, 10_000u32, 1_000u32, at),
+		);
+	}
+}
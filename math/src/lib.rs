@@ -15,6 +15,8 @@ extern crate approx;
 #[cfg(test)]
 mod test_pow_accuracy;
 
+pub mod concentrated_liquidity;
+pub mod cryptoswap;
 pub mod dynamic_fees;
 pub mod ema;
 pub mod fee;
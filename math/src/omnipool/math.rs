@@ -1,7 +1,7 @@
 use crate::omnipool::types::BalanceUpdate::{Decrease, Increase};
 use crate::omnipool::types::{
-	AssetReserveState, AssetStateChange, HubTradeStateChange, LiquidityStateChange, Position, TradeFee,
-	TradeStateChange,
+	AssetReserveState, AssetStateChange, BalanceUpdate, HubTradeStateChange, LiquidityStateChange, Position,
+	PositionValuation, SlipFee, SlipFeeState, TradeFee, TradeStateChange,
 };
 use crate::types::Balance;
 use crate::MathError::Overflow;
@@ -17,6 +17,42 @@ fn amount_without_fee(amount: Balance, fee: Permill) -> Option<Balance> {
 	Some(Permill::from_percent(100).checked_sub(&fee)?.mul_floor(amount))
 }
 
+/// Calculate slip fee rate of an asset for a trade which changes its hub reserve by `delta_hub_reserve`.
+///
+/// Rate is proportional to the net change of the hub reserve in current block, including the trade,
+/// relative to the hub reserve at the beginning of the block. It is capped by the maximum slip fee of the asset.
+pub fn calculate_slip_fee_rate(
+	slip_fee_state: &SlipFeeState<Balance>,
+	delta_hub_reserve: BalanceUpdate<Balance>,
+) -> Option<Permill> {
+	if slip_fee_state.hub_reserve_at_block_start.is_zero() {
+		return Some(Permill::zero());
+	}
+
+	let net_delta_hub_reserve = slip_fee_state.delta_hub_reserve.merge(delta_hub_reserve)?;
+
+	let relative_delta =
+		FixedU128::checked_from_rational(*net_delta_hub_reserve, slip_fee_state.hub_reserve_at_block_start)?;
+	let rate = slip_fee_state.factor.saturating_mul(relative_delta);
+
+	Some(Permill::from_rational(rate.into_inner(), FixedU128::DIV).min(slip_fee_state.max_slip_fee))
+}
+
+/// Calculate total slip fee rate of a trade between two assets.
+///
+/// Hub amount of the trade is used as the change of hub reserve of both assets.
+fn calculate_trade_slip_fee_rate(slip_fee: &SlipFee<Balance>, hub_amount: Balance) -> Option<Permill> {
+	let rate_in = match slip_fee.asset_in {
+		Some(ref state) => calculate_slip_fee_rate(state, Decrease(hub_amount))?,
+		None => Permill::zero(),
+	};
+	let rate_out = match slip_fee.asset_out {
+		Some(ref state) => calculate_slip_fee_rate(state, Increase(hub_amount))?,
+		None => Permill::zero(),
+	};
+	Some(rate_in.saturating_add(rate_out))
+}
+
 /// Calculate delta changes of a sell trade given current state of asset in and out.
 pub fn calculate_sell_state_changes(
 	asset_in_state: &AssetReserveState<Balance>,
@@ -25,6 +61,30 @@ pub fn calculate_sell_state_changes(
 	asset_fee: Permill,
 	protocol_fee: Permill,
 	m: Permill,
+) -> Option<TradeStateChange<Balance>> {
+	calculate_sell_state_changes_with_slip_fee(
+		asset_in_state,
+		asset_out_state,
+		amount,
+		asset_fee,
+		protocol_fee,
+		m,
+		&SlipFee::default(),
+	)
+}
+
+/// Calculate delta changes of a sell trade given current state of asset in and out, including slip fee.
+///
+/// Slip fee is taken from the hub asset amount transferred from asset in to asset out subpool.
+/// It is not burned and is added to the extra protocol fee amount.
+pub fn calculate_sell_state_changes_with_slip_fee(
+	asset_in_state: &AssetReserveState<Balance>,
+	asset_out_state: &AssetReserveState<Balance>,
+	amount: Balance,
+	asset_fee: Permill,
+	protocol_fee: Permill,
+	m: Permill,
+	slip_fee: &SlipFee<Balance>,
 ) -> Option<TradeStateChange<Balance>> {
 	let (in_hub_reserve, in_reserve, in_amount) = to_u256!(asset_in_state.hub_reserve, asset_in_state.reserve, amount);
 
@@ -36,7 +96,13 @@ pub fn calculate_sell_state_changes(
 
 	let protocol_fee_amount = protocol_fee.mul_floor(delta_hub_reserve_in);
 
-	let delta_hub_reserve_out = delta_hub_reserve_in.checked_sub(protocol_fee_amount)?;
+	let slip_fee_rate =
+		calculate_trade_slip_fee_rate(slip_fee, delta_hub_reserve_in)?.min(Permill::one().saturating_sub(protocol_fee));
+	let slip_fee_amount = slip_fee_rate.mul_floor(delta_hub_reserve_in);
+
+	let delta_hub_reserve_out = delta_hub_reserve_in
+		.checked_sub(protocol_fee_amount)?
+		.checked_sub(slip_fee_amount)?;
 
 	let (out_reserve_hp, out_hub_reserve_hp, delta_hub_reserve_out_hp) = to_u256!(
 		asset_out_state.reserve,
@@ -66,7 +132,9 @@ pub fn calculate_sell_state_changes(
 	// note that we dont need to include burned amount anywhere, as it is already part of delta_hub_reserve_in value.
 	// we only to need to include extra_protocol_fee when the deltas are calculated, as it used to be done for hdx hub amount.
 	let burned_protocol_fee = m.mul_floor(protocol_fee_amount);
-	let extra_protocol_fee = protocol_fee_amount.checked_sub(burned_protocol_fee)?;
+	let extra_protocol_fee = protocol_fee_amount
+		.checked_sub(burned_protocol_fee)?
+		.checked_add(slip_fee_amount)?;
 
 	Some(TradeStateChange {
 		asset_in: AssetStateChange {
@@ -84,6 +152,7 @@ pub fn calculate_sell_state_changes(
 		fee: TradeFee {
 			asset_fee: asset_fee_amount,
 			protocol_fee: protocol_fee_amount,
+			slip_fee: slip_fee_amount,
 		},
 	})
 }
@@ -94,6 +163,23 @@ pub fn calculate_sell_hub_state_changes(
 	hub_asset_amount: Balance,
 	asset_fee: Permill,
 ) -> Option<HubTradeStateChange<Balance>> {
+	calculate_sell_hub_state_changes_with_slip_fee(asset_out_state, hub_asset_amount, asset_fee, &SlipFee::default())
+}
+
+/// Calculate delta changes of a sell where asset_in is Hub Asset, including slip fee of asset out.
+///
+/// Slip fee is taken from the sold hub asset amount before it is added to asset out subpool.
+/// It is not burned and has to be processed as extra protocol fee.
+pub fn calculate_sell_hub_state_changes_with_slip_fee(
+	asset_out_state: &AssetReserveState<Balance>,
+	hub_asset_amount: Balance,
+	asset_fee: Permill,
+	slip_fee: &SlipFee<Balance>,
+) -> Option<HubTradeStateChange<Balance>> {
+	let slip_fee_rate = calculate_trade_slip_fee_rate(slip_fee, hub_asset_amount)?;
+	let slip_fee_amount = slip_fee_rate.mul_floor(hub_asset_amount);
+	let hub_asset_amount = hub_asset_amount.checked_sub(slip_fee_amount)?;
+
 	let (reserve_hp, hub_reserve_hp, amount_hp) =
 		to_u256!(asset_out_state.reserve, asset_out_state.hub_reserve, hub_asset_amount);
 
@@ -123,6 +209,7 @@ pub fn calculate_sell_hub_state_changes(
 		},
 		fee: TradeFee {
 			asset_fee: asset_fee_amount,
+			slip_fee: slip_fee_amount,
 			..Default::default()
 		},
 	})
@@ -154,6 +241,24 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 	asset_out_state: &AssetReserveState<Balance>,
 	asset_out_amount: Balance,
 	asset_fee: Permill,
+) -> Option<HubTradeStateChange<Balance>> {
+	calculate_buy_for_hub_asset_state_changes_with_slip_fee(
+		asset_out_state,
+		asset_out_amount,
+		asset_fee,
+		&SlipFee::default(),
+	)
+}
+
+/// Calculate delta changes of a buy trade where asset_in is Hub Asset, including slip fee of asset out.
+///
+/// Slip fee is paid on top of the hub asset amount added to asset out subpool, so the total amount paid
+/// is `delta_hub_reserve + slip_fee`. It is not burned and has to be processed as extra protocol fee.
+pub fn calculate_buy_for_hub_asset_state_changes_with_slip_fee(
+	asset_out_state: &AssetReserveState<Balance>,
+	asset_out_amount: Balance,
+	asset_fee: Permill,
+	slip_fee: &SlipFee<Balance>,
 ) -> Option<HubTradeStateChange<Balance>> {
 	let reserve_no_fee = amount_without_fee(asset_out_state.reserve, asset_fee)?;
 	let hub_denominator = reserve_no_fee.checked_sub(asset_out_amount)?;
@@ -178,6 +283,15 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 	);
 	let delta_q_m = n.checked_div(hub_denominator)?;
 
+	let slip_fee_rate = calculate_trade_slip_fee_rate(slip_fee, delta_hub_reserve)?;
+	if slip_fee_rate == Permill::one() {
+		return None;
+	}
+	let hub_amount_in: Balance = FixedU128::from_inner(delta_hub_reserve)
+		.checked_div(&Permill::one().sub(slip_fee_rate).into())?
+		.into_inner();
+	let slip_fee_amount = hub_amount_in.saturating_sub(delta_hub_reserve);
+
 	Some(HubTradeStateChange {
 		asset: AssetStateChange {
 			delta_reserve: Decrease(asset_out_amount),
@@ -187,6 +301,7 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 		},
 		fee: TradeFee {
 			asset_fee: fee_amount,
+			slip_fee: slip_fee_amount,
 			..Default::default()
 		},
 	})
@@ -200,6 +315,30 @@ pub fn calculate_buy_state_changes(
 	asset_fee: Permill,
 	protocol_fee: Permill,
 	m: Permill,
+) -> Option<TradeStateChange<Balance>> {
+	calculate_buy_state_changes_with_slip_fee(
+		asset_in_state,
+		asset_out_state,
+		amount,
+		asset_fee,
+		protocol_fee,
+		m,
+		&SlipFee::default(),
+	)
+}
+
+/// Calculate delta changes of a buy trade given current state of asset in and out, including slip fee.
+///
+/// Slip fee is taken from the hub asset amount transferred from asset in to asset out subpool.
+/// It is not burned and is added to the extra protocol fee amount.
+pub fn calculate_buy_state_changes_with_slip_fee(
+	asset_in_state: &AssetReserveState<Balance>,
+	asset_out_state: &AssetReserveState<Balance>,
+	amount: Balance,
+	asset_fee: Permill,
+	protocol_fee: Permill,
+	m: Permill,
+	slip_fee: &SlipFee<Balance>,
 ) -> Option<TradeStateChange<Balance>> {
 	let reserve_no_fee = amount_without_fee(asset_out_state.reserve, asset_fee)?;
 	let (out_hub_reserve_hp, out_reserve_no_fee_hp, out_amount_hp) =
@@ -215,9 +354,16 @@ pub fn calculate_buy_state_changes(
 	let delta_hub_reserve_out = to_balance!(delta_hub_reserve_out_hp).ok()?;
 	let delta_hub_reserve_out = delta_hub_reserve_out.checked_add(Balance::one())?;
 
+	let slip_fee_rate = calculate_trade_slip_fee_rate(slip_fee, delta_hub_reserve_out)?
+		.min(Permill::one().saturating_sub(protocol_fee));
+	let total_fee = protocol_fee.saturating_add(slip_fee_rate);
+	if total_fee == Permill::one() {
+		return None;
+	}
+
 	// Negative
 	let delta_hub_reserve_in: Balance = FixedU128::from_inner(delta_hub_reserve_out)
-		.checked_div(&Permill::from_percent(100).sub(protocol_fee).into())?
+		.checked_div(&Permill::from_percent(100).sub(total_fee).into())?
 		.into_inner();
 
 	if delta_hub_reserve_in >= asset_in_state.hub_reserve {
@@ -236,6 +382,13 @@ pub fn calculate_buy_state_changes(
 
 	let asset_fee_amount = calculate_fee_amount_for_buy(asset_fee, amount);
 	let protocol_fee_amount = protocol_fee.mul_floor(delta_hub_reserve_in);
+	let slip_fee_amount = if slip_fee_rate.is_zero() {
+		Balance::zero()
+	} else {
+		delta_hub_reserve_in
+			.saturating_sub(delta_hub_reserve_out)
+			.saturating_sub(protocol_fee_amount)
+	};
 
 	// mint amount to account for asset fee that stays in the pool
 	let delta_hub_reserve_out_hp = to_u256!(delta_hub_reserve_out);
@@ -249,7 +402,9 @@ pub fn calculate_buy_state_changes(
 
 	// Protocol fee to burn and transfer
 	let burned_protocol_fee = m.mul_floor(protocol_fee_amount);
-	let extra_protocol_fee = protocol_fee_amount.checked_sub(burned_protocol_fee)?;
+	let extra_protocol_fee = protocol_fee_amount
+		.checked_sub(burned_protocol_fee)?
+		.checked_add(slip_fee_amount)?;
 
 	Some(TradeStateChange {
 		asset_in: AssetStateChange {
@@ -267,6 +422,7 @@ pub fn calculate_buy_state_changes(
 		fee: TradeFee {
 			asset_fee: asset_fee_amount,
 			protocol_fee: protocol_fee_amount,
+			slip_fee: slip_fee_amount,
 		},
 	})
 }
@@ -416,6 +572,150 @@ pub fn calculate_remove_liquidity_state_changes(
 	})
 }
 
+/// Calculate positions resulting from splitting `shares` off the position.
+///
+/// Returns remaining position and new position. Amount is split proportionally to shares, price is kept.
+pub fn calculate_split_position(
+	position: &Position<Balance>,
+	shares: Balance,
+) -> Option<(Position<Balance>, Position<Balance>)> {
+	if shares.is_zero() || shares >= position.shares {
+		return None;
+	}
+
+	let (shares_hp, amount_hp, position_shares_hp) = to_u256!(shares, position.amount, position.shares);
+	let new_amount = to_balance!(shares_hp.checked_mul(amount_hp)?.checked_div(position_shares_hp)?).ok()?;
+
+	Some((
+		Position {
+			amount: position.amount.checked_sub(new_amount)?,
+			shares: position.shares.checked_sub(shares)?,
+			price: position.price,
+		},
+		Position {
+			amount: new_amount,
+			shares,
+			price: position.price,
+		},
+	))
+}
+
+/// Calculate position resulting from merging two positions of the same asset.
+///
+/// Amounts and shares are summed. Positions with the same price keep the price, otherwise the merged price is
+/// the share-weighted average of both prices, rounded up.
+///
+/// Value of a position at current price P (hub asset converted at P) is `R * s / S * 2P / (P + p)`,
+/// which is convex in position price p. Removing liquidity of the merged position therefore never pays out
+/// more value than removing liquidity of both positions, regardless of the current price.
+pub fn calculate_merged_position(
+	position_a: &Position<Balance>,
+	position_b: &Position<Balance>,
+) -> Option<Position<Balance>> {
+	let amount = position_a.amount.checked_add(position_b.amount)?;
+	let shares = position_a.shares.checked_add(position_b.shares)?;
+
+	if is_same_position_price(position_a, position_b) {
+		return Some(Position {
+			amount,
+			shares,
+			price: position_a.price,
+		});
+	}
+
+	let (a_hub, a_asset, a_shares, b_hub, b_asset, b_shares, shares_hp, div) = to_u256!(
+		position_a.price.0,
+		position_a.price.1,
+		position_a.shares,
+		position_b.price.0,
+		position_b.price.1,
+		position_b.shares,
+		shares,
+		FixedU128::DIV
+	);
+
+	let a_price = div_round_up(a_hub.checked_mul(div)?, a_asset)?;
+	let b_price = div_round_up(b_hub.checked_mul(div)?, b_asset)?;
+
+	let weighted_price = a_price
+		.checked_mul(a_shares)?
+		.checked_add(b_price.checked_mul(b_shares)?)?;
+	let price = to_balance!(div_round_up(weighted_price, shares_hp)?).ok()?;
+
+	Some(Position {
+		amount,
+		shares,
+		price: (price, FixedU128::DIV),
+	})
+}
+
+fn div_round_up(numerator: U256, denominator: U256) -> Option<U256> {
+	numerator
+		.checked_add(denominator.checked_sub(U256::one())?)?
+		.checked_div(denominator)
+}
+
+/// Returns true if both positions were created at the same price, regardless of how the price ratio is represented.
+pub fn is_same_position_price(position_a: &Position<Balance>, position_b: &Position<Balance>) -> bool {
+	let (a_hub, a_asset, b_hub, b_asset) = to_u256!(
+		position_a.price.0,
+		position_a.price.1,
+		position_b.price.0,
+		position_b.price.1
+	);
+
+	!a_asset.is_zero() && !b_asset.is_zero() && a_hub * b_asset == b_hub * a_asset
+}
+
+/// Calculate value of a position if all its shares were removed at current asset state.
+///
+/// Hub asset received is converted to asset at current spot price.
+/// Impermanent loss compares the value before withdrawal fee to holding the position amount of asset.
+pub fn calculate_position_valuation(
+	asset_state: &AssetReserveState<Balance>,
+	position: &Position<Balance>,
+	withdrawal_fee: FixedU128,
+) -> Option<PositionValuation<Balance>> {
+	let state_changes =
+		calculate_remove_liquidity_state_changes(asset_state, position.shares, position, FixedU128::zero())?;
+
+	let asset_amount_before_fee = *state_changes.asset.delta_reserve;
+	let hub_amount_before_fee = state_changes.lp_hub_amount;
+
+	// Withdrawal fee is applied the same way as in remove liquidity
+	let fee_complement = FixedU128::one().saturating_sub(withdrawal_fee);
+	let asset_amount = fee_complement.checked_mul_int(asset_amount_before_fee)?;
+	let hub_amount = fee_complement.checked_mul_int(hub_amount_before_fee)?;
+
+	let value_before_fee =
+		asset_amount_before_fee.checked_add(convert_hub_amount_to_asset(asset_state, hub_amount_before_fee)?)?;
+	let value = asset_amount.checked_add(convert_hub_amount_to_asset(asset_state, hub_amount)?)?;
+
+	let impermanent_loss = if value_before_fee < position.amount {
+		FixedU128::checked_from_rational(position.amount.checked_sub(value_before_fee)?, position.amount)?
+	} else {
+		FixedU128::zero()
+	};
+
+	Some(PositionValuation {
+		asset_amount,
+		hub_amount,
+		value,
+		withdrawal_fee,
+		withdrawal_fee_amount: value_before_fee.saturating_sub(value),
+		impermanent_loss,
+	})
+}
+
+fn convert_hub_amount_to_asset(asset_state: &AssetReserveState<Balance>, hub_amount: Balance) -> Option<Balance> {
+	let (hub_amount_hp, reserve_hp, hub_reserve_hp) =
+		to_u256!(hub_amount, asset_state.reserve, asset_state.hub_reserve);
+
+	let amount = hub_amount_hp.checked_mul(reserve_hp)?.checked_div(hub_reserve_hp)?;
+
+	to_balance!(amount).ok()
+}
+
 pub fn calculate_tvl(hub_reserve: Balance, stable_asset: (Balance, Balance)) -> Option<Balance> {
 	let (hub_reserve_hp, stable_reserve_hp, stable_hub_reserve_hp) =
 		to_u256!(hub_reserve, stable_asset.0, stable_asset.1);
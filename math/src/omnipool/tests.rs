@@ -1,13 +1,19 @@
-use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position, TradeFee};
+use crate::omnipool::types::{
+	AssetReserveState, BalanceUpdate, LiquidityStateChange, Position, PositionValuation, SlipFee, SlipFeeState,
+	TradeFee,
+};
 use crate::omnipool::{
-	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes, calculate_buy_state_changes,
-	calculate_cap_difference, calculate_fee_amount_for_buy, calculate_remove_liquidity_state_changes,
-	calculate_sell_hub_state_changes, calculate_sell_state_changes, calculate_tvl_cap_difference,
-	calculate_withdrawal_fee, verify_asset_cap,
+	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes,
+	calculate_buy_for_hub_asset_state_changes_with_slip_fee, calculate_buy_state_changes,
+	calculate_buy_state_changes_with_slip_fee, calculate_cap_difference, calculate_fee_amount_for_buy,
+	calculate_merged_position, calculate_position_valuation, calculate_remove_liquidity_state_changes,
+	calculate_sell_hub_state_changes, calculate_sell_hub_state_changes_with_slip_fee, calculate_sell_state_changes,
+	calculate_sell_state_changes_with_slip_fee, calculate_slip_fee_rate, calculate_split_position,
+	calculate_tvl_cap_difference, calculate_withdrawal_fee, verify_asset_cap,
 };
 use crate::types::Balance;
 use num_traits::{One, Zero};
-use sp_arithmetic::{FixedU128, Permill};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use std::str::FromStr;
 
 const UNIT: Balance = 1_000_000_000_000;
@@ -107,6 +113,7 @@ fn calculate_sell_should_return_correct_when_protocol_fee_is_zero() {
 		TradeFee {
 			asset_fee: 26666666667,
 			protocol_fee: 0,
+			slip_fee: 0,
 		}
 	);
 }
@@ -147,6 +154,7 @@ fn calculate_sell_should_return_correct_when_protocol_fee_is_not_zero() {
 		TradeFee {
 			asset_fee: 26541554960,
 			protocol_fee: 57142857142,
+			slip_fee: 0,
 		}
 	);
 }
@@ -334,6 +342,7 @@ fn calculate_sell_hub_asset_with_fee_should_work_when_correct_input_provided() {
 		TradeFee {
 			asset_fee: 16666666667,
 			protocol_fee: 0,
+			slip_fee: 0,
 		}
 	);
 }
@@ -434,6 +443,7 @@ fn calculate_buy_should_return_correct_fee_when_protocol_fee_is_zero() {
 		TradeFee {
 			asset_fee: 10101010102,
 			protocol_fee: 0,
+			slip_fee: 0,
 		}
 	)
 }
@@ -481,6 +491,7 @@ fn calculate_buy_should_return_correct_fee_when_protocol_fee_is_non_zero() {
 		TradeFee {
 			asset_fee: 10101010102,
 			protocol_fee: 12786088735,
+			slip_fee: 0,
 		}
 	)
 }
@@ -665,6 +676,7 @@ fn calculate_buy_for_hub_asset_with_fee_should_work_when_correct_input_provided(
 		TradeFee {
 			asset_fee: 20_202_020_203,
 			protocol_fee: 0,
+			slip_fee: 0,
 		}
 	);
 }
@@ -1109,6 +1121,7 @@ fn calculate_buy_should_charge_less_when_fee_is_zero() {
 		TradeFee {
 			asset_fee: 0,
 			protocol_fee: 0,
+			slip_fee: 0,
 		}
 	);
 }
@@ -1160,6 +1173,573 @@ fn calculate_buy_should_charge_more_when_fee_is_not_zero() {
 		TradeFee {
 			asset_fee: 111_111_111_112,
 			protocol_fee: 75_187_969_924,
+			slip_fee: 0,
+		}
+	);
+}
+
+#[test]
+fn calculate_position_valuation_should_have_no_impermanent_loss_when_price_did_not_change() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	let valuation = calculate_position_valuation(&asset_state, &position, FixedU128::from_rational(1, 100)).unwrap();
+
+	assert_eq!(
+		valuation,
+		PositionValuation {
+			asset_amount: 2_970_000_000_000,
+			hub_amount: 0,
+			value: 2_970_000_000_000,
+			withdrawal_fee: FixedU128::from_rational(1, 100),
+			withdrawal_fee_amount: 30_000_000_000,
+			impermanent_loss: FixedU128::zero(),
 		}
 	);
 }
+
+#[test]
+fn calculate_position_valuation_should_match_remove_liquidity_when_price_decreased() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (40 * UNIT, 10 * UNIT),
+	};
+	let withdrawal_fee = FixedU128::from_rational(1, 100);
+
+	let valuation = calculate_position_valuation(&asset_state, &position, withdrawal_fee).unwrap();
+	let state_changes =
+		calculate_remove_liquidity_state_changes(&asset_state, position.shares, &position, withdrawal_fee).unwrap();
+
+	assert_eq!(valuation.asset_amount, *state_changes.asset.delta_reserve);
+	assert_eq!(valuation.hub_amount, state_changes.lp_hub_amount);
+	assert_eq!(
+		valuation,
+		PositionValuation {
+			asset_amount: 1_979_999_999_999,
+			hub_amount: 0,
+			value: 1_979_999_999_999,
+			withdrawal_fee,
+			withdrawal_fee_amount: 20_000_000_000,
+			impermanent_loss: FixedU128::from_inner(333_333_333_333_666_666),
+		}
+	);
+}
+
+#[test]
+fn calculate_position_valuation_should_fail_when_asset_state_is_empty() {
+	let asset_state = AssetReserveState::default();
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	assert!(calculate_position_valuation(&asset_state, &position, FixedU128::zero()).is_none());
+}
+
+#[test]
+fn calculate_split_position_should_split_amount_proportionally_to_shares() {
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 6 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	let (remaining, new) = calculate_split_position(&position, 2 * UNIT).unwrap();
+
+	assert_eq!(
+		remaining,
+		Position {
+			amount: 2 * UNIT,
+			shares: 4 * UNIT,
+			price: (20 * UNIT, 10 * UNIT),
+		}
+	);
+	assert_eq!(
+		new,
+		Position {
+			amount: UNIT,
+			shares: 2 * UNIT,
+			price: (20 * UNIT, 10 * UNIT),
+		}
+	);
+}
+
+#[test]
+fn calculate_split_position_should_fail_when_shares_are_zero_or_all_position_shares() {
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 6 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	assert!(calculate_split_position(&position, 0).is_none());
+	assert!(calculate_split_position(&position, 6 * UNIT).is_none());
+}
+
+#[test]
+fn calculate_merged_position_should_sum_amounts_and_shares_when_prices_are_same() {
+	let position_a = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: 2 * UNIT,
+		price: (4 * UNIT, 2 * UNIT),
+	};
+
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+
+	assert_eq!(
+		merged,
+		Position {
+			amount: 4 * UNIT,
+			shares: 5 * UNIT,
+			price: (20 * UNIT, 10 * UNIT),
+		}
+	);
+}
+
+#[test]
+fn calculate_merged_position_should_use_share_weighted_price_when_prices_are_different() {
+	let position_a = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: 2 * UNIT,
+		price: (60 * UNIT, 10 * UNIT),
+	};
+
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+
+	// (3 * 2 + 2 * 6) / 5 = 3.6
+	assert_eq!(
+		merged,
+		Position {
+			amount: 4 * UNIT,
+			shares: 5 * UNIT,
+			price: (FixedU128::from_rational(36, 10).into_inner(), FixedU128::DIV),
+		}
+	);
+}
+
+#[test]
+fn calculate_merged_position_should_round_price_up_when_prices_are_different() {
+	let position_a = Position {
+		amount: UNIT,
+		shares: UNIT,
+		price: (UNIT, 3 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: UNIT,
+		price: (2 * UNIT, 3 * UNIT),
+	};
+
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+
+	// (333_333_333_333_333_334 + 666_666_666_666_666_667) / 2 rounded up
+	assert_eq!(merged.price, (500_000_000_000_000_001, FixedU128::DIV));
+}
+
+#[test]
+fn merged_position_should_be_valued_same_as_positions_when_prices_are_same() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 30 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let position_a = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	let value_a = calculate_position_valuation(&asset_state, &position_a, FixedU128::zero()).unwrap();
+	let value_b = calculate_position_valuation(&asset_state, &position_b, FixedU128::zero()).unwrap();
+
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+	let merged_value = calculate_position_valuation(&asset_state, &merged, FixedU128::zero()).unwrap();
+
+	assert_eq!(merged_value.asset_amount, value_a.asset_amount + value_b.asset_amount);
+	// Hub amounts can differ by rounding only
+	assert!(
+		merged_value
+			.hub_amount
+			.abs_diff(value_a.hub_amount + value_b.hub_amount)
+			<= 1
+	);
+}
+
+#[test]
+fn merged_position_should_not_be_valued_more_than_positions_when_prices_are_different() {
+	let position_a = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: UNIT,
+		price: (60 * UNIT, 10 * UNIT),
+	};
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+
+	// Current price below, between and above the prices of positions
+	for hub_reserve in [10 * UNIT, 30 * UNIT, 100 * UNIT] {
+		let asset_state = AssetReserveState {
+			reserve: 10 * UNIT,
+			hub_reserve,
+			shares: 10 * UNIT,
+			protocol_shares: 0u128,
+		};
+
+		let value_a = calculate_position_valuation(&asset_state, &position_a, FixedU128::zero()).unwrap();
+		let value_b = calculate_position_valuation(&asset_state, &position_b, FixedU128::zero()).unwrap();
+		let merged_value = calculate_position_valuation(&asset_state, &merged, FixedU128::zero()).unwrap();
+
+		assert!(merged_value.value <= value_a.value + value_b.value);
+	}
+}
+
+#[test]
+fn merged_position_should_not_pay_out_more_than_positions_when_prices_are_different() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 30 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let position_a = Position {
+		amount: 3 * UNIT,
+		shares: 3 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+	let position_b = Position {
+		amount: UNIT,
+		shares: UNIT,
+		price: (40 * UNIT, 10 * UNIT),
+	};
+	let merged = calculate_merged_position(&position_a, &position_b).unwrap();
+
+	let removed_a =
+		calculate_remove_liquidity_state_changes(&asset_state, position_a.shares, &position_a, FixedU128::zero())
+			.unwrap();
+	let removed_b =
+		calculate_remove_liquidity_state_changes(&asset_state, position_b.shares, &position_b, FixedU128::zero())
+			.unwrap();
+	let removed_merged =
+		calculate_remove_liquidity_state_changes(&asset_state, merged.shares, &merged, FixedU128::zero()).unwrap();
+
+	// Hub asset received is converted to asset at current price 3
+	let value = |changes: &LiquidityStateChange<Balance>| {
+		*changes.asset.delta_reserve + changes.lp_hub_amount * asset_state.reserve / asset_state.hub_reserve
+	};
+	assert!(value(&removed_merged) <= value(&removed_a) + value(&removed_b));
+}
+
+#[test]
+fn split_and_merge_should_result_in_original_position() {
+	let position = Position {
+		amount: 3 * UNIT,
+		shares: 6 * UNIT,
+		price: (20 * UNIT, 10 * UNIT),
+	};
+
+	let (remaining, new) = calculate_split_position(&position, 2 * UNIT).unwrap();
+	let merged = calculate_merged_position(&remaining, &new).unwrap();
+
+	assert_eq!(merged, position);
+}
+
+fn slip_fee_state(delta_hub_reserve: BalanceUpdate<Balance>) -> SlipFeeState<Balance> {
+	SlipFeeState {
+		factor: FixedU128::one(),
+		max_slip_fee: Permill::from_percent(5),
+		hub_reserve_at_block_start: 1000 * UNIT,
+		delta_hub_reserve,
+	}
+}
+
+#[test]
+fn calculate_slip_fee_rate_should_be_proportional_to_net_block_delta() {
+	let state = slip_fee_state(BalanceUpdate::Increase(0));
+	assert_eq!(
+		calculate_slip_fee_rate(&state, BalanceUpdate::Increase(10 * UNIT)),
+		Some(Permill::from_percent(1))
+	);
+	assert_eq!(
+		calculate_slip_fee_rate(&state, BalanceUpdate::Decrease(20 * UNIT)),
+		Some(Permill::from_percent(2))
+	);
+
+	let state = slip_fee_state(BalanceUpdate::Decrease(30 * UNIT));
+	assert_eq!(
+		calculate_slip_fee_rate(&state, BalanceUpdate::Increase(30 * UNIT)),
+		Some(Permill::zero())
+	);
+	assert_eq!(
+		calculate_slip_fee_rate(&state, BalanceUpdate::Decrease(10 * UNIT)),
+		Some(Permill::from_percent(4))
+	);
+}
+
+#[test]
+fn calculate_slip_fee_rate_should_be_capped_by_max_slip_fee() {
+	let state = slip_fee_state(BalanceUpdate::Increase(40 * UNIT));
+	assert_eq!(
+		calculate_slip_fee_rate(&state, BalanceUpdate::Increase(100 * UNIT)),
+		Some(Permill::from_percent(5))
+	);
+}
+
+#[test]
+fn calculate_sell_with_slip_fee_should_be_same_as_sell_when_no_slip_fee_state_is_provided() {
+	let asset_in_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let expected = calculate_sell_state_changes(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT,
+		Permill::from_percent(1),
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+	)
+	.unwrap();
+	let state_changes = calculate_sell_state_changes_with_slip_fee(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT,
+		Permill::from_percent(1),
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+		&SlipFee::default(),
+	)
+	.unwrap();
+
+	assert_eq!(state_changes, expected);
+	assert_eq!(state_changes.fee.slip_fee, 0);
+}
+
+#[test]
+fn calculate_sell_with_slip_fee_should_take_slip_fee_from_hub_amount() {
+	let asset_in_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let slip_fee = SlipFee {
+		asset_in: Some(SlipFeeState {
+			factor: FixedU128::one(),
+			max_slip_fee: Permill::from_percent(5),
+			hub_reserve_at_block_start: 20 * UNIT,
+			delta_hub_reserve: BalanceUpdate::Increase(0),
+		}),
+		asset_out: None,
+	};
+
+	let state_changes = calculate_sell_state_changes_with_slip_fee(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT / 10,
+		Permill::zero(),
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+		&slip_fee,
+	)
+	.unwrap();
+
+	let delta_hub_reserve_in = *state_changes.asset_in.delta_hub_reserve;
+	assert_eq!(delta_hub_reserve_in, 198_019_801_980);
+	// ~0.99% of slip fee for ~0.99% change of hub reserve
+	assert_eq!(state_changes.fee.slip_fee, 1_960_396_039);
+	assert_eq!(
+		*state_changes.asset_out.delta_hub_reserve,
+		delta_hub_reserve_in - state_changes.fee.protocol_fee - state_changes.fee.slip_fee
+	);
+	assert_eq!(
+		state_changes.extra_protocol_fee_amount,
+		state_changes.fee.protocol_fee - Permill::from_percent(50).mul_floor(state_changes.fee.protocol_fee)
+			+ state_changes.fee.slip_fee
+	);
+}
+
+#[test]
+fn calculate_buy_with_slip_fee_should_take_slip_fee_from_hub_amount() {
+	let asset_in_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let slip_fee = SlipFee {
+		asset_in: None,
+		asset_out: Some(SlipFeeState {
+			factor: FixedU128::one(),
+			max_slip_fee: Permill::from_percent(5),
+			hub_reserve_at_block_start: 5 * UNIT,
+			delta_hub_reserve: BalanceUpdate::Increase(0),
+		}),
+	};
+
+	let expected = calculate_buy_state_changes(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT / 10,
+		Permill::zero(),
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+	)
+	.unwrap();
+	let state_changes = calculate_buy_state_changes_with_slip_fee(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT / 10,
+		Permill::zero(),
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+		&slip_fee,
+	)
+	.unwrap();
+
+	assert_eq!(
+		state_changes.asset_out.delta_hub_reserve,
+		expected.asset_out.delta_hub_reserve
+	);
+	assert!(*state_changes.asset_in.delta_reserve > *expected.asset_in.delta_reserve);
+	assert_eq!(state_changes.fee.slip_fee, 2_147_758_005);
+	assert_eq!(
+		*state_changes.asset_in.delta_hub_reserve,
+		*state_changes.asset_out.delta_hub_reserve + state_changes.fee.protocol_fee + state_changes.fee.slip_fee
+	);
+}
+
+#[test]
+fn calculate_sell_hub_with_slip_fee_should_take_slip_fee_from_hub_amount() {
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let slip_fee = SlipFee {
+		asset_in: None,
+		asset_out: Some(SlipFeeState {
+			factor: FixedU128::one(),
+			max_slip_fee: Permill::from_percent(5),
+			hub_reserve_at_block_start: 5 * UNIT,
+			delta_hub_reserve: BalanceUpdate::Increase(0),
+		}),
+	};
+
+	let state_changes = calculate_sell_hub_state_changes_with_slip_fee(
+		&asset_out_state,
+		UNIT / 10,
+		Permill::from_percent(1),
+		&slip_fee,
+	)
+	.unwrap();
+
+	// 2% of slip fee for 2% change of hub reserve
+	assert_eq!(state_changes.fee.slip_fee, 2_000_000_000);
+	assert_eq!(*state_changes.asset.delta_hub_reserve, 98_000_000_000);
+
+	let expected =
+		calculate_sell_hub_state_changes(&asset_out_state, 98_000_000_000, Permill::from_percent(1)).unwrap();
+	assert_eq!(state_changes.asset, expected.asset);
+	assert_eq!(state_changes.fee.asset_fee, expected.fee.asset_fee);
+}
+
+#[test]
+fn calculate_buy_for_hub_asset_with_slip_fee_should_charge_slip_fee_on_top_of_hub_amount() {
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let slip_fee = SlipFee {
+		asset_in: None,
+		asset_out: Some(SlipFeeState {
+			factor: FixedU128::one(),
+			max_slip_fee: Permill::from_percent(5),
+			hub_reserve_at_block_start: 5 * UNIT,
+			delta_hub_reserve: BalanceUpdate::Increase(0),
+		}),
+	};
+
+	let expected =
+		calculate_buy_for_hub_asset_state_changes(&asset_out_state, UNIT / 10, Permill::from_percent(1)).unwrap();
+	let state_changes = calculate_buy_for_hub_asset_state_changes_with_slip_fee(
+		&asset_out_state,
+		UNIT / 10,
+		Permill::from_percent(1),
+		&slip_fee,
+	)
+	.unwrap();
+
+	assert_eq!(state_changes.asset, expected.asset);
+	assert_eq!(expected.fee.slip_fee, 0);
+
+	let delta_hub_reserve = *state_changes.asset.delta_hub_reserve;
+	let slip_fee_rate = Permill::from_rational(delta_hub_reserve, 5 * UNIT);
+	assert!(state_changes.fee.slip_fee >= slip_fee_rate.mul_floor(delta_hub_reserve));
+	// hub amount added to the pool is the paid amount without slip fee, up to rounding
+	assert!(
+		(Permill::one() - slip_fee_rate)
+			.mul_floor(delta_hub_reserve + state_changes.fee.slip_fee)
+			.abs_diff(delta_hub_reserve)
+			<= 1
+	);
+}
@@ -1,8 +1,10 @@
 use crate::omnipool::calculate_burn_amount_based_on_fee_taken;
 use crate::omnipool::types::BalanceUpdate::{Decrease, Increase};
+use codec::{Decode, Encode};
 use num_traits::{CheckedAdd, CheckedSub, SaturatingAdd};
+use scale_info::TypeInfo;
 use sp_arithmetic::traits::Saturating;
-use sp_arithmetic::{FixedPointNumber, FixedU128};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_std::ops::{Add, Deref};
 
 /// Asset state representation including asset pool reserve.
@@ -216,6 +218,27 @@ impl<
 pub struct TradeFee<Balance> {
 	pub asset_fee: Balance,
 	pub protocol_fee: Balance,
+	pub slip_fee: Balance,
+}
+
+/// Slip fee parameters of an asset together with its hub reserve changes in current block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlipFeeState<Balance> {
+	/// Slip fee rate per unit of relative hub reserve change
+	pub factor: FixedU128,
+	/// Maximum slip fee rate
+	pub max_slip_fee: Permill,
+	/// Hub reserve of the asset at the beginning of the block
+	pub hub_reserve_at_block_start: Balance,
+	/// Net change of the hub reserve of the asset caused by trades in current block
+	pub delta_hub_reserve: BalanceUpdate<Balance>,
+}
+
+/// Slip fee states of assets involved in a trade. Slip fee is not charged for an asset without state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SlipFee<Balance> {
+	pub asset_in: Option<SlipFeeState<Balance>>,
+	pub asset_out: Option<SlipFeeState<Balance>>,
 }
 
 /// Delta changes after a trade is executed
@@ -272,6 +295,23 @@ where
 	pub lp_hub_amount: Balance,
 }
 
+/// Value of a position if all its shares were removed.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct PositionValuation<Balance> {
+	/// Amount of asset received
+	pub asset_amount: Balance,
+	/// Amount of hub asset received
+	pub hub_amount: Balance,
+	/// Value of received amounts denominated in asset. Hub asset is converted at current spot price.
+	pub value: Balance,
+	/// Withdrawal fee applied to the received amounts
+	pub withdrawal_fee: FixedU128,
+	/// Value denominated in asset which is paid as withdrawal fee
+	pub withdrawal_fee_amount: Balance,
+	/// Loss of value before withdrawal fee compared to holding the position amount of asset
+	pub impermanent_loss: FixedU128,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Position<Balance> {
 	/// Amount of asset added to omnipool
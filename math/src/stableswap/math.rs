@@ -3,7 +3,9 @@ use crate::stableswap::types::{AssetReserve, PegDelta};
 use crate::support::rational::round_to_rational;
 use crate::to_u256;
 use crate::types::{AssetId, Balance, Ratio};
-use num_traits::{CheckedDiv, CheckedMul, CheckedSub, One, SaturatingAdd, SaturatingMul, SaturatingSub, Zero};
+use num_traits::{
+	CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, SaturatingAdd, SaturatingMul, SaturatingSub, Zero,
+};
 use primitive_types::U256;
 use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_arithmetic::per_things::Rounding as PTRounding;
@@ -123,6 +125,177 @@ pub fn calculate_in_given_out_with_fee<const D: u8, const Y: u8>(
 	Some((amount_in, fee_amount))
 }
 
+/// Calculate amount to be received from the pool given the amount to be sent to the pool with dynamic fee applied.
+/// Fee is scaled by `offpeg_multiplier` depending on the imbalance of traded assets - see `calculate_dynamic_fee`.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_out_given_in_with_dynamic_fee<const D: u8, const Y: u8>(
+	initial_reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	amplification: Balance,
+	base_fee: Permill,
+	offpeg_multiplier: FixedU128,
+	pegs: &[(Balance, Balance)],
+) -> Option<(Balance, Balance)> {
+	let amount_out = calculate_out_given_in::<D, Y>(initial_reserves, idx_in, idx_out, amount_in, amplification, pegs)?;
+	let fee = calculate_trade_dynamic_fee(
+		initial_reserves,
+		idx_in,
+		idx_out,
+		amount_in,
+		amount_out,
+		base_fee,
+		offpeg_multiplier,
+		pegs,
+	)?;
+	let fee_amount = calculate_fee_amount(amount_out, fee, Rounding::Down);
+	let amount_out = amount_out.checked_sub(fee_amount)?;
+	Some((amount_out, fee_amount))
+}
+
+/// Calculate amount to be sent to the pool given the amount to be received from the pool with dynamic fee applied.
+/// Fee is scaled by `offpeg_multiplier` depending on the imbalance of traded assets - see `calculate_dynamic_fee`.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_in_given_out_with_dynamic_fee<const D: u8, const Y: u8>(
+	initial_reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_out: Balance,
+	amplification: Balance,
+	base_fee: Permill,
+	offpeg_multiplier: FixedU128,
+	pegs: &[(Balance, Balance)],
+) -> Option<(Balance, Balance)> {
+	let amount_in = calculate_in_given_out::<D, Y>(initial_reserves, idx_in, idx_out, amount_out, amplification, pegs)?;
+	let fee = calculate_trade_dynamic_fee(
+		initial_reserves,
+		idx_in,
+		idx_out,
+		amount_in,
+		amount_out,
+		base_fee,
+		offpeg_multiplier,
+		pegs,
+	)?;
+	let fee_amount = calculate_fee_amount(amount_in, fee, Rounding::Up);
+	let amount_in = amount_in.checked_add(fee_amount)?;
+	Some((amount_in, fee_amount))
+}
+
+/// Calculate dynamic fee of a trade between two pool assets.
+/// Imbalance is measured on the average of pre-trade and post-trade reserves, normalized and adjusted by pegs.
+/// Zero amounts can be used to get the fee of a marginal trade at current reserves.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_trade_dynamic_fee(
+	initial_reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	amount_out: Balance,
+	base_fee: Permill,
+	offpeg_multiplier: FixedU128,
+	pegs: &[(Balance, Balance)],
+) -> Option<Permill> {
+	// Safety guards
+	let assets_ct = initial_reserves.len();
+	if idx_in >= assets_ct || idx_out >= assets_ct || pegs.len() != assets_ct {
+		return None;
+	}
+	let amount_in = normalize_value(
+		amount_in,
+		initial_reserves[idx_in].decimals,
+		TARGET_PRECISION,
+		Rounding::Down,
+	);
+	let amount_out = normalize_value(
+		amount_out,
+		initial_reserves[idx_out].decimals,
+		TARGET_PRECISION,
+		Rounding::Down,
+	);
+	let reserve_in = normalize_value(
+		initial_reserves[idx_in].amount,
+		initial_reserves[idx_in].decimals,
+		TARGET_PRECISION,
+		Rounding::Down,
+	)
+	.checked_add(amount_in / 2)?;
+	let reserve_out = normalize_value(
+		initial_reserves[idx_out].amount,
+		initial_reserves[idx_out].decimals,
+		TARGET_PRECISION,
+		Rounding::Down,
+	)
+	.checked_sub(amount_out / 2)?;
+	let pegged = peg_reserves(&[reserve_in, reserve_out], &[pegs[idx_in], pegs[idx_out]])?;
+
+	calculate_dynamic_fee(base_fee, offpeg_multiplier, pegged[0], pegged[1])
+}
+
+/// Calculate dynamic fee of adding or removing liquidity.
+/// Imbalance of each asset is measured against the average of normalized and pegged reserves of the pool,
+/// and the fee of the most imbalanced asset is used.
+pub fn calculate_liquidity_dynamic_fee(
+	reserves: &[AssetReserve],
+	base_fee: Permill,
+	offpeg_multiplier: FixedU128,
+	pegs: &[(Balance, Balance)],
+) -> Option<Permill> {
+	if reserves.is_empty() || pegs.len() != reserves.len() {
+		return None;
+	}
+	let pegged = peg_reserves(&normalize_reserves(reserves), pegs)?;
+	let average = pegged
+		.iter()
+		.try_fold(Balance::zero(), |acc, reserve| acc.checked_add(*reserve))?
+		.checked_div(pegged.len() as Balance)?;
+
+	pegged.iter().try_fold(base_fee, |fee, reserve| {
+		Some(fee.max(calculate_dynamic_fee(base_fee, offpeg_multiplier, *reserve, average)?))
+	})
+}
+
+/// Calculate fee scaled by imbalance of two reserves (Curve-style off-peg fee multiplier).
+///
+/// `fee = multiplier * base_fee / ((multiplier - 1) * 4 * x_i * x_j / (x_i + x_j)^2 + 1)`
+///
+/// Fee equals `base_fee` when reserves are balanced and approaches `multiplier * base_fee` as the imbalance grows.
+/// Multiplier less than or equal to one disables the dynamic fee.
+pub fn calculate_dynamic_fee(
+	base_fee: Permill,
+	offpeg_multiplier: FixedU128,
+	reserve_i: Balance,
+	reserve_j: Balance,
+) -> Option<Permill> {
+	if offpeg_multiplier <= FixedU128::one() {
+		return Some(base_fee);
+	}
+	let sum = reserve_i.checked_add(reserve_j)?;
+	if sum.is_zero() {
+		return Some(base_fee);
+	}
+
+	// 4 * x_i * x_j / (x_i + x_j)^2, expressed as (2 * x_i / sum) * (2 * x_j / sum) to avoid overflow
+	let ratio_i = FixedU128::checked_from_rational(reserve_i.checked_mul(2)?, sum)?;
+	let ratio_j = FixedU128::checked_from_rational(reserve_j.checked_mul(2)?, sum)?;
+	let balance_ratio = ratio_i.checked_mul(&ratio_j)?;
+
+	let denominator = offpeg_multiplier
+		.checked_sub(&FixedU128::one())?
+		.checked_mul(&balance_ratio)?
+		.checked_add(&FixedU128::one())?;
+	let fee = FixedU128::from(base_fee)
+		.checked_mul(&offpeg_multiplier)?
+		.checked_div(&denominator)?;
+
+	if fee >= FixedU128::one() {
+		Some(Permill::one())
+	} else {
+		Some(Permill::from_rational(fee.into_inner(), FixedU128::DIV))
+	}
+}
+
 /// Calculate amount of shares to be given to LP after LP provided liquidity of some assets to the pool.
 pub fn calculate_shares<const D: u8>(
 	initial_reserves: &[AssetReserve],
@@ -186,6 +359,78 @@ pub fn calculate_shares<const D: u8>(
 	}
 }
 
+/// Calculate amount of shares to be minted when a new asset with initial liquidity is added to the pool.
+///
+/// Shares are minted in proportion to the increase of D, so the value of existing shares is not diluted.
+pub fn calculate_shares_for_added_asset<const D: u8>(
+	initial_reserves: &[AssetReserve],
+	initial_pegs: &[(Balance, Balance)],
+	new_reserve: AssetReserve,
+	new_peg: (Balance, Balance),
+	amplification: Balance,
+	share_issuance: Balance,
+) -> Option<Balance> {
+	// Safety guards
+	let assets_ct = initial_reserves.len();
+	if assets_ct <= 1 || initial_pegs.len() != assets_ct {
+		return None;
+	}
+	let initial_d = calculate_d::<D>(initial_reserves, amplification, initial_pegs)?;
+
+	let mut updated_reserves = initial_reserves.to_vec();
+	updated_reserves.push(new_reserve);
+	let mut updated_pegs = initial_pegs.to_vec();
+	updated_pegs.push(new_peg);
+
+	// Updated D is rounded down so that the provider does not receive too many shares.
+	let updated_d = calculate_d::<D>(&updated_reserves, amplification, &updated_pegs)?.checked_sub(2_u128)?;
+	if updated_d < initial_d {
+		return None;
+	}
+
+	let (issuance_hp, d_diff, d0) = to_u256!(share_issuance, updated_d.checked_sub(initial_d)?, initial_d);
+	let share_amount = issuance_hp.checked_mul(d_diff)?.checked_div(d0)?;
+	Balance::try_from(share_amount).ok()
+}
+
+/// Calculate amount of shares to be burned when an asset, together with its remaining reserve, is removed from the pool.
+///
+/// Shares are burned in proportion to the decrease of D, so the value of remaining shares is not diluted.
+/// Returns zero if D does not decrease.
+pub fn calculate_shares_for_removed_asset<const D: u8>(
+	initial_reserves: &[AssetReserve],
+	asset_idx: usize,
+	amplification: Balance,
+	share_issuance: Balance,
+	pegs: &[(Balance, Balance)],
+) -> Option<Balance> {
+	// Safety guards
+	let assets_ct = initial_reserves.len();
+	if assets_ct <= 2 || asset_idx >= assets_ct || pegs.len() != assets_ct {
+		return None;
+	}
+	let initial_d = calculate_d::<D>(initial_reserves, amplification, pegs)?;
+
+	let mut updated_reserves = initial_reserves.to_vec();
+	updated_reserves.remove(asset_idx);
+	let mut updated_pegs = pegs.to_vec();
+	updated_pegs.remove(asset_idx);
+
+	// Updated D is rounded down so that enough shares are burned.
+	// Removing a drained asset can rebalance the pool and increase D, in which case no shares are burned.
+	let updated_d = calculate_d::<D>(&updated_reserves, amplification, &updated_pegs)?.saturating_sub(2_u128);
+	if updated_d >= initial_d {
+		return Some(0);
+	}
+
+	let (issuance_hp, d_diff, d0) = to_u256!(share_issuance, initial_d - updated_d, initial_d);
+	let share_amount = issuance_hp
+		.checked_mul(d_diff)?
+		.checked_add(d0.checked_sub(U256::one())?)?
+		.checked_div(d0)?;
+	Balance::try_from(share_amount).ok()
+}
+
 /// Calculate amount of shares to be given to LP after LP provided liquidity of one asset with given amount.
 pub fn calculate_shares_for_amount<const D: u8>(
 	initial_reserves: &[AssetReserve],
@@ -728,7 +973,7 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+pub fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
 	reserves
 		.iter()
 		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
@@ -0,0 +1,164 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use crate::stableswap::tests::{default_pegs, ONE};
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use sp_arithmetic::{FixedU128, Permill};
+
+#[test]
+fn dynamic_fee_should_equal_base_fee_when_reserves_are_balanced() {
+	let fee = calculate_dynamic_fee(Permill::from_percent(1), FixedU128::from(10), 1_000 * ONE, 1_000 * ONE);
+
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn dynamic_fee_should_equal_base_fee_when_multiplier_is_one() {
+	let fee = calculate_dynamic_fee(Permill::from_percent(1), FixedU128::from(1), 100 * ONE, 1_000 * ONE);
+
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn dynamic_fee_should_increase_with_imbalance() {
+	let base_fee = Permill::from_percent(1);
+	let multiplier = FixedU128::from(10);
+
+	let slightly_imbalanced = calculate_dynamic_fee(base_fee, multiplier, 900 * ONE, 1_100 * ONE).unwrap();
+	let imbalanced = calculate_dynamic_fee(base_fee, multiplier, 500 * ONE, 1_500 * ONE).unwrap();
+	let very_imbalanced = calculate_dynamic_fee(base_fee, multiplier, 10 * ONE, 1_990 * ONE).unwrap();
+
+	assert_eq!(slightly_imbalanced, Permill::from_parts(10_090));
+	assert_eq!(imbalanced, Permill::from_parts(12_903));
+	assert_eq!(very_imbalanced, Permill::from_parts(84_810));
+	assert!(very_imbalanced < Permill::from_percent(10));
+}
+
+#[test]
+fn dynamic_fee_should_not_exceed_one() {
+	let fee = calculate_dynamic_fee(Permill::from_percent(50), FixedU128::from(10), ONE, 1_000_000 * ONE);
+
+	assert_eq!(fee, Some(Permill::one()));
+}
+
+#[test]
+fn trade_worsening_imbalance_should_pay_higher_fee_than_rebalancing_trade() {
+	let amp = 100_u128;
+	let reserves = [AssetReserve::new(600 * ONE, 12), AssetReserve::new(1_400 * ONE, 12)];
+	let base_fee = Permill::from_percent(1);
+	let multiplier = FixedU128::from(5);
+
+	let (_, fee_rebalancing) = calculate_out_given_in_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+		&reserves,
+		0,
+		1,
+		100 * ONE,
+		amp,
+		base_fee,
+		multiplier,
+		&default_pegs(2),
+	)
+	.unwrap();
+	let (_, fee_worsening) = calculate_out_given_in_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+		&reserves,
+		1,
+		0,
+		100 * ONE,
+		amp,
+		base_fee,
+		multiplier,
+		&default_pegs(2),
+	)
+	.unwrap();
+
+	let (_, flat_fee) = calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+		&reserves,
+		1,
+		0,
+		100 * ONE,
+		amp,
+		base_fee,
+		&default_pegs(2),
+	)
+	.unwrap();
+
+	assert_eq!(fee_rebalancing, 1_118_537_819_972);
+	assert_eq!(fee_worsening, 1_176_246_974_983);
+	assert!(fee_worsening > flat_fee);
+}
+
+#[test]
+fn buy_with_dynamic_fee_should_include_fee_in_amount_in() {
+	let amp = 100_u128;
+	let reserves = [AssetReserve::new(600 * ONE, 12), AssetReserve::new(1_400 * ONE, 12)];
+
+	let amount_in =
+		calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 1, 0, 100 * ONE, amp, &default_pegs(2))
+			.unwrap();
+	let (amount_in_with_fee, fee) = calculate_in_given_out_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+		&reserves,
+		1,
+		0,
+		100 * ONE,
+		amp,
+		Permill::from_percent(1),
+		FixedU128::from(5),
+		&default_pegs(2),
+	)
+	.unwrap();
+
+	assert_eq!(amount_in_with_fee, amount_in + fee);
+	assert_eq!(fee, 1_210_352_709_315);
+}
+
+#[test]
+fn dynamic_fee_should_use_peg_adjusted_reserves() {
+	let reserves = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(2_000 * ONE, 12)];
+
+	let fee = calculate_trade_dynamic_fee(
+		&reserves,
+		0,
+		1,
+		0,
+		0,
+		Permill::from_percent(1),
+		FixedU128::from(10),
+		&[(1, 1), (1, 2)],
+	);
+
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn liquidity_dynamic_fee_should_equal_base_fee_when_pool_is_balanced() {
+	let reserves = [
+		AssetReserve::new(1_000 * ONE, 12),
+		AssetReserve::new(1_000 * ONE * 1_000_000, 18),
+		AssetReserve::new(2_000 * ONE, 12),
+	];
+
+	let fee = calculate_liquidity_dynamic_fee(
+		&reserves,
+		Permill::from_percent(1),
+		FixedU128::from(10),
+		&[(1, 1), (1, 1), (1, 2)],
+	);
+
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn liquidity_dynamic_fee_should_use_fee_of_most_imbalanced_asset() {
+	let reserves = [AssetReserve::new(600 * ONE, 12), AssetReserve::new(1_400 * ONE, 12)];
+	let base_fee = Permill::from_percent(1);
+	let multiplier = FixedU128::from(5);
+
+	let fee = calculate_liquidity_dynamic_fee(&reserves, base_fee, multiplier, &default_pegs(2)).unwrap();
+
+	let fee_0 = calculate_dynamic_fee(base_fee, multiplier, 600 * ONE * 1_000_000, 1_000 * ONE * 1_000_000).unwrap();
+	let fee_1 = calculate_dynamic_fee(base_fee, multiplier, 1_400 * ONE * 1_000_000, 1_000 * ONE * 1_000_000).unwrap();
+	assert!(fee_0 > fee_1);
+	assert_eq!(fee, fee_0);
+	assert!(fee > base_fee);
+}
@@ -1,4 +1,5 @@
 mod amplification;
+mod dynamic_fee;
 mod invariants;
 mod multi_assets;
 mod prices;
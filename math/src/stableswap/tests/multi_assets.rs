@@ -1,7 +1,7 @@
 const D_ITERATIONS: u8 = 128;
 const Y_ITERATIONS: u8 = 64;
 
-use crate::stableswap::tests::default_pegs;
+use crate::stableswap::tests::{default_pegs, ONE};
 use crate::stableswap::types::AssetReserve;
 use crate::stableswap::*;
 use crate::types::Balance;
@@ -971,3 +971,65 @@ fn calculate_shares_should_return_fees_for_all_assets() {
 
 	assert_eq!(result.1, vec![(3), (3), (12), (3), (3)]);
 }
+
+#[test]
+fn calculate_shares_for_added_asset_should_mint_shares_proportional_to_d_increase() {
+	let amp = 100_u128;
+	let initial_balances = [AssetReserve::new(1_000 * ONE, 12); 3];
+	let issuance: Balance = 3_000 * ONE;
+
+	let result = calculate_shares_for_added_asset::<D_ITERATIONS>(
+		&initial_balances,
+		&default_pegs(3),
+		AssetReserve::new(1_000 * ONE, 12),
+		(1, 1),
+		amp,
+		issuance,
+	);
+
+	assert_eq!(result, Some(999_999_999_999_999));
+}
+
+#[test]
+fn calculate_shares_for_added_asset_should_fail_when_d_decreases() {
+	let amp = 100_u128;
+	let initial_balances = [AssetReserve::new(1_000 * ONE, 12); 3];
+	let issuance: Balance = 3_000 * ONE;
+
+	let result = calculate_shares_for_added_asset::<D_ITERATIONS>(
+		&initial_balances,
+		&default_pegs(3),
+		AssetReserve::new(1, 12),
+		(1, 1),
+		amp,
+		issuance,
+	);
+
+	assert_eq!(result, None);
+}
+
+#[test]
+fn calculate_shares_for_removed_asset_should_burn_shares_proportional_to_d_decrease() {
+	let amp = 100_u128;
+	let balances = [AssetReserve::new(1_000 * ONE, 12); 4];
+	let issuance: Balance = 4_000 * ONE;
+
+	let result = calculate_shares_for_removed_asset::<D_ITERATIONS>(&balances, 3, amp, issuance, &default_pegs(4));
+
+	assert_eq!(result, Some(1_000_000_000_000_001));
+}
+
+#[test]
+fn calculate_shares_for_removed_asset_should_return_zero_when_d_increases() {
+	let amp = 100_u128;
+	let balances = [
+		AssetReserve::new(1_500 * ONE, 12),
+		AssetReserve::new(1_500 * ONE, 12),
+		AssetReserve::new(ONE, 12),
+	];
+	let issuance: Balance = 3_000 * ONE;
+
+	let result = calculate_shares_for_removed_asset::<D_ITERATIONS>(&balances, 2, amp, issuance, &default_pegs(3));
+
+	assert_eq!(result, Some(0));
+}
@@ -156,3 +156,28 @@ proptest! {
 		assert!(l >= r);
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn single_asset_swap_amount_should_match_pool_ratio_after_trade( asset_in_reserve in asset_reserve(),
+		asset_out_reserve in asset_reserve(),
+		amount in trade_amount(),
+		fee_n in 0u32..100u32,
+	) {
+		let fee = (fee_n, 10_000);
+		let swap_amount = crate::xyk::calculate_single_asset_swap_amount(asset_in_reserve, amount, fee).unwrap();
+
+		let amount_out = crate::xyk::calculate_out_given_in(asset_in_reserve, asset_out_reserve, swap_amount).unwrap();
+		let amount_out = amount_out - crate::fee::calculate_pool_trade_fee(amount_out, fee).unwrap();
+
+		let new_in_reserve = asset_in_reserve + swap_amount;
+		let new_out_reserve = asset_out_reserve - amount_out;
+		let amount_left = amount - swap_amount;
+
+		// amount left and amount received are in the pool ratio, up to rounding of the trade fee
+		let required_in = crate::xyk::calculate_liquidity_in(new_out_reserve, new_in_reserve, amount_out).unwrap();
+		let diff = required_in.abs_diff(amount_left);
+		assert!(diff <= amount / 1_000_000, "single asset swap amount - diff {diff} for amount {amount}");
+	}
+}
@@ -4,7 +4,7 @@ use crate::{
 };
 use core::convert::TryFrom;
 use num_traits::{CheckedMul, CheckedSub, Zero};
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 use sp_arithmetic::{FixedPointNumber, FixedU128};
 
 type Balance = u128;
@@ -216,3 +216,63 @@ pub fn calculate_shares(asset_reserve: Balance, asset_amount: Balance, share_iss
 
 	to_balance!(result).ok()
 }
+
+/// Calculating amount of asset to be sold to the pool so that the remaining amount and the amount received
+/// can be added as liquidity in the pool ratio after the trade.
+///
+/// Trade fee is deducted from the amount out, same as in a sell.
+///
+/// Formula : (sqrt(B^2 + 4 * D^2 * AMOUNT * IN_RESERVE) - B) / (2 * D)
+///   where fee = N / D and B = IN_RESERVE * (2 * D - N) - AMOUNT * N
+///
+/// - `in_reserve` - reserve amount of the provided asset
+/// - `amount` - amount of the provided asset
+/// - `fee` - trade fee of the pool
+///
+/// Returns MathError in case of error
+pub fn calculate_single_asset_swap_amount(
+	in_reserve: Balance,
+	amount: Balance,
+	fee: (u32, u32),
+) -> Result<Balance, MathError> {
+	ensure!(in_reserve != 0, ZeroReserve);
+
+	if amount.is_zero() {
+		return Ok(0);
+	}
+
+	// Zero denominator means no fee
+	let (fee_n, fee_d) = if fee.1 == 0 { (0, 1) } else { fee };
+	ensure!(fee_n <= fee_d, Overflow);
+
+	let (reserve_hp, amount_hp) = (U512::from(in_reserve), U512::from(amount));
+	let (fee_n_hp, fee_d_hp) = (U512::from(fee_n), U512::from(fee_d));
+
+	// B = IN_RESERVE * (2 * D - N) - AMOUNT * N, B can be negative
+	let b_positive = reserve_hp * (fee_d_hp * 2 - fee_n_hp);
+	let b_negative = amount_hp * fee_n_hp;
+	let (b_abs, b_is_negative) = if b_positive >= b_negative {
+		(b_positive - b_negative, false)
+	} else {
+		(b_negative - b_positive, true)
+	};
+
+	let discriminant = b_abs
+		.checked_mul(b_abs)
+		.and_then(|v| v.checked_add(fee_d_hp * fee_d_hp * 4 * amount_hp * reserve_hp))
+		.ok_or(Overflow)?;
+
+	let sqrt_discriminant = discriminant.integer_sqrt();
+
+	let numerator = if b_is_negative {
+		sqrt_discriminant.checked_add(b_abs)
+	} else {
+		sqrt_discriminant.checked_sub(b_abs)
+	}
+	.ok_or(Overflow)?;
+
+	let swap_amount_hp = numerator.checked_div(fee_d_hp * 2).ok_or(Overflow)?;
+
+	// Result is rounded down and never exceeds the provided amount
+	Ok(to_balance!(swap_amount_hp)?.min(amount))
+}
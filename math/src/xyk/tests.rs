@@ -138,3 +138,25 @@ fn calculate_shares() {
 		);
 	}
 }
+
+#[test]
+fn calculate_single_asset_swap_amount_should_work() {
+	let one: Balance = 1_000_000_000_000;
+
+	let cases = vec![
+		(100 * one, 10 * one, (2, 1000), Ok(4_886_009_181_491), "Easy case"),
+		(one, 1_000_000, (0, 0), Ok(499_999), "Zero fee"),
+		(one, 0, (2, 1000), Ok(0), "Zero amount"),
+		(0, one, (2, 1000), Err(ZeroReserve), "Zero reserve"),
+		(one, one, (2, 1), Err(Overflow), "Fee higher than 100%"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			crate::xyk::calculate_single_asset_swap_amount(case.0, case.1, case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+	}
+}
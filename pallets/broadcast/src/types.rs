@@ -9,6 +9,7 @@ pub type IncrementalIdType = u32;
 pub type OtcOrderId = u32;
 pub type ScheduleId = u32;
 
+// Not supported yet: ICE(solution_id/block id),      swapper: alice, filler: solver
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum Filler {
 	Omnipool,
@@ -16,7 +17,8 @@ pub enum Filler {
 	XYK(AssetId),        // share token
 	LBP,
 	OTC(OtcOrderId),
-	// ICE(solution_id/block id),      swapper: alice, filler: solver
+	Cryptoswap(AssetId),                     // pool id
+	ConcentratedLiquidity(AssetId, AssetId), // pool assets
 }
 
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
@@ -0,0 +1,273 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ASSET_A: u32 = 2_000;
+const ASSET_B: u32 = 2_001;
+
+const ONE: Balance = 1_000_000_000_000;
+
+// Wide position spans two bitmap words in each direction, so all trades stay in range.
+const WIDE_RANGE_MULTIPLIER: i32 = 512;
+
+fn create_pool<T: Config>()
+where
+	T::AssetId: From<u32>,
+{
+	T::BenchmarkHelper::register_asset(ASSET_A.into(), 12).expect("Failed to register asset");
+	T::BenchmarkHelper::register_asset(ASSET_B.into(), 12).expect("Failed to register asset");
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().expect("Failed to get successful origin");
+	crate::Pallet::<T>::create_pool(successful_origin, ASSET_A.into(), ASSET_B.into(), FixedU128::from(1))
+		.expect("Failed to create pool");
+}
+
+fn create_position<T: Config>(
+	acc: &T::AccountId,
+	tick_lower: i32,
+	tick_upper: i32,
+	amount: Balance,
+) -> T::PositionItemId
+where
+	T::AssetId: From<u32>,
+{
+	let position_id = NextPositionId::<T>::get();
+	crate::Pallet::<T>::create_position(
+		RawOrigin::Signed(acc.clone()).into(),
+		ASSET_A.into(),
+		ASSET_B.into(),
+		tick_lower,
+		tick_upper,
+		amount,
+		amount,
+	)
+	.expect("Failed to create position");
+	position_id
+}
+
+/// Creates pool with a wide position and `n` nested positions in ranges `[-k * spacing, k * spacing]` for `k` in `1..=n`.
+/// Selling `ASSET_A` moves the price down and crosses lower ticks of the nested positions one by one.
+fn setup_pool_with_positions<T: Config>(acc: &T::AccountId, n: u32) -> T::PositionItemId
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	create_pool::<T>();
+
+	T::Currency::update_balance(ASSET_A.into(), acc, (2_000_000 * ONE) as i128).expect("Failed to update balance");
+	T::Currency::update_balance(ASSET_B.into(), acc, (2_000_000 * ONE) as i128).expect("Failed to update balance");
+
+	let spacing = T::TickSpacing::get();
+	let position_id = create_position::<T>(
+		acc,
+		-WIDE_RANGE_MULTIPLIER * spacing,
+		WIDE_RANGE_MULTIPLIER * spacing,
+		1_000_000 * ONE,
+	);
+
+	for k in 1..=n as i32 {
+		create_position::<T>(acc, -k * spacing, k * spacing, 1_000 * ONE);
+	}
+
+	position_id
+}
+
+/// Finds smallest amount (with 10% precision) of a trade selling `ASSET_A` for `ASSET_B` which crosses `n` ticks.
+fn trade_amount_crossing_ticks<T: Config>(n: u32, exact_in: bool) -> Balance
+where
+	T::AssetId: From<u32>,
+{
+	let mut amount = ONE;
+	while crate::Pallet::<T>::calculate_swap(ASSET_A.into(), ASSET_B.into(), amount, exact_in)
+		.expect("Failed to calculate swap")
+		.crossed_ticks
+		.len() < n as usize
+	{
+		amount = amount.saturating_add(amount / 10);
+	}
+	amount
+}
+
+benchmarks! {
+	 where_clause {  where T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T: crate::pallet::Config,
+	}
+
+	create_pool {
+		T::BenchmarkHelper::register_asset(ASSET_A.into(), 12)?;
+		T::BenchmarkHelper::register_asset(ASSET_B.into(), 12)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, ASSET_B.into(), ASSET_A.into(), FixedU128::from(2))
+	verify {
+		assert!(<Pools<T>>::get((T::AssetId::from(ASSET_A), T::AssetId::from(ASSET_B))).is_some());
+	}
+
+	create_position {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, 0);
+
+		// worst case is when both ticks of the position are initialized
+		let spacing = T::TickSpacing::get();
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &caller, (1_000 * ONE) as i128)?;
+		T::Currency::update_balance(ASSET_B.into(), &caller, (1_000 * ONE) as i128)?;
+		let position_id = NextPositionId::<T>::get();
+	}: _(RawOrigin::Signed(caller.clone()), ASSET_A.into(), ASSET_B.into(), -spacing, spacing, 1_000 * ONE, 1_000 * ONE)
+	verify {
+		assert!(<Positions<T>>::get(position_id).is_some());
+	}
+
+	add_liquidity {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let position_id = setup_pool_with_positions::<T>(&lp_provider, 0);
+		let liquidity_before = <Positions<T>>::get(position_id).expect("Position not found").liquidity;
+	}: _(RawOrigin::Signed(lp_provider.clone()), position_id, 1_000 * ONE, 1_000 * ONE)
+	verify {
+		assert!(<Positions<T>>::get(position_id).expect("Position not found").liquidity > liquidity_before);
+	}
+
+	remove_liquidity {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let position_id = setup_pool_with_positions::<T>(&lp_provider, 0);
+
+		// worst case is when position earned fees and all liquidity is removed
+		let trader: T::AccountId = account("trader", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &trader, (100 * ONE) as i128)?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader).into(), ASSET_A.into(), ASSET_B.into(), 100 * ONE, Balance::zero())?;
+
+		let liquidity = <Positions<T>>::get(position_id).expect("Position not found").liquidity;
+	}: _(RawOrigin::Signed(lp_provider.clone()), position_id, liquidity, Balance::zero(), Balance::zero())
+	verify {
+		assert!(<Positions<T>>::get(position_id).is_none());
+	}
+
+	collect_fees {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let position_id = setup_pool_with_positions::<T>(&lp_provider, 0);
+
+		let trader: T::AccountId = account("trader", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &trader, (100 * ONE) as i128)?;
+		T::Currency::update_balance(ASSET_B.into(), &trader, (100 * ONE) as i128)?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader.clone()).into(), ASSET_A.into(), ASSET_B.into(), 100 * ONE, Balance::zero())?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader).into(), ASSET_B.into(), ASSET_A.into(), 100 * ONE, Balance::zero())?;
+
+		let balance_a = T::Currency::free_balance(ASSET_A.into(), &lp_provider);
+		let balance_b = T::Currency::free_balance(ASSET_B.into(), &lp_provider);
+	}: collect(RawOrigin::Signed(lp_provider.clone()), position_id)
+	verify {
+		assert!(T::Currency::free_balance(ASSET_A.into(), &lp_provider) > balance_a);
+		assert!(T::Currency::free_balance(ASSET_B.into(), &lp_provider) > balance_b);
+	}
+
+	sell {
+		// number of swap steps - the initial word boundary, crossed ticks and the final step
+		let s in 2 .. T::MaxSwapSteps::get();
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, s - 2);
+		let amount_sell = trade_amount_crossing_ticks::<T>(s - 2, true);
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &seller, amount_sell as i128)?;
+	}: _(RawOrigin::Signed(seller.clone()), ASSET_A.into(), ASSET_B.into(), amount_sell, Balance::zero())
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_A.into(), &seller), 0u128);
+		assert!(T::Currency::free_balance(ASSET_B.into(), &seller) > 0u128);
+	}
+
+	buy {
+		let s in 2 .. T::MaxSwapSteps::get();
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, s - 2);
+		let amount_buy = trade_amount_crossing_ticks::<T>(s - 2, false);
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &buyer, (1_000_000 * ONE) as i128)?;
+	}: _(RawOrigin::Signed(buyer.clone()), ASSET_B.into(), ASSET_A.into(), amount_buy, 1_000_000 * ONE)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_B.into(), &buyer), amount_buy);
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// number of times calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+		let s in 2 .. T::MaxSwapSteps::get();
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, s - 2);
+		let amount_sell = trade_amount_crossing_ticks::<T>(s - 2, true);
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &seller, amount_sell as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount_sell).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount_sell, Balance::zero()).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_A.into(), &seller), 0u128);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..2;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+		let s in 2 .. T::MaxSwapSteps::get();
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, s - 2);
+		let amount_buy = trade_amount_crossing_ticks::<T>(s - 2, false);
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &buyer, (1_000_000 * ONE) as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount_buy).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount_buy, 1_000_000 * ONE).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_B.into(), &buyer), amount_buy);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		setup_pool_with_positions::<T>(&lp_provider, 0);
+	}: {
+		assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::ConcentratedLiquidity, ASSET_B.into(), ASSET_A.into()).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1275 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! Constant product AMM for asset pairs where LPs provide liquidity within chosen price ranges.
+//!
+//! ## Overview
+//!
+//! Price space of a pool is divided into ticks, price at tick `i` is `1.0001^i`. Position of an LP provides
+//! liquidity between two ticks which are multiples of `TickSpacing`. Within a range between two initialized ticks
+//! the pool behaves like a constant product pool with virtual reserves given by the sum of liquidity of the
+//! positions which contain current price. When a trade crosses an initialized tick, liquidity of the positions
+//! starting or ending at the tick is added or removed.
+//!
+//! Positions are represented by NFTs minted into `NFTCollectionId` collection. Owner of an NFT can change
+//! liquidity of the position and collect fees earned by the position.
+//!
+//! Trade fee `TradeFee` is charged in asset in and distributed to positions in range proportionally to their
+//! liquidity.
+//!
+//! ### Hooks
+//! - `AMMHandler` is notified of each trade and liquidity change, which is used to update on-chain oracle.
+//!
+//! ### Terminology
+//!
+//! * **LP** - liquidity provider
+//! * **Tick** - boundary of a price range, price at tick `i` is `1.0001^i`
+//! * **Pool price** - price of asset 0 denominated in asset 1 (amount of asset 1 per unit of asset 0), in the smallest
+//!   units of the assets. Assets of a pool are ordered by asset id.
+//!
+//! ## Assumptions
+//!
+//! A pool can be created only by allowed `AuthorityOrigin`. There is at most one pool for an asset pair.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::manual_inspect)]
+
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect as NFTInspect, Mutate};
+use frame_support::{ensure, require_transactional, transactional, PalletId};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use hydra_dx_math::concentrated_liquidity as math;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{
+	oracle::{OracleWhitelistHandler, Source},
+	registry::Inspect,
+	AssetPairAccountIdFor, OnLiquidityChangedHandler, OnTradeHandler,
+};
+use orml_traits::MultiCurrency;
+pub use pallet::*;
+use pallet_broadcast::types::{Asset, Destination, Fee};
+use sp_core::U256;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_std::prelude::*;
+use sp_std::vec;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{Balance, PoolInfo, Position, SwapResult, TickInfo};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// Concentrated liquidity account id identifier.
+/// Used as identifier to create pool account id.
+pub const POOL_IDENTIFIER: &str = "clxyk";
+
+pub type NFTCollectionIdOf<T> =
+	<<T as Config>::NFTHandler as NFTInspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_broadcast::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo
+			+ Into<u32>;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Asset registry mechanism to check if asset is registered.
+		type AssetRegistry: Inspect<AssetId = Self::AssetId>;
+
+		/// Account ID constructor - pool accounts are derived from pool assets
+		type AssetPairAccountId: AssetPairAccountIdFor<Self::AssetId, Self::AccountId>;
+
+		/// The origin which can create a new pool
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Pallet id - owner of the position NFT collection.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Trade fee charged in asset in.
+		#[pallet::constant]
+		type TradeFee: Get<Permill>;
+
+		/// Ticks of position ranges must be multiples of tick spacing.
+		#[pallet::constant]
+		type TickSpacing: Get<i32>;
+
+		/// Maximum number of steps of a trade. Trade moves to next step when it crosses an initialized tick
+		/// or a word of tick bitmap.
+		#[pallet::constant]
+		type MaxSwapSteps: Get<u32>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Whitelist of asset pairs whose oracles are tracked. Pool assets are added on pool creation.
+		type OracleWhitelist: OracleWhitelistHandler<Self::AssetId>;
+
+		/// AMM handlers called on trades and liquidity changes.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Position identifier type
+		type PositionItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Collection id type
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible class id
+		#[pallet::constant]
+		type NFTCollectionId: Get<NFTCollectionIdOf<Self>>;
+
+		/// Non fungible handling - mint,burn, check owner
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ NFTInspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Existing pools, keyed by ordered asset pair.
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), PoolInfo>;
+
+	/// Initialized ticks of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn ticks)]
+	pub type Ticks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), Twox64Concat, i32, TickInfo>;
+
+	/// Bitmap of initialized ticks of a pool. Tick `t` is represented by bit `(t / TickSpacing) % 256`
+	/// of word `(t / TickSpacing) / 256`.
+	#[pallet::storage]
+	pub type TickBitmap<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), Twox64Concat, i16, U256, ValueQuery>;
+
+	/// LP positions, keyed by position NFT id.
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Position<T::AssetId>>;
+
+	/// Position ids sequencer
+	#[pallet::storage]
+	#[pallet::getter(fn next_position_id)]
+	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			sqrt_price: U256,
+			tick: i32,
+		},
+		/// A position was created and position NFT was minted.
+		PositionCreated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			tick_lower: i32,
+			tick_upper: i32,
+			liquidity: u128,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Liquidity was added to a position.
+		LiquidityAdded {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			liquidity: u128,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Liquidity was removed from a position.
+		LiquidityRemoved {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			liquidity: u128,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Fees earned by a position were collected.
+		FeesCollected {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// All liquidity was removed from a position, position was destroyed and its NFT was burned.
+		PositionDestroyed {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool with same assets is not allowed.
+		IncorrectAssets,
+
+		/// Pool for given asset pair does not exist.
+		PoolNotFound,
+
+		/// Pool for given asset pair already exists.
+		PoolExists,
+
+		/// One or more assets are not registered in AssetRegistry
+		AssetNotRegistered,
+
+		/// Initial price is zero or out of the supported price range.
+		InvalidInitialPrice,
+
+		/// Ticks of a position are out of bounds, not ordered or not multiples of tick spacing.
+		InvalidTickRange,
+
+		/// Invalid asset amount provided. Amount must be greater than zero.
+		InvalidAssetAmount,
+
+		/// Amounts provided result in zero liquidity.
+		InsufficientLiquidityAmount,
+
+		/// Position does not have enough liquidity.
+		InsufficientPositionLiquidity,
+
+		/// Balance of an asset is not sufficient to perform an operation.
+		InsufficientBalance,
+
+		/// Amount is less than the minimum trading amount configured.
+		InsufficientTradingAmount,
+
+		/// Pool does not have enough liquidity in the price range to execute the trade.
+		InsufficientLiquidity,
+
+		/// Trade crosses more ticks than allowed.
+		MaxSwapStepsExceeded,
+
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+
+		/// Slippage protection.
+		SlippageLimit,
+
+		/// Position does not exist.
+		PositionNotFound,
+
+		/// Signer is not the owner of the position.
+		Forbidden,
+
+		/// Not allowed to perform an operation on given asset.
+		NotAllowed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a concentrated liquidity pool for an asset pair.
+		///
+		/// Pool does not contain any liquidity when created. Use `create_position` to provide liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `asset_a`: First pool asset
+		/// - `asset_b`: Second pool asset
+		/// - `initial_price`: Price of `asset_a` denominated in `asset_b`, in the smallest units of the assets
+		///
+		/// Emits `PoolCreated` event if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool()
+			.saturating_add(T::OracleWhitelist::add_to_whitelist_weight()))]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			initial_price: FixedU128,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::IncorrectAssets);
+			ensure!(
+				T::AssetRegistry::exists(asset_a) && T::AssetRegistry::exists(asset_b),
+				Error::<T>::AssetNotRegistered
+			);
+
+			let assets = Self::ordered_assets(asset_a, asset_b);
+			ensure!(!Pools::<T>::contains_key(assets), Error::<T>::PoolExists);
+
+			// pool price is the price of asset 0 denominated in asset 1
+			let price = if asset_a == assets.0 {
+				Some(initial_price)
+			} else {
+				initial_price.reciprocal()
+			};
+			let sqrt_price = price
+				.filter(|p| !p.is_zero())
+				.and_then(math::sqrt_price_from_price)
+				.ok_or(Error::<T>::InvalidInitialPrice)?;
+			let tick = math::tick_at_sqrt_price(sqrt_price).ok_or(Error::<T>::InvalidInitialPrice)?;
+
+			Pools::<T>::insert(
+				assets,
+				PoolInfo {
+					sqrt_price,
+					tick,
+					liquidity: 0,
+					fee_growth_global_0: U256::zero(),
+					fee_growth_global_1: U256::zero(),
+					reserve_0: 0,
+					reserve_1: 0,
+				},
+			);
+
+			T::NonDustableWhitelistHandler::add_account(&Self::pool_account(assets))?;
+			T::OracleWhitelist::add_to_whitelist(T::OracleSource::get(), assets.0, assets.1)?;
+
+			// ensure collection is created, we can simply ignore the error if it was already created.
+			let _ =
+				T::NFTHandler::create_collection(&T::NFTCollectionId::get(), &Self::account_id(), &Self::account_id());
+
+			Self::deposit_event(Event::PoolCreated {
+				asset_a: assets.0,
+				asset_b: assets.1,
+				sqrt_price,
+				tick,
+			});
+
+			Ok(())
+		}
+
+		/// Create a new position which provides liquidity in a price range.
+		///
+		/// Maximum liquidity which can be provided with given amounts in the price range is added to the pool.
+		/// Amounts of assets transferred from the LP depend on the current pool price - only `asset_a` is needed
+		/// when the price is below the range, only `asset_b` when the price is above the range.
+		///
+		/// Position NFT is minted to the LP.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `asset_a`: First pool asset
+		/// - `asset_b`: Second pool asset
+		/// - `tick_lower`: Lower tick of the price range
+		/// - `tick_upper`: Upper tick of the price range
+		/// - `amount_a_max`: Maximum amount of `asset_a` to provide
+		/// - `amount_b_max`: Maximum amount of `asset_b` to provide
+		///
+		/// Emits `PositionCreated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_position()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn create_position(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			tick_lower: i32,
+			tick_upper: i32,
+			amount_a_max: Balance,
+			amount_b_max: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::IncorrectAssets);
+			Self::ensure_valid_tick_range(tick_lower, tick_upper)?;
+
+			let assets = Self::ordered_assets(asset_a, asset_b);
+			let pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+			let (amount_0_max, amount_1_max) = if asset_a == assets.0 {
+				(amount_a_max, amount_b_max)
+			} else {
+				(amount_b_max, amount_a_max)
+			};
+			let liquidity = Self::liquidity_for_amounts(&pool, tick_lower, tick_upper, amount_0_max, amount_1_max)?;
+
+			let mut position = Position {
+				assets,
+				tick_lower,
+				tick_upper,
+				liquidity: 0,
+				fee_growth_inside_0_last: U256::zero(),
+				fee_growth_inside_1_last: U256::zero(),
+				tokens_owed_0: 0,
+				tokens_owed_1: 0,
+			};
+			let (amount_0, amount_1) = Self::modify_position(&mut position, Self::to_liquidity_delta(liquidity)?)?;
+			ensure!(
+				amount_0 <= amount_0_max && amount_1 <= amount_1_max,
+				Error::<T>::SlippageLimit
+			);
+
+			Self::transfer_to_pool(&who, assets, amount_0, amount_1)?;
+
+			let position_id = Self::create_and_mint_position_instance(&who)?;
+			Positions::<T>::insert(position_id, position);
+
+			Self::call_on_liquidity_changed_hook(assets, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id,
+				owner: who.clone(),
+				asset_a: assets.0,
+				asset_b: assets.1,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_a: amount_0,
+				amount_b: amount_1,
+			});
+
+			Self::deposit_liquidity_event(who, assets, amount_0, amount_1, true);
+
+			Ok(())
+		}
+
+		/// Add liquidity to an existing position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: Id of the position
+		/// - `amount_a_max`: Maximum amount of the first asset (asset with lower id) of the pool to provide
+		/// - `amount_b_max`: Maximum amount of the second asset of the pool to provide
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount_a_max: Balance,
+			amount_b_max: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::load_position(position_id, &who)?;
+			let assets = position.assets;
+			let pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+			let liquidity = Self::liquidity_for_amounts(
+				&pool,
+				position.tick_lower,
+				position.tick_upper,
+				amount_a_max,
+				amount_b_max,
+			)?;
+			let (amount_0, amount_1) = Self::modify_position(&mut position, Self::to_liquidity_delta(liquidity)?)?;
+			ensure!(
+				amount_0 <= amount_a_max && amount_1 <= amount_b_max,
+				Error::<T>::SlippageLimit
+			);
+
+			Self::transfer_to_pool(&who, assets, amount_0, amount_1)?;
+			Positions::<T>::insert(position_id, position);
+
+			Self::call_on_liquidity_changed_hook(assets, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				position_id,
+				who: who.clone(),
+				liquidity,
+				amount_a: amount_0,
+				amount_b: amount_1,
+			});
+
+			Self::deposit_liquidity_event(who, assets, amount_0, amount_1, true);
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a position.
+		///
+		/// LP receives assets corresponding to removed liquidity together with all fees earned by the position.
+		/// If all liquidity is removed, position is destroyed and position NFT is burned.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: Id of the position
+		/// - `liquidity`: Amount of liquidity to remove
+		/// - `min_amount_a`: Minimum amount of the first asset (asset with lower id) of the pool to receive, fees excluded
+		/// - `min_amount_b`: Minimum amount of the second asset of the pool to receive, fees excluded
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		/// Emits `FeesCollected` event when position earned any fees.
+		/// Emits `PositionDestroyed` event when all liquidity is removed.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			liquidity: u128,
+			min_amount_a: Balance,
+			min_amount_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!liquidity.is_zero(), Error::<T>::InvalidAssetAmount);
+
+			let mut position = Self::load_position(position_id, &who)?;
+			ensure!(
+				position.liquidity >= liquidity,
+				Error::<T>::InsufficientPositionLiquidity
+			);
+			let assets = position.assets;
+
+			let liquidity_delta = Self::to_liquidity_delta(liquidity)?
+				.checked_neg()
+				.ok_or(ArithmeticError::Overflow)?;
+			let (amount_0, amount_1) = Self::modify_position(&mut position, liquidity_delta)?;
+			ensure!(
+				amount_0 >= min_amount_a && amount_1 >= min_amount_b,
+				Error::<T>::SlippageLimit
+			);
+
+			Self::transfer_from_pool(&who, assets, amount_0, amount_1)?;
+			Self::collect_fees(position_id, &who, &mut position)?;
+
+			if position.liquidity.is_zero() {
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+				Positions::<T>::remove(position_id);
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id,
+					owner: who.clone(),
+				});
+			} else {
+				Positions::<T>::insert(position_id, position);
+			}
+
+			Self::call_on_liquidity_changed_hook(assets, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				position_id,
+				who: who.clone(),
+				liquidity,
+				amount_a: amount_0,
+				amount_b: amount_1,
+			});
+
+			Self::deposit_liquidity_event(who, assets, amount_0, amount_1, false);
+
+			Ok(())
+		}
+
+		/// Collect fees earned by a position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: Id of the position
+		///
+		/// Emits `FeesCollected` event when position earned any fees.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		#[transactional]
+		pub fn collect(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::load_position(position_id, &who)?;
+
+			// update position to account for fees earned since last update
+			Self::modify_position(&mut position, 0)?;
+			Self::collect_fees(position_id, &who, &mut position)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out`.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset to be sold to the pool
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(T::MaxSwapSteps::get())
+							.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let swap = Self::calculate_swap(asset_in, asset_out, amount_in, true)?;
+			ensure!(swap.amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			Self::execute_swap(who, asset_in, asset_out, swap, true)
+		}
+
+		/// Execute a swap of `asset_out` for `asset_in`.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: Amount of asset to receive from the pool
+		/// - `max_sell_amount`: Maximum amount allowed to be sold
+		///
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(T::MaxSwapSteps::get())
+							.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let swap = Self::calculate_swap(asset_in, asset_out, amount_out, false)?;
+			ensure!(swap.amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= swap.amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			Self::execute_swap(who, asset_in, asset_out, swap, false)
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account owning the position NFT collection.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	#[inline]
+	pub(crate) fn pool_account(assets: (T::AssetId, T::AssetId)) -> T::AccountId {
+		T::AssetPairAccountId::from_assets(assets.0, assets.1, POOL_IDENTIFIER)
+	}
+
+	#[inline]
+	pub(crate) fn ordered_assets(asset_a: T::AssetId, asset_b: T::AssetId) -> (T::AssetId, T::AssetId) {
+		if asset_a < asset_b {
+			(asset_a, asset_b)
+		} else {
+			(asset_b, asset_a)
+		}
+	}
+
+	fn ensure_valid_tick_range(tick_lower: i32, tick_upper: i32) -> DispatchResult {
+		let tick_spacing = T::TickSpacing::get();
+		ensure!(
+			tick_lower < tick_upper
+				&& tick_lower >= math::MIN_TICK
+				&& tick_upper <= math::MAX_TICK
+				&& tick_lower % tick_spacing == 0
+				&& tick_upper % tick_spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+		Ok(())
+	}
+
+	fn to_liquidity_delta(liquidity: u128) -> Result<i128, DispatchError> {
+		i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow.into())
+	}
+
+	fn liquidity_for_amounts(
+		pool: &PoolInfo,
+		tick_lower: i32,
+		tick_upper: i32,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> Result<u128, DispatchError> {
+		let sqrt_price_lower = math::sqrt_price_at_tick(tick_lower).ok_or(Error::<T>::InvalidTickRange)?;
+		let sqrt_price_upper = math::sqrt_price_at_tick(tick_upper).ok_or(Error::<T>::InvalidTickRange)?;
+		let liquidity =
+			math::liquidity_for_amounts(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, amount_0, amount_1)
+				.ok_or(ArithmeticError::Overflow)?;
+		ensure!(!liquidity.is_zero(), Error::<T>::InsufficientLiquidityAmount);
+		Ok(liquidity)
+	}
+
+	fn load_position(
+		position_id: T::PositionItemId,
+		who: &T::AccountId,
+	) -> Result<Position<T::AssetId>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).as_ref() == Some(who),
+			Error::<T>::Forbidden
+		);
+		Ok(position)
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// Updates liquidity of a position, ticks of its price range and pool liquidity.
+	///
+	/// Fees earned by the position since its last update are added to tokens owed by the position.
+	/// Position itself is not stored.
+	///
+	/// Returns amounts of pool assets which correspond to the liquidity change - rounded up when liquidity
+	/// is added, rounded down when liquidity is removed.
+	#[require_transactional]
+	fn modify_position(
+		position: &mut Position<T::AssetId>,
+		liquidity_delta: i128,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let assets = position.assets;
+		let mut pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+		let lower = Self::update_tick(assets, position.tick_lower, &pool, liquidity_delta, false)?;
+		let upper = Self::update_tick(assets, position.tick_upper, &pool, liquidity_delta, true)?;
+
+		let fee_growth_inside_0 = math::fee_growth_inside(
+			position.tick_lower,
+			position.tick_upper,
+			pool.tick,
+			pool.fee_growth_global_0,
+			lower.fee_growth_outside_0,
+			upper.fee_growth_outside_0,
+		);
+		let fee_growth_inside_1 = math::fee_growth_inside(
+			position.tick_lower,
+			position.tick_upper,
+			pool.tick,
+			pool.fee_growth_global_1,
+			lower.fee_growth_outside_1,
+			upper.fee_growth_outside_1,
+		);
+
+		let fees_0 = math::fees_earned(
+			fee_growth_inside_0,
+			position.fee_growth_inside_0_last,
+			position.liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let fees_1 = math::fees_earned(
+			fee_growth_inside_1,
+			position.fee_growth_inside_1_last,
+			position.liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		position.liquidity =
+			math::add_liquidity_delta(position.liquidity, liquidity_delta).ok_or(ArithmeticError::Overflow)?;
+		position.fee_growth_inside_0_last = fee_growth_inside_0;
+		position.fee_growth_inside_1_last = fee_growth_inside_1;
+		position.tokens_owed_0 = position
+			.tokens_owed_0
+			.checked_add(fees_0)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.tokens_owed_1 = position
+			.tokens_owed_1
+			.checked_add(fees_1)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if liquidity_delta.is_zero() {
+			return Ok((0, 0));
+		}
+
+		if pool.tick >= position.tick_lower && pool.tick < position.tick_upper {
+			pool.liquidity =
+				math::add_liquidity_delta(pool.liquidity, liquidity_delta).ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let sqrt_price_lower = math::sqrt_price_at_tick(position.tick_lower).ok_or(Error::<T>::InvalidTickRange)?;
+		let sqrt_price_upper = math::sqrt_price_at_tick(position.tick_upper).ok_or(Error::<T>::InvalidTickRange)?;
+		let amounts = math::amounts_for_liquidity(
+			pool.sqrt_price,
+			sqrt_price_lower,
+			sqrt_price_upper,
+			liquidity_delta.unsigned_abs(),
+			liquidity_delta > 0,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if liquidity_delta > 0 {
+			pool.reserve_0 = pool.reserve_0.checked_add(amounts.0).ok_or(ArithmeticError::Overflow)?;
+			pool.reserve_1 = pool.reserve_1.checked_add(amounts.1).ok_or(ArithmeticError::Overflow)?;
+		} else {
+			// removed amounts are rounded down, trades are rounded in favor of the pool,
+			// so the reserves are never lower than the amounts - saturating just in case
+			pool.reserve_0 = pool.reserve_0.saturating_sub(amounts.0);
+			pool.reserve_1 = pool.reserve_1.saturating_sub(amounts.1);
+		}
+		Pools::<T>::insert(assets, pool);
+
+		Ok(amounts)
+	}
+
+	/// Applies liquidity delta of a position to one of the boundary ticks of its range.
+	///
+	/// Tick is initialized when it becomes a boundary of first position and cleared when no position uses it.
+	/// Returns tick state after the update.
+	fn update_tick(
+		assets: (T::AssetId, T::AssetId),
+		tick: i32,
+		pool: &PoolInfo,
+		liquidity_delta: i128,
+		upper: bool,
+	) -> Result<TickInfo, DispatchError> {
+		let mut info = Ticks::<T>::get(assets, tick).unwrap_or_default();
+		let liquidity_gross_before = info.liquidity_gross;
+		info.liquidity_gross =
+			math::add_liquidity_delta(liquidity_gross_before, liquidity_delta).ok_or(ArithmeticError::Overflow)?;
+
+		if liquidity_gross_before.is_zero() && tick <= pool.tick {
+			// by convention, all fees are assumed to be collected below the tick
+			(info.fee_growth_outside_0, info.fee_growth_outside_1) = pool.fee_growth_global();
+		}
+
+		info.liquidity_net = if upper {
+			info.liquidity_net.checked_sub(liquidity_delta)
+		} else {
+			info.liquidity_net.checked_add(liquidity_delta)
+		}
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if liquidity_gross_before.is_zero() != info.liquidity_gross.is_zero() {
+			Self::flip_tick(assets, tick);
+		}
+
+		if info.liquidity_gross.is_zero() {
+			Ticks::<T>::remove(assets, tick);
+		} else {
+			Ticks::<T>::insert(assets, tick, info.clone());
+		}
+
+		Ok(info)
+	}
+
+	fn flip_tick(assets: (T::AssetId, T::AssetId), tick: i32) {
+		let (word, bit) = math::tick_bitmap_position(tick / T::TickSpacing::get());
+		TickBitmap::<T>::mutate(assets, word, |value| *value ^= U256::one() << bit as usize);
+	}
+
+	/// Simulates a swap of `asset_in` for `asset_out` without changing the state of the pool.
+	///
+	/// `exact_in` - `amount` is amount in, otherwise it is amount out.
+	///
+	/// Returns result of the swap together with the updated pool state which can be committed by `execute_swap`.
+	pub(crate) fn calculate_swap(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<SwapResult, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAssetAmount);
+
+		let assets = Self::ordered_assets(asset_in, asset_out);
+		let mut pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+		let zero_for_one = asset_in == assets.0;
+		let sqrt_price_limit = if zero_for_one {
+			math::MIN_SQRT_PRICE + 1
+		} else {
+			math::MAX_SQRT_PRICE - 1
+		};
+		let tick_spacing = T::TickSpacing::get();
+		let fee = T::TradeFee::get();
+
+		let mut amount_remaining = amount;
+		let mut amount_calculated: Balance = 0;
+		let mut fee_amount: Balance = 0;
+		let mut crossed_ticks = Vec::new();
+		let mut steps = 0u32;
+
+		while !amount_remaining.is_zero() && pool.sqrt_price != sqrt_price_limit {
+			ensure!(steps < T::MaxSwapSteps::get(), Error::<T>::MaxSwapStepsExceeded);
+			steps = steps.saturating_add(1);
+
+			let (tick_next, initialized) = math::next_initialized_tick_within_one_word(
+				|word| TickBitmap::<T>::get(assets, word),
+				pool.tick,
+				tick_spacing,
+				zero_for_one,
+			);
+			let tick_next = tick_next.clamp(math::MIN_TICK, math::MAX_TICK);
+			let sqrt_price_next = math::sqrt_price_at_tick(tick_next).ok_or(ArithmeticError::Overflow)?;
+			let sqrt_price_target = if zero_for_one {
+				sqrt_price_next.max(sqrt_price_limit)
+			} else {
+				sqrt_price_next.min(sqrt_price_limit)
+			};
+
+			let step = math::compute_swap_step(
+				pool.sqrt_price,
+				sqrt_price_target,
+				pool.liquidity,
+				amount_remaining,
+				exact_in,
+				fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let step_amount_in = step
+				.amount_in
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			if exact_in {
+				amount_remaining = amount_remaining
+					.checked_sub(step_amount_in)
+					.ok_or(ArithmeticError::Underflow)?;
+				amount_calculated = amount_calculated
+					.checked_add(step.amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+			} else {
+				amount_remaining = amount_remaining
+					.checked_sub(step.amount_out)
+					.ok_or(ArithmeticError::Underflow)?;
+				amount_calculated = amount_calculated
+					.checked_add(step_amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+			fee_amount = fee_amount
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let fee_growth_delta =
+				math::fee_growth_delta(step.fee_amount, pool.liquidity).ok_or(ArithmeticError::Overflow)?;
+			if zero_for_one {
+				pool.fee_growth_global_0 = pool.fee_growth_global_0.overflowing_add(fee_growth_delta).0;
+			} else {
+				pool.fee_growth_global_1 = pool.fee_growth_global_1.overflowing_add(fee_growth_delta).0;
+			}
+
+			if step.sqrt_price_next == sqrt_price_next {
+				if initialized {
+					let mut info = Ticks::<T>::get(assets, tick_next).unwrap_or_default();
+					info.fee_growth_outside_0 = pool.fee_growth_global_0.overflowing_sub(info.fee_growth_outside_0).0;
+					info.fee_growth_outside_1 = pool.fee_growth_global_1.overflowing_sub(info.fee_growth_outside_1).0;
+
+					let liquidity_net = if zero_for_one {
+						info.liquidity_net.checked_neg().ok_or(ArithmeticError::Overflow)?
+					} else {
+						info.liquidity_net
+					};
+					pool.liquidity =
+						math::add_liquidity_delta(pool.liquidity, liquidity_net).ok_or(ArithmeticError::Overflow)?;
+					crossed_ticks.push((tick_next, info));
+				}
+				pool.tick = if zero_for_one { tick_next - 1 } else { tick_next };
+			} else if step.sqrt_price_next != pool.sqrt_price {
+				pool.tick = math::tick_at_sqrt_price(step.sqrt_price_next).ok_or(ArithmeticError::Overflow)?;
+			}
+			pool.sqrt_price = step.sqrt_price_next;
+		}
+
+		ensure!(amount_remaining.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		let (amount_in, amount_out) = if exact_in {
+			(amount, amount_calculated)
+		} else {
+			(amount_calculated, amount)
+		};
+		ensure!(!amount_out.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		// fee stays in the pool account until it is collected by positions
+		let (reserve_in, reserve_out) = if zero_for_one {
+			(&mut pool.reserve_0, &mut pool.reserve_1)
+		} else {
+			(&mut pool.reserve_1, &mut pool.reserve_0)
+		};
+		*reserve_in = amount_in
+			.checked_sub(fee_amount)
+			.and_then(|amount| reserve_in.checked_add(amount))
+			.ok_or(ArithmeticError::Overflow)?;
+		*reserve_out = reserve_out
+			.checked_sub(amount_out)
+			.ok_or(Error::<T>::InsufficientLiquidity)?;
+
+		Ok(SwapResult {
+			amount_in,
+			amount_out,
+			fee_amount,
+			pool,
+			crossed_ticks,
+		})
+	}
+
+	/// Transfers assets of a swap and commits updated pool state.
+	#[require_transactional]
+	fn execute_swap(
+		who: T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		swap: SwapResult,
+		exact_in: bool,
+	) -> DispatchResult {
+		let assets = Self::ordered_assets(asset_in, asset_out);
+		let pool_account = Self::pool_account(assets);
+
+		T::Currency::transfer(asset_in, &who, &pool_account, swap.amount_in)?;
+		T::Currency::transfer(asset_out, &pool_account, &who, swap.amount_out)?;
+
+		for (tick, info) in swap.crossed_ticks {
+			Ticks::<T>::insert(assets, tick, info);
+		}
+		Pools::<T>::insert(assets, swap.pool.clone());
+
+		let (liquidity_in, liquidity_out) = swap.pool.reserves_of(asset_in == assets.0);
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			swap.amount_in,
+			swap.amount_out,
+			liquidity_in,
+			liquidity_out,
+			Self::oracle_price(&swap.pool, assets, asset_in),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		pallet_broadcast::Pallet::<T>::deposit_trade_event(
+			who,
+			pool_account.clone(),
+			pallet_broadcast::types::Filler::ConcentratedLiquidity(assets.0.into(), assets.1.into()),
+			if exact_in {
+				pallet_broadcast::types::TradeOperation::ExactIn
+			} else {
+				pallet_broadcast::types::TradeOperation::ExactOut
+			},
+			vec![Asset::new(asset_in.into(), swap.amount_in)],
+			vec![Asset::new(asset_out.into(), swap.amount_out)],
+			vec![Fee {
+				asset: asset_in.into(),
+				amount: swap.fee_amount,
+				destination: Destination::Account(pool_account),
+			}],
+		);
+
+		Ok(())
+	}
+
+	/// Calculates spot price of `asset_b` denominated in `asset_a` without fee.
+	pub(crate) fn calculate_spot_price(asset_a: T::AssetId, asset_b: T::AssetId) -> Result<FixedU128, DispatchError> {
+		ensure!(asset_a != asset_b, Error::<T>::NotAllowed);
+		let assets = Self::ordered_assets(asset_a, asset_b);
+		let pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+		let price = Self::oracle_price(&pool, assets, asset_a);
+		FixedU128::checked_from_rational(price.n, price.d).ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	// Pool price in the form expected by oracle - amount of `asset_a` per one unit of the other pool asset.
+	fn oracle_price(pool: &PoolInfo, assets: (T::AssetId, T::AssetId), asset_a: T::AssetId) -> Ratio {
+		// price of asset 0 denominated in asset 1
+		let (n, d) = math::price_from_sqrt_price(pool.sqrt_price);
+		if asset_a == assets.1 {
+			Ratio::new(n, d)
+		} else {
+			Ratio::new(d, n)
+		}
+	}
+
+	fn transfer_to_pool(
+		who: &T::AccountId,
+		assets: (T::AssetId, T::AssetId),
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		ensure!(
+			T::Currency::free_balance(assets.0, who) >= amount_0
+				&& T::Currency::free_balance(assets.1, who) >= amount_1,
+			Error::<T>::InsufficientBalance
+		);
+		let pool_account = Self::pool_account(assets);
+		T::Currency::transfer(assets.0, who, &pool_account, amount_0)?;
+		T::Currency::transfer(assets.1, who, &pool_account, amount_1)?;
+		Ok(())
+	}
+
+	fn transfer_from_pool(
+		who: &T::AccountId,
+		assets: (T::AssetId, T::AssetId),
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(assets);
+		T::Currency::transfer(assets.0, &pool_account, who, amount_0)?;
+		T::Currency::transfer(assets.1, &pool_account, who, amount_1)?;
+		Ok(())
+	}
+
+	/// Pays out fees owed by the position.
+	fn collect_fees(
+		position_id: T::PositionItemId,
+		who: &T::AccountId,
+		position: &mut Position<T::AssetId>,
+	) -> DispatchResult {
+		let (amount_0, amount_1) = (position.tokens_owed_0, position.tokens_owed_1);
+		if amount_0.is_zero() && amount_1.is_zero() {
+			return Ok(());
+		}
+
+		Self::transfer_from_pool(who, position.assets, amount_0, amount_1)?;
+		position.tokens_owed_0 = 0;
+		position.tokens_owed_1 = 0;
+
+		Self::deposit_event(Event::FeesCollected {
+			position_id,
+			who: who.clone(),
+			amount_a: amount_0,
+			amount_b: amount_1,
+		});
+
+		Ok(())
+	}
+
+	fn call_on_liquidity_changed_hook(
+		assets: (T::AssetId, T::AssetId),
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		let pool = Pools::<T>::get(assets).ok_or(Error::<T>::PoolNotFound)?;
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			assets.0,
+			assets.1,
+			amount_0,
+			amount_1,
+			pool.reserve_0,
+			pool.reserve_1,
+			Self::oracle_price(&pool, assets, assets.0),
+		)
+		.map_err(|(_w, e)| e)?;
+		Ok(())
+	}
+
+	fn deposit_liquidity_event(
+		who: T::AccountId,
+		assets: (T::AssetId, T::AssetId),
+		amount_0: Balance,
+		amount_1: Balance,
+		added: bool,
+	) {
+		let amounts = vec![
+			Asset::new(assets.0.into(), amount_0),
+			Asset::new(assets.1.into(), amount_1),
+		];
+		let (operation, inputs, outputs) = if added {
+			(pallet_broadcast::types::TradeOperation::LiquidityAdd, amounts, vec![])
+		} else {
+			(
+				pallet_broadcast::types::TradeOperation::LiquidityRemove,
+				vec![],
+				amounts,
+			)
+		};
+		pallet_broadcast::Pallet::<T>::deposit_trade_event(
+			who,
+			Self::pool_account(assets),
+			pallet_broadcast::types::Filler::ConcentratedLiquidity(assets.0.into(), assets.1.into()),
+			operation,
+			inputs,
+			outputs,
+			vec![],
+		);
+	}
+}
@@ -0,0 +1,143 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{Error, Event, Pools};
+use frame_support::traits::Get;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity::Q96;
+use sp_core::U256;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128};
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DOT,
+			FixedU128::from(1)
+		));
+
+		assert_eq!(
+			Pools::<Test>::get((HDX, DOT)),
+			Some(PoolInfo {
+				sqrt_price: Q96,
+				tick: 0,
+				liquidity: 0,
+				fee_growth_global_0: U256::zero(),
+				fee_growth_global_1: U256::zero(),
+				reserve_0: 0,
+				reserve_1: 0,
+			})
+		);
+		assert!(DUSTER_WHITELIST.with(|v| v.borrow().contains(&HDX_DOT_POOL_ACCOUNT)));
+		assert!(ORACLE_WHITELIST.with(|v| v
+			.borrow()
+			.contains(&(ConcentratedLiquidityOracleSource::get(), HDX, DOT))));
+
+		expect_events(vec![Event::PoolCreated {
+			asset_a: HDX,
+			asset_b: DOT,
+			sqrt_price: Q96,
+			tick: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_store_price_of_first_asset_when_assets_are_not_ordered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// 2 HDX per DOT
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DOT,
+			HDX,
+			FixedU128::from(2)
+		));
+
+		assert!(Pools::<Test>::get((DOT, HDX)).is_none());
+		let pool = Pools::<Test>::get((HDX, DOT)).unwrap();
+		// 0.5 DOT per HDX - 1.0001^-6932 < 0.5 < 1.0001^-6931
+		assert_eq!(pool.tick, -6932);
+
+		let spot_price = ConcentratedLiquidity::calculate_spot_price(HDX, DOT).unwrap();
+		assert!(spot_price.into_inner().abs_diff(FixedU128::from(2).into_inner()) <= 1);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::signed(ALICE), HDX, DOT, FixedU128::from(1)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, HDX, FixedU128::from(1)),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, ACA, FixedU128::from(1)),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_work_when_asset_is_registered() {
+	ExtBuilder::default()
+		.with_registered_asset(ACA, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				ACA,
+				FixedU128::from(1)
+			));
+			assert!(Pools::<Test>::get((HDX, ACA)).is_some());
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DOT,
+			FixedU128::from(1)
+		));
+
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DOT, HDX, FixedU128::from(3)),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DOT, FixedU128::from(0)),
+			Error::<Test>::InvalidInitialPrice
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DOT, HDX, FixedU128::from(0)),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
@@ -0,0 +1,465 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Concentrated liquidity pallet.
+#![allow(clippy::type_complexity)]
+
+use sp_runtime::DispatchResult;
+use sp_std::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_concentrated_liquidity;
+
+use crate::Config;
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect as NFTInspect, Mutate};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, Permill,
+};
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+
+pub const HDX: AssetId = 1000;
+pub const DOT: AssetId = 2000;
+pub const ACA: AssetId = 3000;
+
+pub const HDX_DOT_POOL_ACCOUNT: AccountId = 1_002_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const TICK_SPACING: i32 = 60;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
+	pub static ORACLE_WHITELIST: RefCell<Vec<(Source, AssetId, AssetId)>> = const { RefCell::new(Vec::new()) };
+	pub static POSITIONS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::default());
+	pub static LAST_TRADE_HOOK: RefCell<Option<HookCall>> = const { RefCell::new(None) };
+	pub static LAST_LIQUIDITY_CHANGE_HOOK: RefCell<Option<HookCall>> = const { RefCell::new(None) };
+}
+
+/// Arguments of last AMM handler call - (asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price)
+pub type HookCall = (AssetId, AssetId, Balance, Balance, Balance, Balance, Ratio);
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		ConcentratedLiquidity: pallet_concentrated_liquidity,
+		Broadcast: pallet_broadcast,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"clxyk___");
+	pub const TradeFee: Permill = Permill::from_parts(3_000);
+	pub const TickSpacing: i32 = TICK_SPACING;
+	pub const MaxSwapSteps: u32 = 128;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub const ConcentratedLiquidityOracleSource: Source = *b"concentr";
+	pub const PositionCollectionId: u32 = 2000;
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+impl pallet_broadcast::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type AssetRegistry = DummyRegistry;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type TradeFee = TradeFee;
+	type TickSpacing = TickSpacing;
+	type MaxSwapSteps = MaxSwapSteps;
+	type MinTradingLimit = MinimumTradingLimit;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type OracleWhitelist = DummyOracleWhitelist;
+	type AMMHandler = DummyAMMHandler;
+	type PositionItemId = u32;
+	type CollectionId = u32;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(AssetId, u8)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each test case
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		ORACLE_WHITELIST.with(|v| v.borrow_mut().clear());
+		POSITIONS.with(|v| v.borrow_mut().clear());
+		LAST_TRADE_HOOK.with(|v| *v.borrow_mut() = None);
+		LAST_LIQUIDITY_CHANGE_HOOK.with(|v| *v.borrow_mut() = None);
+
+		Self {
+			endowed_accounts: vec![],
+			registered_assets: vec![(HDX, 12), (DOT, 12)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId, decimals: u8) -> Self {
+		self.registered_assets.push((asset, decimals));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		for (asset, decimals) in self.registered_assets.into_iter() {
+			REGISTERED_ASSETS.with(|v| {
+				v.borrow_mut().insert(asset, decimals);
+			});
+		}
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| {
+			frame_system::Pallet::<Test>::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+use crate::types::BenchmarkHelper;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{
+	AssetPairAccountIdFor, Inspect, OnLiquidityChangedHandler, OnTradeHandler, OracleWhitelistHandler, Source,
+};
+
+pub struct DummyRegistry;
+
+impl Inspect for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<hydradx_traits::AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+}
+
+pub struct AssetPairAccountIdTest;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			std::mem::swap(&mut a, &mut b)
+		}
+		(a * 1000 + b) as u64
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> NFTInspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub(crate) fn position_owner(position_id: u32) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+/// Transfers position NFT to another account.
+pub(crate) fn transfer_position(position_id: u32, to: AccountId) {
+	POSITIONS.with(|v| {
+		v.borrow_mut().insert(position_id, to);
+	});
+}
+
+pub struct DummyAMMHandler;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for DummyAMMHandler {
+	fn on_trade(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LAST_TRADE_HOOK.with(|v| {
+			*v.borrow_mut() = Some((asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price));
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, Ratio> for DummyAMMHandler {
+	fn on_liquidity_changed(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LAST_LIQUIDITY_CHANGE_HOOK.with(|v| {
+			*v.borrow_mut() = Some((asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price));
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyOracleWhitelist;
+
+impl OracleWhitelistHandler<AssetId> for DummyOracleWhitelist {
+	fn add_to_whitelist(source: Source, asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+		ORACLE_WHITELIST.with(|v| v.borrow_mut().push((source, asset_a, asset_b)));
+		Ok(())
+	}
+
+	fn add_to_whitelist_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn last_trade_hook() -> Option<HookCall> {
+	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn last_liquidity_changed_hook() -> Option<HookCall> {
+	LAST_LIQUIDITY_CHANGE_HOOK.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+pub fn get_last_swapped_events() -> Vec<RuntimeEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.map(|e| e.event)
+		.filter(|e| matches!(e, RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped { .. })))
+		.collect()
+}
@@ -0,0 +1,50 @@
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use sp_runtime::FixedU128;
+
+mod creation;
+pub(crate) mod mock;
+mod positions;
+mod trade_execution;
+mod trades;
+
+pub(crate) const WIDE_TICK_LOWER: i32 = -960;
+pub(crate) const WIDE_TICK_UPPER: i32 = 960;
+
+/// Creates HDX/DOT pool with initial price of 1 DOT per HDX.
+pub(crate) fn create_pool() {
+	assert_ok!(ConcentratedLiquidity::create_pool(
+		RuntimeOrigin::root(),
+		HDX,
+		DOT,
+		FixedU128::from(1),
+	));
+}
+
+/// HDX/DOT pool with ALICE's position with 1000 HDX and 1000 DOT in the range `[-960, 960]`.
+/// Position id of ALICE's position is 0.
+pub(crate) fn pool_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 10_000 * ONE),
+			(ALICE, DOT, 10_000 * ONE),
+			(BOB, HDX, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.build();
+
+	ext.execute_with(|| {
+		create_pool();
+		assert_ok!(ConcentratedLiquidity::create_position(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			WIDE_TICK_LOWER,
+			WIDE_TICK_UPPER,
+			1_000 * ONE,
+			1_000 * ONE,
+		));
+	});
+
+	ext
+}
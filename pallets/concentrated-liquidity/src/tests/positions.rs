@@ -0,0 +1,424 @@
+use crate::tests::mock::*;
+use crate::tests::{create_pool, pool_ext, WIDE_TICK_LOWER, WIDE_TICK_UPPER};
+use crate::types::{Position, TickInfo};
+use crate::{assert_balance, Error, Event, Pools, Positions, Ticks};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity::{amounts_for_liquidity, liquidity_for_amounts, sqrt_price_at_tick, Q96};
+use sp_core::U256;
+
+fn liquidity_for(tick_lower: i32, tick_upper: i32, amount_a: Balance, amount_b: Balance) -> u128 {
+	liquidity_for_amounts(
+		Q96,
+		sqrt_price_at_tick(tick_lower).unwrap(),
+		sqrt_price_at_tick(tick_upper).unwrap(),
+		amount_a,
+		amount_b,
+	)
+	.unwrap()
+}
+
+fn amounts_for(tick_lower: i32, tick_upper: i32, liquidity: u128, round_up: bool) -> (Balance, Balance) {
+	amounts_for_liquidity(
+		Q96,
+		sqrt_price_at_tick(tick_lower).unwrap(),
+		sqrt_price_at_tick(tick_upper).unwrap(),
+		liquidity,
+		round_up,
+	)
+	.unwrap()
+}
+
+#[test]
+fn create_position_should_work_when_price_is_in_range() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 200 * ONE), (BOB, DOT, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			create_pool();
+
+			assert_ok!(ConcentratedLiquidity::create_position(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				DOT,
+				-960,
+				960,
+				100 * ONE,
+				100 * ONE,
+			));
+
+			let liquidity = liquidity_for(-960, 960, 100 * ONE, 100 * ONE);
+			let (amount_hdx, amount_dot) = amounts_for(-960, 960, liquidity, true);
+			assert!(amount_hdx <= 100 * ONE && amount_dot <= 100 * ONE);
+			assert!(amount_hdx > 0 && amount_dot > 0);
+
+			assert_balance!(BOB, HDX, 200 * ONE - amount_hdx);
+			assert_balance!(BOB, DOT, 200 * ONE - amount_dot);
+			assert_balance!(HDX_DOT_POOL_ACCOUNT, HDX, amount_hdx);
+			assert_balance!(HDX_DOT_POOL_ACCOUNT, DOT, amount_dot);
+
+			assert_eq!(
+				Positions::<Test>::get(0),
+				Some(Position {
+					assets: (HDX, DOT),
+					tick_lower: -960,
+					tick_upper: 960,
+					liquidity,
+					fee_growth_inside_0_last: U256::zero(),
+					fee_growth_inside_1_last: U256::zero(),
+					tokens_owed_0: 0,
+					tokens_owed_1: 0,
+				})
+			);
+			assert_eq!(position_owner(0), Some(BOB));
+			assert_eq!(ConcentratedLiquidity::next_position_id(), 1);
+
+			assert_eq!(Pools::<Test>::get((HDX, DOT)).unwrap().liquidity, liquidity);
+			assert_eq!(
+				Ticks::<Test>::get((HDX, DOT), -960),
+				Some(TickInfo {
+					liquidity_gross: liquidity,
+					liquidity_net: liquidity as i128,
+					fee_growth_outside_0: U256::zero(),
+					fee_growth_outside_1: U256::zero(),
+				})
+			);
+			assert_eq!(
+				Ticks::<Test>::get((HDX, DOT), 960),
+				Some(TickInfo {
+					liquidity_gross: liquidity,
+					liquidity_net: -(liquidity as i128),
+					fee_growth_outside_0: U256::zero(),
+					fee_growth_outside_1: U256::zero(),
+				})
+			);
+
+			let (asset_a, asset_b, amount_a, amount_b, ..) = last_liquidity_changed_hook().unwrap();
+			assert_eq!(
+				(asset_a, asset_b, amount_a, amount_b),
+				(HDX, DOT, amount_hdx, amount_dot)
+			);
+
+			expect_events(vec![Event::PositionCreated {
+				position_id: 0,
+				owner: BOB,
+				asset_a: HDX,
+				asset_b: DOT,
+				tick_lower: -960,
+				tick_upper: 960,
+				liquidity,
+				amount_a: amount_hdx,
+				amount_b: amount_dot,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn create_position_should_transfer_only_first_asset_when_price_is_below_range() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 200 * ONE), (BOB, DOT, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			create_pool();
+
+			assert_ok!(ConcentratedLiquidity::create_position(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				DOT,
+				120,
+				960,
+				100 * ONE,
+				100 * ONE,
+			));
+
+			assert_balance!(BOB, DOT, 200 * ONE);
+			assert_balance!(HDX_DOT_POOL_ACCOUNT, DOT, 0);
+			assert!(Tokens::free_balance(HDX, &HDX_DOT_POOL_ACCOUNT) > 0);
+			// position is not active
+			assert_eq!(Pools::<Test>::get((HDX, DOT)).unwrap().liquidity, 0);
+		});
+}
+
+#[test]
+fn create_position_should_transfer_only_second_asset_when_price_is_above_range() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 200 * ONE), (BOB, DOT, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			create_pool();
+
+			assert_ok!(ConcentratedLiquidity::create_position(
+				RuntimeOrigin::signed(BOB),
+				DOT,
+				HDX,
+				-960,
+				-120,
+				100 * ONE,
+				100 * ONE,
+			));
+
+			assert_balance!(BOB, HDX, 200 * ONE);
+			assert_balance!(HDX_DOT_POOL_ACCOUNT, HDX, 0);
+			assert!(Tokens::free_balance(DOT, &BOB) >= 100 * ONE);
+			assert!(Tokens::free_balance(DOT, &HDX_DOT_POOL_ACCOUNT) > 0);
+			assert_eq!(Pools::<Test>::get((HDX, DOT)).unwrap().liquidity, 0);
+		});
+}
+
+#[test]
+fn create_position_should_fail_when_tick_range_is_invalid() {
+	pool_ext().execute_with(|| {
+		for (tick_lower, tick_upper) in [(120, 120), (120, -120), (-100, 120), (-120, 100), (-887_280, 120)] {
+			assert_noop!(
+				ConcentratedLiquidity::create_position(
+					RuntimeOrigin::signed(BOB),
+					HDX,
+					DOT,
+					tick_lower,
+					tick_upper,
+					100 * ONE,
+					100 * ONE,
+				),
+				Error::<Test>::InvalidTickRange
+			);
+		}
+	});
+}
+
+#[test]
+fn create_position_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 200 * ONE), (BOB, DOT, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::create_position(
+					RuntimeOrigin::signed(BOB),
+					HDX,
+					DOT,
+					-960,
+					960,
+					100 * ONE,
+					100 * ONE,
+				),
+				Error::<Test>::PoolNotFound
+			);
+		});
+}
+
+#[test]
+fn create_position_should_fail_when_amounts_are_zero() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_position(RuntimeOrigin::signed(BOB), HDX, DOT, -960, 960, 0, 0),
+			Error::<Test>::InsufficientLiquidityAmount
+		);
+	});
+}
+
+#[test]
+fn create_position_should_fail_when_balance_is_insufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_position(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				DOT,
+				-960,
+				960,
+				100_000 * ONE,
+				100_000 * ONE,
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_work() {
+	pool_ext().execute_with(|| {
+		let position = Positions::<Test>::get(0).unwrap();
+		let pool_liquidity = Pools::<Test>::get((HDX, DOT)).unwrap().liquidity;
+		let alice_hdx = Tokens::free_balance(HDX, &ALICE);
+		let alice_dot = Tokens::free_balance(DOT, &ALICE);
+
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			100 * ONE,
+			100 * ONE,
+		));
+
+		let added = liquidity_for(WIDE_TICK_LOWER, WIDE_TICK_UPPER, 100 * ONE, 100 * ONE);
+		let (amount_hdx, amount_dot) = amounts_for(WIDE_TICK_LOWER, WIDE_TICK_UPPER, added, true);
+
+		assert_eq!(Positions::<Test>::get(0).unwrap().liquidity, position.liquidity + added);
+		assert_eq!(
+			Pools::<Test>::get((HDX, DOT)).unwrap().liquidity,
+			pool_liquidity + added
+		);
+		assert_balance!(ALICE, HDX, alice_hdx - amount_hdx);
+		assert_balance!(ALICE, DOT, alice_dot - amount_dot);
+
+		expect_events(vec![Event::LiquidityAdded {
+			position_id: 0,
+			who: ALICE,
+			liquidity: added,
+			amount_a: amount_hdx,
+			amount_b: amount_dot,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_caller_is_not_position_owner() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, 100 * ONE, 100 * ONE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_position_does_not_exist() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), 1, 100 * ONE, 100 * ONE),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_part_of_liquidity_is_removed() {
+	pool_ext().execute_with(|| {
+		let position = Positions::<Test>::get(0).unwrap();
+		let alice_hdx = Tokens::free_balance(HDX, &ALICE);
+		let alice_dot = Tokens::free_balance(DOT, &ALICE);
+		let removed = position.liquidity / 2;
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			removed,
+			0,
+			0,
+		));
+
+		let (amount_hdx, amount_dot) = amounts_for(WIDE_TICK_LOWER, WIDE_TICK_UPPER, removed, false);
+		assert_balance!(ALICE, HDX, alice_hdx + amount_hdx);
+		assert_balance!(ALICE, DOT, alice_dot + amount_dot);
+		assert_eq!(
+			Positions::<Test>::get(0).unwrap().liquidity,
+			position.liquidity - removed
+		);
+		assert_eq!(
+			Pools::<Test>::get((HDX, DOT)).unwrap().liquidity,
+			position.liquidity - removed
+		);
+		assert_eq!(position_owner(0), Some(ALICE));
+
+		expect_events(vec![Event::LiquidityRemoved {
+			position_id: 0,
+			who: ALICE,
+			liquidity: removed,
+			amount_a: amount_hdx,
+			amount_b: amount_dot,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_destroy_position_when_all_liquidity_is_removed() {
+	pool_ext().execute_with(|| {
+		let position = Positions::<Test>::get(0).unwrap();
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			position.liquidity,
+			0,
+			0,
+		));
+
+		assert!(Positions::<Test>::get(0).is_none());
+		assert_eq!(position_owner(0), None);
+		assert!(Ticks::<Test>::get((HDX, DOT), WIDE_TICK_LOWER).is_none());
+		assert!(Ticks::<Test>::get((HDX, DOT), WIDE_TICK_UPPER).is_none());
+		assert_eq!(Pools::<Test>::get((HDX, DOT)).unwrap().liquidity, 0);
+
+		// only rounding dust remains in the pool
+		assert!(Tokens::free_balance(HDX, &HDX_DOT_POOL_ACCOUNT) <= 2);
+		assert!(Tokens::free_balance(DOT, &HDX_DOT_POOL_ACCOUNT) <= 2);
+
+		expect_events(vec![Event::PositionDestroyed {
+			position_id: 0,
+			owner: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_exceeds_position_liquidity() {
+	pool_ext().execute_with(|| {
+		let position = Positions::<Test>::get(0).unwrap();
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, position.liquidity + 1, 0, 0),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_is_zero() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_received_amount_is_below_limit() {
+	pool_ext().execute_with(|| {
+		let position = Positions::<Test>::get(0).unwrap();
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				position.liquidity,
+				1_001 * ONE,
+				0
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn position_should_be_managed_by_new_owner_when_nft_is_transferred() {
+	pool_ext().execute_with(|| {
+		transfer_position(0, BOB);
+		let position = Positions::<Test>::get(0).unwrap();
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, position.liquidity, 0, 0),
+			Error::<Test>::Forbidden
+		);
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			position.liquidity,
+			0,
+			0
+		));
+		assert!(Positions::<Test>::get(0).is_none());
+	});
+}
@@ -0,0 +1,143 @@
+use crate::assert_balance;
+use crate::tests::mock::*;
+use crate::tests::pool_ext;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use sp_runtime::{FixedPointNumber, FixedU128};
+use test_utils::assert_eq_approx;
+
+#[test]
+fn calculate_sell_should_return_same_amount_as_sell() {
+	pool_ext().execute_with(|| {
+		let expected =
+			ConcentratedLiquidity::calculate_sell(PoolType::ConcentratedLiquidity, HDX, DOT, 10 * ONE).unwrap();
+		assert_eq!(expected, 9_965_343_859_126);
+
+		assert_ok!(ConcentratedLiquidity::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DOT,
+			10 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, HDX, 9_990 * ONE);
+		assert_balance!(BOB, DOT, 10_000 * ONE + expected);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_same_amount_as_buy() {
+	pool_ext().execute_with(|| {
+		let expected_in =
+			ConcentratedLiquidity::calculate_buy(PoolType::ConcentratedLiquidity, HDX, DOT, 10 * ONE).unwrap();
+		assert_eq!(expected_in, 10_034_792_968_720);
+
+		assert_ok!(ConcentratedLiquidity::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DOT,
+			10 * ONE,
+			expected_in,
+		));
+
+		assert_balance!(BOB, HDX, 10_000 * ONE - expected_in);
+		assert_balance!(BOB, DOT, 10_010 * ONE);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::calculate_sell(PoolType::XYK, HDX, DOT, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::calculate_buy(PoolType::Stableswap(HDX), HDX, DOT, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::execute_sell(RuntimeOrigin::signed(BOB), PoolType::Omnipool, HDX, DOT, 10 * ONE, 0),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::execute_buy(
+				RuntimeOrigin::signed(BOB),
+				PoolType::Cryptoswap(HDX),
+				HDX,
+				DOT,
+				10 * ONE,
+				u128::MAX
+			),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::LBP, HDX, DOT),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_pool_reserve() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::ConcentratedLiquidity, HDX, DOT),
+			Ok(1_000 * ONE)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::ConcentratedLiquidity, DOT, HDX),
+			Ok(1_000 * ONE)
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_include_fee() {
+	pool_ext().execute_with(|| {
+		// 1 HDX per DOT, increased by 0.3% fee
+		let expected = FixedU128::from(1) / FixedU128::from_rational(997, 1000);
+		let spot_price =
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::ConcentratedLiquidity, HDX, DOT).unwrap();
+		assert_eq_approx!(
+			spot_price,
+			expected,
+			FixedU128::from_rational(1, 1_000_000),
+			"unexpected spot price"
+		);
+
+		let inverse_spot_price =
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::ConcentratedLiquidity, DOT, HDX).unwrap();
+		assert_eq_approx!(
+			inverse_spot_price,
+			expected,
+			FixedU128::from_rational(1, 1_000_000),
+			"unexpected inverse spot price"
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_match_small_trade() {
+	pool_ext().execute_with(|| {
+		let spot_price =
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::ConcentratedLiquidity, HDX, DOT).unwrap();
+		let amount_out = ConcentratedLiquidity::calculate_sell(PoolType::ConcentratedLiquidity, HDX, DOT, ONE).unwrap();
+
+		let expected_out = spot_price.reciprocal().unwrap().checked_mul_int(ONE).unwrap();
+		assert_eq_approx!(amount_out, expected_out, ONE / 1_000_000, "unexpected amount out");
+	});
+}
+
+#[test]
+fn pools_should_return_all_concentrated_liquidity_pools() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::pools(),
+			vec![(PoolType::ConcentratedLiquidity, vec![HDX, DOT])]
+		);
+	});
+}
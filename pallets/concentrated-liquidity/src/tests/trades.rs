@@ -0,0 +1,372 @@
+use crate::tests::mock::*;
+use crate::tests::{pool_ext, WIDE_TICK_LOWER, WIDE_TICK_UPPER};
+use crate::{assert_balance, Error, Event, Pools, Positions, Ticks};
+use frame_support::{assert_noop, assert_ok};
+use pallet_broadcast::types::{Asset, Destination, Fee, Filler, TradeOperation};
+
+const POOL_HDX: Balance = 1_000 * ONE;
+const POOL_DOT: Balance = 1_000 * ONE;
+
+#[test]
+fn sell_should_work() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			9 * ONE,
+		));
+
+		let expected = 9_965_343_859_126;
+		assert_balance!(BOB, HDX, 9_990 * ONE);
+		assert_balance!(BOB, DOT, 10_000 * ONE + expected);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, HDX, POOL_HDX + 10 * ONE);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, DOT, POOL_DOT - expected);
+
+		let pool = Pools::<Test>::get((HDX, DOT)).unwrap();
+		assert!(pool.tick < 0);
+		assert!(!pool.fee_growth_global_0.is_zero());
+		assert!(pool.fee_growth_global_1.is_zero());
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_second_asset() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			10 * ONE,
+			9 * ONE,
+		));
+
+		// pool is symmetric at price 1
+		let expected = 9_965_343_859_126;
+		assert_balance!(BOB, DOT, 9_990 * ONE);
+		assert_balance!(BOB, HDX, 10_000 * ONE + expected);
+
+		let pool = Pools::<Test>::get((HDX, DOT)).unwrap();
+		assert!(pool.tick >= 0);
+		assert!(pool.fee_growth_global_0.is_zero());
+		assert!(!pool.fee_growth_global_1.is_zero());
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			10 * ONE,
+			11 * ONE,
+		));
+
+		let expected_in = 10_034_792_968_720;
+		assert_balance!(BOB, DOT, 10_010 * ONE);
+		assert_balance!(BOB, HDX, 10_000 * ONE - expected_in);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, HDX, POOL_HDX + expected_in);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, DOT, POOL_DOT - 10 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 9_965_343_859_127),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DOT, HDX, 10 * ONE, 10_034_792_968_719),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_amount_is_below_min_trading_limit() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DOT, HDX, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_balance_is_insufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10_001 * ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_assets_are_same() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, HDX, 10 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_pool_does_not_exist() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, ACA, 10 * ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_exceeds_liquidity_in_price_ranges() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 2_000 * ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DOT, HDX, 2_000 * ONE, u128::MAX),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn sell_should_update_pool_liquidity_when_initialized_tick_is_crossed() {
+	pool_ext().execute_with(|| {
+		let wide_liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		assert_ok!(ConcentratedLiquidity::create_position(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			-120,
+			120,
+			100 * ONE,
+			100 * ONE,
+		));
+		let narrow_liquidity = Positions::<Test>::get(1).unwrap().liquidity;
+		assert_eq!(
+			Pools::<Test>::get((HDX, DOT)).unwrap().liquidity,
+			wide_liquidity + narrow_liquidity
+		);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			300 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, DOT, 10_000 * ONE + 296_668_734_138_594);
+
+		let pool = Pools::<Test>::get((HDX, DOT)).unwrap();
+		assert_eq!(pool.tick, -186);
+		assert_eq!(pool.liquidity, wide_liquidity);
+
+		// fees collected in the range below the crossed tick are tracked outside of it
+		let crossed = Ticks::<Test>::get((HDX, DOT), -120).unwrap();
+		assert!(!crossed.fee_growth_outside_0.is_zero());
+		assert!(crossed.fee_growth_outside_0 < pool.fee_growth_global_0);
+	});
+}
+
+#[test]
+fn sell_should_notify_amm_handler() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			0,
+		));
+
+		let (asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, _) = last_trade_hook().unwrap();
+		assert_eq!(
+			(asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b),
+			(
+				HDX,
+				DOT,
+				10 * ONE,
+				9_965_343_859_126,
+				POOL_HDX + 10 * ONE - 30_000_000_000,
+				POOL_DOT - 9_965_343_859_126
+			)
+		);
+	});
+}
+
+#[test]
+fn pool_reserves_should_not_include_uncollected_fees() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			0,
+		));
+
+		let pool = Pools::<Test>::get((HDX, DOT)).unwrap();
+		assert_eq!(pool.reserve_0, POOL_HDX + 10 * ONE - 30_000_000_000);
+		assert_eq!(pool.reserve_1, POOL_DOT - 9_965_343_859_126);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, HDX, pool.reserve_0 + 30_000_000_000);
+		assert_balance!(HDX_DOT_POOL_ACCOUNT, DOT, pool.reserve_1);
+	});
+}
+
+#[test]
+fn sell_should_emit_swapped_event() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			0,
+		));
+
+		let swapped_events = get_last_swapped_events();
+		assert_eq!(
+			*swapped_events.last().unwrap(),
+			RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped {
+				swapper: BOB,
+				filler: HDX_DOT_POOL_ACCOUNT,
+				filler_type: Filler::ConcentratedLiquidity(HDX, DOT),
+				operation: TradeOperation::ExactIn,
+				inputs: vec![Asset::new(HDX, 10 * ONE)],
+				outputs: vec![Asset::new(DOT, 9_965_343_859_126)],
+				fees: vec![Fee::new(
+					HDX,
+					30_000_000_000,
+					Destination::Account(HDX_DOT_POOL_ACCOUNT)
+				)],
+				operation_stack: vec![],
+			})
+		);
+	});
+}
+
+#[test]
+fn collect_should_pay_fees_earned_by_position() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			100 * ONE,
+			0,
+		));
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			100 * ONE,
+			0,
+		));
+		let alice_hdx = Tokens::free_balance(HDX, &ALICE);
+		let alice_dot = Tokens::free_balance(DOT, &ALICE);
+
+		assert_ok!(ConcentratedLiquidity::collect(RuntimeOrigin::signed(ALICE), 0));
+
+		// 0.3% of amount in, rounded down
+		assert_balance!(ALICE, HDX, alice_hdx + 299_999_999_999);
+		assert_balance!(ALICE, DOT, alice_dot + 300_000_000_000);
+
+		let position = Positions::<Test>::get(0).unwrap();
+		assert_eq!((position.tokens_owed_0, position.tokens_owed_1), (0, 0));
+
+		expect_events(vec![Event::FeesCollected {
+			position_id: 0,
+			who: ALICE,
+			amount_a: 299_999_999_999,
+			amount_b: 300_000_000_000,
+		}
+		.into()]);
+
+		// fees can be collected only once
+		assert_ok!(ConcentratedLiquidity::collect(RuntimeOrigin::signed(ALICE), 0));
+		assert_balance!(ALICE, HDX, alice_hdx + 299_999_999_999);
+		assert_balance!(ALICE, DOT, alice_dot + 300_000_000_000);
+	});
+}
+
+#[test]
+fn collect_should_fail_when_caller_is_not_position_owner() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::collect(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn fees_should_be_distributed_proportionally_to_liquidity_of_positions_in_range() {
+	pool_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_position(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			WIDE_TICK_LOWER,
+			WIDE_TICK_UPPER,
+			1_000 * ONE,
+			1_000 * ONE,
+		));
+		// out of range position, does not earn any fees
+		assert_ok!(ConcentratedLiquidity::create_position(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			120,
+			960,
+			100 * ONE,
+			100 * ONE,
+		));
+		assert_eq!(
+			Positions::<Test>::get(0).unwrap().liquidity,
+			Positions::<Test>::get(1).unwrap().liquidity
+		);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			100 * ONE,
+			0,
+		));
+
+		let alice_hdx = Tokens::free_balance(HDX, &ALICE);
+		let bob_hdx = Tokens::free_balance(HDX, &BOB);
+		assert_ok!(ConcentratedLiquidity::collect(RuntimeOrigin::signed(ALICE), 0));
+		assert_ok!(ConcentratedLiquidity::collect(RuntimeOrigin::signed(BOB), 1));
+		assert_ok!(ConcentratedLiquidity::collect(RuntimeOrigin::signed(BOB), 2));
+
+		let alice_fee = Tokens::free_balance(HDX, &ALICE) - alice_hdx;
+		let bob_fee = Tokens::free_balance(HDX, &BOB) - bob_hdx;
+		assert_eq!(alice_fee, bob_fee);
+		assert!(alice_fee + bob_fee <= 300_000_000_000);
+		assert!(alice_fee + bob_fee >= 300_000_000_000 - 2);
+	});
+}
@@ -0,0 +1,116 @@
+use crate::{Balance, Config, Error, Pallet, Pools};
+use frame_support::traits::Get;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use sp_runtime::traits::One;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				let swap = Self::calculate_swap(asset_in, asset_out, amount_in, true).map_err(ExecutorError::Error)?;
+				Ok(swap.amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				let swap =
+					Self::calculate_swap(asset_in, asset_out, amount_out, false).map_err(ExecutorError::Error)?;
+				Ok(swap.amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				let assets = Self::ordered_assets(asset_a, asset_b);
+				let pool = Pools::<T>::get(assets).ok_or(ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				Ok(pool.reserves_of(asset_a == assets.0).0)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::ConcentratedLiquidity => {
+				let spot_price = Self::calculate_spot_price(asset_a, asset_b).map_err(ExecutorError::Error)?;
+
+				// Fee is taken from amount in, which makes asset_b more expensive in terms of asset_a
+				let fee_multiplier = FixedU128::one()
+					.checked_sub(&T::TradeFee::get().into())
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Underflow.into()))?;
+				spot_price
+					.checked_div(&fee_multiplier)
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter_keys()
+			.map(|(asset_a, asset_b)| (PoolType::ConcentratedLiquidity, vec![asset_a, asset_b]))
+			.collect()
+	}
+}
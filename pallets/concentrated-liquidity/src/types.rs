@@ -0,0 +1,90 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{RuntimeDebug, U256};
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::DispatchResult;
+use sp_std::vec::Vec;
+
+pub(crate) type Balance = u128;
+
+/// Concentrated liquidity pool state.
+/// `sqrt_price`: square root of the price of asset 0 denominated in asset 1 (Q64.96)
+/// `tick`: current tick - the greatest tick whose square root price is less than or equal to `sqrt_price`
+/// `liquidity`: liquidity of positions whose price range contains current price
+/// `fee_growth_global_0`, `fee_growth_global_1`: total fees collected per unit of liquidity (Q128.128)
+/// `reserve_0`, `reserve_1`: amounts of pool assets provided by positions and trades, uncollected fees excluded
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo {
+	pub sqrt_price: U256,
+	pub tick: i32,
+	pub liquidity: u128,
+	pub fee_growth_global_0: U256,
+	pub fee_growth_global_1: U256,
+	pub reserve_0: Balance,
+	pub reserve_1: Balance,
+}
+
+impl PoolInfo {
+	pub(crate) fn fee_growth_global(&self) -> (U256, U256) {
+		(self.fee_growth_global_0, self.fee_growth_global_1)
+	}
+
+	/// Returns reserves of given pool asset and the other pool asset.
+	pub(crate) fn reserves_of(&self, asset_is_first: bool) -> (Balance, Balance) {
+		if asset_is_first {
+			(self.reserve_0, self.reserve_1)
+		} else {
+			(self.reserve_1, self.reserve_0)
+		}
+	}
+}
+
+/// State of an initialized tick.
+/// `liquidity_gross`: total liquidity of positions which use the tick as a range boundary
+/// `liquidity_net`: liquidity added when the tick is crossed from left to right
+/// `fee_growth_outside_0`, `fee_growth_outside_1`: fee growth on the other side of the tick relative to current tick
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+	pub liquidity_gross: u128,
+	pub liquidity_net: i128,
+	pub fee_growth_outside_0: U256,
+	pub fee_growth_outside_1: U256,
+}
+
+/// Liquidity position of an LP.
+/// `assets`: pool assets, ordered
+/// `tick_lower`, `tick_upper`: price range of the position
+/// `liquidity`: liquidity provided by the position
+/// `fee_growth_inside_0_last`, `fee_growth_inside_1_last`: fee growth inside the range at last update of the position
+/// `tokens_owed_0`, `tokens_owed_1`: fees earned by the position which have not been collected yet
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position<AssetId> {
+	pub assets: (AssetId, AssetId),
+	pub tick_lower: i32,
+	pub tick_upper: i32,
+	pub liquidity: u128,
+	pub fee_growth_inside_0_last: U256,
+	pub fee_growth_inside_1_last: U256,
+	pub tokens_owed_0: Balance,
+	pub tokens_owed_1: Balance,
+}
+
+/// Result of a trade calculation.
+/// `amount_in`: amount of asset in, fee included
+/// `amount_out`: amount of asset out
+/// `fee_amount`: fee charged in asset in
+/// `pool`: pool state after the trade
+/// `crossed_ticks`: updated state of ticks crossed by the trade
+#[derive(Clone, RuntimeDebug)]
+pub(crate) struct SwapResult {
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub fee_amount: Balance,
+	pub pool: PoolInfo,
+	pub crossed_ticks: Vec<(i32, TickInfo)>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
+}
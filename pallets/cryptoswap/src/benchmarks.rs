@@ -0,0 +1,201 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use frame_system::{Pallet as System, RawOrigin};
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ASSET_A: u32 = 2_000;
+const ASSET_B: u32 = 2_001;
+const POOL_ID: u32 = 1_000;
+
+const ONE: Balance = 1_000_000_000_000;
+
+fn create_pool<T: Config>() -> T::AssetId
+where
+	T::AssetId: From<u32>,
+{
+	T::BenchmarkHelper::register_asset(ASSET_A.into(), 12).expect("Failed to register asset");
+	T::BenchmarkHelper::register_asset(ASSET_B.into(), 12).expect("Failed to register asset");
+	T::BenchmarkHelper::register_asset(POOL_ID.into(), 18).expect("Failed to register asset");
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().expect("Failed to get successful origin");
+	crate::Pallet::<T>::create_pool(
+		successful_origin,
+		POOL_ID.into(),
+		ASSET_A.into(),
+		ASSET_B.into(),
+		400_000,
+		145_000_000_000_000,
+		Permill::from_rational(3u32, 1000u32),
+		FixedU128::from(2),
+		Permill::from_percent(1),
+	)
+	.expect("Failed to create pool");
+
+	POOL_ID.into()
+}
+
+fn setup_pool_with_initial_liquidity<T: Config>(acc: &T::AccountId) -> T::AssetId
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let pool_id = create_pool::<T>();
+
+	T::Currency::update_balance(ASSET_A.into(), acc, (2_000_000 * ONE) as i128).expect("Failed to update balance");
+	T::Currency::update_balance(ASSET_B.into(), acc, (1_000_000 * ONE) as i128).expect("Failed to update balance");
+	crate::Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(acc.clone()).into(),
+		pool_id,
+		2_000_000 * ONE,
+		1_000_000 * ONE,
+		Balance::zero(),
+	)
+	.expect("Failed to add initial liquidity");
+
+	// Worst case is when price scale is re-pegged towards the oracle price
+	T::BenchmarkHelper::register_oracle_price((ASSET_A.into(), ASSET_B.into()), (21, 10), T::OracleSource::get())
+		.expect("Failed to register oracle price");
+	System::<T>::set_block_number(System::<T>::block_number() + 10u32.into());
+
+	pool_id
+}
+
+benchmarks! {
+	 where_clause {  where T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T: crate::pallet::Config,
+	}
+
+	create_pool {
+		T::BenchmarkHelper::register_asset(ASSET_A.into(), 12)?;
+		T::BenchmarkHelper::register_asset(ASSET_B.into(), 12)?;
+		T::BenchmarkHelper::register_asset(POOL_ID.into(), 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, POOL_ID.into(), ASSET_A.into(), ASSET_B.into(), 400_000, 145_000_000_000_000, Permill::from_rational(3u32, 1000u32), FixedU128::from(2), Permill::from_percent(1))
+	verify {
+		assert!(<Pools<T>>::get::<T::AssetId>(POOL_ID.into()).is_some());
+	}
+
+	add_liquidity {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::update_balance(ASSET_A.into(), &caller, (1_000 * ONE) as i128)?;
+		T::Currency::update_balance(ASSET_B.into(), &caller, (300 * ONE) as i128)?;
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, 1_000 * ONE, 300 * ONE, Balance::zero())
+	verify {
+		assert!(T::Currency::free_balance(pool_id, &caller) > 0u128);
+	}
+
+	remove_liquidity {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+		let shares = T::Currency::free_balance(pool_id, &lp_provider) / 2;
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, shares, Balance::zero(), Balance::zero())
+	verify {
+		assert!(T::Currency::free_balance(ASSET_A.into(), &lp_provider) > 0u128);
+		assert!(T::Currency::free_balance(ASSET_B.into(), &lp_provider) > 0u128);
+	}
+
+	sell {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(ASSET_B.into(), &seller, (100 * ONE) as i128)?;
+	}: _(RawOrigin::Signed(seller.clone()), pool_id, ASSET_B.into(), ASSET_A.into(), 100 * ONE, Balance::zero())
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_B.into(), &seller), 0u128);
+		assert!(T::Currency::free_balance(ASSET_A.into(), &seller) > 0u128);
+	}
+
+	buy {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(ASSET_B.into(), &buyer, (100 * ONE) as i128)?;
+	}: _(RawOrigin::Signed(buyer.clone()), pool_id, ASSET_A.into(), ASSET_B.into(), 100 * ONE, 100 * ONE)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_A.into(), &buyer), 100 * ONE);
+	}
+
+	router_execution_sell {
+		let c in 1..2;
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		let amount_sell = 100 * ONE;
+		T::Currency::update_balance(ASSET_B.into(), &seller, amount_sell as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::Cryptoswap(pool_id), ASSET_B.into(), ASSET_A.into(), amount_sell).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::Cryptoswap(pool_id), ASSET_B.into(), ASSET_A.into(), amount_sell, Balance::zero()).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_B.into(), &seller), 0u128);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..2;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		let amount_buy = 100 * ONE;
+		T::Currency::update_balance(ASSET_B.into(), &buyer, (100 * ONE) as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Cryptoswap(pool_id), ASSET_B.into(), ASSET_A.into(), amount_buy).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::Cryptoswap(pool_id), ASSET_B.into(), ASSET_A.into(), amount_buy, 100 * ONE).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_A.into(), &buyer), amount_buy);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let pool_id = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+	}: {
+		assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Cryptoswap(pool_id), ASSET_B.into(), ASSET_A.into()).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
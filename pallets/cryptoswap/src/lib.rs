@@ -0,0 +1,1074 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Cryptoswap pallet
+//!
+//! Curve v2 style AMM for pairs of volatile assets.
+//!
+//! ## Overview
+//!
+//! Liquidity of a pool is concentrated around the pool's internal price scale - price of the second pool asset
+//! denominated in the first pool asset. Close to the price scale, the invariant behaves like stableswap invariant;
+//! far from it, it behaves like constant product. The shape of the curve is given by the amplification and gamma
+//! parameters of the pool.
+//!
+//! ### Price scale
+//! Price scale re-pegs towards the EMA oracle price of the pool assets (`PriceOracle` with `OracleSource`
+//! and `PriceOraclePeriod`). The re-peg is performed before each operation with the pool and price scale can move
+//! by at most `max_price_scale_update` per block. If there is no oracle entry available yet, price scale remains unchanged.
+//! Oracle of pool assets is whitelisted when a pool is created.
+//!
+//! A re-peg realizes a loss of LPs, so it is paid from profit of the pool (Curve v2 style). Pool tracks virtual price
+//! of a share and `xcp_profit` - growth of virtual price made by trades and liquidity operations. Price scale
+//! is re-pegged only if virtual price after the re-peg keeps at least half of the profit.
+//!
+//! ### Hooks
+//! - `AMMHandler` is notified of each trade and liquidity change, which is used to update on-chain oracle.
+//! - `CircuitBreaker` limits are enforced on each trade and liquidity change.
+//!
+//! ### Terminology
+//!
+//! * **LP** - liquidity provider
+//! * **Share Token** - a token representing share asset of specific pool. Each pool has its own share token.
+//! * **Price scale** - price of whole unit of the second asset in whole units of the first asset
+//!
+//! ## Assumptions
+//!
+//! A pool can be created only by allowed `AuthorityOrigin`.
+//!
+//! Pool has always 2 assets. First LP must provide liquidity of both assets.
+//!
+//! Share token is minted in 18 decimals, share asset should be registered accordingly.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::manual_inspect)]
+
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::{ensure, transactional};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::{
+	oracle::{AggregatedPriceOracle, OraclePeriod, OracleWhitelistHandler, Source},
+	registry::Inspect,
+	AccountIdFor, OnLiquidityChangedHandler, OnTradeHandler,
+};
+pub use pallet::*;
+use sp_runtime::traits::{BlockNumberProvider, CheckedDiv, CheckedMul, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, SaturatedConversion};
+use sp_std::prelude::*;
+use sp_std::vec;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{Balance, PoolInfo, PoolStateChangeLimits};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::stableswap::AssetAmount;
+use orml_traits::MultiCurrency;
+use pallet_broadcast::types::{Asset, Destination, Fee};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// Cryptoswap account id identifier.
+/// Used as identifier to create pool account id.
+pub const POOL_IDENTIFIER: &[u8] = b"cts";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_broadcast::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Provider for the current block number.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo
+			+ Into<u32>;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Account ID constructor - pool account are derived from unique pool id
+		type ShareAccountId: AccountIdFor<Self::AssetId, AccountId = Self::AccountId>;
+
+		/// Asset registry mechanism to check if asset is registered and retrieve asset decimals.
+		type AssetInspection: Inspect<AssetId = Self::AssetId>;
+
+		/// The origin which can create a new pool
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// AMM handlers called on trades and liquidity changes.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Oracle providing target price of price scale.
+		type PriceOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, Ratio>;
+
+		/// Oracle period used to re-peg price scale.
+		#[pallet::constant]
+		type PriceOraclePeriod: Get<OraclePeriod>;
+
+		/// Oracle whitelist handler - oracle of pool assets has to be tracked to re-peg price scale.
+		type OracleWhitelist: OracleWhitelistHandler<Self::AssetId>;
+
+		/// Limits of trades and liquidity changes (circuit breaker).
+		type CircuitBreaker: PoolStateChangeLimits<Self::AccountId, Self::AssetId>;
+
+		/// Minimum pool liquidity
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Existing pools
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, PoolInfo<T::AssetId, BlockNumberFor<T>>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			pool_id: T::AssetId,
+			assets: Vec<T::AssetId>,
+			amplification: u32,
+			gamma: u128,
+			fee: Permill,
+			price_scale: FixedU128,
+			max_price_scale_update: Permill,
+		},
+		/// Liquidity was added to a pool.
+		LiquidityAdded {
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			assets: Vec<AssetAmount<T::AssetId>>,
+		},
+		/// Liquidity was removed from a pool.
+		LiquidityRemoved {
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			amounts: Vec<AssetAmount<T::AssetId>>,
+		},
+		/// Price scale of a pool has been re-pegged towards the oracle price.
+		PriceScaleUpdated {
+			pool_id: T::AssetId,
+			price_scale: FixedU128,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool with same assets is not allowed.
+		IncorrectAssets,
+
+		/// A pool with given id does not exist.
+		PoolNotFound,
+
+		/// A pool with given id already exists.
+		PoolExists,
+
+		/// Asset is not in the pool.
+		AssetNotInPool,
+
+		/// Share asset is not registered in Registry.
+		ShareAssetNotRegistered,
+
+		/// Share asset is among pool assets.
+		ShareAssetInPoolAssets,
+
+		/// One or more assets are not registered in AssetRegistry
+		AssetNotRegistered,
+
+		/// Invalid asset amount provided. Amount must be greater than zero.
+		InvalidAssetAmount,
+
+		/// Balance of an asset is not sufficient to perform a trade.
+		InsufficientBalance,
+
+		/// Balance of a share asset is not sufficient to withdraw liquidity.
+		InsufficientShares,
+
+		/// Liquidity has not reached the required minimum.
+		InsufficientLiquidity,
+
+		/// Insufficient liquidity left in the pool after withdrawal.
+		InsufficientLiquidityRemaining,
+
+		/// Amount is less than the minimum trading amount configured.
+		InsufficientTradingAmount,
+
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+
+		/// Initial liquidity of both assets must be provided.
+		InvalidInitialLiquidity,
+
+		/// Amplification is outside allowed range.
+		InvalidAmplification,
+
+		/// Gamma is outside allowed range.
+		InvalidGamma,
+
+		/// Initial price scale must be greater than zero.
+		InvalidInitialPrice,
+
+		/// Remaining balance of share asset is below minimum pool liquidity.
+		InsufficientShareBalance,
+
+		/// Not allowed to perform an operation on given asset.
+		NotAllowed,
+
+		/// Slippage protection.
+		SlippageLimit,
+
+		/// Failed to retrieve asset decimals.
+		UnknownDecimals,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a cryptoswap pool of two assets.
+		///
+		/// All assets must be correctly registered in `T::AssetInspection`.
+		/// Note that this does not seed the pool with liquidity. Use `add_liquidity` to provide
+		/// initial liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `asset_a`: First pool asset - price scale is denominated in this asset
+		/// - `asset_b`: Second pool asset
+		/// - `amplification`: Pool amplification (`A * N^N * A_MULTIPLIER`)
+		/// - `gamma`: Pool gamma (18 decimals)
+		/// - `fee`: fee to be applied on trades
+		/// - `initial_price`: Initial price scale - price of whole unit of `asset_b` in whole units of `asset_a`
+		/// - `max_price_scale_update`: Maximum relative change of price scale per block
+		///
+		/// Oracle of pool assets is added to the oracle whitelist, so that price scale can be re-pegged.
+		///
+		/// Emits `PoolCreated` event if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool()
+							.saturating_add(T::OracleWhitelist::add_to_whitelist_weight()))]
+		#[transactional]
+		#[allow(clippy::too_many_arguments)]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			share_asset: T::AssetId,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			amplification: u32,
+			gamma: u128,
+			fee: Permill,
+			initial_price: FixedU128,
+			max_price_scale_update: Permill,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!Pools::<T>::contains_key(share_asset), Error::<T>::PoolExists);
+			ensure!(
+				T::AssetInspection::exists(share_asset),
+				Error::<T>::ShareAssetNotRegistered
+			);
+			ensure!(asset_a != asset_b, Error::<T>::IncorrectAssets);
+			ensure!(
+				share_asset != asset_a && share_asset != asset_b,
+				Error::<T>::ShareAssetInPoolAssets
+			);
+			ensure!(
+				T::AssetInspection::exists(asset_a) && T::AssetInspection::exists(asset_b),
+				Error::<T>::AssetNotRegistered
+			);
+			ensure!(
+				(hydra_dx_math::cryptoswap::MIN_AMPLIFICATION..=hydra_dx_math::cryptoswap::MAX_AMPLIFICATION)
+					.contains(&amplification.into()),
+				Error::<T>::InvalidAmplification
+			);
+			ensure!(
+				(hydra_dx_math::cryptoswap::MIN_GAMMA..=hydra_dx_math::cryptoswap::MAX_GAMMA).contains(&gamma),
+				Error::<T>::InvalidGamma
+			);
+			ensure!(!initial_price.is_zero(), Error::<T>::InvalidInitialPrice);
+
+			let pool = PoolInfo {
+				assets: [asset_a, asset_b],
+				amplification,
+				gamma,
+				fee,
+				price_scale: initial_price,
+				max_price_scale_update,
+				updated_at: T::BlockNumberProvider::current_block_number(),
+				virtual_price: FixedU128::one(),
+				xcp_profit: FixedU128::one(),
+			};
+			Pools::<T>::insert(share_asset, pool);
+
+			T::DustAccountHandler::add_account(&Self::pool_account(share_asset))?;
+			T::OracleWhitelist::add_to_whitelist(T::OracleSource::get(), asset_a, asset_b)?;
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id: share_asset,
+				assets: vec![asset_a, asset_b],
+				amplification,
+				gamma,
+				fee,
+				price_scale: initial_price,
+				max_price_scale_update,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to selected pool.
+		///
+		/// First LP must provide liquidity of both assets. Subsequent LPs can provide liquidity of one or both
+		/// assets, imbalanced liquidity is charged a fee.
+		///
+		/// LP must have sufficient amount of each asset.
+		///
+		/// Origin is given corresponding amount of shares.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `amount_a`: amount of first pool asset to add
+		/// - `amount_b`: amount of second pool asset to add
+		/// - `min_shares`: minimum amount of shares to receive
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+							.saturating_add(T::CircuitBreaker::liquidity_limit_weight().saturating_mul(2)))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			amount_a: Balance,
+			amount_b: Balance,
+			min_shares: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let amounts = [amount_a, amount_b];
+			ensure!(amounts.iter().any(|v| !v.is_zero()), Error::<T>::InvalidAssetAmount);
+			for (asset_id, amount) in pool.assets.iter().zip(amounts.iter()) {
+				if amount.is_zero() {
+					continue;
+				}
+				ensure!(
+					*amount >= T::MinTradingLimit::get(),
+					Error::<T>::InsufficientTradingAmount
+				);
+				ensure!(
+					T::Currency::free_balance(*asset_id, &who) >= *amount,
+					Error::<T>::InsufficientBalance
+				);
+			}
+
+			let pool_account = Self::pool_account(pool_id);
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			if share_issuance.is_zero() {
+				ensure!(
+					amounts.iter().all(|v| !v.is_zero()),
+					Error::<T>::InvalidInitialLiquidity
+				);
+			}
+
+			let mut updated_reserves = initial_reserves;
+			for (reserve, amount) in updated_reserves.iter_mut().zip(amounts.iter()) {
+				reserve.amount = reserve.amount.checked_add(*amount).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			Self::update_price_scale(pool_id, &mut pool)?;
+			let initial_virtual_price = if share_issuance.is_zero() {
+				None
+			} else {
+				Some(Self::current_virtual_price(pool_id, &pool)?)
+			};
+			let (share_amount, fee_shares) = hydra_dx_math::cryptoswap::calculate_shares(
+				&initial_reserves,
+				&updated_reserves,
+				pool.curve_params(),
+				pool.price_scale,
+				share_issuance,
+				pool.fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
+			ensure!(share_amount >= min_shares, Error::<T>::SlippageLimit);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(
+				current_share_balance.saturating_add(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			T::Currency::deposit(pool_id, &who, share_amount)?;
+
+			let mut added_assets = Vec::with_capacity(2);
+			for ((asset_id, amount), reserve) in pool.assets.iter().zip(amounts.iter()).zip(initial_reserves.iter()) {
+				if amount.is_zero() {
+					continue;
+				}
+				T::CircuitBreaker::ensure_add_liquidity_limit(&who, *asset_id, reserve.amount, *amount)?;
+				T::Currency::transfer(*asset_id, &who, &pool_account, *amount)?;
+				added_assets.push(AssetAmount::new(*asset_id, *amount));
+			}
+
+			if let Some(virtual_price) = initial_virtual_price {
+				Self::update_profit(pool_id, &mut pool, virtual_price)?;
+			} else {
+				// Initial liquidity - profit is tracked from now on.
+				pool.virtual_price = FixedU128::one();
+				pool.xcp_profit = FixedU128::one();
+				Pools::<T>::insert(pool_id, pool.clone());
+			}
+
+			Self::call_on_liquidity_changed_hook(pool_id, &pool, amounts)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				pool_id,
+				who: who.clone(),
+				shares: share_amount,
+				assets: added_assets.clone(),
+			});
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who,
+				pool_account.clone(),
+				pallet_broadcast::types::Filler::Cryptoswap(pool_id.into()),
+				pallet_broadcast::types::TradeOperation::LiquidityAdd,
+				added_assets
+					.iter()
+					.map(|asset| Asset::new(asset.asset_id.into(), asset.amount))
+					.collect(),
+				vec![Asset::new(pool_id.into(), share_amount)],
+				vec![Fee::new(pool_id.into(), fee_shares, Destination::Account(pool_account))],
+			);
+
+			Ok(())
+		}
+
+		/// Remove liquidity from selected pool.
+		///
+		/// LP receives both pool assets proportionally to the amount of shares burned.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `share_amount`: amount of shares to withdraw
+		/// - `min_amount_a`: minimum amount of first pool asset to receive
+		/// - `min_amount_b`: minimum amount of second pool asset to receive
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+							.saturating_add(T::CircuitBreaker::liquidity_limit_weight().saturating_mul(2)))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			share_amount: Balance,
+			min_amount_a: Balance,
+			min_amount_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+			ensure!(
+				current_share_balance == share_amount
+					|| current_share_balance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pool_id);
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			ensure!(
+				share_issuance == share_amount
+					|| share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			Self::update_price_scale(pool_id, &mut pool)?;
+
+			let min_amounts = [min_amount_a, min_amount_b];
+			let mut amounts = [Balance::zero(); 2];
+			let mut removed_assets = Vec::with_capacity(2);
+			for (idx, asset_id) in pool.assets.iter().enumerate() {
+				let reserve = T::Currency::free_balance(*asset_id, &pool_account);
+				let amount = hydra_dx_math::cryptoswap::calculate_liquidity_out(reserve, share_amount, share_issuance)
+					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(amount >= min_amounts[idx], Error::<T>::SlippageLimit);
+
+				T::CircuitBreaker::ensure_remove_liquidity_limit(&who, *asset_id, reserve, amount)?;
+				T::Currency::transfer(*asset_id, &pool_account, &who, amount)?;
+
+				amounts[idx] = amount;
+				removed_assets.push(AssetAmount::new(*asset_id, amount));
+			}
+
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+
+			Self::call_on_liquidity_changed_hook(pool_id, &pool, amounts)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who: who.clone(),
+				shares: share_amount,
+				amounts: removed_assets.clone(),
+			});
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who,
+				pool_account,
+				pallet_broadcast::types::Filler::Cryptoswap(pool_id.into()),
+				pallet_broadcast::types::TradeOperation::LiquidityRemove,
+				vec![Asset::new(pool_id.into(), share_amount)],
+				removed_assets
+					.iter()
+					.map(|asset| Asset::new(asset.asset_id.into(), asset.amount))
+					.collect(),
+				vec![],
+			);
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out`.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `pool_id`: Id of a pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset to be sold to the pool
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+							.saturating_add(T::AMMHandler::on_trade_weight())
+							.saturating_add(T::CircuitBreaker::trade_limit_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let reserve_in = T::Currency::free_balance(asset_in, &pool_account);
+			let reserve_out = T::Currency::free_balance(asset_out, &pool_account);
+
+			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, true)?;
+			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let initial_virtual_price = Self::current_virtual_price(pool_id, &pool)?;
+
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::update_profit(pool_id, &mut pool, initial_virtual_price)?;
+
+			T::CircuitBreaker::ensure_trade_limit(asset_in, reserve_in, amount_in, asset_out, reserve_out, amount_out)?;
+			Self::call_on_trade_hook(pool_id, asset_in, asset_out, amount_in, amount_out)?;
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who,
+				pool_account.clone(),
+				pallet_broadcast::types::Filler::Cryptoswap(pool_id.into()),
+				pallet_broadcast::types::TradeOperation::ExactIn,
+				vec![Asset::new(asset_in.into(), amount_in)],
+				vec![Asset::new(asset_out.into(), amount_out)],
+				vec![Fee {
+					asset: asset_out.into(),
+					amount: fee_amount,
+					destination: Destination::Account(pool_account),
+				}],
+			);
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_out` for `asset_in`.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `pool_id`: Id of a pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: Amount of asset to receive from the pool
+		/// - `max_sell_amount`: Maximum amount allowed to be sold
+		///
+		/// Emits `pallet_broadcast::Swapped` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+							.saturating_add(T::AMMHandler::on_trade_weight())
+							.saturating_add(T::CircuitBreaker::trade_limit_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let reserve_in = T::Currency::free_balance(asset_in, &pool_account);
+			let reserve_out = T::Currency::free_balance(asset_out, &pool_account);
+
+			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, true)?;
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let initial_virtual_price = Self::current_virtual_price(pool_id, &pool)?;
+
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::update_profit(pool_id, &mut pool, initial_virtual_price)?;
+
+			T::CircuitBreaker::ensure_trade_limit(asset_in, reserve_in, amount_in, asset_out, reserve_out, amount_out)?;
+			Self::call_on_trade_hook(pool_id, asset_in, asset_out, amount_in, amount_out)?;
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who,
+				pool_account.clone(),
+				pallet_broadcast::types::Filler::Cryptoswap(pool_id.into()),
+				pallet_broadcast::types::TradeOperation::ExactOut,
+				vec![Asset::new(asset_in.into(), amount_in)],
+				vec![Asset::new(asset_out.into(), amount_out)],
+				vec![Fee {
+					asset: asset_out.into(),
+					amount: fee_amount,
+					destination: Destination::Account(pool_account),
+				}],
+			);
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Calculates out amount given in amount.
+	/// Returns (out_amount, fee_amount) on success. Note that fee amount is already subtracted from the out amount.
+	fn calculate_out_amount(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		persist_price_scale: bool,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+
+		let initial_reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
+		ensure!(
+			!initial_reserves[index_out].is_zero(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		if persist_price_scale {
+			Self::update_price_scale(pool_id, &mut pool)?;
+		} else {
+			Self::repeg_price_scale(pool_id, &mut pool)?;
+		}
+
+		hydra_dx_math::cryptoswap::calculate_out_given_in(
+			&initial_reserves,
+			index_in,
+			index_out,
+			amount_in,
+			pool.curve_params(),
+			pool.price_scale,
+			pool.fee,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Calculates in amount given out amount.
+	/// Returns (in_amount, fee_amount) on success. Note that fee amount is paid in asset out.
+	fn calculate_in_amount(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		persist_price_scale: bool,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+
+		let initial_reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		ensure!(
+			initial_reserves[index_out].amount > amount_out,
+			Error::<T>::InsufficientLiquidity
+		);
+		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
+
+		if persist_price_scale {
+			Self::update_price_scale(pool_id, &mut pool)?;
+		} else {
+			Self::repeg_price_scale(pool_id, &mut pool)?;
+		}
+
+		hydra_dx_math::cryptoswap::calculate_in_given_out(
+			&initial_reserves,
+			index_in,
+			index_out,
+			amount_out,
+			pool.curve_params(),
+			pool.price_scale,
+			pool.fee,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Calculates spot price of `asset_out` denominated in `asset_in` without fee.
+	pub(crate) fn calculate_spot_price(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<FixedU128, DispatchError> {
+		let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+		ensure!(index_in != index_out, Error::<T>::NotAllowed);
+
+		let reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		ensure!(
+			!reserves[0].is_zero() && !reserves[1].is_zero(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		Self::repeg_price_scale(pool_id, &mut pool)?;
+		let price = hydra_dx_math::cryptoswap::calculate_spot_price(&reserves, pool.curve_params(), pool.price_scale)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if index_in == 0 {
+			Ok(price)
+		} else {
+			price.reciprocal().ok_or_else(|| ArithmeticError::DivisionByZero.into())
+		}
+	}
+
+	#[inline]
+	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
+	}
+
+	#[inline]
+	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
+		T::AssetInspection::decimals(asset_id)
+	}
+
+	/// Returns price scale moved towards the oracle price by at most `max_price_scale_update` per block
+	/// since the last update. Returns current price scale if the oracle price is not available.
+	fn get_target_price_scale(pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>) -> Result<FixedU128, DispatchError> {
+		let Ok((price, _)) = T::PriceOracle::get_price(
+			pool.assets[0],
+			pool.assets[1],
+			T::PriceOraclePeriod::get(),
+			T::OracleSource::get(),
+		) else {
+			return Ok(pool.price_scale);
+		};
+
+		let decimals_a = Self::retrieve_decimals(pool.assets[0]).ok_or(Error::<T>::UnknownDecimals)?;
+		let decimals_b = Self::retrieve_decimals(pool.assets[1]).ok_or(Error::<T>::UnknownDecimals)?;
+		let Some(target) =
+			hydra_dx_math::cryptoswap::calculate_price_scale_from_price((price.n, price.d), [decimals_a, decimals_b])
+		else {
+			return Ok(pool.price_scale);
+		};
+
+		let block_diff = T::BlockNumberProvider::current_block_number().saturating_sub(pool.updated_at);
+		Ok(hydra_dx_math::cryptoswap::calculate_price_scale(
+			pool.price_scale,
+			target,
+			pool.max_price_scale_update,
+			block_diff.saturated_into(),
+		))
+	}
+
+	/// Re-pegs price scale of the pool towards the oracle price, without storing it.
+	///
+	/// Re-peg is skipped if the virtual price after the re-peg would not keep at least half of the profit
+	/// of the pool.
+	fn repeg_price_scale(pool_id: T::AssetId, pool: &mut PoolInfo<T::AssetId, BlockNumberFor<T>>) -> DispatchResult {
+		let price_scale = Self::get_target_price_scale(pool)?;
+		if price_scale == pool.price_scale {
+			return Ok(());
+		}
+
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		if share_issuance.is_zero() {
+			pool.price_scale = price_scale;
+			return Ok(());
+		}
+
+		let reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		let current_virtual_price = hydra_dx_math::cryptoswap::calculate_virtual_price(
+			&reserves,
+			pool.curve_params(),
+			pool.price_scale,
+			share_issuance,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let updated_virtual_price = hydra_dx_math::cryptoswap::calculate_virtual_price(
+			&reserves,
+			pool.curve_params(),
+			price_scale,
+			share_issuance,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let virtual_price = updated_virtual_price
+			.checked_div(&current_virtual_price)
+			.and_then(|change| pool.virtual_price.checked_mul(&change))
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if hydra_dx_math::cryptoswap::is_price_scale_update_allowed(virtual_price, pool.xcp_profit) {
+			pool.price_scale = price_scale;
+			pool.virtual_price = virtual_price;
+		}
+		Ok(())
+	}
+
+	/// Re-pegs price scale of the pool and stores it, if changed.
+	fn update_price_scale(pool_id: T::AssetId, pool: &mut PoolInfo<T::AssetId, BlockNumberFor<T>>) -> DispatchResult {
+		let initial_price_scale = pool.price_scale;
+		Self::repeg_price_scale(pool_id, pool)?;
+
+		let current_block = T::BlockNumberProvider::current_block_number();
+		if pool.price_scale == initial_price_scale && pool.updated_at == current_block {
+			return Ok(());
+		}
+
+		pool.updated_at = current_block;
+		Pools::<T>::insert(pool_id, pool.clone());
+
+		if pool.price_scale != initial_price_scale {
+			Self::deposit_event(Event::PriceScaleUpdated {
+				pool_id,
+				price_scale: pool.price_scale,
+			});
+		}
+		Ok(())
+	}
+
+	/// Virtual price of a share of the pool, given its current reserves and share issuance.
+	fn current_virtual_price(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+	) -> Result<FixedU128, DispatchError> {
+		let reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		hydra_dx_math::cryptoswap::calculate_virtual_price(
+			&reserves,
+			pool.curve_params(),
+			pool.price_scale,
+			T::Currency::total_issuance(pool_id),
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Updates and stores virtual price and profit of the pool after an operation, given the virtual price
+	/// before the operation.
+	fn update_profit(
+		pool_id: T::AssetId,
+		pool: &mut PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		initial_virtual_price: FixedU128,
+	) -> DispatchResult {
+		let change = Self::current_virtual_price(pool_id, pool)?
+			.checked_div(&initial_virtual_price)
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+		pool.virtual_price = pool
+			.virtual_price
+			.checked_mul(&change)
+			.ok_or(ArithmeticError::Overflow)?;
+		if change > FixedU128::one() {
+			pool.xcp_profit = pool.xcp_profit.checked_mul(&change).ok_or(ArithmeticError::Overflow)?;
+		}
+		Pools::<T>::insert(pool_id, pool.clone());
+		Ok(())
+	}
+
+	// Spot price of `asset_b` denominated in `asset_a` in the form expected by oracle - amount of `asset_a` per `asset_b`.
+	fn oracle_price(pool_id: T::AssetId, asset_a: T::AssetId, asset_b: T::AssetId) -> Result<Ratio, DispatchError> {
+		let price = Self::calculate_spot_price(pool_id, asset_a, asset_b)?;
+		Ok(Ratio::new(price.into_inner(), FixedU128::DIV))
+	}
+
+	fn call_on_trade_hook(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_id);
+		let liquidity_in = T::Currency::free_balance(asset_in, &pool_account);
+		let liquidity_out = T::Currency::free_balance(asset_out, &pool_account);
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			Self::oracle_price(pool_id, asset_in, asset_out)?,
+		)
+		.map_err(|(_w, e)| e)?;
+		Ok(())
+	}
+
+	fn call_on_liquidity_changed_hook(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		amounts: [Balance; 2],
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_id);
+		let liquidity_a = T::Currency::free_balance(pool.assets[0], &pool_account);
+		let liquidity_b = T::Currency::free_balance(pool.assets[1], &pool_account);
+		// Pool can be emptied by the last LP, there is no price in that case.
+		let price = if liquidity_a.is_zero() || liquidity_b.is_zero() {
+			Ratio::new(liquidity_a, liquidity_b)
+		} else {
+			Self::oracle_price(pool_id, pool.assets[0], pool.assets[1])?
+		};
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			pool.assets[0],
+			pool.assets[1],
+			amounts[0],
+			amounts[1],
+			liquidity_a,
+			liquidity_b,
+			price,
+		)
+		.map_err(|(_w, e)| e)?;
+		Ok(())
+	}
+}
@@ -0,0 +1,232 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{Error, Event, Pools};
+use frame_support::traits::Get;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::One;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128, Permill};
+
+fn create(
+	share_asset: AssetId,
+	asset_a: AssetId,
+	asset_b: AssetId,
+	amplification: u32,
+	gamma: u128,
+	initial_price: FixedU128,
+) -> frame_support::dispatch::DispatchResult {
+	Cryptoswap::create_pool(
+		RuntimeOrigin::root(),
+		share_asset,
+		asset_a,
+		asset_b,
+		amplification,
+		gamma,
+		Permill::from_rational(3u32, 1000u32),
+		initial_price,
+		Permill::from_percent(1),
+	)
+}
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create(
+			POOL_ID,
+			DOT,
+			ETH,
+			DEFAULT_AMPLIFICATION,
+			DEFAULT_GAMMA,
+			FixedU128::from(2)
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(POOL_ID),
+			Some(PoolInfo {
+				assets: [DOT, ETH],
+				amplification: DEFAULT_AMPLIFICATION,
+				gamma: DEFAULT_GAMMA,
+				fee: Permill::from_rational(3u32, 1000u32),
+				price_scale: FixedU128::from(2),
+				max_price_scale_update: Permill::from_percent(1),
+				updated_at: 1,
+				virtual_price: FixedU128::one(),
+				xcp_profit: FixedU128::one(),
+			})
+		);
+		assert!(DUSTER_WHITELIST.with(|v| v.borrow().contains(&pool_account(POOL_ID))));
+		assert!(ORACLE_WHITELIST.with(|v| v.borrow().contains(&(CryptoswapOracleSource::get(), DOT, ETH))));
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id: POOL_ID,
+			assets: vec![DOT, ETH],
+			amplification: DEFAULT_AMPLIFICATION,
+			gamma: DEFAULT_GAMMA,
+			fee: Permill::from_rational(3u32, 1000u32),
+			price_scale: FixedU128::from(2),
+			max_price_scale_update: Permill::from_percent(1),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				DOT,
+				ETH,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				Permill::from_rational(3u32, 1000u32),
+				FixedU128::from(2),
+				Permill::from_percent(1),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create(
+			POOL_ID,
+			DOT,
+			ETH,
+			DEFAULT_AMPLIFICATION,
+			DEFAULT_GAMMA,
+			FixedU128::from(2)
+		));
+
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				HDX,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				FixedU128::from(2)
+			),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				DOT,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				FixedU128::from(2)
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_share_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(200, DOT, ETH, DEFAULT_AMPLIFICATION, DEFAULT_GAMMA, FixedU128::from(2)),
+			Error::<Test>::ShareAssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_share_asset_is_pool_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(DOT, DOT, ETH, DEFAULT_AMPLIFICATION, DEFAULT_GAMMA, FixedU128::from(2)),
+			Error::<Test>::ShareAssetInPoolAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				50,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				FixedU128::from(2)
+			),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_amplification_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		let min = hydra_dx_math::cryptoswap::MIN_AMPLIFICATION as u32;
+		let max = hydra_dx_math::cryptoswap::MAX_AMPLIFICATION as u32;
+
+		assert_noop!(
+			create(POOL_ID, DOT, ETH, min - 1, DEFAULT_GAMMA, FixedU128::from(2)),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			create(POOL_ID, DOT, ETH, max + 1, DEFAULT_GAMMA, FixedU128::from(2)),
+			Error::<Test>::InvalidAmplification
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_gamma_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				ETH,
+				DEFAULT_AMPLIFICATION,
+				hydra_dx_math::cryptoswap::MIN_GAMMA - 1,
+				FixedU128::from(2)
+			),
+			Error::<Test>::InvalidGamma
+		);
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				ETH,
+				DEFAULT_AMPLIFICATION,
+				hydra_dx_math::cryptoswap::MAX_GAMMA + 1,
+				FixedU128::from(2)
+			),
+			Error::<Test>::InvalidGamma
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			create(
+				POOL_ID,
+				DOT,
+				ETH,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				FixedU128::from(0)
+			),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
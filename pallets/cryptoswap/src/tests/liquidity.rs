@@ -0,0 +1,300 @@
+use crate::tests::mock::*;
+use crate::tests::{create_pool, pool_ext, SHARE_ONE};
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::stableswap::AssetAmount;
+use pallet_broadcast::types::{Asset, Destination, Fee, Filler, TradeOperation};
+use sp_runtime::Permill;
+
+#[test]
+fn add_initial_liquidity_should_mint_shares_equal_to_invariant() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DOT, 2_000_000 * ONE), (ALICE, ETH, 1_000_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			create_pool(Permill::from_percent(1));
+
+			assert_ok!(Cryptoswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				2_000_000 * ONE,
+				1_000_000 * ONE,
+				0,
+			));
+
+			// D of balanced pool is sum of reserves valued in the first asset
+			assert_balance!(ALICE, POOL_ID, 4_000_000 * SHARE_ONE);
+			assert_balance!(ALICE, DOT, 0);
+			assert_balance!(ALICE, ETH, 0);
+			assert_balance!(pool_account(POOL_ID), DOT, 2_000_000 * ONE);
+			assert_balance!(pool_account(POOL_ID), ETH, 1_000_000 * ONE);
+
+			expect_events(vec![Event::LiquidityAdded {
+				pool_id: POOL_ID,
+				who: ALICE,
+				shares: 4_000_000 * SHARE_ONE,
+				assets: vec![
+					AssetAmount::new(DOT, 2_000_000 * ONE),
+					AssetAmount::new(ETH, 1_000_000 * ONE),
+				],
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn add_initial_liquidity_should_fail_when_only_one_asset_is_provided() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DOT, 2_000_000 * ONE), (ALICE, ETH, 1_000_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			create_pool(Permill::from_percent(1));
+
+			assert_noop!(
+				Cryptoswap::add_liquidity(RuntimeOrigin::signed(ALICE), POOL_ID, 2_000_000 * ONE, 0, 0),
+				Error::<Test>::InvalidInitialLiquidity
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DOT, 2_000_000 * ONE), (ALICE, ETH, 1_000_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Cryptoswap::add_liquidity(RuntimeOrigin::signed(ALICE), POOL_ID, ONE, ONE, 0),
+				Error::<Test>::PoolNotFound
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_not_sufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::add_liquidity(RuntimeOrigin::signed(BOB), POOL_ID, 1_001 * ONE, 0, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_amounts_are_zero() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::add_liquidity(RuntimeOrigin::signed(BOB), POOL_ID, 0, 0, 0),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn add_balanced_liquidity_should_mint_proportional_shares() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			200 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, POOL_ID, 400 * SHARE_ONE);
+		assert_balance!(BOB, DOT, 800 * ONE);
+		assert_balance!(BOB, ETH, 900 * ONE);
+	});
+}
+
+#[test]
+fn add_imbalanced_liquidity_should_charge_fee() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			400 * ONE,
+			0,
+			0,
+		));
+
+		let shares = Tokens::free_balance(POOL_ID, &BOB);
+		assert!(shares > 0);
+		assert!(shares < 400 * SHARE_ONE);
+		assert_balance!(BOB, DOT, 600 * ONE);
+		assert_balance!(BOB, ETH, 1_000 * ONE);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_min_shares_is_not_reached() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				200 * ONE,
+				100 * ONE,
+				400 * SHARE_ONE + 1
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_circuit_breaker_limit_is_exceeded() {
+	pool_ext().execute_with(|| {
+		set_liquidity_limit(100 * ONE);
+
+		assert_noop!(
+			Cryptoswap::add_liquidity(RuntimeOrigin::signed(BOB), POOL_ID, 200 * ONE, 100 * ONE, 0),
+			sp_runtime::DispatchError::Other("liquidity limit")
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_notify_amm_handler() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			200 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		let (asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price) =
+			last_liquidity_changed_hook().unwrap();
+		assert_eq!((asset_a, asset_b), (DOT, ETH));
+		assert_eq!((amount_a, amount_b), (200 * ONE, 100 * ONE));
+		assert_eq!((liquidity_a, liquidity_b), (2_000_200 * ONE, 1_000_100 * ONE));
+		// balanced pool is priced at price scale - 2 DOT per ETH
+		assert_eq!(price, hydra_dx_math::ratio::Ratio::new(2 * SHARE_ONE, SHARE_ONE));
+	});
+}
+
+#[test]
+fn add_liquidity_should_emit_swapped_event() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			200 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		let swapped_events = get_last_swapped_events();
+		let last = swapped_events.last().unwrap();
+		assert_eq!(
+			*last,
+			RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped {
+				swapper: BOB,
+				filler: pool_account(POOL_ID),
+				filler_type: Filler::Cryptoswap(POOL_ID),
+				operation: TradeOperation::LiquidityAdd,
+				inputs: vec![Asset::new(DOT, 200 * ONE), Asset::new(ETH, 100 * ONE)],
+				outputs: vec![Asset::new(POOL_ID, 400 * SHARE_ONE)],
+				fees: vec![Fee::new(POOL_ID, 0, Destination::Account(pool_account(POOL_ID)))],
+				operation_stack: vec![],
+			})
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_return_assets_proportionally() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL_ID,
+			2_000_000 * SHARE_ONE,
+			0,
+			0,
+		));
+
+		assert_balance!(ALICE, POOL_ID, 2_000_000 * SHARE_ONE);
+		assert_balance!(ALICE, DOT, 2_000_000 * ONE);
+		assert_balance!(ALICE, ETH, 1_500_000 * ONE);
+		assert_balance!(pool_account(POOL_ID), DOT, 1_000_000 * ONE);
+		assert_balance!(pool_account(POOL_ID), ETH, 500_000 * ONE);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			pool_id: POOL_ID,
+			who: ALICE,
+			shares: 2_000_000 * SHARE_ONE,
+			amounts: vec![
+				AssetAmount::new(DOT, 1_000_000 * ONE),
+				AssetAmount::new(ETH, 500_000 * ONE),
+			],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_all_liquidity_should_empty_pool() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL_ID,
+			4_000_000 * SHARE_ONE,
+			0,
+			0,
+		));
+
+		assert_eq!(Tokens::total_issuance(POOL_ID), 0);
+		assert_balance!(pool_account(POOL_ID), DOT, 0);
+		assert_balance!(pool_account(POOL_ID), ETH, 0);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_min_amount_is_not_reached() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				2_000_000 * SHARE_ONE,
+				0,
+				500_000 * ONE + 1
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_not_sufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::remove_liquidity(RuntimeOrigin::signed(BOB), POOL_ID, SHARE_ONE, 0, 0),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_shares_are_below_minimum() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL_ID, 4_000_000 * SHARE_ONE - 1, 0, 0),
+			Error::<Test>::InsufficientShareBalance
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_circuit_breaker_limit_is_exceeded() {
+	pool_ext().execute_with(|| {
+		set_liquidity_limit(100_000 * ONE);
+
+		assert_noop!(
+			Cryptoswap::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL_ID, 2_000_000 * SHARE_ONE, 0, 0),
+			sp_runtime::DispatchError::Other("liquidity limit")
+		);
+	});
+}
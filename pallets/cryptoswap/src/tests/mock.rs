@@ -0,0 +1,523 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Cryptoswap pallet.
+#![allow(clippy::type_complexity)]
+
+use sp_runtime::DispatchResult;
+use sp_std::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_cryptoswap;
+
+use crate::Config;
+
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const ETH: AssetId = 2;
+pub const POOL_ID: AssetId = 100;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const DEFAULT_AMPLIFICATION: u32 = 400_000;
+pub const DEFAULT_GAMMA: u128 = 145_000_000_000_000;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static ORACLE_WHITELIST: RefCell<Vec<(Source, AssetId, AssetId)>> = const { RefCell::new(Vec::new()) };
+	pub static LAST_TRADE_HOOK: RefCell<Option<HookCall>> = const { RefCell::new(None) };
+	pub static LAST_LIQUIDITY_CHANGE_HOOK: RefCell<Option<HookCall>> = const { RefCell::new(None) };
+	pub static TRADE_LIMIT: RefCell<Option<Balance>> = const { RefCell::new(None) };
+	pub static LIQUIDITY_LIMIT: RefCell<Option<Balance>> = const { RefCell::new(None) };
+}
+
+/// Arguments of last AMM handler call - (asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price)
+pub type HookCall = (AssetId, AssetId, Balance, Balance, Balance, Balance, Ratio);
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Cryptoswap: pallet_cryptoswap,
+		Broadcast: pallet_broadcast,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1_000_000;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub const CryptoswapOracleSource: Source = *b"cryptosw";
+	pub const CryptoswapOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+impl pallet_broadcast::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Whitelist;
+	type OracleSource = CryptoswapOracleSource;
+	type AMMHandler = DummyAMMHandler;
+	type PriceOracle = DummyOracle;
+	type PriceOraclePeriod = CryptoswapOraclePeriod;
+	type OracleWhitelist = DummyOracleWhitelist;
+	type CircuitBreaker = DummyCircuitBreaker;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(AssetId, u8)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each test case
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		ORACLE_PRICES.with(|v| v.borrow_mut().clear());
+		ORACLE_WHITELIST.with(|v| v.borrow_mut().clear());
+		LAST_TRADE_HOOK.with(|v| *v.borrow_mut() = None);
+		LAST_LIQUIDITY_CHANGE_HOOK.with(|v| *v.borrow_mut() = None);
+		TRADE_LIMIT.with(|v| *v.borrow_mut() = None);
+		LIQUIDITY_LIMIT.with(|v| *v.borrow_mut() = None);
+
+		Self {
+			endowed_accounts: vec![],
+			registered_assets: vec![(HDX, 12), (DOT, 12), (ETH, 12), (POOL_ID, 18)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId, decimals: u8) -> Self {
+		self.registered_assets.push((asset, decimals));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		for (asset, decimals) in self.registered_assets.into_iter() {
+			REGISTERED_ASSETS.with(|v| {
+				v.borrow_mut().insert(asset, decimals);
+			});
+		}
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| {
+			frame_system::Pallet::<Test>::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+use crate::types::BenchmarkHelper;
+use crate::types::PoolStateChangeLimits;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{
+	AccountIdFor, AggregatedPriceOracle, Inspect, OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod,
+	OracleWhitelistHandler, Source,
+};
+
+pub struct DummyRegistry;
+
+impl Inspect for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<hydradx_traits::AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+
+	fn register_oracle_price(
+		asset_pair: (AssetId, AssetId),
+		price: (Balance, Balance),
+		_source: Source,
+	) -> DispatchResult {
+		set_oracle_price(asset_pair.0, asset_pair.1, price);
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub(crate) fn pool_account(pool_id: AssetId) -> AccountId {
+	AccountIdConstructor::from_assets(&pool_id, None)
+}
+
+pub struct DummyOracle;
+
+impl AggregatedPriceOracle<AssetId, u64, Ratio> for DummyOracle {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(Ratio, u64), ()> {
+		let (n, d) = ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.ok_or(())?;
+		Ok((Ratio::new(n, d), 1))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Sets oracle price - amount of `asset_a` per `asset_b` in the smallest units.
+pub(crate) fn set_oracle_price(asset_a: AssetId, asset_b: AssetId, price: (Balance, Balance)) {
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert((asset_a, asset_b), price);
+	});
+}
+
+pub struct DummyOracleWhitelist;
+
+impl OracleWhitelistHandler<AssetId> for DummyOracleWhitelist {
+	fn add_to_whitelist(source: Source, asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+		ORACLE_WHITELIST.with(|v| v.borrow_mut().push((source, asset_a, asset_b)));
+		Ok(())
+	}
+
+	fn add_to_whitelist_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyAMMHandler;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for DummyAMMHandler {
+	fn on_trade(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LAST_TRADE_HOOK.with(|v| {
+			*v.borrow_mut() = Some((asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price));
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, Ratio> for DummyAMMHandler {
+	fn on_liquidity_changed(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LAST_LIQUIDITY_CHANGE_HOOK.with(|v| {
+			*v.borrow_mut() = Some((asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price));
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn last_trade_hook() -> Option<HookCall> {
+	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn last_liquidity_changed_hook() -> Option<HookCall> {
+	LAST_LIQUIDITY_CHANGE_HOOK.with(|v| v.borrow().clone())
+}
+
+pub struct DummyCircuitBreaker;
+
+impl PoolStateChangeLimits<AccountId, AssetId> for DummyCircuitBreaker {
+	fn ensure_trade_limit(
+		_asset_in: AssetId,
+		_asset_in_reserve: Balance,
+		amount_in: Balance,
+		_asset_out: AssetId,
+		_asset_out_reserve: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		match TRADE_LIMIT.with(|v| *v.borrow()) {
+			Some(limit) if amount_in > limit || amount_out > limit => Err(DispatchError::Other("trade limit")),
+			_ => Ok(()),
+		}
+	}
+
+	fn ensure_add_liquidity_limit(
+		_who: &AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		added_liquidity: Balance,
+	) -> DispatchResult {
+		match LIQUIDITY_LIMIT.with(|v| *v.borrow()) {
+			Some(limit) if added_liquidity > limit => Err(DispatchError::Other("liquidity limit")),
+			_ => Ok(()),
+		}
+	}
+
+	fn ensure_remove_liquidity_limit(
+		_who: &AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		removed_liquidity: Balance,
+	) -> DispatchResult {
+		match LIQUIDITY_LIMIT.with(|v| *v.borrow()) {
+			Some(limit) if removed_liquidity > limit => Err(DispatchError::Other("liquidity limit")),
+			_ => Ok(()),
+		}
+	}
+
+	fn trade_limit_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn liquidity_limit_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn set_trade_limit(limit: Balance) {
+	TRADE_LIMIT.with(|v| *v.borrow_mut() = Some(limit));
+}
+
+pub(crate) fn set_liquidity_limit(limit: Balance) {
+	LIQUIDITY_LIMIT.with(|v| *v.borrow_mut() = Some(limit));
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+pub fn get_last_swapped_events() -> Vec<RuntimeEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.map(|e| e.event)
+		.filter(|e| matches!(e, RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped { .. })))
+		.collect()
+}
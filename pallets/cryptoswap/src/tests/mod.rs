@@ -0,0 +1,52 @@
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use sp_runtime::{FixedU128, Permill};
+
+mod creation;
+mod liquidity;
+pub(crate) mod mock;
+mod price_scale;
+mod trade_execution;
+mod trades;
+
+pub(crate) const SHARE_ONE: Balance = 1_000_000_000_000_000_000;
+
+/// Creates DOT/ETH pool with price scale of 2 DOT per ETH.
+pub(crate) fn create_pool(max_price_scale_update: Permill) {
+	assert_ok!(Cryptoswap::create_pool(
+		RuntimeOrigin::root(),
+		POOL_ID,
+		DOT,
+		ETH,
+		DEFAULT_AMPLIFICATION,
+		DEFAULT_GAMMA,
+		Permill::from_rational(3u32, 1000u32),
+		FixedU128::from(2),
+		max_price_scale_update,
+	));
+}
+
+/// DOT/ETH pool with balanced initial liquidity of 2M DOT and 1M ETH provided by ALICE.
+pub(crate) fn pool_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DOT, 3_000_000 * ONE),
+			(ALICE, ETH, 2_000_000 * ONE),
+			(BOB, DOT, 1_000 * ONE),
+			(BOB, ETH, 1_000 * ONE),
+		])
+		.build();
+
+	ext.execute_with(|| {
+		create_pool(Permill::from_percent(1));
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL_ID,
+			2_000_000 * ONE,
+			1_000_000 * ONE,
+			0,
+		));
+	});
+
+	ext
+}
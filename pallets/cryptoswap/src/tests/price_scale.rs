@@ -0,0 +1,286 @@
+use crate::tests::mock::*;
+use crate::tests::{create_pool, pool_ext};
+use crate::{Event, Pools};
+use frame_support::assert_ok;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use sp_runtime::traits::One;
+use sp_runtime::{FixedU128, Permill};
+
+#[test]
+fn price_scale_should_not_change_when_oracle_price_is_not_available() {
+	pool_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0
+		));
+
+		let pool = Pools::<Test>::get(POOL_ID).unwrap();
+		assert_eq!(pool.price_scale, FixedU128::from(2));
+		assert_eq!(pool.updated_at, 10);
+	});
+}
+
+#[test]
+fn price_scale_should_move_towards_oracle_price_by_max_update_per_block() {
+	pool_ext().execute_with(|| {
+		set_oracle_price(DOT, ETH, (22, 10));
+		System::set_block_number(3);
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0
+		));
+
+		// 2 blocks, 1% per block
+		let expected = FixedU128::from_rational(204, 100);
+		let pool = Pools::<Test>::get(POOL_ID).unwrap();
+		assert_eq!(pool.price_scale, expected);
+		assert_eq!(pool.updated_at, 3);
+		expect_events(vec![Event::PriceScaleUpdated {
+			pool_id: POOL_ID,
+			price_scale: expected,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn price_scale_should_reach_oracle_price_when_within_max_update() {
+	pool_ext().execute_with(|| {
+		set_oracle_price(DOT, ETH, (199, 100));
+		System::set_block_number(2);
+
+		assert_ok!(Cryptoswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			200 * ONE,
+			100 * ONE,
+			0
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(POOL_ID).unwrap().price_scale,
+			FixedU128::from_rational(199, 100)
+		);
+	});
+}
+
+#[test]
+fn price_scale_should_be_converted_from_oracle_price_in_smallest_units() {
+	ExtBuilder::default()
+		.with_registered_asset(3, 18)
+		.with_endowed_accounts(vec![
+			(ALICE, DOT, 2_000_000 * ONE),
+			(ALICE, 3, 1_000_000 * 1_000_000 * ONE),
+			(BOB, DOT, 1_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Cryptoswap::create_pool(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				DOT,
+				3,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				Permill::from_rational(3u32, 1000u32),
+				FixedU128::from(2),
+				Permill::from_percent(100),
+			));
+			assert_ok!(Cryptoswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				2_000_000 * ONE,
+				1_000_000 * 1_000_000 * ONE,
+				0,
+			));
+
+			// 2.1 DOT (12 decimals) per one unit of asset with 18 decimals
+			set_oracle_price(DOT, 3, (21, 10_000_000));
+			System::set_block_number(2);
+
+			assert_ok!(Cryptoswap::sell(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				DOT,
+				3,
+				100 * ONE,
+				0
+			));
+
+			assert_eq!(
+				Pools::<Test>::get(POOL_ID).unwrap().price_scale,
+				FixedU128::from_rational(21, 10)
+			);
+		});
+}
+
+#[test]
+fn price_scale_should_be_updated_only_once_per_block() {
+	pool_ext().execute_with(|| {
+		set_oracle_price(DOT, ETH, (3, 1));
+		System::set_block_number(2);
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0
+		));
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(POOL_ID).unwrap().price_scale,
+			FixedU128::from_rational(202, 100)
+		);
+	});
+}
+
+#[test]
+fn calculate_sell_should_not_persist_price_scale() {
+	pool_ext().execute_with(|| {
+		set_oracle_price(DOT, ETH, (22, 10));
+		System::set_block_number(3);
+
+		let repegged = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		let pool = Pools::<Test>::get(POOL_ID).unwrap();
+		assert_eq!(pool.price_scale, FixedU128::from(2));
+		assert_eq!(pool.updated_at, 1);
+
+		// quote is calculated with re-pegged price scale, in which ETH is more valuable
+		set_oracle_price(DOT, ETH, (2, 1));
+		let at_current_scale = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+		assert!(repegged > at_current_scale);
+	});
+}
+
+#[test]
+fn trades_should_use_updated_price_scale() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DOT, 2_000_000 * ONE),
+			(ALICE, ETH, 1_000_000 * ONE),
+			(BOB, ETH, 1_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			create_pool(Permill::from_percent(100));
+			assert_ok!(Cryptoswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				2_000_000 * ONE,
+				1_000_000 * ONE,
+				0,
+			));
+			let before = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+			set_oracle_price(DOT, ETH, (21, 10));
+			System::set_block_number(2);
+
+			assert_ok!(Cryptoswap::sell(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				ETH,
+				DOT,
+				100 * ONE,
+				0
+			));
+
+			assert!(Tokens::free_balance(DOT, &BOB) > before);
+		});
+}
+
+#[test]
+fn xcp_profit_should_grow_when_pool_collects_fee() {
+	pool_ext().execute_with(|| {
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0
+		));
+
+		let pool = Pools::<Test>::get(POOL_ID).unwrap();
+		assert!(pool.xcp_profit > FixedU128::one());
+		assert_eq!(pool.virtual_price, pool.xcp_profit);
+	});
+}
+
+#[test]
+fn price_scale_should_not_be_repegged_when_pool_has_no_profit_to_pay_for_it() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DOT, 2_000_000 * ONE),
+			(ALICE, ETH, 1_000_000 * ONE),
+			(BOB, ETH, 100_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Cryptoswap::create_pool(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				DOT,
+				ETH,
+				DEFAULT_AMPLIFICATION,
+				DEFAULT_GAMMA,
+				Permill::zero(),
+				FixedU128::from(2),
+				Permill::from_percent(100),
+			));
+			assert_ok!(Cryptoswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				2_000_000 * ONE,
+				1_000_000 * ONE,
+				0,
+			));
+			assert_ok!(Cryptoswap::sell(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				ETH,
+				DOT,
+				20_000 * ONE,
+				0
+			));
+
+			// Oracle follows the pool - re-peg towards the balanced state of the pool is a loss of LPs,
+			// which can't be paid from profit of a pool without fees.
+			set_oracle_price(DOT, ETH, (19, 10));
+			System::set_block_number(2);
+
+			assert_ok!(Cryptoswap::sell(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				ETH,
+				DOT,
+				100 * ONE,
+				0
+			));
+
+			let pool = Pools::<Test>::get(POOL_ID).unwrap();
+			assert_eq!(pool.price_scale, FixedU128::from(2));
+			assert_eq!(pool.updated_at, 2);
+		});
+}
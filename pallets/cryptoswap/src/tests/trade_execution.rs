@@ -0,0 +1,127 @@
+use crate::assert_balance;
+use crate::tests::mock::*;
+use crate::tests::pool_ext;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use sp_runtime::{FixedPointNumber, FixedU128};
+use test_utils::assert_eq_approx;
+
+#[test]
+fn calculate_sell_should_return_same_amount_as_sell() {
+	pool_ext().execute_with(|| {
+		let expected = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Cryptoswap(POOL_ID),
+			ETH,
+			DOT,
+			100 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, ETH, 900 * ONE);
+		assert_balance!(BOB, DOT, 1_000 * ONE + expected);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_same_amount_as_buy() {
+	pool_ext().execute_with(|| {
+		let expected_in = Cryptoswap::calculate_buy(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Cryptoswap(POOL_ID),
+			ETH,
+			DOT,
+			100 * ONE,
+			expected_in,
+		));
+
+		assert_balance!(BOB, ETH, 1_000 * ONE - expected_in);
+		assert_balance!(BOB, DOT, 1_100 * ONE);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			Cryptoswap::calculate_sell(PoolType::XYK, ETH, DOT, 100 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			Cryptoswap::calculate_buy(PoolType::Stableswap(POOL_ID), ETH, DOT, 100 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			Cryptoswap::execute_sell(RuntimeOrigin::signed(BOB), PoolType::Omnipool, ETH, DOT, 100 * ONE, 0),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			Cryptoswap::calculate_spot_price_with_fee(PoolType::LBP, ETH, DOT),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_pool_reserve() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			Cryptoswap::get_liquidity_depth(PoolType::Cryptoswap(POOL_ID), DOT, ETH),
+			Ok(2_000_000 * ONE)
+		);
+		assert_eq!(
+			Cryptoswap::get_liquidity_depth(PoolType::Cryptoswap(POOL_ID), ETH, DOT),
+			Ok(1_000_000 * ONE)
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_include_fee() {
+	pool_ext().execute_with(|| {
+		// 2 DOT per ETH, increased by 0.3% fee
+		let expected = FixedU128::from(2) / FixedU128::from_rational(997, 1000);
+		let spot_price = Cryptoswap::calculate_spot_price_with_fee(PoolType::Cryptoswap(POOL_ID), DOT, ETH).unwrap();
+		assert_eq_approx!(
+			spot_price,
+			expected,
+			FixedU128::from_rational(1, 1_000_000),
+			"unexpected spot price"
+		);
+
+		let inverse_spot_price =
+			Cryptoswap::calculate_spot_price_with_fee(PoolType::Cryptoswap(POOL_ID), ETH, DOT).unwrap();
+		let expected = FixedU128::from_rational(1, 2) / FixedU128::from_rational(997, 1000);
+		assert_eq_approx!(
+			inverse_spot_price,
+			expected,
+			FixedU128::from_rational(1, 1_000_000),
+			"unexpected inverse spot price"
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_match_small_trade() {
+	pool_ext().execute_with(|| {
+		let spot_price = Cryptoswap::calculate_spot_price_with_fee(PoolType::Cryptoswap(POOL_ID), DOT, ETH).unwrap();
+		let amount_out = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), DOT, ETH, ONE).unwrap();
+
+		let expected_out = spot_price.reciprocal().unwrap().checked_mul_int(ONE).unwrap();
+		assert_eq_approx!(amount_out, expected_out, ONE / 1_000_000, "unexpected amount out");
+	});
+}
+
+#[test]
+fn pools_should_return_all_cryptoswap_pools() {
+	pool_ext().execute_with(|| {
+		assert_eq!(
+			Cryptoswap::pools(),
+			vec![(PoolType::Cryptoswap(POOL_ID), vec![DOT, ETH])]
+		);
+	});
+}
@@ -0,0 +1,280 @@
+use crate::tests::mock::*;
+use crate::tests::pool_ext;
+use crate::{assert_balance, Error};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeExecution};
+use pallet_broadcast::types::{Asset, Destination, Fee, Filler, TradeOperation};
+use test_utils::assert_eq_approx;
+
+#[test]
+fn sell_should_work() {
+	pool_ext().execute_with(|| {
+		let expected = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			expected,
+		));
+
+		// ~200 DOT minus 0.3% fee
+		assert_eq_approx!(expected, 199_400_000_000_000u128, ONE / 100, "unexpected amount out");
+		assert_balance!(BOB, ETH, 900 * ONE);
+		assert_balance!(BOB, DOT, 1_000 * ONE + expected);
+		assert_balance!(pool_account(POOL_ID), ETH, 1_000_100 * ONE);
+		assert_balance!(pool_account(POOL_ID), DOT, 2_000_000 * ONE - expected);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	pool_ext().execute_with(|| {
+		let expected_in = Cryptoswap::calculate_buy(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::buy(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			DOT,
+			ETH,
+			100 * ONE,
+			expected_in,
+		));
+
+		// ~50 ETH plus 0.3% fee
+		assert_eq_approx!(expected_in, 50_150_000_000_000u128, ONE / 100, "unexpected amount in");
+		assert_balance!(BOB, DOT, 1_100 * ONE);
+		assert_balance!(BOB, ETH, 1_000 * ONE - expected_in);
+		assert_balance!(pool_account(POOL_ID), DOT, 1_999_900 * ONE);
+		assert_balance!(pool_account(POOL_ID), ETH, 1_000_000 * ONE + expected_in);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	pool_ext().execute_with(|| {
+		let expected = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 100 * ONE, expected + 1),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	pool_ext().execute_with(|| {
+		let expected_in = Cryptoswap::calculate_buy(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_noop!(
+			Cryptoswap::buy(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				DOT,
+				ETH,
+				100 * ONE,
+				expected_in - 1
+			),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_balance_is_not_sufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 1_001 * ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_balance_is_not_sufficient() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::buy(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 1_000 * ONE, u128::MAX),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_assets_are_same() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, ETH, 100 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Cryptoswap::buy(RuntimeOrigin::signed(BOB), POOL_ID, ETH, ETH, 100 * ONE, u128::MAX),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_asset_is_not_in_pool() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, HDX, 100 * ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+		assert_noop!(
+			Cryptoswap::buy(RuntimeOrigin::signed(BOB), POOL_ID, HDX, ETH, 100 * ONE, u128::MAX),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_minimum_trading_limit() {
+	pool_ext().execute_with(|| {
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			Cryptoswap::buy(RuntimeOrigin::signed(BOB), POOL_ID, DOT, ETH, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_no_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, ETH, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			crate::tests::create_pool(sp_runtime::Permill::from_percent(1));
+
+			assert_noop!(
+				Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 100 * ONE, 0),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_circuit_breaker_limit_is_exceeded() {
+	pool_ext().execute_with(|| {
+		set_trade_limit(150 * ONE);
+
+		assert_noop!(
+			Cryptoswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ETH, DOT, 100 * ONE, 0),
+			sp_runtime::DispatchError::Other("trade limit")
+		);
+	});
+}
+
+#[test]
+fn sell_should_notify_amm_handler() {
+	pool_ext().execute_with(|| {
+		let expected = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0,
+		));
+
+		let (asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b, price) = last_trade_hook().unwrap();
+		assert_eq!((asset_a, asset_b), (ETH, DOT));
+		assert_eq!((amount_a, amount_b), (100 * ONE, expected));
+		assert_eq!(
+			(liquidity_a, liquidity_b),
+			(1_000_100 * ONE, 2_000_000 * ONE - expected)
+		);
+		// price of DOT in ETH after the trade, ETH got cheaper
+		let price = sp_runtime::FixedU128::from_rational(price.n, price.d);
+		assert!(price > sp_runtime::FixedU128::from_rational(1, 2));
+	});
+}
+
+#[test]
+fn sell_should_emit_swapped_event() {
+	pool_ext().execute_with(|| {
+		let expected = Cryptoswap::calculate_sell(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+		let fee = hydra_dx_math::cryptoswap::calculate_out_given_in(
+			&[
+				hydra_dx_math::cryptoswap::types::AssetReserve::new(2_000_000 * ONE, 12),
+				hydra_dx_math::cryptoswap::types::AssetReserve::new(1_000_000 * ONE, 12),
+			],
+			1,
+			0,
+			100 * ONE,
+			hydra_dx_math::cryptoswap::types::CurveParams::new(DEFAULT_AMPLIFICATION.into(), DEFAULT_GAMMA),
+			sp_runtime::FixedU128::from(2),
+			sp_runtime::Permill::from_rational(3u32, 1000u32),
+		)
+		.unwrap()
+		.1;
+
+		assert_ok!(Cryptoswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ETH,
+			DOT,
+			100 * ONE,
+			0,
+		));
+
+		let swapped_events = get_last_swapped_events();
+		assert_eq!(
+			*swapped_events.last().unwrap(),
+			RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped {
+				swapper: BOB,
+				filler: pool_account(POOL_ID),
+				filler_type: Filler::Cryptoswap(POOL_ID),
+				operation: TradeOperation::ExactIn,
+				inputs: vec![Asset::new(ETH, 100 * ONE)],
+				outputs: vec![Asset::new(DOT, expected)],
+				fees: vec![Fee::new(DOT, fee, Destination::Account(pool_account(POOL_ID)))],
+				operation_stack: vec![],
+			})
+		);
+	});
+}
+
+#[test]
+fn buy_should_emit_swapped_event() {
+	pool_ext().execute_with(|| {
+		let expected_in = Cryptoswap::calculate_buy(PoolType::Cryptoswap(POOL_ID), ETH, DOT, 100 * ONE).unwrap();
+
+		assert_ok!(Cryptoswap::buy(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			DOT,
+			ETH,
+			100 * ONE,
+			u128::MAX,
+		));
+
+		let swapped_events = get_last_swapped_events();
+		let RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped {
+			filler_type,
+			operation,
+			inputs,
+			outputs,
+			fees,
+			..
+		}) = swapped_events.last().unwrap().clone()
+		else {
+			panic!("unexpected event");
+		};
+		assert_eq!(filler_type, Filler::Cryptoswap(POOL_ID));
+		assert_eq!(operation, TradeOperation::ExactOut);
+		assert_eq!(inputs, vec![Asset::new(ETH, expected_in)]);
+		assert_eq!(outputs, vec![Asset::new(DOT, 100 * ONE)]);
+		assert_eq!(fees.len(), 1);
+		assert_eq!(fees[0].asset, DOT);
+	});
+}
@@ -0,0 +1,117 @@
+use crate::{Balance, Config, Error, Pallet, Pools};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::One;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				let (amount_out, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, false)
+					.map_err(ExecutorError::Error)?;
+				Ok(amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				let (amount_in, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, false)
+					.map_err(ExecutorError::Error)?;
+				Ok(amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		_asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				let pool_account = Self::pool_account(pool_id);
+				Ok(T::Currency::free_balance(asset_a, &pool_account))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Cryptoswap(pool_id) => {
+				let pool =
+					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				let spot_price = Self::calculate_spot_price(pool_id, asset_a, asset_b).map_err(ExecutorError::Error)?;
+
+				// Fee is taken from amount out, which makes asset_b more expensive in terms of asset_a
+				let fee_multiplier = FixedU128::one()
+					.checked_sub(&pool.fee.into())
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Underflow.into()))?;
+				spot_price
+					.checked_div(&fee_multiplier)
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter()
+			.map(|(pool_id, pool)| (PoolType::Cryptoswap(pool_id), pool.assets.to_vec()))
+			.collect()
+	}
+}
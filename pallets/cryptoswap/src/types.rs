@@ -0,0 +1,147 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Config, Pallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::weights::Weight;
+use hydra_dx_math::cryptoswap::types::{AssetReserve, CurveParams};
+#[cfg(feature = "runtime-benchmarks")]
+use hydradx_traits::oracle::Source;
+use orml_traits::MultiCurrency;
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::{DispatchResult, FixedU128, Permill};
+
+pub(crate) type Balance = u128;
+
+/// Cryptoswap pool properties
+/// `assets`: pool assets. Price scale is the price of the second asset denominated in the first asset.
+/// `amplification`: amp parameter, `A * N^N * A_MULTIPLIER`
+/// `gamma`: distance from constant product at which the curve starts to flatten (18 decimals)
+/// `fee`: trade fee to be withdrawn on sell/buy
+/// `price_scale`: price of whole unit of the second asset in whole units of the first asset around which liquidity is concentrated
+/// `max_price_scale_update`: maximum relative change of price scale per block
+/// `updated_at`: block number of last price scale update
+/// `virtual_price`: virtual price of a share relative to the initial liquidity
+/// `xcp_profit`: growth of virtual price made by trades and liquidity operations, excluding re-pegs of price scale
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PoolInfo<AssetId, BlockNumber> {
+	pub assets: [AssetId; 2],
+	pub amplification: u32,
+	pub gamma: u128,
+	pub fee: Permill,
+	pub price_scale: FixedU128,
+	pub max_price_scale_update: Permill,
+	pub updated_at: BlockNumber,
+	pub virtual_price: FixedU128,
+	pub xcp_profit: FixedU128,
+}
+
+impl<AssetId, BlockNumber> PoolInfo<AssetId, BlockNumber>
+where
+	AssetId: PartialEq + Copy,
+{
+	pub(crate) fn find_asset(&self, asset: AssetId) -> Option<usize> {
+		self.assets.iter().position(|v| *v == asset)
+	}
+
+	pub(crate) fn curve_params(&self) -> CurveParams {
+		CurveParams::new(self.amplification.into(), self.gamma)
+	}
+
+	pub(crate) fn reserves_with_decimals<T: Config>(&self, account: &T::AccountId) -> Option<[AssetReserve; 2]>
+	where
+		T::AssetId: From<AssetId>,
+	{
+		let reserve = |asset: AssetId| -> Option<AssetReserve> {
+			let amount = T::Currency::free_balance(asset.into(), account);
+			let decimals = Pallet::<T>::retrieve_decimals(asset.into())?;
+			Some(AssetReserve::new(amount, decimals))
+		};
+
+		Some([reserve(self.assets[0])?, reserve(self.assets[1])?])
+	}
+}
+
+/// Limits of pool state changes (e.g. circuit breaker) which are enforced on trades and liquidity changes.
+pub trait PoolStateChangeLimits<AccountId, AssetId> {
+	/// Ensures that a trade does not exceed trade volume limits of traded assets.
+	fn ensure_trade_limit(
+		asset_in: AssetId,
+		asset_in_reserve: Balance,
+		amount_in: Balance,
+		asset_out: AssetId,
+		asset_out_reserve: Balance,
+		amount_out: Balance,
+	) -> DispatchResult;
+
+	/// Ensures that liquidity added by `who` does not exceed add liquidity limit of the asset.
+	fn ensure_add_liquidity_limit(
+		who: &AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		added_liquidity: Balance,
+	) -> DispatchResult;
+
+	/// Ensures that liquidity removed by `who` does not exceed remove liquidity limit of the asset.
+	fn ensure_remove_liquidity_limit(
+		who: &AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		removed_liquidity: Balance,
+	) -> DispatchResult;
+
+	fn trade_limit_weight() -> Weight;
+
+	fn liquidity_limit_weight() -> Weight;
+}
+
+impl<AccountId, AssetId> PoolStateChangeLimits<AccountId, AssetId> for () {
+	fn ensure_trade_limit(
+		_asset_in: AssetId,
+		_asset_in_reserve: Balance,
+		_amount_in: Balance,
+		_asset_out: AssetId,
+		_asset_out_reserve: Balance,
+		_amount_out: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn ensure_add_liquidity_limit(
+		_who: &AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		_added_liquidity: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn ensure_remove_liquidity_limit(
+		_who: &AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		_removed_liquidity: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn trade_limit_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn liquidity_limit_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
+	fn register_oracle_price(
+		asset_pair: (AssetId, AssetId),
+		price: (Balance, Balance),
+		source: Source,
+	) -> DispatchResult;
+}
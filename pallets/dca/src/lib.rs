@@ -56,6 +56,18 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Price Condition
+//!
+//! A schedule can optionally specify a price condition, measured by the oracle price of the order route
+//! for the chosen oracle period. A buy order is executed only if the price of the bought asset
+//! is not higher than the limit price, a sell order only if the price of the sold asset is not lower than the limit price.
+//!
+//! If the condition is not met, the execution is skipped and planned again after `period` blocks.
+//! Skipped executions do not consume retries, but the fee is still deducted.
+//!
+//! The oracle price has to be available when the schedule is created. If it is not available at the time of an execution,
+//! the trade fails with `CalculatingPriceError` and the schedule is retried or terminated as for other failed trades.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and TerminateOrigin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -84,7 +96,7 @@ use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::CheckedMul;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Saturating},
+	traits::{BlockNumberProvider, Saturating, Zero},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Percent, Permill, Rounding,
 };
 use sp_std::cmp::min;
@@ -104,6 +116,7 @@ use crate::types::*;
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -124,7 +137,10 @@ pub mod pallet {
 
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -148,7 +164,8 @@ pub mod pallet {
 					continue;
 				};
 
-				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
+				let weight_for_single_execution =
+					Self::get_trade_weight(&schedule.order).saturating_add(Self::get_price_condition_weight(&schedule));
 				weight.saturating_accrue(weight_for_single_execution);
 
 				if let Err(e) = Self::prepare_schedule(
@@ -166,7 +183,15 @@ pub mod pallet {
 					}
 				};
 
-				match Self::execute_trade(schedule_id, &schedule) {
+				let trade_result = Self::skip_if_price_condition_not_met(
+					schedule_id,
+					&schedule,
+					current_blocknumber,
+					&mut randomness_generator,
+				)
+				.and_then(|_| Self::execute_trade(schedule_id, &schedule));
+
+				match trade_result {
 					Ok(amounts) => {
 						if let Err(err) = Self::replan_or_complete(
 							schedule_id,
@@ -178,6 +203,9 @@ pub mod pallet {
 							Self::terminate_schedule(schedule_id, &schedule, err);
 						}
 					}
+					Err(error) if error == Error::<T>::PriceConditionNotMet.into() => {
+						continue;
+					}
 					Err(error) => {
 						Self::deposit_event(Event::TradeFailed {
 							id: schedule_id,
@@ -352,6 +380,8 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The DCA execution is skipped as the price condition is not met
+		ExecutionSkipped { id: ScheduleId, who: T::AccountId },
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -397,6 +427,10 @@ pub mod pallet {
 		PeriodTooShort,
 		///Stability threshold cannot be higher than `MaxConfigurablePriceDifferenceBetweenBlock`
 		StabilityThresholdTooHigh,
+		///Limit price of the price condition cannot be zero
+		InvalidPriceCondition,
+		///Price condition of the schedule is not met, leading to skipping the execution
+		PriceConditionNotMet,
 	}
 
 	/// Id sequencer for schedules
@@ -458,6 +492,9 @@ pub mod pallet {
 		/// the schedule will be terminated permanently.
 		/// In the case of a successful trade, the retry counter is reset.
 		///
+		/// If the schedule has a price condition which is not met, the execution is skipped
+		/// without consuming retries and planned again after `period` blocks.
+		///
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule`: schedule details
@@ -467,7 +504,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule()
-			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>()))]
+			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>())
+			+ Pallet::<T>::get_price_condition_weight(schedule))]
 		#[transactional]
 		pub fn schedule(
 			origin: OriginFor<T>,
@@ -492,6 +530,10 @@ pub mod pallet {
 				},
 				Error::<T>::StabilityThresholdTooHigh
 			);
+			if let Some(condition) = &schedule.price_condition {
+				ensure!(!condition.limit_price.is_zero(), Error::<T>::InvalidPriceCondition);
+				Self::get_price_for_price_condition(&schedule.order, condition)?;
+			}
 
 			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
 
@@ -705,6 +747,28 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn skip_if_price_condition_not_met(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
+		if Self::is_price_condition_met(schedule)? {
+			return Ok(());
+		}
+
+		Self::deposit_event(Event::ExecutionSkipped {
+			id: schedule_id,
+			who: schedule.owner.clone(),
+		});
+		let next_execution_block = current_blocknumber
+			.checked_add(&schedule.period)
+			.ok_or(ArithmeticError::Overflow)?;
+		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)?;
+
+		Err(Error::<T>::PriceConditionNotMet.into())
+	}
+
 	#[transactional]
 	pub fn execute_trade(
 		schedule_id: ScheduleId,
@@ -923,6 +987,43 @@ impl<T: Config> Pallet<T> {
 		diff > max_allowed_difference
 	}
 
+	fn is_price_condition_met(
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> Result<bool, DispatchError> {
+		let Some(condition) = &schedule.price_condition else {
+			return Ok(true);
+		};
+
+		let price = Self::get_price_for_price_condition(&schedule.order, condition)?;
+
+		Ok(match schedule.order {
+			Order::Buy { .. } => price <= condition.limit_price,
+			Order::Sell { .. } => price >= condition.limit_price,
+		})
+	}
+
+	fn get_price_for_price_condition(
+		order: &Order<T::AssetId>,
+		condition: &PriceCondition,
+	) -> Result<FixedU128, DispatchError> {
+		let route = order.get_route_or_default::<T::RouteProvider>();
+
+		match order {
+			Order::Buy { .. } => Self::get_price_from_oracle(&route, condition.oracle_period),
+			Order::Sell { .. } => Self::get_price_from_oracle(&inverse_route(route), condition.oracle_period),
+		}
+	}
+
+	/// Weight of the oracle reads of the price condition, two oracle entries are read per trade at most.
+	fn get_price_condition_weight(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> Weight {
+		if schedule.price_condition.is_none() {
+			return Weight::zero();
+		}
+
+		let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+		T::DbWeight::get().reads((route.len() as u64).saturating_mul(2))
+	}
+
 	fn get_amount_in_for_buy(amount_out: &Balance, route: &[Trade<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(route, *amount_out)?;
 
@@ -1202,18 +1303,15 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn get_price_from_last_block_oracle(route: &[Trade<T::AssetId>]) -> Result<FixedU128, DispatchError> {
-		let price =
-			T::OraclePriceProvider::price(route, OraclePeriod::LastBlock).ok_or(Error::<T>::CalculatingPriceError)?;
-
-		let price_from_rational =
-			FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
-
-		Ok(price_from_rational)
+		Self::get_price_from_oracle(route, OraclePeriod::LastBlock)
 	}
 
 	fn get_price_from_short_oracle(route: &[Trade<T::AssetId>]) -> Result<FixedU128, DispatchError> {
-		let price =
-			T::OraclePriceProvider::price(route, OraclePeriod::Short).ok_or(Error::<T>::CalculatingPriceError)?;
+		Self::get_price_from_oracle(route, OraclePeriod::Short)
+	}
+
+	fn get_price_from_oracle(route: &[Trade<T::AssetId>], period: OraclePeriod) -> Result<FixedU128, DispatchError> {
+		let price = T::OraclePriceProvider::price(route, period).ok_or(Error::<T>::CalculatingPriceError)?;
 
 		let price_from_rational =
 			FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
@@ -0,0 +1,110 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// The log target.
+const TARGET: &str = "runtime::dca::migration::v1";
+
+pub mod v0 {
+	use super::*;
+
+	/// Schedule without the price condition.
+	#[derive(Encode, Decode)]
+	pub struct Schedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Migrates all existing schedules to schedules without a price condition.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let schedules_count = Schedules::<T>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "DCA migration: PRE checks successful!");
+
+			Ok(schedules_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(target: TARGET, "DCA migration to v1 already applied, skipping");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for DCA");
+
+			let mut weight = T::DbWeight::get().reads(1);
+
+			Schedules::<T>::translate::<v0::Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(|_, schedule| {
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+				Some(Schedule {
+					owner: schedule.owner,
+					period: schedule.period,
+					total_amount: schedule.total_amount,
+					max_retries: schedule.max_retries,
+					stability_threshold: schedule.stability_threshold,
+					slippage: schedule.slippage,
+					price_condition: None,
+					order: schedule.order,
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let schedules_count = <u64 as Decode>::decode(&mut state.as_slice()).unwrap();
+
+			let mut migrated_count = 0u64;
+			for (_, schedule) in Schedules::<T>::iter() {
+				assert_eq!(schedule.price_condition, None, "Schedule has not been migrated!");
+				migrated_count.saturating_inc();
+			}
+			assert_eq!(migrated_count, schedules_count);
+
+			log::info!(target: TARGET, "DCA migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
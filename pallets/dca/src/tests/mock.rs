@@ -108,6 +108,7 @@ thread_local! {
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = const { RefCell::new((0u32,0u32, false)) };
 	pub static CALCULATED_AMOUNT_OUT_FOR_SELL: RefCell<Balance> = RefCell::new(*AMOUNT_OUT_FOR_OMNIPOOL_SELL);
 	pub static USE_PROD_RANDOMNESS: RefCell<bool> = const { RefCell::new(false) };
+	pub static UNAVAILABLE_ORACLE_PERIOD: RefCell<Option<OraclePeriod>> = const { RefCell::new(None) };
 	pub static PARENT_HASH: RefCell<Option<Hash>> = RefCell::new(Some([
 			14, 87, 81, 192, 38, 229, 67, 178, 232, 171, 46, 176, 96, 153, 218, 161, 209, 229, 223, 71, 119, 143, 119,
 			135, 250, 171, 69, 205, 241, 47, 227, 168,
@@ -407,6 +408,9 @@ impl PriceOracle<AssetId> for crate::tests::mock::PriceProviderMock {
 	type Price = Ratio;
 
 	fn price(_: &[Trade<AssetId>], period: OraclePeriod) -> Option<Ratio> {
+		if UNAVAILABLE_ORACLE_PERIOD.with(|v| *v.borrow() == Some(period)) {
+			return None;
+		}
 		if period == OraclePeriod::Short {
 			return Some(Ratio::new(80, 100));
 		}
@@ -1083,6 +1087,7 @@ pub fn expect_dca_events(e: Vec<RuntimeEvent>) {
 				| RuntimeEvent::DCA(crate::Event::<Test>::TradeFailed { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::Terminated { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::Completed { .. })
+				| RuntimeEvent::DCA(crate::Event::<Test>::ExecutionSkipped { .. })
 		) {
 			dca_events.push(e);
 		}
@@ -1,5 +1,5 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, Order, PriceCondition, Schedule, ScheduleId};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use sp_runtime::traits::ConstU32;
@@ -25,6 +25,7 @@ struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub price_condition: Option<Option<PriceCondition>>,
 }
 
 impl ScheduleBuilder {
@@ -34,6 +35,7 @@ impl ScheduleBuilder {
 			period: Some(ONE_HUNDRED_BLOCKS),
 			stability_threshold: Some(None),
 			slippage: Some(None),
+			price_condition: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
 			order: Some(Order::Buy {
@@ -80,6 +82,11 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_price_condition(mut self, price_condition: Option<PriceCondition>) -> ScheduleBuilder {
+		self.price_condition = Some(price_condition);
+		self
+	}
+
 	fn with_max_retries(mut self, max_retries: Option<u8>) -> ScheduleBuilder {
 		self.max_retries = Some(max_retries);
 		self
@@ -91,6 +98,7 @@ impl ScheduleBuilder {
 			period: self.period.unwrap(),
 			stability_threshold: self.stability_threshold.unwrap(),
 			slippage: self.slippage.unwrap(),
+			price_condition: self.price_condition.unwrap(),
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
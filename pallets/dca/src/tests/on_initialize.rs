@@ -20,17 +20,18 @@ use crate::tests::*;
 use crate::{
 	assert_balance, assert_executed_buy_trades, assert_executed_sell_trades, assert_number_of_executed_buy_trades,
 	assert_number_of_executed_sell_trades, assert_scheduled_ids, assert_that_schedule_has_been_removed_from_storages,
-	Error, Event as DcaEvent, Order, Permill, ScheduleId,
+	Error, Event as DcaEvent, Order, Permill, PriceCondition, ScheduleId,
 };
 use frame_support::assert_ok;
 use frame_support::traits::OnInitialize;
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::PoolType::Omnipool;
+use hydradx_traits::OraclePeriod;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, FixedU128};
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 
@@ -2706,6 +2707,282 @@ fn dca_schedule_should_still_take_fee_when_order_fails() {
 		});
 }
 
+#[test]
+fn sell_dca_execution_should_be_skipped_when_oracle_price_is_below_min_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(90, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(
+				total_amount - get_fee_for_sell_in_hdx(),
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+
+			let schedule_id = 0;
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_scheduled_ids!(602, vec![schedule_id]);
+			expect_dca_events(vec![
+				DcaEvent::ExecutionSkipped {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn sell_dca_execution_should_be_executed_when_oracle_price_is_above_min_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(85, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn buy_dca_execution_should_be_skipped_when_oracle_price_is_above_max_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(85, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 10 * ONE,
+					max_amount_in: 50 * ONE,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(0);
+
+			let schedule_id = 0;
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_scheduled_ids!(602, vec![schedule_id]);
+		});
+}
+
+#[test]
+fn buy_dca_execution_should_be_executed_when_oracle_price_is_below_max_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(90, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 10 * ONE,
+					max_amount_in: 50 * ONE,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn skipped_dca_executions_should_not_consume_retries() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_max_retries(Some(0))
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(85, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 10 * ONE,
+					max_amount_in: 50 * ONE,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+			set_to_blocknumber(602);
+			set_to_blocknumber(702);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_buy_trades!(0);
+			assert!(DCA::schedules(schedule_id).is_some());
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_scheduled_ids!(802, vec![schedule_id]);
+		});
+}
+
+#[test]
+fn dca_schedule_should_be_terminated_when_price_of_price_condition_is_not_available() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(85, 100),
+					oracle_period: OraclePeriod::Hour,
+				}))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			UNAVAILABLE_ORACLE_PERIOD.with(|v| *v.borrow_mut() = Some(OraclePeriod::Hour));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_that_dca_is_terminated(ALICE, schedule_id, Error::<Test>::CalculatingPriceError.into());
+		});
+}
+
 pub fn proceed_to_blocknumber(from: u64, to: u64) {
 	for block_number in RangeInclusive::new(from, to) {
 		System::set_block_number(block_number);
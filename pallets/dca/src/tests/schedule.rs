@@ -19,12 +19,15 @@ use crate::assert_scheduled_ids;
 use crate::tests::create_bounded_vec_with_schedule_ids;
 use crate::tests::mock::*;
 use crate::tests::{create_bounded_vec, ScheduleBuilder};
-use crate::{Error, Event, Order};
+use crate::{Error, Event, Order, PriceCondition};
 use frame_support::{assert_noop, assert_ok};
 use hydradx_traits::router::{PoolType, Trade};
+use hydradx_traits::OraclePeriod;
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
+use sp_runtime::traits::Zero;
 use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::FixedU128;
 use std::ops::RangeInclusive;
 
 #[test]
@@ -886,6 +889,83 @@ fn schedule_should_be_created_when_no_routes_specified() {
 		});
 }
 
+#[test]
+fn schedule_should_fail_when_limit_price_of_price_condition_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::zero(),
+					oracle_period: OraclePeriod::Short,
+				}))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidPriceCondition
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_price_of_price_condition_is_not_available() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			UNAVAILABLE_ORACLE_PERIOD.with(|v| *v.borrow_mut() = Some(OraclePeriod::Day));
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_price_condition(Some(PriceCondition {
+					limit_price: FixedU128::from_rational(1, 2),
+					oracle_period: OraclePeriod::Day,
+				}))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::CalculatingPriceError
+			);
+		});
+}
+
+#[test]
+fn schedule_should_store_price_condition() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let price_condition = PriceCondition {
+				limit_price: FixedU128::from_rational(1, 2),
+				oracle_period: OraclePeriod::Day,
+			};
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_price_condition(Some(price_condition.clone()))
+				.build();
+
+			//Act
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Assert
+			assert_eq!(DCA::schedules(0).unwrap().price_condition, Some(price_condition));
+		});
+}
+
 #[test]
 fn thousands_of_dcas_can_be_scheduled_on_a_specific_block_because_of_salt_added_to_block_search_randomness() {
 	ExtBuilder::default()
@@ -1,8 +1,9 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
+use hydradx_traits::OraclePeriod;
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -31,6 +32,9 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	pub stability_threshold: Option<Permill>,
 	/// The slippage limit used to calculate the `min_amount_out` and `max_amount_in` trade limits.
 	pub slippage: Option<Permill>,
+	/// The oracle price condition under which the schedule is executed.
+	/// Executions are skipped without consuming retries when the condition is not met.
+	pub price_condition: Option<PriceCondition>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
 }
@@ -41,6 +45,16 @@ impl<AccountId, AssetId, BlockNumber> Schedule<AccountId, AssetId, BlockNumber>
 	}
 }
 
+/// Price condition of a schedule, measured by the oracle price of the order route.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct PriceCondition {
+	/// For `Order::Buy`, the max price of `asset_out` denominated in `asset_in` to buy at.
+	/// For `Order::Sell`, the min price of `asset_in` denominated in `asset_out` to sell at.
+	pub limit_price: FixedU128,
+	/// The oracle period the price is measured for.
+	pub oracle_period: OraclePeriod,
+}
+
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
 pub enum Order<AssetId> {
 	Sell {
@@ -74,7 +74,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::{
 	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
-	OnLiquidityChangedHandler, OnTradeHandler, RawEntry, RawOracle, Volume,
+	OnLiquidityChangedHandler, OnTradeHandler, OracleWhitelistHandler, RawEntry, RawOracle, Volume,
 };
 use sp_arithmetic::traits::Saturating;
 use sp_arithmetic::FixedU128;
@@ -270,16 +270,7 @@ pub mod pallet {
 		pub fn add_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
-			let assets = ordered_pair(assets.0, assets.1);
-
-			WhitelistedAssets::<T>::mutate(|list| {
-				list.try_insert((source, (assets)))
-					.map_err(|_| Error::<T>::TooManyUniqueEntries)
-			})?;
-
-			Self::deposit_event(Event::AddedToWhitelist { source, assets });
-
-			Ok(())
+			Self::do_add_oracle(source, ordered_pair(assets.0, assets.1))
 		}
 
 		#[pallet::call_index(1)]
@@ -356,6 +347,18 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Add ordered asset pair of given source to `WhitelistedAssets`.
+	fn do_add_oracle(source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
+		WhitelistedAssets::<T>::mutate(|list| {
+			list.try_insert((source, (assets)))
+				.map_err(|_| Error::<T>::TooManyUniqueEntries)
+		})?;
+
+		Self::deposit_event(Event::AddedToWhitelist { source, assets });
+
+		Ok(())
+	}
+
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
 	pub(crate) fn on_entry(
@@ -744,6 +747,22 @@ impl<T: Config> AggregatedPriceOracle<AssetId, BlockNumberFor<T>, Price> for Pal
 	}
 }
 
+impl<T: Config> OracleWhitelistHandler<AssetId> for Pallet<T> {
+	/// Adds the asset pair to `WhitelistedAssets`, unless it is already tracked due to `T::OracleWhitelist`.
+	fn add_to_whitelist(source: Source, asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+		let assets = ordered_pair(asset_a, asset_b);
+		if T::OracleWhitelist::contains(&(source, assets.0, assets.1)) {
+			return Ok(());
+		}
+
+		Self::do_add_oracle(source, assets)
+	}
+
+	fn add_to_whitelist_weight() -> Weight {
+		T::WeightInfo::add_oracle()
+	}
+}
+
 /// Oracle whitelist based on the pallet's storage.
 pub struct OracleWhitelist<T>(PhantomData<T>);
 impl<T: Config> Contains<(Source, AssetId, AssetId)> for OracleWhitelist<T> {
@@ -79,6 +79,30 @@ fn add_oracle_should_fail_when_storage_is_full() {
 	});
 }
 
+#[test]
+fn add_to_whitelist_should_add_entry_to_storage_when_pair_is_not_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<EmaOracle as OracleWhitelistHandler<AssetId>>::add_to_whitelist(
+			SOURCE,
+			INSUFFICIENT_ASSET,
+			HDX
+		));
+
+		assert!(WhitelistedAssets::<Test>::get().contains(&(SOURCE, (HDX, INSUFFICIENT_ASSET))));
+	});
+}
+
+#[test]
+fn add_to_whitelist_should_not_add_entry_to_storage_when_pair_is_already_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<EmaOracle as OracleWhitelistHandler<AssetId>>::add_to_whitelist(
+			SOURCE, HDX, DOT
+		));
+
+		assert!(WhitelistedAssets::<Test>::get().is_empty());
+	});
+}
+
 #[test]
 fn remove_oracle_should_remove_entry_from_storage() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,38 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the LBP pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_lbp::types::{PoolState, PricePoint};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query LBP pools and their projected prices.
+	pub trait LbpApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get current weights, spot price and repay fee status of the pool. Returns `None` if the pool does not exist.
+		fn pool_state(pool_id: AccountId) -> Option<PoolState<BlockNumber>>;
+
+		/// Get spot prices of the pool at `count` blocks starting at `from` and spaced by `step` blocks,
+		/// assuming no trades happen in the meantime.
+		fn projected_prices(pool_id: AccountId, from: BlockNumber, step: BlockNumber, count: u32) -> Result<Vec<PricePoint<BlockNumber>>, DispatchError>;
+	}
+}
@@ -0,0 +1,95 @@
+use crate::types::{AssetId, Balance, PoolState, PricePoint};
+use crate::{Config, Error, Pallet, Pool, PoolData, MAX_WEIGHT};
+use frame_support::ensure;
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::types::LBPWeight;
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
+
+/// Max number of prices returned by `projected_prices`.
+pub const MAX_PROJECTED_PRICES: u32 = 1_000;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the current state of the pool or `None` if the pool does not exist.
+	pub fn pool_state(pool_id: T::AccountId) -> Option<PoolState<BlockNumberFor<T>>> {
+		let pool_data = <PoolData<T>>::get(&pool_id)?;
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let reserves = (
+			T::MultiCurrency::free_balance(pool_data.assets.0, &pool_id),
+			T::MultiCurrency::free_balance(pool_data.assets.1, &pool_id),
+		);
+		let weights = Self::weights_at(&pool_data, now).ok()?;
+		let spot_price = Self::price_at(reserves, weights, pool_data.assets.0).ok()?;
+
+		Some(PoolState {
+			assets: pool_data.assets,
+			reserves,
+			weights,
+			spot_price,
+			repay_target_reached: !Self::is_repay_fee_applied(&pool_data),
+			block: now,
+		})
+	}
+
+	/// Returns spot prices of the pool at `count` blocks starting at `from` and spaced by `step` blocks,
+	/// assuming no trades happen in the meantime.
+	///
+	/// Number of returned prices is capped at `MAX_PROJECTED_PRICES`.
+	pub fn projected_prices(
+		pool_id: T::AccountId,
+		from: BlockNumberFor<T>,
+		step: BlockNumberFor<T>,
+		count: u32,
+	) -> Result<Vec<PricePoint<BlockNumberFor<T>>>, DispatchError> {
+		let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+		ensure!(!step.is_zero(), Error::<T>::InvalidBlockRange);
+
+		let reserves = (
+			T::MultiCurrency::free_balance(pool_data.assets.0, &pool_id),
+			T::MultiCurrency::free_balance(pool_data.assets.1, &pool_id),
+		);
+
+		let mut block = from;
+		let mut prices = Vec::new();
+		for _ in 0..count.min(MAX_PROJECTED_PRICES) {
+			let weights = Self::weights_at(&pool_data, block)?;
+			prices.push(PricePoint {
+				block,
+				spot_price: Self::price_at(reserves, weights, pool_data.assets.0)?,
+			});
+			block = block.saturating_add(step);
+		}
+
+		Ok(prices)
+	}
+
+	/// Weights of the pool at `at`. Weights before the start and after the end of the sale
+	/// are equal to the weights at the start and at the end respectively.
+	fn weights_at(
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		at: BlockNumberFor<T>,
+	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
+		match (pool_data.start, pool_data.end) {
+			(Some(start), Some(end)) => Self::calculate_weights(pool_data, at.clamp(start, end)),
+			_ => Ok((
+				pool_data.initial_weight,
+				MAX_WEIGHT.saturating_sub(pool_data.initial_weight),
+			)),
+		}
+	}
+
+	/// Price of asset b denominated in asset a.
+	fn price_at(
+		(reserve_a, reserve_b): (Balance, Balance),
+		(weight_a, weight_b): (LBPWeight, LBPWeight),
+		asset_a: AssetId,
+	) -> Result<FixedU128, DispatchError> {
+		hydra_dx_math::lbp::calculate_spot_price_with_fee(
+			reserve_b, reserve_a, weight_b, weight_a, asset_a, asset_a, None,
+		)
+		.map_err(|_| Error::<T>::Overflow.into())
+	}
+}
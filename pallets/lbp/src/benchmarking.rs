@@ -21,7 +21,7 @@ use crate::Pallet as LBP;
 
 use hydradx_traits::router::{PoolType, TradeExecution};
 
-use frame_benchmarking::{account, benchmarks};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
 use frame_system::RawOrigin;
 
 const SEED: u32 = 1;
@@ -44,6 +44,43 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+// Worst case sale config - allowlist, purchase limit and vesting of the bought asset
+fn sale_config<T: Config>(allowlist: Allowlist) -> SaleConfig<BlockNumberFor<T>> {
+	SaleConfig {
+		allowlist: Some(allowlist),
+		max_buy_per_account: Some(ASSET_B_AMOUNT),
+		vesting: Some(VestingSchedule {
+			start: BlockNumberFor::<T>::from(11u32),
+			period: BlockNumberFor::<T>::from(10u32),
+			period_count: 10,
+		}),
+	}
+}
+
+fn allowlist_accounts<T: Config>(n: u32) -> BoundedVec<T::AccountId, ConstU32<MAX_ALLOWLIST_ACCOUNTS>> {
+	(0..n)
+		.map(|i| account("participant", i, SEED))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn allowlist_proof<T: Config>(who: &T::AccountId) -> (H256, BoundedVec<H256, ConstU32<MAX_ALLOWLIST_PROOF_LENGTH>>) {
+	let proof: BoundedVec<H256, ConstU32<MAX_ALLOWLIST_PROOF_LENGTH>> = (0..MAX_ALLOWLIST_PROOF_LENGTH)
+		.map(|i| H256::repeat_byte(i as u8))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap();
+	let root = proof.iter().fold(BlakeTwo256::hash_of(who), |node, sibling| {
+		if node <= *sibling {
+			BlakeTwo256::hash_of(&(node, sibling))
+		} else {
+			BlakeTwo256::hash_of(&(sibling, node))
+		}
+	});
+	(root, proof)
+}
+
 benchmarks! {
 	create_pool {
 		let caller = funded_account::<T>("caller", 0);
@@ -63,17 +100,26 @@ benchmarks! {
 		let new_initial_weight = 45_250_600;
 		let new_final_weight = 55_250_600;
 		let fee = (5, 1000);
+		let new_weight_curve = WeightCurveType::PiecewiseLinear {
+			points: WeightCurvePoints::new(
+				&(1..=MAX_WEIGHT_CURVE_POINTS)
+					.map(|i| (Permill::from_percent(i * 9), new_initial_weight))
+					.collect::<Vec<_>>(),
+			)
+			.unwrap(),
+		};
 
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, fee, caller.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
-	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(DEFAULT_FEE), Some(fee_collector), Some(1))
+	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(new_weight_curve), Some(DEFAULT_FEE), Some(fee_collector), Some(1))
 	verify {
 		let pool_data = LBP::<T>::pool_data(pool_id).unwrap();
 		assert_eq!(pool_data.start, new_start);
 		assert_eq!(pool_data.end, new_end);
 		assert_eq!(pool_data.initial_weight, new_initial_weight);
 		assert_eq!(pool_data.final_weight, new_final_weight);
+		assert_eq!(pool_data.weight_curve, new_weight_curve);
 	}
 
 	add_liquidity {
@@ -103,6 +149,152 @@ benchmarks! {
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &caller), 1000000000000000);
 	}
 
+	set_sale_config {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let config = sale_config::<T>(Allowlist::MerkleRoot(H256::repeat_byte(1)));
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), config.clone())
+	verify {
+		assert_eq!(SaleConfigs::<T>::get(pool_id), Some(config));
+	}
+
+	add_to_allowlist {
+		let n in 1..MAX_ALLOWLIST_ACCOUNTS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let accounts = allowlist_accounts::<T>(n);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), accounts.clone())
+	verify {
+		assert!(accounts.iter().all(|account| Allowlisted::<T>::get(&pool_id, account)));
+	}
+
+	remove_from_allowlist {
+		let n in 1..MAX_ALLOWLIST_ACCOUNTS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let accounts = allowlist_accounts::<T>(n);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), accounts.clone())?;
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), accounts.clone())
+	verify {
+		assert!(accounts.iter().all(|account| !Allowlisted::<T>::get(&pool_id, account)));
+	}
+
+	prove_allowlist_membership {
+		let caller = funded_account::<T>("caller", 0);
+		let participant: T::AccountId = account("participant", 0, SEED);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let (root, proof) = allowlist_proof::<T>(&participant);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller).into(), pool_id.clone(), sale_config::<T>(Allowlist::MerkleRoot(root)))?;
+
+	}: _(RawOrigin::Signed(participant.clone()), pool_id.clone(), proof)
+	verify {
+		assert!(Allowlisted::<T>::get(&pool_id, &participant));
+	}
+
+	graduate_pool {
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		let start = BlockNumberFor::<T>::from(1u32);
+		let end = BlockNumberFor::<T>::from(11u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None)?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(12u32));
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), GraduationTarget::XYK, Permill::from_percent(50))
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+	}
+
+	approve_graduation {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let target = GraduationTarget::Omnipool { weight_cap: Permill::from_percent(10) };
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller, 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		let origin = T::GraduationOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+	}: _<T::RuntimeOrigin>(origin, pool_id.clone(), target)
+	verify {
+		assert_eq!(GraduationApprovals::<T>::get(&pool_id), Some(target));
+	}
+
+	clear_sale_data {
+		let n in 0..MAX_ALLOWLIST_ACCOUNTS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let accounts = allowlist_accounts::<T>(n);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), accounts.clone())?;
+		for account in accounts.iter() {
+			Purchased::<T>::insert(&pool_id, account, ASSET_B_AMOUNT);
+		}
+
+		LBP::<T>::remove_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id.clone())?;
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), n)
+	verify {
+		assert!(Allowlisted::<T>::iter_key_prefix(&pool_id).next().is_none());
+	}
+
+	unlock_vested {
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), sale_config::<T>(Allowlist::Accounts))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), vec![caller.clone()].try_into().unwrap())?;
+
+		let start = BlockNumberFor::<T>::from(1u32);
+		let end = BlockNumberFor::<T>::from(11u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None)?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
+
+		LBP::<T>::buy(RawOrigin::Signed(caller.clone()).into(), ASSET_B_ID, ASSET_A_ID, 100_000_000, 1_000_000_000)?;
+		ensure!(VestedPurchases::<T>::contains_key(&pool_id, &caller), "Vested purchase does not exist.");
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(200u32));
+
+	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone())
+	verify {
+		assert!(!VestedPurchases::<T>::contains_key(&pool_id, &caller));
+	}
+
 	sell {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
@@ -116,10 +308,13 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), sale_config::<T>(Allowlist::Accounts))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), vec![caller.clone()].try_into().unwrap())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -141,10 +336,13 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), sale_config::<T>(Allowlist::Accounts))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), vec![caller.clone()].try_into().unwrap())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -172,10 +370,13 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), sale_config::<T>(Allowlist::Accounts))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), vec![caller.clone()].try_into().unwrap())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -210,10 +411,13 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		LBP::<T>::set_sale_config(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), sale_config::<T>(Allowlist::Accounts))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), vec![caller.clone()].try_into().unwrap())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -249,7 +453,7 @@ benchmarks! {
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -274,7 +478,7 @@ benchmarks! {
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -300,6 +504,14 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_config());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_to_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_from_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_prove_allowlist_membership());
+			assert_ok!(Pallet::<Test>::test_benchmark_graduate_pool());
+			assert_ok!(Pallet::<Test>::test_benchmark_clear_sale_data());
+			assert_ok!(Pallet::<Test>::test_benchmark_unlock_vested());
+			assert_ok!(Pallet::<Test>::test_benchmark_approve_graduation());
 		});
 	}
 }
@@ -122,6 +122,7 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
 				));
 
 				let block_num = 10;
@@ -182,6 +183,7 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
 				));
 
 				let block_num = 10;
@@ -241,6 +243,7 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
 				));
 
 				let block_num = 10;
@@ -300,6 +303,7 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
 				));
 
 				let block_num = 10;
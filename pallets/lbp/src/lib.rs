@@ -22,17 +22,20 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::manual_inspect)]
 
-pub use crate::types::{Amount, AssetId, AssetPair, Balance};
+pub use crate::types::{
+	Allowlist, Amount, AssetId, AssetPair, Balance, GraduationTarget, PoolState, PricePoint, SaleConfig,
+	VestedPurchase, VestingSchedule,
+};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	traits::{AtLeast32BitUnsigned, BlakeTwo256, BlockNumberProvider, Hash, One, Saturating, Zero},
+	DispatchError, FixedU128, Permill, RuntimeDebug,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{EnsureOrigin, Get, LockIdentifier},
-	transactional,
+	traits::{ConstU32, EnsureOrigin, Get, LockIdentifier},
+	transactional, BoundedVec,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -43,6 +46,7 @@ use pallet_broadcast::types::{Asset, Destination, Fee};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 
 use scale_info::TypeInfo;
+use sp_core::H256;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -63,10 +67,13 @@ pub mod weights;
 #[cfg(test)]
 mod invariants;
 
+pub mod api;
 mod provider;
 mod trade_execution;
+pub mod traits;
 pub mod types;
 
+pub use traits::GraduatePool;
 pub use weights::WeightInfo;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
@@ -79,8 +86,43 @@ type PoolId<T> = <T as frame_system::Config>::AccountId;
 pub enum WeightCurveType {
 	#[default]
 	Linear,
+	/// Weight changes fast at the beginning of the sale and slows down towards its end.
+	/// Higher `rate` makes the initial change faster.
+	ExponentialDecay { rate: FixedU128 },
+	/// Sale is split into `steps` equally long parts. Weight is constant within each part.
+	Step { steps: u32 },
+	/// Weight is linearly interpolated between the points. Each point consists of the elapsed part
+	/// of the sale and the weight of the asset_a at that moment.
+	PiecewiseLinear { points: WeightCurvePoints },
+}
+
+/// Points of the piecewise linear weight curve.
+/// Points are kept in a fixed size array so that the weight curve is `Copy`, only first `len` points are used.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct WeightCurvePoints {
+	points: [(Permill, LBPWeight); MAX_WEIGHT_CURVE_POINTS as usize],
+	len: u8,
+}
+
+impl WeightCurvePoints {
+	/// Returns `None` if there are more than `MAX_WEIGHT_CURVE_POINTS` points.
+	pub fn new(points: &[(Permill, LBPWeight)]) -> Option<Self> {
+		let mut curve_points = Self::default();
+		curve_points.points.get_mut(..points.len())?.copy_from_slice(points);
+		curve_points.len = points.len() as u8;
+		Some(curve_points)
+	}
+
+	/// Returns used points.
+	pub fn as_slice(&self) -> &[(Permill, LBPWeight)] {
+		self.points.get(..self.len as usize).unwrap_or_default()
+	}
 }
 
+/// Max number of points of the piecewise linear weight curve
+pub const MAX_WEIGHT_CURVE_POINTS: u32 = 10;
+
 /// Max weight corresponds to 100%
 pub const MAX_WEIGHT: LBPWeight = 100_000_000;
 
@@ -90,6 +132,15 @@ pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Prefix of lock identifiers of the assets bought with vesting, followed by a part of the pool id hash
+pub const VESTING_LOCK_ID_PREFIX: [u8; 4] = *b"lbpv";
+
+/// Max length of the merkle proof of the allowlist membership
+pub const MAX_ALLOWLIST_PROOF_LENGTH: u32 = 32;
+
+/// Max number of accounts added to or removed from the allowlist in one call
+pub const MAX_ALLOWLIST_ACCOUNTS: u32 = 100;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -153,7 +204,7 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
-		weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
@@ -165,14 +216,33 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::ExponentialDecay { rate } => {
+				hydra_dx_math::lbp::calculate_exponential_weights(start, end, initial_weight, final_weight, *rate, at)
+			}
+			WeightCurveType::Step { steps } => {
+				hydra_dx_math::lbp::calculate_step_weights(start, end, initial_weight, final_weight, *steps, at)
+			}
+			WeightCurveType::PiecewiseLinear { points } => hydra_dx_math::lbp::calculate_piecewise_linear_weights(
+				start,
+				end,
+				initial_weight,
+				final_weight,
+				points.as_slice(),
+				at,
+			),
+		}
+		.ok()
 	}
 }
 
@@ -226,6 +296,16 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Pools receiving the liquidity of graduated pools
+		type Graduation: GraduatePool<Self::AccountId, AssetId, Balance>;
+
+		/// The origin which can approve graduation of pools into Omnipool
+		type GraduationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Max difference between the final spot price of a pool and the spot price of the pool receiving its liquidity
+		#[pallet::constant]
+		type MaxGraduationPriceDifference: Get<Permill>;
 	}
 
 	#[pallet::hooks]
@@ -308,6 +388,42 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Weight curve parameters are invalid
+		InvalidWeightCurve,
+
+		/// Sale config parameters are invalid
+		InvalidSaleConfig,
+
+		/// Account is not allowed to trade in the pool
+		NotAllowlisted,
+
+		/// Account can't buy more of the distributed asset from the pool
+		MaxBuyPerAccountExceeded,
+
+		/// Allowlist proof is invalid or the pool has no merkle root set
+		InvalidAllowlistProof,
+
+		/// Fraction of the pool liquidity to graduate must be greater than zero
+		InvalidGraduationFraction,
+
+		/// Spot price of the new pool differs from the final spot price of the sale
+		GraduationPriceMismatch,
+
+		/// Allowlist and purchases of a previous sale of the pool have to be cleared first
+		SaleDataNotCleared,
+
+		/// Sale data can be cleared only after the pool was removed
+		PoolNotRemoved,
+
+		/// Account has no vested purchase in the pool
+		NoVestedPurchase,
+
+		/// Purchase from a previous sale of the pool has to be fully released first
+		VestingNotReleased,
+
+		/// Graduation of the pool into the target was not approved by `GraduationOrigin`
+		GraduationNotApproved,
 	}
 
 	#[pallet::event]
@@ -366,6 +482,47 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Sale config of a pool was set.
+		SaleConfigSet {
+			pool: PoolId<T>,
+			config: SaleConfig<BlockNumberFor<T>>,
+		},
+
+		/// Accounts were allowed to trade in the pool.
+		AddedToAllowlist {
+			pool: PoolId<T>,
+			accounts: Vec<T::AccountId>,
+		},
+
+		/// Accounts were removed from the pool allowlist.
+		RemovedFromAllowlist {
+			pool: PoolId<T>,
+			accounts: Vec<T::AccountId>,
+		},
+
+		/// Liquidity of a pool was moved into the target pool.
+		PoolGraduated {
+			pool: PoolId<T>,
+			target: GraduationTarget,
+			amount_a: BalanceOf<T>,
+			amount_b: BalanceOf<T>,
+			price: FixedU128,
+		},
+
+		/// Allowlist and purchases of a removed pool were cleared.
+		SaleDataCleared { pool: PoolId<T>, removed: u32 },
+
+		/// Lock of the vested purchase was updated to the amount which is still locked.
+		VestedPurchaseUnlocked {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			asset_id: AssetId,
+			locked: Balance,
+		},
+
+		/// Graduation of the pool into the target was approved.
+		GraduationApproved { pool: PoolId<T>, target: GraduationTarget },
 	}
 
 	/// Details of a pool.
@@ -380,6 +537,38 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Optional restrictions of a sale
+	#[pallet::storage]
+	pub type SaleConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, SaleConfig<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Accounts allowed to trade in pools with an allowlist
+	#[pallet::storage]
+	pub type Allowlisted<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Amount of the distributed asset bought by an account in pools with max buy per account limit
+	#[pallet::storage]
+	pub type Purchased<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Distributed asset bought in pools with vesting. Entries are kept after the pool is removed
+	/// until the bought amount is fully released.
+	#[pallet::storage]
+	pub type VestedPurchases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		VestedPurchase<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Graduation targets approved by `GraduationOrigin`
+	#[pallet::storage]
+	pub type GraduationApprovals<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, GraduationTarget, OptionQuery>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -409,8 +598,8 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. Linear, exponential decay,
+		/// step and piecewise linear functions are supported.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
@@ -423,7 +612,7 @@ pub mod pallet {
 		/// This increases the price of the sold asset on every trade. Make sure to only run this with
 		/// previously illiquid assets.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool().saturating_add(T::DbWeight::get().reads(2)))]
 		pub fn create_pool(
 			origin: OriginFor<T>,
 			pool_owner: T::AccountId,
@@ -485,6 +674,12 @@ pub mod pallet {
 
 			let pool_id = Self::get_pair_id(asset_pair);
 
+			ensure!(
+				<Allowlisted<T>>::iter_key_prefix(&pool_id).next().is_none()
+					&& <Purchased<T>>::iter_key_prefix(&pool_id).next().is_none(),
+				Error::<T>::SaleDataNotCleared
+			);
+
 			<PoolData<T>>::insert(&pool_id, &pool_data);
 			<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
 
@@ -521,6 +716,7 @@ pub mod pallet {
 		/// - `end`: The new ending time of the sale. This parameter is optional.
 		/// - `initial_weight`: The new initial weight. This parameter is optional.
 		/// - `final_weight`: The new final weight. This parameter is optional.
+		/// - `weight_curve`: The new weight function. This parameter is optional.
 		/// - `fee`: The new trading fee charged on every trade. This parameter is optional.
 		/// - `fee_collector`: The new receiver of trading fees. This parameter is optional.
 		///
@@ -535,6 +731,7 @@ pub mod pallet {
 			end: Option<BlockNumberFor<T>>,
 			initial_weight: Option<LBPWeight>,
 			final_weight: Option<LBPWeight>,
+			weight_curve: Option<WeightCurveType>,
 			fee: Option<(u32, u32)>,
 			fee_collector: Option<T::AccountId>,
 			repay_target: Option<Balance>,
@@ -549,6 +746,7 @@ pub mod pallet {
 					start.is_some()
 						|| end.is_some() || initial_weight.is_some()
 						|| final_weight.is_some()
+						|| weight_curve.is_some()
 						|| fee.is_some() || fee_collector.is_some()
 						|| repay_target.is_some(),
 					Error::<T>::NothingToUpdate
@@ -567,6 +765,8 @@ pub mod pallet {
 
 				pool.final_weight = final_weight.unwrap_or(pool.final_weight);
 
+				pool.weight_curve = weight_curve.unwrap_or(pool.weight_curve);
+
 				pool.fee = fee.unwrap_or(pool.fee);
 
 				// Handle update of fee collector - validate and replace old fee collector
@@ -683,30 +883,7 @@ pub mod pallet {
 
 			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
 
-			let (asset_a, asset_b) = pool_data.assets;
-
-			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
-			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
-
-			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a)?;
-			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b)?;
-
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
-
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a,
-				amount_b,
-			});
-
-			Ok(())
+			Self::do_remove_liquidity(pool_id, &pool_data)
 		}
 
 		/// Trade `asset_in` for `asset_out`.
@@ -770,6 +947,354 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set optional restrictions of a sale.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The sale config can be updated only if the sale has not already started.
+		/// Setting a config with no restrictions removes the sale config of the pool.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `config`: The sale config.
+		///     - `allowlist`: Accounts allowed to trade in the pool. Accounts are added by the pool owner
+		///       with `add_to_allowlist` or prove their membership with `prove_allowlist_membership`.
+		///     - `max_buy_per_account`: Max amount of the distributed asset one account can buy from the pool.
+		///     - `vesting`: Vesting schedule of the distributed asset bought from the pool.
+		///
+		/// Emits `SaleConfigSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_config())]
+		pub fn set_sale_config(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			config: SaleConfig<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			ensure!(
+				config.max_buy_per_account.map_or(true, |max_buy| !max_buy.is_zero()),
+				Error::<T>::InvalidSaleConfig
+			);
+
+			if let Some(vesting) = &config.vesting {
+				ensure!(
+					!vesting.period.is_zero() && !vesting.period_count.is_zero(),
+					Error::<T>::InvalidSaleConfig
+				);
+			}
+
+			if config.is_empty() {
+				<SaleConfigs<T>>::remove(&pool_id);
+			} else {
+				<SaleConfigs<T>>::insert(&pool_id, &config);
+			}
+
+			Self::deposit_event(Event::SaleConfigSet { pool: pool_id, config });
+
+			Ok(())
+		}
+
+		/// Allow accounts to trade in the pool.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `accounts`: Accounts to add to the pool allowlist, at most `MAX_ALLOWLIST_ACCOUNTS`.
+		///
+		/// Emits `AddedToAllowlist` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_allowlist(accounts.len() as u32))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_ALLOWLIST_ACCOUNTS>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			for account in accounts.iter() {
+				<Allowlisted<T>>::insert(&pool_id, account, true);
+			}
+
+			Self::deposit_event(Event::AddedToAllowlist {
+				pool: pool_id,
+				accounts: accounts.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Remove accounts from the pool allowlist.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `accounts`: Accounts to remove from the pool allowlist, at most `MAX_ALLOWLIST_ACCOUNTS`.
+		///
+		/// Emits `RemovedFromAllowlist` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_from_allowlist(accounts.len() as u32))]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_ALLOWLIST_ACCOUNTS>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			for account in accounts.iter() {
+				<Allowlisted<T>>::remove(&pool_id, account);
+			}
+
+			Self::deposit_event(Event::RemovedFromAllowlist {
+				pool: pool_id,
+				accounts: accounts.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Prove that the origin is a member of the pool allowlist merkle tree.
+		///
+		/// Leaf of the tree is the blake2_256 hash of the SCALE encoded account. Parent node is the blake2_256
+		/// hash of the SCALE encoded pair of its child nodes sorted in ascending order.
+		///
+		/// The dispatch origin for this call must be signed.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `proof`: Sibling nodes on the path from the leaf to the root of the tree.
+		///
+		/// Emits `AddedToAllowlist` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::prove_allowlist_membership())]
+		pub fn prove_allowlist_membership(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			proof: BoundedVec<H256, ConstU32<MAX_ALLOWLIST_PROOF_LENGTH>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+
+			let root = match <SaleConfigs<T>>::get(&pool_id).and_then(|config| config.allowlist) {
+				Some(Allowlist::MerkleRoot(root)) => root,
+				_ => return Err(Error::<T>::InvalidAllowlistProof.into()),
+			};
+
+			let leaf = BlakeTwo256::hash_of(&who);
+			let computed_root = proof.iter().fold(leaf, |node, sibling| {
+				if node <= *sibling {
+					BlakeTwo256::hash_of(&(node, sibling))
+				} else {
+					BlakeTwo256::hash_of(&(sibling, node))
+				}
+			});
+
+			ensure!(computed_root == root, Error::<T>::InvalidAllowlistProof);
+
+			<Allowlisted<T>>::insert(&pool_id, &who, true);
+
+			Self::deposit_event(Event::AddedToAllowlist {
+				pool: pool_id,
+				accounts: vec![who],
+			});
+
+			Ok(())
+		}
+
+		/// Move liquidity of the pool into a new pool after the sale ended.
+		///
+		/// `fraction` of the pool liquidity is moved to the `target` pool keeping the final spot price
+		/// of the sale. Pool owner receives the shares of the target pool and the rest of the pool liquidity.
+		/// Pool is destroyed.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		/// Graduation into Omnipool has to be approved by `GraduationOrigin` first.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `target`: The pool receiving the liquidity.
+		/// - `fraction`: Fraction of the reserve of lower value moved to the target pool. The amount of
+		///   the other asset is determined by the final spot price.
+		///
+		/// Emits 'LiquidityRemoved' and `PoolGraduated` events when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::graduate_pool().saturating_add(T::Graduation::graduate_weight(*target)))]
+		#[transactional]
+		pub fn graduate_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			target: GraduationTarget,
+			fraction: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+			ensure!(
+				target == GraduationTarget::XYK || <GraduationApprovals<T>>::get(&pool_id) == Some(target),
+				Error::<T>::GraduationNotApproved
+			);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let end = pool_data.end.filter(|end| *end < now).ok_or(Error::<T>::SaleNotEnded)?;
+
+			ensure!(!fraction.is_zero(), Error::<T>::InvalidGraduationFraction);
+
+			let (asset_a, asset_b) = pool_data.assets;
+			let reserve_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
+			let reserve_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
+			let (weight_a, weight_b) = Self::calculate_weights(&pool_data, end)?;
+
+			// price of asset b denominated in asset a
+			let price = hydra_dx_math::lbp::calculate_spot_price_with_fee(
+				reserve_b, reserve_a, weight_b, weight_a, asset_a, asset_a, None,
+			)
+			.map_err(|_| Error::<T>::Overflow)?;
+
+			let (amount_a, amount_b) =
+				hydra_dx_math::lbp::calculate_graduation_amounts(reserve_a, reserve_b, weight_a, weight_b, fraction)
+					.map_err(|_| Error::<T>::Overflow)?;
+
+			Self::do_remove_liquidity(pool_id.clone(), &pool_data)?;
+
+			T::Graduation::graduate(
+				target,
+				&pool_data.owner,
+				(asset_a, amount_a),
+				(asset_b, amount_b),
+				price,
+			)?;
+
+			let new_price =
+				T::Graduation::spot_price(target, asset_a, asset_b).ok_or(Error::<T>::GraduationPriceMismatch)?;
+			let max_difference = price.saturating_mul(T::MaxGraduationPriceDifference::get().into());
+			ensure!(
+				new_price.max(price).saturating_sub(new_price.min(price)) <= max_difference,
+				Error::<T>::GraduationPriceMismatch
+			);
+
+			Self::deposit_event(Event::PoolGraduated {
+				pool: pool_id,
+				target,
+				amount_a,
+				amount_b,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Approve graduation of the pool into the target which requires approval.
+		///
+		/// The dispatch origin for this call must be `GraduationOrigin`.
+		/// Pool owner can graduate the pool into the approved target by calling `graduate_pool`.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `target`: The pool receiving the liquidity.
+		///
+		/// Emits `GraduationApproved` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_graduation())]
+		pub fn approve_graduation(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			target: GraduationTarget,
+		) -> DispatchResult {
+			T::GraduationOrigin::ensure_origin(origin)?;
+
+			ensure!(<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+
+			<GraduationApprovals<T>>::insert(&pool_id, target);
+
+			Self::deposit_event(Event::GraduationApproved { pool: pool_id, target });
+
+			Ok(())
+		}
+
+		/// Remove allowlist and purchases of a removed pool.
+		///
+		/// The dispatch origin for this call must be signed. Anyone can clear the data of a removed pool.
+		/// Pool for the same asset pair can be created again only after its data are cleared.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the removed pool.
+		/// - `limit`: Max number of entries to remove.
+		///
+		/// Emits `SaleDataCleared` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_sale_data(*limit))]
+		pub fn clear_sale_data(origin: OriginFor<T>, pool_id: PoolId<T>, limit: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolNotRemoved);
+
+			let removed_allowlisted = <Allowlisted<T>>::drain_prefix(&pool_id).take(limit as usize).count();
+			let removed_purchased = <Purchased<T>>::drain_prefix(&pool_id)
+				.take((limit as usize).saturating_sub(removed_allowlisted))
+				.count();
+
+			Self::deposit_event(Event::SaleDataCleared {
+				pool: pool_id,
+				removed: removed_allowlisted.saturating_add(removed_purchased) as u32,
+			});
+
+			Ok(())
+		}
+
+		/// Unlock part of the vested purchase of the origin which was released by the vesting schedule.
+		///
+		/// The dispatch origin for this call must be signed by the buyer.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool the asset was bought from.
+		///
+		/// Emits `VestedPurchaseUnlocked` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_vested())]
+		pub fn unlock_vested(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let purchase = <VestedPurchases<T>>::get(&pool_id, &who).ok_or(Error::<T>::NoVestedPurchase)?;
+			let locked = purchase
+				.schedule
+				.locked_amount(purchase.total, T::BlockNumberProvider::current_block_number());
+
+			let lock_id = Self::vesting_lock_id(&pool_id);
+			if locked.is_zero() {
+				T::MultiCurrency::remove_lock(lock_id, purchase.asset_id, &who)?;
+				<VestedPurchases<T>>::remove(&pool_id, &who);
+			} else {
+				T::MultiCurrency::set_lock(lock_id, purchase.asset_id, &who, locked)?;
+			}
+
+			Self::deposit_event(Event::VestedPurchaseUnlocked {
+				pool: pool_id,
+				who,
+				asset_id: purchase.asset_id,
+				locked,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -779,7 +1304,7 @@ impl<T: Config> Pallet<T> {
 		at: BlockNumberFor<T>,
 	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
 		let weight_a = T::LBPWeightFunction::calculate_weight(
-			pool_data.weight_curve,
+			&pool_data.weight_curve,
 			pool_data.start.unwrap_or_else(Zero::zero),
 			pool_data.end.unwrap_or_else(Zero::zero),
 			pool_data.initial_weight,
@@ -832,6 +1357,29 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
+		Self::validate_weight_curve(&pool_data.weight_curve)
+	}
+
+	fn validate_weight_curve(weight_curve: &WeightCurveType) -> DispatchResult {
+		let is_valid = match weight_curve {
+			WeightCurveType::Linear => true,
+			WeightCurveType::ExponentialDecay { rate } => {
+				!rate.is_zero() && *rate <= FixedU128::from(hydra_dx_math::lbp::MAX_EXPONENTIAL_DECAY_RATE)
+			}
+			WeightCurveType::Step { steps } => *steps > 1,
+			WeightCurveType::PiecewiseLinear { points } => {
+				let points = points.as_slice();
+				// points have to be inside of the sale and sorted by time, weights follow the same rules as
+				// the initial and final weights
+				!points.is_empty()
+					&& points.iter().all(|(at, weight)| {
+						!at.is_zero() && *at < Permill::one() && *weight < MAX_WEIGHT && *weight >= MAX_WEIGHT / 50
+					}) && points.windows(2).all(|pair| pair[0].0 < pair[1].0)
+			}
+		};
+
+		ensure!(is_valid, Error::<T>::InvalidWeightCurve);
+
 		Ok(())
 	}
 
@@ -894,6 +1442,23 @@ impl<T: Config> Pallet<T> {
 			&pool_account,
 			transfer.amount,
 		)?;
+
+		// Purchases of the distributed asset are subject to the sale config
+		let sale_config = <SaleConfigs<T>>::get(&pool_account).filter(|_| transfer.assets.asset_out == pool.assets.1);
+
+		let vesting = match sale_config {
+			Some(config) => {
+				if config.max_buy_per_account.is_some() {
+					<Purchased<T>>::try_mutate(&pool_account, &transfer.origin, |purchased| -> DispatchResult {
+						*purchased = purchased.checked_add(transfer.amount_b).ok_or(Error::<T>::Overflow)?;
+						Ok(())
+					})?;
+				}
+				config.vesting
+			}
+			None => None,
+		};
+
 		T::MultiCurrency::transfer(
 			transfer.assets.asset_out,
 			&pool_account,
@@ -901,6 +1466,16 @@ impl<T: Config> Pallet<T> {
 			transfer.amount_b,
 		)?;
 
+		if let Some(schedule) = vesting {
+			Self::lock_vested_purchase(
+				&pool_account,
+				&transfer.origin,
+				transfer.assets.asset_out,
+				transfer.amount_b,
+				schedule,
+			)?;
+		}
+
 		// Fee is deducted from the sent out amount of accumulated asset and transferred to the fee collector
 		let (fee_asset, fee_amount) = transfer.fee;
 		let fee_payer = if transfer.assets.asset_in == fee_asset {
@@ -918,6 +1493,120 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensures that `who` is allowed to trade in the pool.
+	fn ensure_allowlisted(
+		who: &T::AccountId,
+		pool_id: &PoolId<T>,
+		sale_config: &Option<SaleConfig<BlockNumberFor<T>>>,
+	) -> DispatchResult {
+		if let Some(SaleConfig { allowlist: Some(_), .. }) = sale_config {
+			ensure!(<Allowlisted<T>>::get(pool_id, who), Error::<T>::NotAllowlisted);
+		}
+		Ok(())
+	}
+
+	/// Transfers the pool liquidity to the pool owner and removes the pool.
+	fn do_remove_liquidity(pool_id: PoolId<T>, pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
+
+		T::MultiCurrency::transfer(asset_a, &pool_id, &pool_data.owner, amount_a)?;
+		T::MultiCurrency::transfer(asset_b, &pool_id, &pool_data.owner, amount_b)?;
+
+		if Self::collected_fees(pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(&pool_data.fee_collector, pool_data.assets.0);
+		<PoolData<T>>::remove(&pool_id);
+		<GraduationApprovals<T>>::remove(&pool_id);
+
+		// Allowlist and purchases are not bounded, they are removed by `clear_sale_data`.
+		<SaleConfigs<T>>::remove(&pool_id);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: pool_id,
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+		});
+
+		Ok(())
+	}
+
+	/// Adds `amount` to the vested purchase of `who` and locks the part of the purchase which is not released yet.
+	fn lock_vested_purchase(
+		pool_id: &PoolId<T>,
+		who: &T::AccountId,
+		asset_id: AssetId,
+		amount: Balance,
+		schedule: VestingSchedule<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		<VestedPurchases<T>>::try_mutate(pool_id, who, |maybe_purchase| -> DispatchResult {
+			let mut purchase = match maybe_purchase.take() {
+				Some(purchase) if purchase.asset_id == asset_id && purchase.schedule == schedule => purchase,
+				// purchase from a previous sale of the same asset pair
+				Some(purchase) => {
+					ensure!(
+						purchase.schedule.locked_amount(purchase.total, now).is_zero(),
+						Error::<T>::VestingNotReleased
+					);
+					VestedPurchase {
+						asset_id,
+						schedule,
+						total: 0,
+					}
+				}
+				None => VestedPurchase {
+					asset_id,
+					schedule,
+					total: 0,
+				},
+			};
+
+			purchase.total = purchase.total.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+
+			T::MultiCurrency::set_lock(
+				Self::vesting_lock_id(pool_id),
+				asset_id,
+				who,
+				purchase.schedule.locked_amount(purchase.total, now),
+			)?;
+
+			*maybe_purchase = Some(purchase);
+			Ok(())
+		})
+	}
+
+	/// Lock identifier of the assets bought with vesting from the pool.
+	fn vesting_lock_id(pool_id: &PoolId<T>) -> LockIdentifier {
+		let mut lock_id = [0u8; 8];
+		lock_id[..4].copy_from_slice(&VESTING_LOCK_ID_PREFIX);
+		lock_id[4..].copy_from_slice(&BlakeTwo256::hash_of(pool_id).as_ref()[..4]);
+		lock_id
+	}
+
+	/// Ensures that `who` can buy `amount` of the distributed asset from the pool.
+	fn ensure_max_buy_not_exceeded(
+		who: &T::AccountId,
+		pool_id: &PoolId<T>,
+		sale_config: &Option<SaleConfig<BlockNumberFor<T>>>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if let Some(max_buy) = sale_config.as_ref().and_then(|config| config.max_buy_per_account) {
+			let purchased = <Purchased<T>>::get(pool_id, who)
+				.checked_add(amount)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(purchased <= max_buy, Error::<T>::MaxBuyPerAccountExceeded);
+		}
+		Ok(())
+	}
+
 	/// determines fee rate and applies it to the amount
 	fn calculate_fees(
 		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
@@ -1010,6 +1699,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 		ensure!(Self::is_pool_running(&pool_data), Error::<T>::SaleIsNotRunning);
 
+		let sale_config = <SaleConfigs<T>>::get(&pool_id);
+		Self::ensure_allowlisted(who, &pool_id, &sale_config)?;
+
 		let now = T::BlockNumberProvider::current_block_number();
 		let (weight_in, weight_out) = Self::get_sorted_weight(assets.asset_in, now, &pool_data)?;
 		let asset_in_reserve = T::MultiCurrency::free_balance(assets.asset_in, &pool_id);
@@ -1053,6 +1745,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(min_bought <= amount_out, Error::<T>::TradingLimitReached);
 
+			Self::ensure_max_buy_not_exceeded(who, &pool_id, &sale_config, amount_out)?;
+
 			let amount_without_fee = amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
 			Ok(AMMTransfer {
@@ -1153,6 +1847,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 		ensure!(Self::is_pool_running(&pool_data), Error::<T>::SaleIsNotRunning);
 
+		let sale_config = <SaleConfigs<T>>::get(&pool_id);
+		Self::ensure_allowlisted(who, &pool_id, &sale_config)?;
+
 		let now = T::BlockNumberProvider::current_block_number();
 		let (weight_in, weight_out) = Self::get_sorted_weight(assets.asset_in, now, &pool_data)?;
 		let asset_in_reserve = T::MultiCurrency::free_balance(assets.asset_in, &pool_id);
@@ -1245,6 +1942,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
+			Self::ensure_max_buy_not_exceeded(who, &pool_id, &sale_config, amount)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
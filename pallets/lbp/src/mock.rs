@@ -1,16 +1,17 @@
 use crate as lbp;
 use crate::{
-	types::{AssetId, AssetPair, Balance},
-	AssetPairAccountIdFor, Config, Pool, WeightCurveType,
+	types::{AssetId, AssetPair, Balance, GraduationTarget},
+	AssetPairAccountIdFor, Config, GraduatePool, Pool, WeightCurveType,
 };
 use frame_support::parameter_types;
 use frame_support::traits::{Everything, LockIdentifier, Nothing};
+use frame_support::weights::Weight;
 use hydradx_traits::{AMMTransfer, LockedBalance};
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, Zero},
-	BuildStorage,
+	BuildStorage, DispatchResult, FixedPointNumber, FixedU128, Permill,
 };
 use std::collections::BTreeMap;
 
@@ -38,6 +39,9 @@ pub const SALE_END: Option<BlockNumber> = Some(40);
 pub const HDX_BSX_POOL_ID: AccountId = 3_000;
 pub const KUSD_BSX_POOL_ID: AccountId = 2_003_000;
 
+pub const XYK_POOL_ACCOUNT: AccountId = 10_000_000;
+pub const OMNIPOOL_ACCOUNT: AccountId = 20_000_000;
+
 pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
 pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
@@ -157,6 +161,7 @@ parameter_types! {
 	pub const MinPoolLiquidity: Balance = 1_000;
 	pub const MaxInRatio: u128 = 3;
 	pub const MaxOutRatio: u128 = 3;
+	pub const MaxGraduationPriceDifference: Permill = Permill::from_percent(1);
 }
 
 pub struct MultiLockedBalance();
@@ -173,6 +178,43 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+pub struct DummyGraduation;
+
+impl DummyGraduation {
+	fn target_account(target: GraduationTarget) -> AccountId {
+		match target {
+			GraduationTarget::XYK => XYK_POOL_ACCOUNT,
+			GraduationTarget::Omnipool { .. } => OMNIPOOL_ACCOUNT,
+		}
+	}
+}
+
+impl GraduatePool<AccountId, AssetId, Balance> for DummyGraduation {
+	fn graduate(
+		target: GraduationTarget,
+		owner: &AccountId,
+		asset_a: (AssetId, Balance),
+		asset_b: (AssetId, Balance),
+		_price: FixedU128,
+	) -> DispatchResult {
+		let pool = Self::target_account(target);
+		<Currency as MultiCurrency<AccountId>>::transfer(asset_a.0, owner, &pool, asset_a.1)?;
+		<Currency as MultiCurrency<AccountId>>::transfer(asset_b.0, owner, &pool, asset_b.1)
+	}
+
+	fn spot_price(target: GraduationTarget, asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128> {
+		let pool = Self::target_account(target);
+		FixedU128::checked_from_rational(
+			<Currency as MultiCurrency<AccountId>>::free_balance(asset_a, &pool),
+			<Currency as MultiCurrency<AccountId>>::free_balance(asset_b, &pool),
+		)
+	}
+
+	fn graduate_weight(_target: GraduationTarget) -> Weight {
+		Weight::zero()
+	}
+}
+
 impl pallet_broadcast::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -190,6 +232,9 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type Graduation = DummyGraduation;
+	type GraduationOrigin = frame_system::EnsureRoot<u64>;
+	type MaxGraduationPriceDifference = MaxGraduationPriceDifference;
 }
 
 pub struct ExtBuilder {
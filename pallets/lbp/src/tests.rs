@@ -20,9 +20,9 @@
 
 use super::*;
 use crate::mock::{
-	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, AccountId, RuntimeCall as Call, DEFAULT_FEE,
-	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START,
-	SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
+	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, AccountId, BlockNumber, RuntimeCall as Call,
+	DEFAULT_FEE, EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID,
+	OMNIPOOL_ACCOUNT, SALE_END, SALE_START, SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA, XYK_POOL_ACCOUNT,
 };
 pub use crate::mock::{
 	set_block_number, Currency, ExtBuilder, LBPPallet, RuntimeOrigin as Origin, Test, ALICE, BOB, BSX, CHARLIE, ETH,
@@ -69,6 +69,7 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data2 = Pool {
@@ -136,6 +137,7 @@ pub fn predefined_test_ext_with_repay_target() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		assert_ok!(LBPPallet::add_liquidity(
@@ -181,6 +183,7 @@ pub fn start_50_50_lbp_without_fee_and_repay_target(
 		None,
 		None,
 		None,
+		None,
 	));
 
 	//start sale
@@ -359,6 +362,164 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_work_with_non_linear_weight_curves() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 50_000_000,
+			final_weight: 20_000_000,
+			weight_curve: WeightCurveType::ExponentialDecay {
+				rate: FixedU128::from(5),
+			},
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 130),
+			Ok((26_535_804, 73_464_196))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((22_275_745, 77_724_255))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((20_000_000, 80_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Step { steps: 4 };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 124),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((30_000_000, 70_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((20_000_000, 80_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::PiecewiseLinear {
+			points: WeightCurvePoints::new(&[(Permill::from_percent(25), 30_000_000)]).unwrap(),
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 110),
+			Ok((42_000_000, 58_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 125),
+			Ok((30_000_000, 70_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((26_666_666, 73_333_334))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((20_000_000, 80_000_000))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
+#[test]
+fn validate_pool_data_should_fail_when_weight_curve_is_invalid() {
+	new_test_ext().execute_with(|| {
+		let valid_curves = vec![
+			WeightCurveType::ExponentialDecay {
+				rate: FixedU128::from(20),
+			},
+			WeightCurveType::Step { steps: 2 },
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[
+					(Permill::from_percent(10), 2_000_000),
+					(Permill::from_percent(90), 99_999_999),
+				])
+				.unwrap(),
+			},
+		];
+		let invalid_curves = vec![
+			WeightCurveType::ExponentialDecay {
+				rate: FixedU128::zero(),
+			},
+			WeightCurveType::ExponentialDecay {
+				rate: FixedU128::from_inner(20_000_000_000_000_000_001),
+			},
+			WeightCurveType::Step { steps: 1 },
+			WeightCurveType::PiecewiseLinear {
+				points: Default::default(),
+			},
+			// point at the start of the sale
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[(Permill::zero(), 50_000_000)]).unwrap(),
+			},
+			// point at the end of the sale
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[(Permill::one(), 50_000_000)]).unwrap(),
+			},
+			// weight too low
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[(Permill::from_percent(50), 1_999_999)]).unwrap(),
+			},
+			// weight too high
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[(Permill::from_percent(50), MAX_WEIGHT)]).unwrap(),
+			},
+			// unsorted points
+			WeightCurveType::PiecewiseLinear {
+				points: WeightCurvePoints::new(&[
+					(Permill::from_percent(50), 50_000_000),
+					(Permill::from_percent(50), 60_000_000),
+				])
+				.unwrap(),
+			},
+		];
+
+		let mut pool_data = SAMPLE_POOL_DATA;
+
+		for weight_curve in valid_curves {
+			pool_data.weight_curve = weight_curve;
+			assert_ok!(LBPPallet::validate_pool_data(&pool_data));
+		}
+
+		for weight_curve in invalid_curves {
+			pool_data.weight_curve = weight_curve;
+			assert_noop!(
+				LBPPallet::validate_pool_data(&pool_data),
+				Error::<Test>::InvalidWeightCurve
+			);
+		}
+	});
+}
+
+#[test]
+fn weight_curve_points_should_fail_when_there_are_too_many_points() {
+	let points = vec![(Permill::from_percent(50), 50_000_000); MAX_WEIGHT_CURVE_POINTS as usize];
+	assert_eq!(
+		WeightCurvePoints::new(&points).map(|p| p.as_slice().to_vec()),
+		Some(points.clone())
+	);
+
+	let too_many_points = [points, vec![(Permill::from_percent(60), 50_000_000)]].concat();
+	assert_eq!(WeightCurvePoints::new(&too_many_points), None);
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -673,6 +834,7 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((5, 100)),
 			Some(BOB),
 			None,
@@ -704,6 +866,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -727,6 +890,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -748,6 +912,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(ALICE),
 			None,
 		));
@@ -770,6 +935,7 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((6, 1_000)),
 			None,
 			None,
@@ -797,6 +963,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(repayment),
 		));
 		let updated_pool_data_6 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -849,6 +1016,7 @@ fn update_non_existing_pool_data_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -870,6 +1038,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10),
 				Some(10_000_000),
+				None,
 				Some(80_000_000),
 				Some((5, 100)),
 				None,
@@ -889,6 +1058,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -905,6 +1075,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -921,6 +1092,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(0),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -945,12 +1117,89 @@ fn update_pool_data_without_changes_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::NothingToUpdate
 		);
 	});
 }
 
+#[test]
+fn update_pool_data_should_update_weight_curve() {
+	predefined_test_ext().execute_with(|| {
+		let weight_curve = WeightCurveType::Step { steps: 10 };
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(weight_curve),
+			None,
+			None,
+			None,
+		));
+
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(pool_data.weight_curve, weight_curve);
+
+		expect_events(vec![Event::PoolUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			data: pool_data,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_weight_curve_is_invalid() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(WeightCurveType::Step { steps: 0 }),
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidWeightCurve
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_weight_curve_is_updated_after_sale_start() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(WeightCurveType::Step { steps: 10 }),
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
 #[test]
 fn update_pool_data_by_non_owner_should_not_work() {
 	predefined_test_ext().execute_with(|| {
@@ -966,6 +1215,7 @@ fn update_pool_data_by_non_owner_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::NotOwner
 		);
@@ -986,6 +1236,7 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data1 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -1001,6 +1252,7 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data2 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -1034,6 +1286,7 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		set_block_number(16);
@@ -1048,6 +1301,7 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 				Some(30),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
@@ -1092,6 +1346,7 @@ fn update_pool_with_existing_fee_collector_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
@@ -1133,6 +1388,7 @@ fn update_pool_interval_should_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -1148,6 +1404,7 @@ fn update_pool_interval_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -1929,6 +2186,7 @@ fn zero_weight_should_not_work() {
 			end: Some(18),
 			initial_weight: Some(0),
 			final_weight: Some(80),
+			weight_curve: None,
 			fee: Some((5, 100)),
 			fee_collector: Some(BOB),
 			repay_target: Some(0),
@@ -2402,6 +2660,7 @@ fn buy_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2549,6 +2808,7 @@ fn update_pool_data_after_sale_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::SaleStarted
 		);
@@ -2676,6 +2936,7 @@ fn sell_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2934,6 +3195,7 @@ fn zero_fee_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3102,6 +3364,7 @@ fn get_spot_price_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3256,6 +3519,7 @@ fn simulate_lbp_event_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3547,6 +3811,7 @@ fn validate_trade_should_not_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 	});
@@ -3619,6 +3884,7 @@ fn calculate_fees_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool = LBPPallet::pool_data(HDX_BSX_POOL_ID).unwrap();
@@ -3674,6 +3940,7 @@ fn can_create_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 		// pool is initialized but is not running
 		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(
@@ -3885,6 +4152,7 @@ fn simulate_lbp_event_with_repayment() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3953,6 +4221,1118 @@ fn simulate_lbp_event_with_repayment() {
 		assert_eq!(Currency::free_balance(sold_asset, &fee_collector), 0);
 	});
 }
+fn sample_sale_config() -> SaleConfig<BlockNumber> {
+	SaleConfig {
+		allowlist: Some(Allowlist::Accounts),
+		max_buy_per_account: Some(15_000_000),
+		vesting: Some(VestingSchedule {
+			start: 50,
+			period: 10,
+			period_count: 5,
+		}),
+	}
+}
+
+#[test]
+fn set_sale_config_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let config = sample_sale_config();
+
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			config.clone()
+		));
+
+		assert_eq!(<SaleConfigs<Test>>::get(KUSD_BSX_POOL_ID), Some(config.clone()));
+
+		expect_events(vec![Event::SaleConfigSet {
+			pool: KUSD_BSX_POOL_ID,
+			config,
+		}
+		.into()]);
+
+		// empty config removes the restrictions
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig::default()
+		));
+
+		assert_eq!(<SaleConfigs<Test>>::get(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn set_sale_config_should_not_work_with_invalid_config() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_config(Origin::signed(ALICE), ALICE, sample_sale_config()),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_config(Origin::signed(BOB), KUSD_BSX_POOL_ID, sample_sale_config()),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_config(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleConfig {
+					max_buy_per_account: Some(0),
+					..sample_sale_config()
+				}
+			),
+			Error::<Test>::InvalidSaleConfig
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_config(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleConfig {
+					vesting: Some(VestingSchedule {
+						start: 50,
+						period: 0,
+						period_count: 5,
+					}),
+					..sample_sale_config()
+				}
+			),
+			Error::<Test>::InvalidSaleConfig
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_config(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleConfig {
+					vesting: Some(VestingSchedule {
+						start: 50,
+						period: 10,
+						period_count: 0,
+					}),
+					..sample_sale_config()
+				}
+			),
+			Error::<Test>::InvalidSaleConfig
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_sale_config(Origin::signed(ALICE), KUSD_BSX_POOL_ID, sample_sale_config()),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn allowlist_should_be_managed_by_pool_owner() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, vec![BOB].try_into().unwrap()),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB, CHARLIE].try_into().unwrap()
+		));
+		assert!(<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, BOB));
+		assert!(<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, CHARLIE));
+
+		assert_noop!(
+			LBPPallet::remove_from_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, vec![BOB].try_into().unwrap()),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![CHARLIE].try_into().unwrap()
+		));
+		assert!(<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, BOB));
+		assert!(!<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, CHARLIE));
+
+		expect_events(vec![
+			Event::AddedToAllowlist {
+				pool: KUSD_BSX_POOL_ID,
+				accounts: vec![BOB, CHARLIE],
+			}
+			.into(),
+			Event::RemovedFromAllowlist {
+				pool: KUSD_BSX_POOL_ID,
+				accounts: vec![CHARLIE],
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_account_is_not_allowlisted() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				allowlist: Some(Allowlist::Accounts),
+				..Default::default()
+			}
+		));
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::NotAllowlisted
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 10_000_000, 1),
+			Error::<Test>::NotAllowlisted
+		);
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000, 1_000_000_000),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1));
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::NotAllowlisted
+		);
+	});
+}
+
+#[test]
+fn router_trade_should_not_work_when_account_is_not_allowlisted() {
+	use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				allowlist: Some(Allowlist::Accounts),
+				..Default::default()
+			}
+		));
+
+		run_to_sale_start();
+
+		assert_noop!(
+			<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_sell(
+				Origin::signed(BOB),
+				PoolType::LBP,
+				KUSD,
+				BSX,
+				10_000_000,
+				1
+			),
+			ExecutorError::Error(Error::<Test>::NotAllowlisted.into())
+		);
+		assert_noop!(
+			<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_buy(
+				Origin::signed(BOB),
+				PoolType::LBP,
+				KUSD,
+				BSX,
+				10_000_000,
+				1_000_000_000
+			),
+			ExecutorError::Error(Error::<Test>::NotAllowlisted.into())
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		assert_ok!(
+			<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_sell(
+				Origin::signed(BOB),
+				PoolType::LBP,
+				KUSD,
+				BSX,
+				10_000_000,
+				1
+			)
+		);
+		assert_ok!(
+			<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_buy(
+				Origin::signed(BOB),
+				PoolType::LBP,
+				KUSD,
+				BSX,
+				10_000_000,
+				1_000_000_000
+			)
+		);
+	});
+}
+
+#[test]
+fn prove_allowlist_membership_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let bob_leaf = BlakeTwo256::hash_of(&BOB);
+		let charlie_leaf = BlakeTwo256::hash_of(&CHARLIE);
+		let root = if bob_leaf <= charlie_leaf {
+			BlakeTwo256::hash_of(&(bob_leaf, charlie_leaf))
+		} else {
+			BlakeTwo256::hash_of(&(charlie_leaf, bob_leaf))
+		};
+
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				allowlist: Some(Allowlist::MerkleRoot(root)),
+				..Default::default()
+			}
+		));
+
+		assert_ok!(LBPPallet::prove_allowlist_membership(
+			Origin::signed(BOB),
+			KUSD_BSX_POOL_ID,
+			vec![charlie_leaf].try_into().unwrap()
+		));
+
+		assert!(<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, BOB));
+
+		expect_events(vec![Event::AddedToAllowlist {
+			pool: KUSD_BSX_POOL_ID,
+			accounts: vec![BOB],
+		}
+		.into()]);
+
+		run_to_sale_start();
+
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1));
+	});
+}
+
+#[test]
+fn prove_allowlist_membership_should_not_work_with_invalid_proof() {
+	predefined_test_ext().execute_with(|| {
+		let bob_leaf = BlakeTwo256::hash_of(&BOB);
+		let charlie_leaf = BlakeTwo256::hash_of(&CHARLIE);
+		let root = if bob_leaf <= charlie_leaf {
+			BlakeTwo256::hash_of(&(bob_leaf, charlie_leaf))
+		} else {
+			BlakeTwo256::hash_of(&(charlie_leaf, bob_leaf))
+		};
+
+		// pool without merkle root
+		assert_noop!(
+			LBPPallet::prove_allowlist_membership(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				vec![charlie_leaf].try_into().unwrap()
+			),
+			Error::<Test>::InvalidAllowlistProof
+		);
+
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				allowlist: Some(Allowlist::MerkleRoot(root)),
+				..Default::default()
+			}
+		));
+
+		assert_noop!(
+			LBPPallet::prove_allowlist_membership(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				vec![charlie_leaf].try_into().unwrap()
+			),
+			Error::<Test>::InvalidAllowlistProof
+		);
+
+		assert_noop!(
+			LBPPallet::prove_allowlist_membership(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				vec![bob_leaf].try_into().unwrap()
+			),
+			Error::<Test>::InvalidAllowlistProof
+		);
+
+		assert_noop!(
+			LBPPallet::prove_allowlist_membership(Origin::signed(BOB), BOB, vec![charlie_leaf].try_into().unwrap()),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_max_buy_per_account_is_exceeded() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				max_buy_per_account: Some(15_000_000),
+				..Default::default()
+			}
+		));
+
+		run_to_sale_start();
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, BOB), 10_000_000);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000, 1_000_000_000),
+			Error::<Test>::MaxBuyPerAccountExceeded
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 20_000_000, 1),
+			Error::<Test>::MaxBuyPerAccountExceeded
+		);
+
+		// selling the distributed asset back to the pool is not limited
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 10_000_000, 1));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, BOB), 10_000_000);
+
+		// limit is per account
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, ALICE), 10_000_000);
+	});
+}
+
+#[test]
+fn bought_asset_should_be_vested_when_vesting_is_set() {
+	predefined_test_ext().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 50,
+			period: 10,
+			period_count: 5,
+		};
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				vesting: Some(schedule.clone()),
+				..Default::default()
+			}
+		));
+
+		run_to_sale_start();
+
+		let bsx_balance_before = Currency::free_balance(BSX, &BOB);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		assert_eq!(Currency::free_balance(BSX, &BOB), bsx_balance_before + 10_000_000);
+		assert_eq!(
+			<VestedPurchases<Test>>::get(KUSD_BSX_POOL_ID, BOB),
+			Some(VestedPurchase {
+				asset_id: BSX,
+				schedule: schedule.clone(),
+				total: 10_000_000,
+			})
+		);
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(
+				LBPPallet::vesting_lock_id(&KUSD_BSX_POOL_ID),
+				BSX,
+				BOB
+			),
+			10_000_000
+		);
+
+		// purchases of the same sale are merged into one vesting record
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		assert_eq!(
+			<VestedPurchases<Test>>::get(KUSD_BSX_POOL_ID, BOB),
+			Some(VestedPurchase {
+				asset_id: BSX,
+				schedule,
+				total: 20_000_000,
+			})
+		);
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(
+				LBPPallet::vesting_lock_id(&KUSD_BSX_POOL_ID),
+				BSX,
+				BOB
+			),
+			20_000_000
+		);
+
+		// selling the distributed asset is not vested
+		assert_ok!(LBPPallet::sell(Origin::signed(ALICE), BSX, KUSD, 10_000_000, 1));
+		assert_eq!(<VestedPurchases<Test>>::get(KUSD_BSX_POOL_ID, ALICE), None);
+	});
+}
+
+#[test]
+fn unlock_vested_should_release_bought_asset_gradually() {
+	predefined_test_ext().execute_with(|| {
+		let schedule = VestingSchedule {
+			start: 50,
+			period: 10,
+			period_count: 5,
+		};
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleConfig {
+				vesting: Some(schedule),
+				..Default::default()
+			}
+		));
+
+		run_to_sale_start();
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		let lock_id = LBPPallet::vesting_lock_id(&KUSD_BSX_POOL_ID);
+
+		// nothing is released before the end of the first period
+		set_block_number(59);
+		assert_ok!(LBPPallet::unlock_vested(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(lock_id, BSX, BOB),
+			10_000_000
+		);
+
+		set_block_number(70);
+		assert_ok!(LBPPallet::unlock_vested(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(lock_id, BSX, BOB),
+			6_000_000
+		);
+		expect_events(vec![Event::VestedPurchaseUnlocked {
+			pool: KUSD_BSX_POOL_ID,
+			who: BOB,
+			asset_id: BSX,
+			locked: 6_000_000,
+		}
+		.into()]);
+
+		set_block_number(100);
+		assert_ok!(LBPPallet::unlock_vested(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(lock_id, BSX, BOB),
+			0
+		);
+		assert_eq!(<VestedPurchases<Test>>::get(KUSD_BSX_POOL_ID, BOB), None);
+
+		assert_noop!(
+			LBPPallet::unlock_vested(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::NoVestedPurchase
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_sale_config() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			sample_sale_config()
+		));
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		run_to_sale_start();
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(<SaleConfigs<Test>>::get(KUSD_BSX_POOL_ID), None);
+		// allowlist and purchases are removed by `clear_sale_data`
+		assert!(<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, BOB));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, BOB), 10_000_000);
+	});
+}
+
+#[test]
+fn clear_sale_data_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			sample_sale_config()
+		));
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		run_to_sale_start();
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000,
+			1_000_000_000
+		));
+
+		assert_noop!(
+			LBPPallet::clear_sale_data(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID, 10),
+			Error::<Test>::PoolNotRemoved
+		);
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_ok!(LBPPallet::clear_sale_data(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID, 1));
+		assert!(!<Allowlisted<Test>>::get(KUSD_BSX_POOL_ID, BOB));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, BOB), 10_000_000);
+		expect_events(vec![Event::SaleDataCleared {
+			pool: KUSD_BSX_POOL_ID,
+			removed: 1,
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::clear_sale_data(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID, 1));
+		assert_eq!(<Purchased<Test>>::get(KUSD_BSX_POOL_ID, BOB), 0);
+	});
+}
+
+#[test]
+fn create_pool_should_not_work_when_sale_data_are_not_cleared() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_config(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			sample_sale_config()
+		));
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_noop!(
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::SaleDataNotCleared
+		);
+
+		assert_ok!(LBPPallet::clear_sale_data(
+			Origin::signed(CHARLIE),
+			KUSD_BSX_POOL_ID,
+			10
+		));
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+	});
+}
+
+#[test]
+fn graduate_pool_should_work() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		let kusd_balance_before = Currency::free_balance(KUSD, &ALICE);
+		let bsx_balance_before = Currency::free_balance(BSX, &ALICE);
+
+		assert_ok!(LBPPallet::graduate_pool(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			GraduationTarget::XYK,
+			Permill::from_percent(50),
+		));
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		// final weights are 80/20, the reserve of BSX has lower value
+		assert_eq!(Currency::free_balance(KUSD, &XYK_POOL_ACCOUNT), 125_000_000);
+		assert_eq!(Currency::free_balance(BSX, &XYK_POOL_ACCOUNT), 1_000_000_000);
+
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 0);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 0);
+
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			kusd_balance_before + 1_000_000_000 - 125_000_000
+		);
+		assert_eq!(
+			Currency::free_balance(BSX, &ALICE),
+			bsx_balance_before + 2_000_000_000 - 1_000_000_000
+		);
+
+		frame_system::Pallet::<Test>::assert_has_event(
+			Event::LiquidityRemoved {
+				who: KUSD_BSX_POOL_ID,
+				asset_a: KUSD,
+				asset_b: BSX,
+				amount_a: 1_000_000_000,
+				amount_b: 2_000_000_000,
+			}
+			.into(),
+		);
+		expect_events(vec![Event::PoolGraduated {
+			pool: KUSD_BSX_POOL_ID,
+			target: GraduationTarget::XYK,
+			amount_a: 125_000_000,
+			amount_b: 1_000_000_000,
+			price: FixedU128::from_rational(1, 8),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn graduate_pool_into_omnipool_should_work_when_approved() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		let target = GraduationTarget::Omnipool {
+			weight_cap: Permill::from_percent(10),
+		};
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				target,
+				Permill::from_percent(100)
+			),
+			Error::<Test>::GraduationNotApproved
+		);
+
+		assert_noop!(
+			LBPPallet::approve_graduation(Origin::signed(ALICE), KUSD_BSX_POOL_ID, target),
+			BadOrigin
+		);
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, target));
+		expect_events(vec![Event::GraduationApproved {
+			pool: KUSD_BSX_POOL_ID,
+			target,
+		}
+		.into()]);
+
+		// approval doesn't allow to graduate the pool without the owner
+		assert_noop!(
+			LBPPallet::graduate_pool(Origin::root(), KUSD_BSX_POOL_ID, target, Permill::from_percent(100)),
+			BadOrigin
+		);
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				target,
+				Permill::from_percent(100)
+			),
+			Error::<Test>::NotOwner
+		);
+
+		// approval is for the given target only
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::Omnipool {
+					weight_cap: Permill::from_percent(20),
+				},
+				Permill::from_percent(100)
+			),
+			Error::<Test>::GraduationNotApproved
+		);
+
+		assert_ok!(LBPPallet::graduate_pool(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			target,
+			Permill::from_percent(100),
+		));
+		assert_eq!(<GraduationApprovals<Test>>::get(KUSD_BSX_POOL_ID), None);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(KUSD, &OMNIPOOL_ACCOUNT), 250_000_000);
+		assert_eq!(Currency::free_balance(BSX, &OMNIPOOL_ACCOUNT), 2_000_000_000);
+
+		expect_events(vec![Event::PoolGraduated {
+			pool: KUSD_BSX_POOL_ID,
+			target,
+			amount_a: 250_000_000,
+			amount_b: 2_000_000_000,
+			price: FixedU128::from_rational(1, 8),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn graduate_pool_should_not_work_when_sale_is_not_ended() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::SaleNotEnded
+		);
+
+		set_block_number(SALE_END.unwrap());
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::SaleNotEnded
+		);
+
+		// pool without sale period
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			HDX,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				HDX_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::SaleNotEnded
+		);
+	});
+}
+
+#[test]
+fn graduate_pool_should_not_work_with_invalid_params() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				HDX_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::zero()
+			),
+			Error::<Test>::InvalidGraduationFraction
+		);
+	});
+}
+
+#[test]
+fn graduate_pool_should_not_work_when_price_of_target_pool_differs() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		assert_ok!(<Currency as MultiCurrency<AccountId>>::transfer(
+			BSX,
+			&BOB,
+			&XYK_POOL_ACCOUNT,
+			100_000_000
+		));
+
+		assert_noop!(
+			LBPPallet::graduate_pool(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				GraduationTarget::XYK,
+				Permill::from_percent(50)
+			),
+			Error::<Test>::GraduationPriceMismatch
+		);
+	});
+}
+
+#[test]
+fn pool_state_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_eq!(
+			LBPPallet::pool_state(KUSD_BSX_POOL_ID),
+			Some(PoolState {
+				assets: (KUSD, BSX),
+				reserves: (1_000_000_000, 2_000_000_000),
+				weights: (20_000_000, 80_000_000),
+				spot_price: FixedU128::from(2),
+				repay_target_reached: true,
+				block: 1,
+			})
+		);
+
+		set_block_number(20);
+
+		assert_eq!(
+			LBPPallet::pool_state(KUSD_BSX_POOL_ID),
+			Some(PoolState {
+				assets: (KUSD, BSX),
+				reserves: (1_000_000_000, 2_000_000_000),
+				weights: (40_000_000, 60_000_000),
+				spot_price: FixedU128::from_rational(3, 4),
+				repay_target_reached: true,
+				block: 20,
+			})
+		);
+
+		set_block_number(41);
+
+		let state = LBPPallet::pool_state(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(state.weights, (80_000_000, 20_000_000));
+		assert_eq!(state.spot_price, FixedU128::from_rational(1, 8));
+	});
+}
+
+#[test]
+fn pool_state_should_report_repay_target_status() {
+	predefined_test_ext_with_repay_target().execute_with(|| {
+		let state = LBPPallet::pool_state(KUSD_BSX_POOL_ID).unwrap();
+		assert!(!state.repay_target_reached);
+	});
+}
+
+#[test]
+fn pool_state_should_use_initial_weight_when_sale_is_not_scheduled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		let state = LBPPallet::pool_state(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(state.weights, (20_000_000, 80_000_000));
+		assert_eq!(state.spot_price, FixedU128::from(2));
+	});
+}
+
+#[test]
+fn pool_state_should_return_none_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(LBPPallet::pool_state(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn projected_prices_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_eq!(
+			LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 0, 10, 6),
+			Ok(vec![
+				PricePoint {
+					block: 0,
+					spot_price: FixedU128::from(2),
+				},
+				PricePoint {
+					block: 10,
+					spot_price: FixedU128::from(2),
+				},
+				PricePoint {
+					block: 20,
+					spot_price: FixedU128::from_rational(3, 4),
+				},
+				PricePoint {
+					block: 30,
+					spot_price: FixedU128::from_rational(1, 3),
+				},
+				PricePoint {
+					block: 40,
+					spot_price: FixedU128::from_rational(1, 8),
+				},
+				PricePoint {
+					block: 50,
+					spot_price: FixedU128::from_rational(1, 8),
+				},
+			])
+		);
+	});
+}
+
+#[test]
+fn projected_prices_should_change_when_trade_happens() {
+	predefined_test_ext().execute_with(|| {
+		set_block_number(11);
+
+		let prices = LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 11, 1, 30).unwrap();
+		assert_eq!(prices.len(), 30);
+		assert!(prices.windows(2).all(|w| w[0].spot_price > w[1].spot_price));
+
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 1_000_000, 1));
+
+		let state = LBPPallet::pool_state(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(
+			LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 11, 1, 1).unwrap()[0].spot_price,
+			state.spot_price
+		);
+		assert!(state.spot_price > prices[0].spot_price);
+	});
+}
+
+#[test]
+fn projected_prices_should_be_capped() {
+	predefined_test_ext().execute_with(|| {
+		assert_eq!(
+			LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 0, 1, u32::MAX)
+				.unwrap()
+				.len(),
+			api::MAX_PROJECTED_PRICES as usize
+		);
+		assert_eq!(LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 0, 1, 0), Ok(vec![]));
+	});
+}
+
+#[test]
+fn projected_prices_should_fail_when_params_are_invalid() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::projected_prices(KUSD_BSX_POOL_ID, 0, 0, 10),
+			Error::<Test>::InvalidBlockRange
+		);
+		assert_noop!(
+			LBPPallet::projected_prices(HDX_BSX_POOL_ID, 0, 1, 10),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
 mod spot_price_calculation {
 	use super::*;
 	use crate::mock::RuntimeOrigin;
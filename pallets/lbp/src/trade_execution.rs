@@ -5,6 +5,7 @@ use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::{vec, vec::Vec};
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -196,4 +197,11 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 
 		Ok(spot_price_with_fee)
 	}
+
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		PoolData::<T>::iter_values()
+			.filter(|pool| Self::is_pool_running(pool))
+			.map(|pool| (PoolType::LBP, vec![pool.assets.0, pool.assets.1]))
+			.collect()
+	}
 }
@@ -0,0 +1,60 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::types::GraduationTarget;
+use frame_support::weights::Weight;
+use sp_runtime::{DispatchResult, FixedU128, TokenError};
+
+/// Moves liquidity of a graduated pool into a new pool.
+pub trait GraduatePool<AccountId, AssetId, Balance> {
+	/// Moves `asset_a` and `asset_b` amounts from `owner` into the `target` pool with initial spot price `price`.
+	/// `price` is the price of asset b denominated in asset a. `owner` receives the shares of the new pool.
+	fn graduate(
+		target: GraduationTarget,
+		owner: &AccountId,
+		asset_a: (AssetId, Balance),
+		asset_b: (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult;
+
+	/// Returns spot price of asset b denominated in asset a in the `target` pool.
+	fn spot_price(target: GraduationTarget, asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128>;
+
+	/// Weight of `graduate` which is not included in the pool graduation benchmark.
+	fn graduate_weight(target: GraduationTarget) -> Weight;
+}
+
+// Default implementation when pool graduation is not supported.
+impl<AccountId, AssetId, Balance> GraduatePool<AccountId, AssetId, Balance> for () {
+	fn graduate(
+		_target: GraduationTarget,
+		_owner: &AccountId,
+		_asset_a: (AssetId, Balance),
+		_asset_b: (AssetId, Balance),
+		_price: FixedU128,
+	) -> DispatchResult {
+		Err(TokenError::Unsupported.into())
+	}
+
+	fn spot_price(_target: GraduationTarget, _asset_a: AssetId, _asset_b: AssetId) -> Option<FixedU128> {
+		None
+	}
+
+	fn graduate_weight(_target: GraduationTarget) -> Weight {
+		Weight::zero()
+	}
+}
@@ -19,8 +19,13 @@ pub type AssetId = u32;
 pub type Amount = i128;
 pub type Balance = u128;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
+use hydra_dx_math::types::LBPWeight;
 use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::{AtLeast32BitUnsigned, UniqueSaturatedInto};
+use sp_runtime::{FixedU128, Permill, Rounding};
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -65,3 +70,117 @@ impl AssetPair {
 		buf
 	}
 }
+
+/// Restriction of accounts which can trade in a pool.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum Allowlist {
+	/// Only accounts added by the pool owner can trade.
+	Accounts,
+	/// Accounts added by the pool owner and accounts which proved to be a leaf of the merkle tree
+	/// with given root can trade.
+	MerkleRoot(H256),
+}
+
+/// Vesting schedule of assets bought from a pool.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VestingSchedule<BlockNumber> {
+	/// Vesting starting block
+	pub start: BlockNumber,
+	/// Number of blocks between vests
+	pub period: BlockNumber,
+	/// Number of vests
+	pub period_count: u32,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingSchedule<BlockNumber> {
+	/// Returns part of `total` which is still locked at block `now`.
+	/// Equal part of `total` is released at the end of each period.
+	pub fn locked_amount(&self, total: Balance, now: BlockNumber) -> Balance {
+		if now < self.start {
+			return total;
+		}
+		let elapsed_periods: u32 = (now - self.start)
+			.checked_div(&self.period)
+			.map_or(self.period_count, |periods| periods.unique_saturated_into());
+		let remaining_periods = self.period_count.saturating_sub(elapsed_periods);
+
+		multiply_by_rational_with_rounding(total, remaining_periods.into(), self.period_count.into(), Rounding::Up)
+			.unwrap_or_default()
+	}
+}
+
+/// Distributed asset bought by an account in a sale with vesting.
+/// Bought amount is locked in the account of the buyer and released according to the vesting schedule.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VestedPurchase<BlockNumber> {
+	/// Bought asset
+	pub asset_id: AssetId,
+	/// Vesting schedule of the sale
+	pub schedule: VestingSchedule<BlockNumber>,
+	/// Total amount bought in the sale
+	pub total: Balance,
+}
+
+/// Optional restrictions of a sale.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SaleConfig<BlockNumber> {
+	/// Accounts allowed to trade in the pool. Everyone can trade if not set.
+	pub allowlist: Option<Allowlist>,
+	/// Max amount of the distributed asset one account can buy from the pool.
+	pub max_buy_per_account: Option<Balance>,
+	/// Vesting schedule applied to the distributed asset bought from the pool.
+	pub vesting: Option<VestingSchedule<BlockNumber>>,
+}
+
+impl<BlockNumber> Default for SaleConfig<BlockNumber> {
+	fn default() -> Self {
+		Self {
+			allowlist: None,
+			max_buy_per_account: None,
+			vesting: None,
+		}
+	}
+}
+
+impl<BlockNumber> SaleConfig<BlockNumber> {
+	/// Returns true if the sale is not restricted in any way.
+	pub fn is_empty(&self) -> bool {
+		self.allowlist.is_none() && self.max_buy_per_account.is_none() && self.vesting.is_none()
+	}
+}
+
+/// Pool which receives the liquidity of a graduated pool.
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum GraduationTarget {
+	/// New XYK pool of the pool assets.
+	XYK,
+	/// Distributed asset is added to the Omnipool with given weight cap.
+	/// Accumulated asset is already listed in the Omnipool and is added to it as liquidity.
+	Omnipool { weight_cap: Permill },
+}
+
+/// Current state of a pool.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub struct PoolState<BlockNumber> {
+	/// Accumulated and distributed asset of the pool
+	pub assets: (AssetId, AssetId),
+	/// Pool reserves of the assets
+	pub reserves: (Balance, Balance),
+	/// Pool weights of the assets at `block`
+	pub weights: (LBPWeight, LBPWeight),
+	/// Price of the distributed asset denominated in the accumulated asset
+	pub spot_price: FixedU128,
+	/// True if the fee collector received fees at least equal to the repay target, the pool fee is applied.
+	/// Repay fee is applied otherwise.
+	pub repay_target_reached: bool,
+	/// Block number of the state
+	pub block: BlockNumber,
+}
+
+/// Spot price of a pool at a block.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub struct PricePoint<BlockNumber> {
+	pub block: BlockNumber,
+	/// Price of the distributed asset denominated in the accumulated asset
+	pub spot_price: FixedU128,
+}
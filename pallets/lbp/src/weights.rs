@@ -21,6 +21,14 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_buy() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn set_sale_config() -> Weight;
+	fn add_to_allowlist(n: u32) -> Weight;
+	fn remove_from_allowlist(n: u32) -> Weight;
+	fn prove_allowlist_membership() -> Weight;
+	fn graduate_pool() -> Weight;
+	fn clear_sale_data(n: u32) -> Weight;
+	fn unlock_vested() -> Weight;
+	fn approve_graduation() -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -90,6 +98,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `LBP::SaleConfigs` (r:1 w:1)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
@@ -110,22 +120,30 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::GraduationApprovals` (r:0 w:1)
+	/// Proof: `LBP::GraduationApprovals` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1962`
-		//  Estimated: `11322`
-		// Minimum execution time: 168_745_000 picoseconds.
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
 		Weight::from_parts(169_823_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
+	/// Storage: `LBP::SaleConfigs` (r:1 w:0)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Purchased` (r:1 w:1)
+	/// Proof: `LBP::Purchased` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedPurchases` (r:1 w:1)
+	/// Proof: `LBP::VestedPurchases` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Storage: `Tokens::Locks` (r:2 w:2)
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
 	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -136,21 +154,27 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2251`
-		//  Estimated: `13905`
-		// Minimum execution time: 278_405_000 picoseconds.
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
 		Weight::from_parts(280_656_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
+	/// Storage: `LBP::SaleConfigs` (r:1 w:0)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Purchased` (r:1 w:1)
+	/// Proof: `LBP::Purchased` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedPurchases` (r:1 w:1)
+	/// Proof: `LBP::VestedPurchases` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Storage: `Tokens::Locks` (r:2 w:2)
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
 	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -161,21 +185,27 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2251`
-		//  Estimated: `13905`
-		// Minimum execution time: 278_628_000 picoseconds.
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
 		Weight::from_parts(280_382_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
+	/// Storage: `LBP::SaleConfigs` (r:1 w:0)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Purchased` (r:1 w:1)
+	/// Proof: `LBP::Purchased` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedPurchases` (r:1 w:1)
+	/// Proof: `LBP::VestedPurchases` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Storage: `Tokens::Locks` (r:2 w:2)
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
 	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -188,27 +218,33 @@ impl WeightInfo for () {
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052 + e * (1199 ±0)`
-		//  Estimated: `8799 + e * (7749 ±0)`
-		// Minimum execution time: 102_791_000 picoseconds.
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
 		Weight::from_parts(103_541_000, 8799)
 			// Standard Error: 232_924
 			.saturating_add(Weight::from_parts(979_511, 0).saturating_mul(c.into()))
 			// Standard Error: 518_787
 			.saturating_add(Weight::from_parts(178_064_307, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((10_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 7749).saturating_mul(e.into()))
 	}
+	/// Storage: `LBP::SaleConfigs` (r:1 w:0)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Purchased` (r:1 w:1)
+	/// Proof: `LBP::Purchased` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedPurchases` (r:1 w:1)
+	/// Proof: `LBP::VestedPurchases` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Storage: `Tokens::Locks` (r:2 w:2)
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
 	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -221,18 +257,16 @@ impl WeightInfo for () {
 	/// The range of component `c` is `[1, 3]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052 + e * (1199 ±0)`
-		//  Estimated: `6190 + e * (8544 ±14_296_005_195_177_084)`
-		// Minimum execution time: 177_688_000 picoseconds.
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
 		Weight::from_parts(178_851_000, 6190)
 			// Standard Error: 450_328
 			.saturating_add(Weight::from_parts(2_999_206, 0).saturating_mul(c.into()))
 			// Standard Error: 1_518_653
 			.saturating_add(Weight::from_parts(137_463_885, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((10_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 8544).saturating_mul(e.into()))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
@@ -265,4 +299,157 @@ impl WeightInfo for () {
 		Weight::from_parts(43_263_000, 6190)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(244), added: 2719, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleConfigs` (r:0 w:1)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn set_sale_config() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(24_862_000, 3709)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(244), added: 2719, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:0 w:100)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn add_to_allowlist(n: u32, ) -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(19_637_412, 3709)
+			.saturating_add(Weight::from_parts(3_276_093, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(244), added: 2719, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:0 w:100)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_from_allowlist(n: u32, ) -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(19_584_236, 3709)
+			.saturating_add(Weight::from_parts(3_198_511, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(244), added: 2719, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleConfigs` (r:1 w:0)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:0 w:1)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn prove_allowlist_membership() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(62_415_000, 3709)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:2 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(244), added: 2719, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:2 w:1)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:4 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:3 w:1)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:3 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencyPrice` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencyPrice` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:0)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:1)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExistentialDepositCounter` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExistentialDepositCounter` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleConfigs` (r:0 w:1)
+	/// Proof: `LBP::SaleConfigs` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XYK::TotalLiquidity` (r:0 w:1)
+	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolAssets` (r:0 w:1)
+	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFee` (r:0 w:1)
+	/// Proof: `XYK::PoolFee` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::GraduationApprovals` (r:1 w:1)
+	/// Proof: `LBP::GraduationApprovals` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn graduate_pool() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(731_046_000, 24258)
+			.saturating_add(RocksDbWeight::get().reads(39_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:{n} w:{n})
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Purchased` (r:{n} w:{n})
+	/// Proof: `LBP::Purchased` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_sale_data(n: u32, ) -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(12_000_000, 3628)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(n.into()))
+	}
+	/// Storage: `LBP::VestedPurchases` (r:1 w:1)
+	/// Proof: `LBP::VestedPurchases` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unlock_vested() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(45_000_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::GraduationApprovals` (r:0 w:1)
+	/// Proof: `LBP::GraduationApprovals` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn approve_graduation() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(15_000_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
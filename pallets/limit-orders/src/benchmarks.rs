@@ -0,0 +1,124 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Hooks};
+use orml_traits::MultiCurrencyExtended;
+
+pub const ONE: Balance = 1_000_000_000_000;
+pub const HDX: u32 = 0;
+pub const DAI: u32 = 2;
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+
+	place_order {
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1)?;
+
+		// worst case - the new order is inserted at the beginning of an almost full order book
+		fill_order_book::<T>(T::MaxOrdersPerPair::get() - 1, FixedU128::from(2))?;
+		let order_id = Pallet::<T>::next_order_id();
+	}: _(RawOrigin::Signed(owner.clone()), HDX.into(), DAI.into(), 10 * ONE, FixedU128::from(1), true, None)
+	verify {
+		assert!(Orders::<T>::get(order_id).is_some());
+		assert_eq!(OrderBook::<T>::get((T::AssetId::from(HDX), T::AssetId::from(DAI)))[0].1, order_id);
+	}
+
+	cancel_order {
+		fill_order_book::<T>(T::MaxOrdersPerPair::get(), FixedU128::from(2))?;
+		let owner = Orders::<T>::get(0u32).unwrap().owner;
+	}: _(RawOrigin::Signed(owner), 0u32)
+	verify {
+		assert!(Orders::<T>::get(0u32).is_none());
+	}
+
+	execute_order {
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1)?;
+		let caller: T::AccountId = create_account_with_balances::<T>("caller", 2)?;
+
+		fill_order_book::<T>(T::MaxOrdersPerPair::get() - 1, FixedU128::from(2))?;
+		let order_id = Pallet::<T>::next_order_id();
+		assert_ok!(
+			Pallet::<T>::place_order(RawOrigin::Signed(owner).into(), HDX.into(), DAI.into(), 10 * ONE, FixedU128::from_rational(1, 100), false, None)
+		);
+		let route = T::Router::get_route(AssetPair::new(HDX.into(), DAI.into())).try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), order_id, route)
+	verify {
+		assert!(Orders::<T>::get(order_id).is_none());
+	}
+
+	process_order_book {
+		let n in 1 .. T::MaxOrdersPerPair::get();
+
+		// orders whose limit price has not been reached yet
+		fill_order_book::<T>(n, FixedU128::from(1_000_000))?;
+	}: {
+		Pallet::<T>::on_idle(frame_system::Pallet::<T>::block_number(), Weight::MAX);
+	}
+	verify {
+		assert_eq!(OrderBook::<T>::get((T::AssetId::from(HDX), T::AssetId::from(DAI))).len() as u32, n);
+	}
+}
+
+/// Fills the order book with orders of different accounts, as the number of orders per account is limited.
+fn fill_order_book<T: Config>(count: u32, limit_price: FixedU128) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	for i in 0..count {
+		let owner: T::AccountId = create_account_with_balances::<T>("filler", i)?;
+		Pallet::<T>::place_order(
+			RawOrigin::Signed(owner).into(),
+			HDX.into(),
+			DAI.into(),
+			10 * ONE,
+			limit_price,
+			true,
+			None,
+		)?;
+	}
+
+	Ok(())
+}
+
+fn create_account_with_balances<T: Config>(name: &'static str, index: u32) -> Result<T::AccountId, DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let account_id: T::AccountId = account(name, index, index);
+
+	let token_amount: Balance = 1_000_000 * ONE;
+
+	for asset in [HDX, DAI] {
+		T::Currency::update_balance(asset.into(), &account_id, token_amount as i128)?;
+	}
+
+	Ok(account_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
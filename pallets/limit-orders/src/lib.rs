@@ -0,0 +1,722 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Limit orders pallet
+//!
+//! ## Description
+//! The pallet allows users to place passive limit orders which sell `asset_in` for `asset_out` once the Omnipool spot
+//! price of `asset_in` denominated in `asset_out` reaches the limit price of the order.
+//!
+//! Placed orders are kept in a per-pair order book, sorted by the limit price. The order books are processed in
+//! `on_idle` - expired orders are removed and orders whose limit price has been reached are executed via the router.
+//! Anyone can also trigger the execution of a specific order by calling `execute_order`.
+//!
+//! `on_idle` processes the order books in rotation, starting after the last order book processed in previous block,
+//! as long as the remaining block weight allows. An order whose execution fails is not retried in `on_idle` for
+//! `ExecutionRetryDelay` blocks.
+//!
+//! Every order expires at the latest `MaxOrderLifetime` blocks after it has been placed. Placing an order reserves
+//! `OrderDeposit` of the native asset, which is returned when the order is filled, cancelled or expired, and an
+//! account can have at most `MaxOrdersPerAccountPerPair` open orders in the order book of an asset pair.
+//!
+//! ## Notes
+//! The order is executed only if the router can deliver at least `limit_price * amount_in` of `asset_out`, so the
+//! trade fees are taken into account. If the whole order cannot be filled at the limit price and the order is
+//! partially fillable, the pallet searches for the biggest amount that can be sold at the limit price. The remaining
+//! amount of a partially filled order can never be lower than `MinimumTradingLimit`.
+//!
+//! The amount of `asset_in` is reserved when the order is placed and unreserved when the order is executed,
+//! cancelled or expired.
+//!
+//! ## Dispatachable functions
+//! * `place_order` -  create a new limit order.
+//! * `cancel_order` - cancel an open limit order.
+//! * `execute_order` - execute an open limit order whose limit price has been reached.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::manual_inspect)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
+use hydradx_traits::pools::SpotPriceProvider;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+
+pub type LimitOrderOf<T> =
+	LimitOrder<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member + Parameter + Copy + Ord + MaybeSerializeDeserialize + MaxEncodedLen;
+
+		/// Named reservable multi currency.
+		type Currency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = NamedReserveIdentifier,
+			CurrencyId = Self::AssetId,
+			Balance = Balance,
+		>;
+
+		/// Spot price provider used to determine whether the limit price of an order has been reached.
+		type SpotPriceProvider: SpotPriceProvider<Self::AssetId, Price = FixedU128>;
+
+		/// Router implementation used to execute the orders.
+		type Router: RouteProvider<Self::AssetId>
+			+ RouterT<Self::RuntimeOrigin, Self::AssetId, Balance, Trade<Self::AssetId>, AmountInAndOut<Balance>>;
+
+		/// Minimum amount of an order. Also the minimum amount left in a partially filled order.
+		#[pallet::constant]
+		type MinimumTradingLimit: Get<Balance>;
+
+		/// Maximum number of open orders per asset pair.
+		#[pallet::constant]
+		type MaxOrdersPerPair: Get<u32>;
+
+		/// Maximum number of open orders of one account per asset pair.
+		#[pallet::constant]
+		type MaxOrdersPerAccountPerPair: Get<u32>;
+
+		/// Maximum number of blocks an order stays in the order book. Also the default lifetime of an order.
+		#[pallet::constant]
+		type MaxOrderLifetime: Get<BlockNumberFor<Self>>;
+
+		/// Amount of native asset reserved for every open order. Returned when the order is removed.
+		#[pallet::constant]
+		type OrderDeposit: Get<Balance>;
+
+		/// Native Asset Id
+		#[pallet::constant]
+		type NativeAssetId: Get<Self::AssetId>;
+
+		/// Maximum number of iterations used to find the fillable amount of a partially fillable order.
+		#[pallet::constant]
+		type MaxIterations: Get<u32>;
+
+		/// Number of blocks after which `on_idle` retries the execution of an order which failed.
+		#[pallet::constant]
+		type ExecutionRetryDelay: Get<BlockNumberFor<Self>>;
+
+		/// Named reserve identifier to store the amounts of open orders.
+		#[pallet::constant]
+		type NamedReserveId: Get<NamedReserveIdentifier>;
+
+		/// Weight information for the router trades.
+		type RouterWeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A limit order has been placed
+		Placed {
+			order_id: OrderId,
+			owner: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			limit_price: FixedU128,
+			partially_fillable: bool,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A limit order has been completely filled
+		Filled {
+			order_id: OrderId,
+			who: T::AccountId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// A limit order has been partially filled
+		PartiallyFilled {
+			order_id: OrderId,
+			who: T::AccountId,
+			amount_in: Balance,
+			amount_out: Balance,
+			remaining_amount_in: Balance,
+		},
+		/// A limit order has been cancelled
+		Cancelled { order_id: OrderId },
+		/// A limit order has expired and has been removed
+		Expired { order_id: OrderId },
+		/// Execution of a limit order failed
+		ExecutionFailed { order_id: OrderId, error: DispatchError },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Asset in and asset out of an order must be different
+		SameAssetTradeNotAllowed,
+		/// Order amount is lower than the minimum trading limit
+		OrderAmountTooSmall,
+		/// Limit price must be greater than zero
+		InvalidLimitPrice,
+		/// Order expiry must not be in the past or after the maximum order lifetime
+		InvalidExpiry,
+		/// Order cannot be found
+		OrderNotFound,
+		/// The caller does not have permission to complete the action
+		Forbidden,
+		/// Order book of the asset pair is full
+		OrderBookFull,
+		/// Size of order ID exceeds the bound
+		OrderIdOutOfBound,
+		/// Reserved amount not sufficient
+		InsufficientReservedAmount,
+		/// The order cannot be executed at its limit price
+		LimitPriceNotReached,
+		/// The order has expired
+		OrderExpired,
+		/// Account has reached the maximum number of open orders of the asset pair
+		TooManyOrders,
+		/// Provided route is not the route of the asset pair used by the router
+		RouteMismatch,
+	}
+
+	/// ID sequencer for orders
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Open limit orders
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, LimitOrderOf<T>, OptionQuery>;
+
+	/// Open orders of an asset pair `(asset_in, asset_out)`, sorted ascending by the limit price.
+	#[pallet::storage]
+	#[pallet::getter(fn order_book)]
+	pub type OrderBook<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AssetId, T::AssetId),
+		BoundedVec<(FixedU128, OrderId), T::MaxOrdersPerPair>,
+		ValueQuery,
+	>;
+
+	/// Number of open orders of an account in the order book of an asset pair `(asset_in, asset_out)`.
+	#[pallet::storage]
+	pub type AccountOrderCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::AssetId, T::AssetId),
+		u32,
+		ValueQuery,
+	>;
+
+	/// Last order book processed by `on_idle`. Processing of the order books in next block continues after it.
+	#[pallet::storage]
+	pub type ProcessingCursor<T: Config> = StorageValue<_, (T::AssetId, T::AssetId), OptionQuery>;
+
+	/// Orders whose execution in `on_idle` failed, mapped to the block in which the execution is retried.
+	#[pallet::storage]
+	pub type ExecutionRetryAt<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(current_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// read and write of the cursor
+			let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+			if !remaining_weight.all_gte(used_weight) {
+				return Weight::zero();
+			}
+
+			let cursor = ProcessingCursor::<T>::get();
+			let mut last_processed = cursor;
+
+			// order books after the cursor first, then from the beginning until the first visited book is reached again
+			let pairs = cursor
+				.map(|pair| OrderBook::<T>::iter_keys_from(OrderBook::<T>::hashed_key_for(pair)))
+				.into_iter()
+				.flatten()
+				.chain(OrderBook::<T>::iter_keys());
+
+			let mut first_pair = None;
+			'books: for pair in pairs {
+				let key_read_weight = T::DbWeight::get().reads(1);
+				if !remaining_weight.all_gte(used_weight.saturating_add(key_read_weight)) {
+					break;
+				}
+				used_weight.saturating_accrue(key_read_weight);
+
+				if first_pair == Some(pair) {
+					break;
+				}
+				let is_first_book = first_pair.is_none();
+				first_pair.get_or_insert(pair);
+
+				let book = OrderBook::<T>::get(pair);
+				let book_weight = <T as Config>::WeightInfo::process_order_book(book.len() as u32);
+				if !remaining_weight.all_gte(used_weight.saturating_add(book_weight)) {
+					// book which does not fit even into the whole remaining weight is skipped to not block other books
+					if is_first_book {
+						used_weight.saturating_accrue(T::DbWeight::get().reads(1));
+						last_processed = Some(pair);
+						continue;
+					}
+					break;
+				}
+				used_weight.saturating_accrue(book_weight);
+
+				for (_, order_id) in book {
+					let Some(order) = Orders::<T>::get(order_id) else {
+						continue;
+					};
+
+					if order.is_expired(current_block) {
+						let expire_weight = <T as Config>::WeightInfo::cancel_order();
+						if !remaining_weight.all_gte(used_weight.saturating_add(expire_weight)) {
+							break 'books;
+						}
+						used_weight.saturating_accrue(expire_weight);
+
+						if Self::remove_order(order_id, &order).is_ok() {
+							Self::deposit_event(Event::Expired { order_id });
+						}
+						continue;
+					}
+
+					if ExecutionRetryAt::<T>::get(order_id).is_some_and(|retry_at| retry_at > current_block) {
+						continue;
+					}
+
+					if !Self::limit_price_reached(&order) {
+						continue;
+					}
+
+					let route = T::Router::get_route(AssetPair::new(order.asset_in, order.asset_out));
+					let execution_weight = <T as Config>::WeightInfo::execute_order()
+						.saturating_add(Self::trade_weight(&route))
+						.saturating_add(T::DbWeight::get().writes(1));
+					if !remaining_weight.all_gte(used_weight.saturating_add(execution_weight)) {
+						break 'books;
+					}
+					used_weight.saturating_accrue(execution_weight);
+
+					match Self::do_execute_order(order_id, order, route) {
+						// Spot price reached the limit, but the trade fees or price impact still prevent the execution
+						Err(error) if error == Error::<T>::LimitPriceNotReached.into() => {}
+						Err(error) => {
+							// back off so that a failing order is not retried and reported in every block
+							ExecutionRetryAt::<T>::insert(
+								order_id,
+								current_block.saturating_add(T::ExecutionRetryDelay::get()),
+							);
+							Self::deposit_event(Event::ExecutionFailed { order_id, error });
+						}
+						Ok(()) => {}
+					}
+				}
+
+				last_processed = Some(pair);
+			}
+
+			if last_processed != cursor {
+				ProcessingCursor::<T>::set(last_processed);
+			}
+
+			used_weight
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MinimumTradingLimit::get() > 0,
+				"Minimum trading limit must be greater than zero."
+			);
+			assert!(
+				T::MaxOrdersPerPair::get() > 0,
+				"Max orders per pair must be greater than zero."
+			);
+			assert!(
+				T::MaxOrdersPerAccountPerPair::get() > 0,
+				"Max orders per account per pair must be greater than zero."
+			);
+			assert!(
+				!T::MaxOrderLifetime::get().is_zero(),
+				"Max order lifetime must be greater than zero."
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new limit order
+		///
+		/// The order sells `amount_in` of `asset_in` for `asset_out` once the price of `asset_in` denominated in
+		/// `asset_out` reaches `limit_price`. The `amount_in` and the `OrderDeposit` of native asset are reserved until
+		/// the order is filled, cancelled or expired.
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being sold
+		/// - `asset_out`: Asset which is being bought
+		/// - `amount_in`: Amount that the order is selling
+		/// - `limit_price`: Minimum price of `asset_in` denominated in `asset_out`
+		/// - `partially_fillable`: Flag indicating whether the order can be filled partially
+		/// - `expires_at`: Optional last block in which the order can be executed. Defaults to and cannot be later than
+		///   `MaxOrderLifetime` blocks from now.
+		///
+		/// Emits `Placed` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			limit_price: FixedU128,
+			partially_fillable: bool,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+			ensure!(
+				amount_in >= T::MinimumTradingLimit::get(),
+				Error::<T>::OrderAmountTooSmall
+			);
+			ensure!(!limit_price.is_zero(), Error::<T>::InvalidLimitPrice);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let max_expires_at = current_block.saturating_add(T::MaxOrderLifetime::get());
+			let expires_at = expires_at.unwrap_or(max_expires_at);
+			ensure!(
+				expires_at >= current_block && expires_at <= max_expires_at,
+				Error::<T>::InvalidExpiry
+			);
+
+			<AccountOrderCount<T>>::try_mutate(&owner, (asset_in, asset_out), |count| -> DispatchResult {
+				ensure!(*count < T::MaxOrdersPerAccountPerPair::get(), Error::<T>::TooManyOrders);
+				*count = count.saturating_add(1);
+				Ok(())
+			})?;
+
+			let order_id = <NextOrderId<T>>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(Error::<T>::OrderIdOutOfBound)?;
+				Ok(current_id)
+			})?;
+
+			<OrderBook<T>>::try_mutate((asset_in, asset_out), |book| -> DispatchResult {
+				// orders with the same limit price are executed in the order they were placed
+				let index = book.partition_point(|(price, _)| *price <= limit_price);
+				book.try_insert(index, (limit_price, order_id))
+					.map_err(|_| Error::<T>::OrderBookFull.into())
+			})?;
+
+			T::Currency::reserve_named(&T::NamedReserveId::get(), asset_in, &owner, amount_in)?;
+
+			let deposit = T::OrderDeposit::get();
+			T::Currency::reserve_named(&T::NamedReserveId::get(), T::NativeAssetId::get(), &owner, deposit)?;
+
+			let order = LimitOrder {
+				owner: owner.clone(),
+				asset_in,
+				asset_out,
+				amount_in,
+				limit_price,
+				partially_fillable,
+				expires_at,
+				deposit,
+			};
+			<Orders<T>>::insert(order_id, order);
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				limit_price,
+				partially_fillable,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel an open limit order
+		///
+		/// The remaining reserved amount and the order deposit are returned to the owner.
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		///
+		/// Emits `Cancelled` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		#[transactional]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::Forbidden);
+
+			Self::remove_order(order_id, &order)?;
+
+			Self::deposit_event(Event::Cancelled { order_id });
+
+			Ok(())
+		}
+
+		/// Execute an open limit order whose limit price has been reached
+		///
+		/// Can be called by anyone. The order is sold via the route provided by the router. The caller has to pass
+		/// that route, so that the weight of the call can be determined before the order is read.
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		/// - `route`: Route of the order's asset pair provided by the router
+		///
+		/// Emits `Filled` or `PartiallyFilled` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_order().saturating_add(Pallet::<T>::trade_weight(route)))]
+		pub fn execute_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			route: BoundedVec<Trade<T::AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(
+				!order.is_expired(frame_system::Pallet::<T>::block_number()),
+				Error::<T>::OrderExpired
+			);
+
+			let route = route.into_inner();
+			ensure!(
+				route == T::Router::get_route(AssetPair::new(order.asset_in, order.asset_out)),
+				Error::<T>::RouteMismatch
+			);
+
+			Self::do_execute_order(order_id, order, route)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if the spot price of `asset_in` denominated in `asset_out` is at least the limit price.
+	fn limit_price_reached(order: &LimitOrderOf<T>) -> bool {
+		T::SpotPriceProvider::spot_price(order.asset_out, order.asset_in)
+			.is_some_and(|spot_price| spot_price >= order.limit_price)
+	}
+
+	/// Calculates the amount of `asset_in` which can be sold at the limit price of the order and the minimum amount
+	/// of `asset_out` to receive for it.
+	///
+	/// Returns `None` if no amount of the order can be sold at its limit price.
+	fn calculate_fill(
+		order: &LimitOrderOf<T>,
+		route: &[Trade<T::AssetId>],
+	) -> Result<Option<(Balance, Balance)>, DispatchError> {
+		let min_amount_out = |amount_in: Balance| -> Result<Balance, DispatchError> {
+			order
+				.limit_price
+				.checked_mul_int(amount_in)
+				.ok_or(ArithmeticError::Overflow.into())
+		};
+		// failed calculation (e.g. exceeded max in ratio) means the amount cannot be sold
+		let can_fill = |amount_in: Balance| -> Result<bool, DispatchError> {
+			let min_amount_out = min_amount_out(amount_in)?;
+			Ok(T::Router::calculate_sell_trade_amounts(route, amount_in)
+				.ok()
+				.and_then(|amounts| amounts.last().map(|amounts| amounts.amount_out))
+				.is_some_and(|amount_out| amount_out >= min_amount_out))
+		};
+
+		if can_fill(order.amount_in)? {
+			return Ok(Some((order.amount_in, min_amount_out(order.amount_in)?)));
+		}
+
+		if !order.partially_fillable {
+			return Ok(None);
+		}
+
+		// remaining amount of the partially filled order has to stay above the minimum trading limit
+		let mut low = T::MinimumTradingLimit::get();
+		let mut high = order.amount_in.saturating_sub(T::MinimumTradingLimit::get());
+		if high < low || !can_fill(low)? {
+			return Ok(None);
+		}
+		if can_fill(high)? {
+			return Ok(Some((high, min_amount_out(high)?)));
+		}
+
+		for _ in 0..T::MaxIterations::get() {
+			if high.saturating_sub(low) <= 1 {
+				break;
+			}
+			let mid = low.saturating_add(high.saturating_sub(low) / 2);
+			if can_fill(mid)? {
+				low = mid;
+			} else {
+				high = mid;
+			}
+		}
+
+		Ok(Some((low, min_amount_out(low)?)))
+	}
+
+	#[transactional]
+	fn do_execute_order(
+		order_id: OrderId,
+		mut order: LimitOrderOf<T>,
+		route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		ensure!(Self::limit_price_reached(&order), Error::<T>::LimitPriceNotReached);
+
+		let (amount_in, min_amount_out) =
+			Self::calculate_fill(&order, &route)?.ok_or(Error::<T>::LimitPriceNotReached)?;
+
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&T::NamedReserveId::get(), order.asset_in, &order.owner, amount_in);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+		<ExecutionRetryAt<T>>::remove(order_id);
+
+		let balance_before = T::Currency::free_balance(order.asset_out, &order.owner);
+
+		T::Router::sell(
+			RawOrigin::Signed(order.owner.clone()).into(),
+			order.asset_in,
+			order.asset_out,
+			amount_in,
+			min_amount_out,
+			route,
+		)?;
+
+		let amount_out = T::Currency::free_balance(order.asset_out, &order.owner).saturating_sub(balance_before);
+
+		order.amount_in = order
+			.amount_in
+			.checked_sub(amount_in)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		if order.amount_in.is_zero() {
+			Self::close_order(order_id, &order)?;
+
+			Self::deposit_event(Event::Filled {
+				order_id,
+				who: order.owner,
+				amount_in,
+				amount_out,
+			});
+		} else {
+			let remaining_amount_in = order.amount_in;
+			let who = order.owner.clone();
+			<Orders<T>>::insert(order_id, order);
+
+			Self::deposit_event(Event::PartiallyFilled {
+				order_id,
+				who,
+				amount_in,
+				amount_out,
+				remaining_amount_in,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Removes the order and unreserves its remaining amount.
+	#[transactional]
+	fn remove_order(order_id: OrderId, order: &LimitOrderOf<T>) -> DispatchResult {
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&T::NamedReserveId::get(), order.asset_in, &order.owner, order.amount_in);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+		Self::close_order(order_id, order)
+	}
+
+	/// Removes the order from the storage and returns its deposit to the owner.
+	fn close_order(order_id: OrderId, order: &LimitOrderOf<T>) -> DispatchResult {
+		let remaining_to_unreserve = T::Currency::unreserve_named(
+			&T::NamedReserveId::get(),
+			T::NativeAssetId::get(),
+			&order.owner,
+			order.deposit,
+		);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+		<Orders<T>>::remove(order_id);
+		<ExecutionRetryAt<T>>::remove(order_id);
+		Self::remove_from_order_book(order_id, order);
+		<AccountOrderCount<T>>::mutate_exists(&order.owner, (order.asset_in, order.asset_out), |maybe_count| {
+			*maybe_count = maybe_count
+				.and_then(|count| count.checked_sub(1))
+				.filter(|count| *count > 0);
+		});
+
+		Ok(())
+	}
+
+	fn remove_from_order_book(order_id: OrderId, order: &LimitOrderOf<T>) {
+		<OrderBook<T>>::mutate_exists((order.asset_in, order.asset_out), |maybe_book| {
+			if let Some(book) = maybe_book {
+				book.retain(|(_, id)| *id != order_id);
+				if book.is_empty() {
+					*maybe_book = None;
+				}
+			}
+		});
+	}
+
+	/// Weight of the trade and of the trade amount calculations needed to execute an order via given route.
+	fn trade_weight(route: &[Trade<T::AssetId>]) -> Weight {
+		// full amount, both bounds of the partial fill search and the search iterations
+		let calculations = T::MaxIterations::get().saturating_add(3) as u64;
+		T::RouterWeightInfo::sell_weight(route)
+			.saturating_add(T::RouterWeightInfo::calculate_buy_trade_amounts_weight(route).saturating_mul(calculations))
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AccountOrderCount, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn cancel_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			FixedU128::from_rational(6, 10),
+			true,
+			None,
+		));
+
+		// Act
+		assert_ok!(LimitOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none());
+		assert!(!crate::OrderBook::<Test>::contains_key((HDX, DAI)));
+
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10_000_000 * ONE);
+		assert!(!AccountOrderCount::<Test>::contains_key(ALICE, (HDX, DAI)));
+
+		expect_events(vec![Event::Cancelled { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn cancel_order_should_keep_other_orders_of_the_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for who in [ALICE, BOB] {
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(who),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from_rational(6, 10),
+				true,
+				None,
+			));
+		}
+
+		// Act
+		assert_ok!(LimitOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(
+			LimitOrders::order_book((HDX, DAI)).into_inner(),
+			vec![(FixedU128::from_rational(6, 10), 1)]
+		);
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &BOB),
+			100 * ONE + ORDER_DEPOSIT
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			FixedU128::from_rational(6, 10),
+			true,
+			None,
+		));
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::cancel_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
@@ -0,0 +1,266 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AccountOrderCount, Error, Event};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn execute_order_should_fill_order_when_limit_price_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			None,
+		));
+
+		// Act
+		assert_ok!(LimitOrders::execute_order(
+			RuntimeOrigin::signed(BOB),
+			0,
+			route(HDX, DAI)
+		));
+
+		// Assert
+		let expected_amount_out = 499_500_499_500_499;
+
+		assert!(LimitOrders::orders(0).is_none());
+		assert!(LimitOrders::order_book((HDX, DAI)).is_empty());
+
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10_000_000 * ONE - 1_000 * ONE);
+		assert!(!AccountOrderCount::<Test>::contains_key(ALICE, (HDX, DAI)));
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			10_000_000 * ONE + expected_amount_out
+		);
+		assert_eq!(MockPool::reserve(HDX), 1_001_000 * ONE);
+
+		expect_events(vec![Event::Filled {
+			order_id: 0,
+			who: ALICE,
+			amount_in: 1_000 * ONE,
+			amount_out: expected_amount_out,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn execute_order_should_fill_order_when_pool_price_moves_above_limit_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(6, 10),
+			false,
+			None,
+		));
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DAI)),
+			Error::<Test>::LimitPriceNotReached
+		);
+
+		// price of HDX moves to 1.28 DAI
+		trade_with_pool(BOB, DAI, HDX, 300_000 * ONE);
+
+		// Act
+		assert_ok!(LimitOrders::execute_order(
+			RuntimeOrigin::signed(BOB),
+			0,
+			route(HDX, DAI)
+		));
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none());
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			10_000_000 * ONE + 1_277_955_271_565_495
+		);
+	});
+}
+
+#[test]
+fn execute_order_should_partially_fill_order_when_whole_amount_cannot_be_sold_at_limit_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			200_000 * ONE,
+			FixedU128::from_rational(45, 100),
+			true,
+			None,
+		));
+
+		// Act
+		assert_ok!(LimitOrders::execute_order(
+			RuntimeOrigin::signed(BOB),
+			0,
+			route(HDX, DAI)
+		));
+
+		// Assert
+		let expected_amount_in = 111_111_111_110_967_307;
+		let expected_amount_out = 49_999_999_999_941_759;
+		let remaining_amount_in = 200_000 * ONE - expected_amount_in;
+
+		assert_eq!(LimitOrders::orders(0).unwrap().amount_in, remaining_amount_in);
+		assert_eq!(
+			LimitOrders::order_book((HDX, DAI)).into_inner(),
+			vec![(FixedU128::from_rational(45, 100), 0)]
+		);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE),
+			remaining_amount_in + ORDER_DEPOSIT
+		);
+		assert_eq!(AccountOrderCount::<Test>::get(ALICE, (HDX, DAI)), 1);
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			10_000_000 * ONE + expected_amount_out
+		);
+
+		// effective price does not go below the limit price
+		assert!(FixedU128::from_rational(expected_amount_out, expected_amount_in) >= FixedU128::from_rational(45, 100));
+
+		expect_events(vec![Event::PartiallyFilled {
+			order_id: 0,
+			who: ALICE,
+			amount_in: expected_amount_in,
+			amount_out: expected_amount_out,
+			remaining_amount_in,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn execute_order_should_fail_when_order_is_not_partially_fillable_and_cannot_be_filled_completely() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			200_000 * ONE,
+			FixedU128::from_rational(45, 100),
+			false,
+			None,
+		));
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DAI)),
+			Error::<Test>::LimitPriceNotReached
+		);
+	});
+}
+
+#[test]
+fn execute_order_should_fail_when_trade_fee_prevents_execution_at_limit_price() {
+	ExtBuilder::default()
+		.with_trade_fee(sp_runtime::Permill::from_percent(30))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				1_000 * ONE,
+				FixedU128::from_rational(4, 10),
+				true,
+				None,
+			));
+
+			// Act & Assert
+			assert_noop!(
+				LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DAI)),
+				Error::<Test>::LimitPriceNotReached
+			);
+		});
+}
+
+#[test]
+fn execute_order_should_fail_when_order_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			Some(5),
+		));
+		System::set_block_number(6);
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DAI)),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn execute_order_should_fail_when_route_is_not_the_route_of_the_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			None,
+		));
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DOT)),
+			Error::<Test>::RouteMismatch
+		);
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, BoundedVec::new()),
+			Error::<Test>::RouteMismatch
+		);
+	});
+}
+
+#[test]
+fn execute_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::execute_order(RuntimeOrigin::signed(BOB), 0, route(HDX, DAI)),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
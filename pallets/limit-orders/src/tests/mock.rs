@@ -0,0 +1,393 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as limit_orders;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, Everything, Nothing},
+	BoundedVec,
+};
+use frame_system::ensure_signed;
+use hydradx_traits::pools::SpotPriceProvider;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
+use orml_tokens::AccountData;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, DispatchResultWithPostInfo, FixedPointNumber, FixedU128, Permill,
+};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const POOL: AccountId = 100;
+
+pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"limitord";
+pub const ORDER_DEPOSIT: Balance = ONE;
+pub const MAX_ORDER_LIFETIME: u64 = 100;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	 {
+		 System: frame_system,
+		 Tokens: orml_tokens,
+		 LimitOrders: limit_orders,
+	 }
+);
+
+thread_local! {
+	pub static TRADE_FEE: RefCell<Permill> = const { RefCell::new(Permill::zero()) };
+}
+
+parameter_types! {
+	pub const MinimumTradingLimit: Balance = ONE;
+	pub const NamedReserveId: NamedReserveIdentifier = NAMED_RESERVE_ID;
+	pub const OrderDeposit: Balance = ORDER_DEPOSIT;
+	pub const NativeAssetId: AssetId = HDX;
+}
+
+impl limit_orders::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type SpotPriceProvider = MockPool;
+	type Router = MockPool;
+	type MinimumTradingLimit = MinimumTradingLimit;
+	type MaxOrdersPerPair = ConstU32<10>;
+	type MaxOrdersPerAccountPerPair = ConstU32<5>;
+	type MaxOrderLifetime = ConstU64<MAX_ORDER_LIFETIME>;
+	type OrderDeposit = OrderDeposit;
+	type NativeAssetId = NativeAssetId;
+	type MaxIterations = ConstU32<40>;
+	type ExecutionRetryDelay = ConstU64<10>;
+	type NamedReserveId = NamedReserveId;
+	type RouterWeightInfo = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+/// Constant product pool of all assets held by the `POOL` account, used both as the spot price provider and
+/// as the router.
+pub struct MockPool;
+
+impl MockPool {
+	pub fn reserve(asset: AssetId) -> Balance {
+		Tokens::free_balance(asset, &POOL)
+	}
+
+	fn calculate_out_given_in(
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, DispatchError> {
+		let reserve_in = Self::reserve(asset_in);
+		let reserve_out = Self::reserve(asset_out);
+		if reserve_in == 0 || reserve_out == 0 {
+			return Err(DispatchError::Other("PoolNotFound"));
+		}
+		let amount_out = reserve_out
+			.checked_mul(amount_in)
+			.and_then(|v| v.checked_div(reserve_in.checked_add(amount_in)?))
+			.ok_or(DispatchError::Other("MathError"))?;
+		let fee = TRADE_FEE.with(|v| v.borrow().mul_floor(amount_out));
+		Ok(amount_out - fee)
+	}
+}
+
+impl SpotPriceProvider<AssetId> for MockPool {
+	type Price = FixedU128;
+
+	fn pair_exists(asset_a: AssetId, asset_b: AssetId) -> bool {
+		Self::reserve(asset_a) > 0 && Self::reserve(asset_b) > 0
+	}
+
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		FixedU128::checked_from_rational(Self::reserve(asset_a), Self::reserve(asset_b))
+	}
+}
+
+impl RouteProvider<AssetId> for MockPool {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockPool {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let amount_out = Self::calculate_out_given_in(asset_in, asset_out, amount_in)?;
+		if amount_out < min_amount_out {
+			return Err(DispatchError::Other("TradingLimitReached"));
+		}
+		Tokens::transfer(asset_in, &who, &POOL, amount_in)?;
+		Tokens::transfer(asset_out, &POOL, &who, amount_out)
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn sell_percentage(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_percentage: Permill,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy_to_balance(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_target_balance: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn sell_split(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+		_routes: Vec<(Permill, Vec<Trade<AssetId>>)>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy_split(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_routes: Vec<(Permill, Vec<Trade<AssetId>>)>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		route: &[Trade<AssetId>],
+		amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		let trade = route.first().ok_or(DispatchError::Other("RouteNotFound"))?;
+		let amount_out = Self::calculate_out_given_in(trade.asset_in, trade.asset_out, amount_in)?;
+		Ok(vec![AmountInAndOut { amount_in, amount_out }])
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	trade_fee: Permill,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 10_000_000 * ONE),
+				(ALICE, DAI, 10_000_000 * ONE),
+				(BOB, HDX, 10_000_000 * ONE),
+				(BOB, DAI, 10_000_000 * ONE),
+				// pool with HDX price of 0.5 DAI
+				(POOL, HDX, 1_000_000 * ONE),
+				(POOL, DAI, 500_000 * ONE),
+			],
+			trade_fee: Permill::zero(),
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn with_trade_fee(mut self, fee: Permill) -> Self {
+		self.trade_fee = fee;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		TRADE_FEE.with(|v| {
+			*v.borrow_mut() = self.trade_fee;
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+/// Moves the pool price by trading `amount_in` of `asset_in` directly with the pool.
+pub fn trade_with_pool(who: AccountId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance) {
+	<MockPool as RouterT<_, _, _, _, _>>::sell(
+		RuntimeOrigin::signed(who),
+		asset_in,
+		asset_out,
+		amount_in,
+		0,
+		MockPool::get_route(AssetPair::new(asset_in, asset_out)),
+	)
+	.unwrap();
+}
+
+/// Route of the asset pair provided by the router, as passed to `execute_order`.
+pub fn route(
+	asset_in: AssetId,
+	asset_out: AssetId,
+) -> BoundedVec<Trade<AssetId>, ConstU32<{ limit_orders::MAX_NUMBER_OF_TRADES }>> {
+	MockPool::get_route(AssetPair::new(asset_in, asset_out))
+		.try_into()
+		.unwrap()
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
@@ -0,0 +1,211 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::weights::WeightInfo;
+use crate::{Error, Event, ExecutionRetryAt, ProcessingCursor};
+use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn on_idle_should_execute_orders_whose_limit_price_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			None,
+		));
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(6, 10),
+			false,
+			None,
+		));
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none());
+		assert!(LimitOrders::orders(1).is_some());
+		assert_eq!(
+			LimitOrders::order_book((HDX, DAI)).into_inner(),
+			vec![(FixedU128::from_rational(6, 10), 1)]
+		);
+
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			10_000_000 * ONE + 499_500_499_500_499
+		);
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &BOB),
+			1_000 * ONE + ORDER_DEPOSIT
+		);
+
+		expect_events(vec![Event::Filled {
+			order_id: 0,
+			who: ALICE,
+			amount_in: 1_000 * ONE,
+			amount_out: 499_500_499_500_499,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(6, 10),
+			false,
+			Some(3),
+		));
+
+		// Act
+		LimitOrders::on_idle(3, Weight::MAX);
+		assert!(LimitOrders::orders(0).is_some());
+
+		LimitOrders::on_idle(4, Weight::MAX);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none());
+		assert!(!crate::OrderBook::<Test>::contains_key((HDX, DAI)));
+
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10_000_000 * ONE);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_keep_order_when_spot_price_is_reached_but_trade_cannot_be_executed_at_limit_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(5, 10),
+			false,
+			None,
+		));
+		let events_before = System::events().len();
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE),
+			1_000 * ONE + ORDER_DEPOSIT
+		);
+		assert_eq!(System::events().len(), events_before);
+	});
+}
+
+#[test]
+fn on_idle_should_continue_with_next_order_book_in_next_block_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			None,
+		));
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			1_000 * ONE,
+			FixedU128::from_rational(15, 10),
+			false,
+			None,
+		));
+		// enough to process only one of the order books
+		let weight = <() as WeightInfo>::process_order_book(1).saturating_add(<() as WeightInfo>::execute_order());
+
+		// Act
+		LimitOrders::on_idle(1, weight);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none() ^ LimitOrders::orders(1).is_none());
+		assert!(ProcessingCursor::<Test>::get().is_some());
+
+		// Act
+		LimitOrders::on_idle(2, weight);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_none());
+		assert!(LimitOrders::orders(1).is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_not_retry_failed_order_before_retry_delay_passes() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			1_000 * ONE,
+			FixedU128::from_rational(4, 10),
+			false,
+			None,
+		));
+		Tokens::unreserve_named(&NAMED_RESERVE_ID, HDX, &ALICE, 1_000 * ONE);
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		expect_events(vec![Event::ExecutionFailed {
+			order_id: 0,
+			error: Error::<Test>::InsufficientReservedAmount.into(),
+		}
+		.into()]);
+		assert_eq!(ExecutionRetryAt::<Test>::get(0), Some(11));
+
+		let events_before = System::events().len();
+		LimitOrders::on_idle(10, Weight::MAX);
+		assert_eq!(System::events().len(), events_before);
+
+		LimitOrders::on_idle(11, Weight::MAX);
+		assert_eq!(System::events().len(), events_before + 1);
+		assert_eq!(ExecutionRetryAt::<Test>::get(0), Some(21));
+	});
+}
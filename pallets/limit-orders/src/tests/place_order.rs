@@ -0,0 +1,390 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AccountOrderCount, Error, Event, LimitOrder};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn place_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			FixedU128::from_rational(6, 10),
+			true,
+			Some(10),
+		));
+
+		// Assert
+		assert_eq!(
+			LimitOrders::orders(0),
+			Some(LimitOrder {
+				owner: ALICE,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: 100 * ONE,
+				limit_price: FixedU128::from_rational(6, 10),
+				partially_fillable: true,
+				expires_at: 10,
+				deposit: ORDER_DEPOSIT,
+			})
+		);
+		assert_eq!(LimitOrders::next_order_id(), 1);
+		assert_eq!(
+			LimitOrders::order_book((HDX, DAI)).into_inner(),
+			vec![(FixedU128::from_rational(6, 10), 0)]
+		);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE + ORDER_DEPOSIT
+		);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			10_000_000 * ONE - 100 * ONE - ORDER_DEPOSIT
+		);
+		assert_eq!(AccountOrderCount::<Test>::get(ALICE, (HDX, DAI)), 1);
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			owner: ALICE,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 100 * ONE,
+			limit_price: FixedU128::from_rational(6, 10),
+			partially_fillable: true,
+			expires_at: 10,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_reserve_deposit_in_native_asset_when_selling_other_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			100 * ONE,
+			FixedU128::from(2),
+			true,
+			None,
+		));
+
+		// Assert
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, DAI, &ALICE),
+			100 * ONE
+		);
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE),
+			ORDER_DEPOSIT
+		);
+	});
+}
+
+#[test]
+fn place_order_should_expire_order_after_max_lifetime_when_expiry_is_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			FixedU128::from(1),
+			true,
+			None,
+		));
+
+		// Assert
+		assert_eq!(LimitOrders::orders(0).unwrap().expires_at, 10 + MAX_ORDER_LIFETIME);
+	});
+}
+
+#[test]
+fn place_order_should_keep_order_book_sorted_by_limit_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		for (who, price) in [(ALICE, 7), (BOB, 6), (ALICE, 8), (BOB, 7)] {
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(who),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from_rational(price, 10),
+				true,
+				None,
+			));
+		}
+
+		// Assert
+		assert_eq!(
+			LimitOrders::order_book((HDX, DAI)).into_inner(),
+			vec![
+				(FixedU128::from_rational(6, 10), 1),
+				(FixedU128::from_rational(7, 10), 0),
+				(FixedU128::from_rational(7, 10), 3),
+				(FixedU128::from_rational(8, 10), 2),
+			]
+		);
+		assert!(LimitOrders::order_book((DAI, HDX)).is_empty());
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				HDX,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				None
+			),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_amount_is_below_minimum_trading_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				ONE - 1,
+				FixedU128::from(1),
+				true,
+				None
+			),
+			Error::<Test>::OrderAmountTooSmall
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_limit_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from(0),
+				true,
+				None
+			),
+			Error::<Test>::InvalidLimitPrice
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_expiry_is_in_the_past() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				Some(9)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_expiry_is_after_max_lifetime() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				Some(10 + MAX_ORDER_LIFETIME + 1)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				DAI,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				None
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_native_balance_is_insufficient_for_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, DAI, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LimitOrders::place_order(
+					RuntimeOrigin::signed(CHARLIE),
+					DAI,
+					HDX,
+					100 * ONE,
+					FixedU128::from(2),
+					true,
+					None
+				),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn place_order_should_fail_when_account_has_max_orders_of_the_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for _ in 0..5 {
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				None,
+			));
+		}
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				None
+			),
+			Error::<Test>::TooManyOrders
+		);
+
+		// other accounts and other pairs are not limited by it
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			100 * ONE,
+			FixedU128::from(1),
+			true,
+			None,
+		));
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			100 * ONE,
+			FixedU128::from(1),
+			true,
+			None,
+		));
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_order_book_is_full() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, 10_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			for who in [ALICE, BOB] {
+				for _ in 0..5 {
+					assert_ok!(LimitOrders::place_order(
+						RuntimeOrigin::signed(who),
+						HDX,
+						DAI,
+						100 * ONE,
+						FixedU128::from(1),
+						true,
+						None,
+					));
+				}
+			}
+
+			// Act & Assert
+			assert_noop!(
+				LimitOrders::place_order(
+					RuntimeOrigin::signed(CHARLIE),
+					HDX,
+					DAI,
+					100 * ONE,
+					FixedU128::from(1),
+					true,
+					None
+				),
+				Error::<Test>::OrderBookFull
+			);
+
+			// the opposite direction has its own order book
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				100 * ONE,
+				FixedU128::from(1),
+				true,
+				None,
+			));
+		});
+}
@@ -0,0 +1,29 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+pub type Balance = u128;
+pub type OrderId = u32;
+pub type NamedReserveIdentifier = [u8; 8];
+
+#[derive(Encode, Decode, RuntimeDebug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct LimitOrder<AccountId, AssetId, BlockNumber> {
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	/// Remaining amount of asset in to sell
+	pub amount_in: Balance,
+	/// Minimum price of asset in denominated in asset out
+	pub limit_price: FixedU128,
+	pub partially_fillable: bool,
+	/// Block after which the order is no longer executed
+	pub expires_at: BlockNumber,
+	/// Amount of native asset reserved for the order
+	pub deposit: Balance,
+}
+
+impl<AccountId, AssetId, BlockNumber: PartialOrd> LimitOrder<AccountId, AssetId, BlockNumber> {
+	pub fn is_expired(&self, current_block: BlockNumber) -> bool {
+		current_block > self.expires_at
+	}
+}
@@ -0,0 +1,91 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_limit_orders.
+pub trait WeightInfo {
+	fn place_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn execute_order() -> Weight;
+	fn process_order_book(n: u32) -> Weight;
+}
+
+/// Estimated weights for pallet_limit_orders - NOT produced by the benchmark CLI, based on the storage accesses of each call.
+impl WeightInfo for () {
+	/// Storage: `LimitOrders::NextOrderId` (r:1 w:1)
+	/// Proof: `LimitOrders::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:2 w:2)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:0 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		Weight::from_parts(78_412_000, 5492)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:2 w:2)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:0 w:1)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		Weight::from_parts(72_986_000, 5492)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:2 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:2 w:2)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:3 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:0 w:1)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn execute_order() -> Weight {
+		Weight::from_parts(91_515_000, 6156)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:0)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:100 w:0)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:2 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:100 w:0)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn process_order_book(n: u32, ) -> Weight {
+		Weight::from_parts(12_871_442, 6110)
+			.saturating_add(Weight::from_parts(9_842_315, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2585).saturating_mul(n.into()))
+	}
+}
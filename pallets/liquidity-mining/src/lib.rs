@@ -2000,6 +2000,18 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 	fn get_global_farm_id(deposit_id: DepositId, yield_farm_id: YieldFarmId) -> Option<u32> {
 		Self::get_global_farm_id(deposit_id, yield_farm_id)
 	}
+
+	fn get_farm_entries(deposit_id: DepositId) -> Vec<(GlobalFarmId, YieldFarmId)> {
+		Self::deposit(deposit_id)
+			.map(|deposit| {
+				deposit
+					.yield_farm_entries
+					.iter()
+					.map(|entry| (entry.global_farm_id, entry.yield_farm_id))
+					.collect()
+			})
+			.unwrap_or_default()
+	}
 }
 
 impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Inspect<T::AccountId> for Pallet<T, I> {
@@ -74,7 +74,7 @@ use pallet_omnipool::{types::Position as OmniPosition, NFTCollectionIdOf};
 use primitive_types::U256;
 use primitives::{Balance, ItemId as DepositId};
 use sp_runtime::{ArithmeticError, FixedU128, Perquintill};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 pub use weights::WeightInfo;
 
 pub const MAX_ASSETS_IN_POOL: u32 = pallet_stableswap::MAX_ASSETS_IN_POOL;
@@ -926,33 +926,9 @@ pub mod pallet {
 			farm_entries: BoundedVec<(GlobalFarmId, YieldFarmId), T::MaxFarmEntriesPerDeposit>,
 			position_id: T::PositionItemId,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			ensure!(!farm_entries.is_empty(), Error::<T>::NoFarmEntriesSpecified);
-
-			let (global_farm_id, yield_farm_id) = farm_entries.first().ok_or(Error::<T>::NoFarmEntriesSpecified)?;
-			let (deposit_id, lp_position) =
-				Self::do_deposit_shares(who.clone(), *global_farm_id, *yield_farm_id, position_id)?;
-
-			for (global_farm_id, yield_farm_id) in farm_entries.into_iter().skip(1) {
-				T::LiquidityMiningHandler::redeposit_lp_shares(
-					global_farm_id,
-					yield_farm_id,
-					deposit_id,
-					|_, _, _| Self::get_position_value_in_hub_asset(&lp_position),
-				)?;
-
-				Self::deposit_event(Event::SharesRedeposited {
-					global_farm_id,
-					yield_farm_id,
-					deposit_id,
-					asset_id: lp_position.asset_id,
-					who: who.clone(),
-					shares_amount: lp_position.shares,
-					position_id,
-				});
-			}
+			let who = ensure_signed(origin)?;
 
-			Ok(())
+			Self::do_join_farms(who, &farm_entries, position_id)
 		}
 
 		/// This function allows user to add liquidity then use that shares to join multiple farms.
@@ -1061,6 +1037,73 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Split omnipool position locked in the deposit into two positions and join the same farms with both.
+		///
+		/// LP shares are withdrawn from all yield farms of the deposit, claiming the rewards, and the deposit is
+		/// destroyed. Omnipool position is split by `split_position` of the omnipool and both resulting positions
+		/// are deposited to the same farms as new deposits. Loyalty of the deposit is not kept, rewards not
+		/// claimable because of exiting early are returned to the global farms.
+		///
+		/// Fails if any of the farms doesn't accept new deposits, e.g. stopped yield farm, or if any of the
+		/// resulting positions is below farm's `min_deposit`.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the deposit.
+		/// - `deposit_id`: id of the deposit to split.
+		/// - `shares`: amount of shares moved to the new position. Must be less than position's shares.
+		///
+		/// Emits events of `exit_farms`, omnipool's `split_position` and `join_farms` for both positions.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::exit_farms(T::MaxFarmEntriesPerDeposit::get())
+			.saturating_add(<<T as pallet_omnipool::Config>::WeightInfo as pallet_omnipool::weights::WeightInfo>::split_position())
+			.saturating_add(<T as Config>::WeightInfo::join_farms(T::MaxFarmEntriesPerDeposit::get()).saturating_mul(2))
+			.saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn split_deposit(origin: OriginFor<T>, deposit_id: DepositId, shares: Balance) -> DispatchResult {
+			let (who, position_id, farm_entries) = Self::exit_all_farms(origin.clone(), deposit_id)?;
+
+			let new_position_id = OmnipoolPallet::<T>::do_split_position(origin, position_id, shares)?;
+
+			Self::do_join_farms(who.clone(), &farm_entries, position_id)?;
+			Self::do_join_farms(who, &farm_entries, new_position_id)
+		}
+
+		/// Merge omnipool positions locked in two deposits and join farms of `deposit_id` with the merged position.
+		///
+		/// LP shares of both deposits are withdrawn from all their yield farms, claiming the rewards, and both deposits
+		/// are destroyed. Omnipool positions are merged by `merge_positions` of the omnipool and the merged position is
+		/// deposited to the farms of `deposit_id` as a new deposit. Loyalty of the deposits is not kept, rewards not
+		/// claimable because of exiting early are returned to the global farms.
+		///
+		/// Fails if any of the farms of `deposit_id` doesn't accept new deposits, e.g. stopped yield farm.
+		///
+		/// Parameters:
+		/// - `origin`: owner of both deposits.
+		/// - `deposit_id`: id of the deposit whose farms are joined with the merged position.
+		/// - `other_deposit_id`: id of the deposit merged into `deposit_id`.
+		///
+		/// Emits events of `exit_farms` for both deposits, omnipool's `merge_positions` and `join_farms`.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::exit_farms(T::MaxFarmEntriesPerDeposit::get()).saturating_mul(2)
+			.saturating_add(<<T as pallet_omnipool::Config>::WeightInfo as pallet_omnipool::weights::WeightInfo>::merge_positions())
+			.saturating_add(<T as Config>::WeightInfo::join_farms(T::MaxFarmEntriesPerDeposit::get()))
+			.saturating_add(T::DbWeight::get().reads(2)))]
+		pub fn merge_deposits(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			other_deposit_id: DepositId,
+		) -> DispatchResult {
+			Self::ensure_nft_owner(origin.clone(), other_deposit_id)?;
+
+			let (who, position_id, farm_entries) = Self::exit_all_farms(origin.clone(), deposit_id)?;
+			let (_, other_position_id, _) = Self::exit_all_farms(origin.clone(), other_deposit_id)?;
+
+			OmnipoolPallet::<T>::do_merge_positions(origin, position_id, other_position_id)?;
+
+			Self::do_join_farms(who, &farm_entries, position_id)
+		}
 	}
 }
 
@@ -1148,6 +1191,59 @@ impl<T: Config> Pallet<T> {
 		Ok(who)
 	}
 
+	/// This function deposits omnipool position to the first farm entry and redeposits it to the rest of
+	/// farm entries.
+	fn do_join_farms(
+		who: T::AccountId,
+		farm_entries: &[(GlobalFarmId, YieldFarmId)],
+		position_id: T::PositionItemId,
+	) -> DispatchResult {
+		let (global_farm_id, yield_farm_id) = farm_entries.first().ok_or(Error::<T>::NoFarmEntriesSpecified)?;
+		let (deposit_id, lp_position) =
+			Self::do_deposit_shares(who.clone(), *global_farm_id, *yield_farm_id, position_id)?;
+
+		for (global_farm_id, yield_farm_id) in farm_entries.iter().skip(1).copied() {
+			T::LiquidityMiningHandler::redeposit_lp_shares(global_farm_id, yield_farm_id, deposit_id, |_, _, _| {
+				Self::get_position_value_in_hub_asset(&lp_position)
+			})?;
+
+			Self::deposit_event(Event::SharesRedeposited {
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				asset_id: lp_position.asset_id,
+				who: who.clone(),
+				shares_amount: lp_position.shares,
+				position_id,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// This function withdraws LP shares of the deposit from all its yield farms. Deposit is destroyed and
+	/// omnipool position is transferred back to the deposit's owner.
+	///
+	/// Returns owner of the deposit, id of the omnipool position and farm entries of the deposit.
+	#[allow(clippy::type_complexity)]
+	fn exit_all_farms(
+		origin: OriginFor<T>,
+		deposit_id: DepositId,
+	) -> Result<(T::AccountId, T::PositionItemId, Vec<(GlobalFarmId, YieldFarmId)>), DispatchError> {
+		let owner = Self::ensure_nft_owner(origin.clone(), deposit_id)?;
+
+		//NOTE: not tested - this should never fail.
+		let position_id = OmniPositionId::<T>::get(deposit_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+
+		let farm_entries = T::LiquidityMiningHandler::get_farm_entries(deposit_id);
+		for (_, yield_farm_id) in farm_entries.iter() {
+			Self::withdraw_shares(origin.clone(), deposit_id, *yield_farm_id)?;
+		}
+
+		Ok((owner, position_id, farm_entries))
+	}
+
 	#[require_transactional]
 	fn do_deposit_shares(
 		who: T::AccountId,
@@ -0,0 +1,204 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use hydradx_traits::liquidity_mining::Mutate as LiquidityMiningMutate;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn merge_deposits_should_join_farms_of_deposit_with_merged_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let gc_g_farm_id = 1;
+			let gc_y_farm_id = 3;
+			let charlie_g_farm_id = 2;
+			let charlie_y_farm_id = 4;
+			let omnipool_position_id = 2;
+			let other_omnipool_position_id = 3;
+			let deposit_id = 1;
+			let other_deposit_id = 2;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), KSM, 500 * ONE));
+			let other_position = Omnipool::positions(other_omnipool_position_id).unwrap();
+
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(gc_g_farm_id, gc_y_farm_id), (charlie_g_farm_id, charlie_y_farm_id)]
+					.try_into()
+					.unwrap(),
+				omnipool_position_id
+			));
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(gc_g_farm_id, gc_y_farm_id)].try_into().unwrap(),
+				other_omnipool_position_id
+			));
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(OmnipoolMining::merge_deposits(
+				RuntimeOrigin::signed(LP1),
+				deposit_id,
+				other_deposit_id
+			));
+
+			//Assert
+			assert!(has_event(
+				crate::Event::DepositDestroyed { who: LP1, deposit_id }.into()
+			));
+			assert!(has_event(
+				crate::Event::DepositDestroyed {
+					who: LP1,
+					deposit_id: other_deposit_id
+				}
+				.into()
+			));
+
+			let position = Omnipool::positions(omnipool_position_id).unwrap();
+			assert_eq!(position.shares, 2_000 * ONE + other_position.shares);
+			assert_eq!(Omnipool::positions(other_omnipool_position_id), None);
+
+			let merged_deposit_id = 3;
+			assert_eq!(crate::OmniPositionId::<Test>::get(deposit_id), None);
+			assert_eq!(crate::OmniPositionId::<Test>::get(other_deposit_id), None);
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(merged_deposit_id),
+				Some(omnipool_position_id)
+			);
+			assert_eq!(
+				WarehouseLM::get_farm_entries(merged_deposit_id),
+				vec![(gc_g_farm_id, gc_y_farm_id), (charlie_g_farm_id, charlie_y_farm_id)]
+			);
+
+			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &merged_deposit_id).unwrap();
+			assert_eq!(owner, LP1);
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
+			assert_eq!(owner, OmnipoolMining::account_id());
+
+			//Merged position's NFT should be burned.
+			let owner: Option<AccountId> = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &other_omnipool_position_id);
+			assert_eq!(owner, None);
+		});
+}
+
+#[test]
+fn merge_deposits_should_fail_when_origin_is_not_owner_of_both_deposits() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let omnipool_position_id = 2;
+			let deposit_id = 1;
+			let other_deposit_id = 2;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 500 * ONE));
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(1, 3)].try_into().unwrap(),
+				omnipool_position_id
+			));
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(ALICE),
+				vec![(1, 3)].try_into().unwrap(),
+				3
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::merge_deposits(RuntimeOrigin::signed(LP1), deposit_id, other_deposit_id),
+				crate::Error::<Test>::Forbidden
+			);
+		});
+}
@@ -37,9 +37,11 @@ pub mod create_yield_farm;
 pub mod deposit_shares;
 pub mod exit_farms;
 pub mod join_farms;
+pub mod merge_deposits;
 pub mod mock;
 pub mod redeposit_shares;
 pub mod resume_yield_farm;
+pub mod split_deposit;
 pub mod stop_yield_farm;
 pub mod terminate_global_farm;
 pub mod terminate_yield_farm;
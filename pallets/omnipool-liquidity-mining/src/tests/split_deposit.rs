@@ -0,0 +1,255 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use hydradx_traits::liquidity_mining::Mutate as LiquidityMiningMutate;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn split_deposit_should_join_same_farms_with_both_positions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let gc_g_farm_id = 1;
+			let gc_y_farm_id = 3;
+			let charlie_g_farm_id = 2;
+			let charlie_y_farm_id = 4;
+			let omnipool_position_id = 2;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(gc_g_farm_id, gc_y_farm_id), (charlie_g_farm_id, charlie_y_farm_id)]
+					.try_into()
+					.unwrap(),
+				omnipool_position_id
+			));
+
+			set_block_number(1_000);
+
+			let new_position_id = Omnipool::next_position_id();
+
+			//Act
+			assert_ok!(OmnipoolMining::split_deposit(
+				RuntimeOrigin::signed(LP1),
+				deposit_id,
+				500 * ONE
+			));
+
+			//Assert
+			assert!(has_event(
+				crate::Event::DepositDestroyed { who: LP1, deposit_id }.into()
+			));
+
+			let position = Omnipool::positions(omnipool_position_id).unwrap();
+			let new_position = Omnipool::positions(new_position_id).unwrap();
+			assert_eq!(position.shares, 1_500 * ONE);
+			assert_eq!(new_position.shares, 500 * ONE);
+
+			let remaining_deposit_id = 2;
+			let new_deposit_id = 3;
+			assert_eq!(crate::OmniPositionId::<Test>::get(deposit_id), None);
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(remaining_deposit_id),
+				Some(omnipool_position_id)
+			);
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(new_deposit_id),
+				Some(new_position_id)
+			);
+
+			for d_id in [remaining_deposit_id, new_deposit_id] {
+				assert_eq!(
+					WarehouseLM::get_farm_entries(d_id),
+					vec![(gc_g_farm_id, gc_y_farm_id), (charlie_g_farm_id, charlie_y_farm_id)]
+				);
+
+				let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &d_id).unwrap();
+				assert_eq!(owner, LP1);
+			}
+
+			//Omnipool's NFTs should be locked in the liquidity mining
+			for p_id in [omnipool_position_id, new_position_id] {
+				let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &p_id).unwrap();
+				assert_eq!(owner, OmnipoolMining::account_id());
+			}
+
+			//Old deposit's NFT should be burned.
+			let owner: Option<AccountId> = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id);
+			assert_eq!(owner, None);
+		});
+}
+
+#[test]
+fn split_deposit_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let omnipool_position_id = 2;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(1, 3), (2, 4)].try_into().unwrap(),
+				omnipool_position_id
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::split_deposit(RuntimeOrigin::signed(ALICE), deposit_id, 500 * ONE),
+				crate::Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn split_deposit_should_fail_when_shares_are_not_less_than_position_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let omnipool_position_id = 2;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::join_farms(
+				RuntimeOrigin::signed(LP1),
+				vec![(1, 3), (2, 4)].try_into().unwrap(),
+				omnipool_position_id
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::split_deposit(RuntimeOrigin::signed(LP1), deposit_id, 2_000 * ONE),
+				pallet_omnipool::Error::<Test>::InsufficientShares
+			);
+		});
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydra_dx_math::omnipool::types::PositionValuation;
+pub use pallet_omnipool::types::{AssetDetails, AssetReserveState, Position, Tradability};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query omnipool assets and positions.
+	pub trait OmnipoolApi<AssetId, Balance, PositionId> where
+		AssetId: Codec,
+		Balance: Codec,
+		PositionId: Codec,
+	{
+		/// Get details of all assets in the omnipool.
+		fn assets() -> Vec<AssetDetails<AssetId, Balance>>;
+
+		/// Get details of an asset in the omnipool. Returns `None` if the asset is not in the omnipool.
+		fn asset(asset_id: AssetId) -> Option<AssetDetails<AssetId, Balance>>;
+
+		/// Get total value locked in the omnipool, denominated in the given stable asset.
+		fn tvl(stable_asset_id: AssetId) -> Option<Balance>;
+
+		/// Get value of the stored position if all its shares were removed now.
+		fn position_value(position_id: PositionId) -> Result<PositionValuation<Balance>, DispatchError>;
+
+		/// Get value of the position if all its shares were removed now,
+		/// including the withdrawal fee and the impermanent loss compared to holding the position amount of asset.
+		fn position_valuation(position: Position<Balance, AssetId>) -> Result<PositionValuation<Balance>, DispatchError>;
+	}
+}
@@ -0,0 +1,84 @@
+use crate::types::{AssetDetails, AssetReserveState, Balance, Position};
+use crate::{Assets, Config, Error, Pallet, Positions};
+use hydra_dx_math::omnipool::types::PositionValuation;
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::Get;
+use sp_runtime::{ArithmeticError, DispatchError};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Returns details of all assets in the omnipool.
+	pub fn assets_details() -> Vec<AssetDetails<T::AssetId, Balance>> {
+		let total_hub_reserve = Self::total_hub_reserve();
+
+		Assets::<T>::iter_keys()
+			.filter_map(|asset_id| {
+				let state = Self::load_asset_state(asset_id).ok()?;
+				Some(Self::asset_details_from_state(asset_id, state, total_hub_reserve))
+			})
+			.collect()
+	}
+
+	/// Returns details of an asset in the omnipool or `None` if the asset is not in the omnipool.
+	pub fn asset_details(asset_id: T::AssetId) -> Option<AssetDetails<T::AssetId, Balance>> {
+		let state = Self::load_asset_state(asset_id).ok()?;
+
+		Some(Self::asset_details_from_state(
+			asset_id,
+			state,
+			Self::total_hub_reserve(),
+		))
+	}
+
+	/// Returns total value locked in the omnipool, denominated in `stable_asset_id`.
+	pub fn tvl(stable_asset_id: T::AssetId) -> Option<Balance> {
+		let stable_asset = Self::load_asset_state(stable_asset_id).ok()?;
+
+		hydra_dx_math::omnipool::calculate_tvl(
+			Self::total_hub_reserve(),
+			(stable_asset.reserve, stable_asset.hub_reserve),
+		)
+	}
+
+	/// Returns the value of a stored position if all its shares were removed at current state.
+	pub fn position_value(position_id: T::PositionItemId) -> Result<PositionValuation<Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		Self::position_valuation(&position)
+	}
+
+	/// Returns the value of a position if all its shares were removed at current state,
+	/// including the withdrawal fee and the impermanent loss compared to holding the position amount of asset.
+	pub fn position_valuation(
+		position: &Position<Balance, T::AssetId>,
+	) -> Result<PositionValuation<Balance>, DispatchError> {
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+		let withdrawal_fee = Self::withdrawal_fee(position.asset_id, &asset_state)?;
+
+		hydra_dx_math::omnipool::calculate_position_valuation(&(&asset_state).into(), &position.into(), withdrawal_fee)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	fn total_hub_reserve() -> Balance {
+		T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
+	}
+
+	fn asset_details_from_state(
+		asset_id: T::AssetId,
+		state: AssetReserveState<Balance>,
+		total_hub_reserve: Balance,
+	) -> AssetDetails<T::AssetId, Balance> {
+		// Cap difference can not be calculated when the cap is zero, no more liquidity can be added in that case
+		let cap_difference =
+			hydra_dx_math::omnipool::calculate_cap_difference(&(&state).into(), state.cap, total_hub_reserve)
+				.unwrap_or_default();
+		let hub_asset_price = hydra_dx_math::omnipool::calculate_lrna_spot_price(&(&state).into(), None);
+
+		AssetDetails {
+			asset_id,
+			state,
+			cap_difference,
+			hub_asset_price,
+		}
+	}
+}
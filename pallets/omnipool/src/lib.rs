@@ -81,11 +81,12 @@ use crate::traits::ShouldAllow;
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::require_transactional;
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::DefensiveOption;
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ema::EmaPrice;
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate};
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, SlipFee, SlipFeeState};
 use hydradx_traits::fee::GetDynamicFee;
 use hydradx_traits::registry::Inspect as RegistryInspect;
 use orml_traits::MultiCurrency;
@@ -101,6 +102,7 @@ use sp_std::prelude::*;
 #[cfg(test)]
 mod tests;
 
+pub mod api;
 pub mod migration;
 pub mod provider;
 pub mod router_execution;
@@ -108,8 +110,10 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, Tradability};
+use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, HubReserveBlockState, Position, SlipFeeParams, Tradability,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -261,6 +265,16 @@ pub mod pallet {
 	/// Position ids sequencer
 	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
 
+	#[pallet::storage]
+	/// Slip fee parameters of assets. Slip fee is charged only for assets with parameters set.
+	#[pallet::getter(fn slip_fee_params)]
+	pub(super) type SlipFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, SlipFeeParams>;
+
+	#[pallet::storage]
+	/// Hub reserve changes caused by trades of assets with slip fee, in the last block the asset was traded.
+	pub(super) type HubReserveBlockStates<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, HubReserveBlockState<BlockNumberFor<T>>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -362,6 +376,25 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// Asset's slip fee parameters have been updated.
+		AssetSlipFeeUpdated {
+			asset_id: T::AssetId,
+			params: Option<SlipFeeParams>,
+		},
+
+		/// LP Position was split and new position created from the split shares.
+		PositionSplit {
+			position_id: T::PositionItemId,
+			new_position_id: T::PositionItemId,
+			shares: Balance,
+		},
+
+		/// LP Position was merged into another position.
+		PositionsMerged {
+			position_id: T::PositionItemId,
+			merged_position_id: T::PositionItemId,
+		},
 	}
 
 	#[pallet::error]
@@ -431,6 +464,10 @@ pub mod pallet {
 		SlippageLimit,
 		/// Extra protocol fee has not been consumed.
 		ProtocolFeeNotConsumed,
+		/// Positions of different assets cannot be merged.
+		PositionsAssetMismatch,
+		/// Position cannot be merged with itself.
+		SamePositionMerge,
 	}
 
 	#[pallet::call]
@@ -715,17 +752,7 @@ pub mod pallet {
 				)
 				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 			}
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
-
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
+			let withdrawal_fee = Self::withdrawal_fee(asset_id, &asset_state)?;
 
 			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
 				&(&asset_state).into(),
@@ -940,13 +967,14 @@ pub mod pallet {
 			let (asset_fee, _) = T::Fee::get_and_store((asset_out, asset_out_state.reserve));
 			let (_, protocol_fee) = T::Fee::get_and_store((asset_in, asset_in_state.reserve));
 
-			let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
+			let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes_with_slip_fee(
 				&(&asset_in_state).into(),
 				&(&asset_out_state).into(),
 				amount,
 				asset_fee,
 				protocol_fee,
 				T::BurnProtocolFee::get(),
+				&Self::slip_fee(asset_in, &asset_in_state, asset_out, &asset_out_state),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -988,9 +1016,11 @@ pub mod pallet {
 				"Extra new minted hub amount for asset in is not zero!"
 			);
 			debug_assert_eq!(
-				(*state_changes.asset_in.delta_hub_reserve).saturating_sub(state_changes.fee.protocol_fee),
+				(*state_changes.asset_in.delta_hub_reserve)
+					.saturating_sub(state_changes.fee.protocol_fee)
+					.saturating_sub(state_changes.fee.slip_fee),
 				*state_changes.asset_out.delta_hub_reserve,
-				"Burned Hub asset amount of IN subpool - protocol fee != hub asset amount minted of OUT subpool"
+				"Burned Hub asset amount of IN subpool - protocol fee - slip fee != hub asset amount minted of OUT subpool"
 			);
 
 			T::Currency::transfer(
@@ -1038,6 +1068,9 @@ pub mod pallet {
 				false,
 			);
 
+			Self::record_hub_reserve_change(asset_in, &asset_in_state, &state_changes.asset_in.delta_hub_reserve);
+			Self::record_hub_reserve_change(asset_out, &asset_out_state, &state_changes.asset_out.delta_hub_reserve);
+
 			Self::set_asset_state(asset_in, new_asset_in_state);
 			Self::set_asset_state(asset_out, new_asset_out_state);
 
@@ -1063,6 +1096,7 @@ pub mod pallet {
 			let burned_protocol_fee = state_changes
 				.fee
 				.protocol_fee
+				.saturating_add(state_changes.fee.slip_fee)
 				.saturating_sub(state_changes.extra_protocol_fee_amount);
 			let mut fees = vec![];
 			if burned_protocol_fee > 0 {
@@ -1182,13 +1216,14 @@ pub mod pallet {
 
 			let (asset_fee, _) = T::Fee::get_and_store((asset_out, asset_out_state.reserve));
 			let (_, protocol_fee) = T::Fee::get_and_store((asset_in, asset_in_state.reserve));
-			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes_with_slip_fee(
 				&(&asset_in_state).into(),
 				&(&asset_out_state).into(),
 				amount,
 				asset_fee,
 				protocol_fee,
 				T::BurnProtocolFee::get(),
+				&Self::slip_fee(asset_in, &asset_in_state, asset_out, &asset_out_state),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -1232,9 +1267,11 @@ pub mod pallet {
 				"Extra new minted hub amount for asset in is not zero!"
 			);
 			debug_assert_eq!(
-				(*state_changes.asset_in.delta_hub_reserve).saturating_sub(state_changes.fee.protocol_fee),
+				(*state_changes.asset_in.delta_hub_reserve)
+					.saturating_sub(state_changes.fee.protocol_fee)
+					.saturating_sub(state_changes.fee.slip_fee),
 				*state_changes.asset_out.delta_hub_reserve,
-				"Burned Hub asset amount of IN subpool - protocol fee != hub asset amount minted of OUT subpool"
+				"Burned Hub asset amount of IN subpool - protocol fee - slip fee != hub asset amount minted of OUT subpool"
 			);
 
 			T::Currency::transfer(
@@ -1282,6 +1319,9 @@ pub mod pallet {
 				false,
 			);
 
+			Self::record_hub_reserve_change(asset_in, &asset_in_state, &state_changes.asset_in.delta_hub_reserve);
+			Self::record_hub_reserve_change(asset_out, &asset_out_state, &state_changes.asset_out.delta_hub_reserve);
+
 			Self::set_asset_state(asset_in, new_asset_in_state);
 			Self::set_asset_state(asset_out, new_asset_out_state);
 
@@ -1306,6 +1346,7 @@ pub mod pallet {
 			let burned_protocol_fee = state_changes
 				.fee
 				.protocol_fee
+				.saturating_add(state_changes.fee.slip_fee)
 				.saturating_sub(state_changes.extra_protocol_fee_amount);
 			let mut fees = vec![];
 			if burned_protocol_fee > 0 {
@@ -1579,6 +1620,8 @@ pub mod pallet {
 			T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), asset_state.hub_reserve)?;
 			T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, asset_state.reserve)?;
 			<Assets<T>>::remove(asset_id);
+			<SlipFees<T>>::remove(asset_id);
+			<HubReserveBlockStates<T>>::remove(asset_id);
 			Self::deposit_event(Event::TokenRemoved {
 				asset_id,
 				amount: asset_state.reserve,
@@ -1586,6 +1629,105 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Split LP position into two positions.
+		///
+		/// New position is created with `shares` of the position and amount proportional to the shares.
+		/// Both positions keep the price of the original position.
+		///
+		/// Only owner of position can perform this action. Positions deposited in liquidity mining
+		/// are owned by the liquidity mining pallet, so they are rejected with `Forbidden`. Such positions
+		/// are split by `split_deposit` of the omnipool liquidity mining pallet.
+		///
+		/// Pool state is not changed, so omnipool hooks are not called.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position to split.
+		/// - `shares`: Amount of shares moved to the new position. Must be less than position's shares.
+		///
+		/// Emits `PositionUpdated`, `PositionCreated` and `PositionSplit` events when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let _ = Self::do_split_position(origin, position_id, shares)?;
+
+			Ok(())
+		}
+
+		/// Merge two LP positions of the same asset.
+		///
+		/// Amounts and shares of `other_position_id` are added to `position_id`, `other_position_id` is destroyed
+		/// and its NFT burned.
+		///
+		/// Positions with the same price keep it, e.g. positions split by `split_position`. Otherwise the merged position
+		/// gets the share-weighted average of both prices, rounded up, so removing liquidity of the merged position never
+		/// pays out more value than removing liquidity of both positions.
+		///
+		/// Only owner of both positions can perform this action. Positions deposited in liquidity mining
+		/// are owned by the liquidity mining pallet, so they are rejected with `Forbidden`. Such positions
+		/// are merged by `merge_deposits` of the omnipool liquidity mining pallet.
+		///
+		/// Pool state is not changed, so omnipool hooks are not called.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is kept.
+		/// - `other_position_id`: The identifier of position merged into `position_id`.
+		///
+		/// Emits `PositionUpdated`, `PositionDestroyed` and `PositionsMerged` events when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			Self::do_merge_positions(origin, position_id, other_position_id)?;
+
+			Ok(())
+		}
+
+		/// Update asset's slip fee parameters.
+		///
+		/// Slip fee is charged in `sell` and `buy` in addition to asset and protocol fees, including trades where
+		/// hub asset is sold for the asset. Its rate is proportional to the net change of asset's hub reserve
+		/// caused by trades in current block. Collected slip fee is processed by `consume_protocol_fee` hook.
+		///
+		/// Only `AuthorityOrigin` can perform this call.
+		///
+		/// Parameters:
+		/// - `asset_id`: asset id
+		/// - `params`: new slip fee parameters. `None` disables slip fee for the asset.
+		///
+		/// Emits `AssetSlipFeeUpdated` event when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_slip_fee())]
+		#[transactional]
+		pub fn set_asset_slip_fee(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			params: Option<SlipFeeParams>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			match params {
+				Some(params) => SlipFees::<T>::insert(asset_id, params),
+				None => {
+					SlipFees::<T>::remove(asset_id);
+					HubReserveBlockStates::<T>::remove(asset_id);
+				}
+			}
+
+			Self::deposit_event(Event::AssetSlipFeeUpdated { asset_id, params });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -1650,6 +1792,25 @@ impl<T: Config> Pallet<T> {
 		Ok((state, reserve).into())
 	}
 
+	/// Calculate the withdrawal fee of asset from its spot price and the external oracle price.
+	fn withdrawal_fee(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+	) -> Result<FixedU128, DispatchError> {
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+
+		Ok(hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		))
+	}
+
 	/// Set new state of asset.
 	/// This converts the new state into correct state type ( by removing the reserve)
 	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
@@ -1672,6 +1833,77 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Hub reserve changes of an asset caused by trades in current block.
+	fn hub_reserve_block_state(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+	) -> HubReserveBlockState<BlockNumberFor<T>> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		HubReserveBlockStates::<T>::get(asset_id)
+			.filter(|block_state| block_state.block == current_block)
+			.unwrap_or(HubReserveBlockState {
+				block: current_block,
+				hub_reserve_at_block_start: asset_state.hub_reserve,
+				added: Balance::zero(),
+				removed: Balance::zero(),
+			})
+	}
+
+	/// Slip fee state of an asset in current block. Returns `None` if slip fee is not set for the asset.
+	fn slip_fee_state(asset_id: T::AssetId, asset_state: &AssetReserveState<Balance>) -> Option<SlipFeeState<Balance>> {
+		let params = SlipFees::<T>::get(asset_id)?;
+		let block_state = Self::hub_reserve_block_state(asset_id, asset_state);
+
+		Some(SlipFeeState {
+			factor: params.factor,
+			max_slip_fee: params.max_slip_fee,
+			hub_reserve_at_block_start: block_state.hub_reserve_at_block_start,
+			delta_hub_reserve: block_state.delta_hub_reserve(),
+		})
+	}
+
+	/// Slip fee states of assets of a trade.
+	fn slip_fee(
+		asset_in: T::AssetId,
+		asset_in_state: &AssetReserveState<Balance>,
+		asset_out: T::AssetId,
+		asset_out_state: &AssetReserveState<Balance>,
+	) -> SlipFee<Balance> {
+		SlipFee {
+			asset_in: Self::slip_fee_state(asset_in, asset_in_state),
+			asset_out: Self::slip_fee_state(asset_out, asset_out_state),
+		}
+	}
+
+	/// Slip fee states of a trade where asset in is hub asset. Slip fee is charged for asset out only.
+	fn hub_asset_trade_slip_fee(
+		asset_out: T::AssetId,
+		asset_out_state: &AssetReserveState<Balance>,
+	) -> SlipFee<Balance> {
+		SlipFee {
+			asset_in: None,
+			asset_out: Self::slip_fee_state(asset_out, asset_out_state),
+		}
+	}
+
+	/// Record hub reserve change caused by a trade. Changes are tracked only for assets with slip fee.
+	fn record_hub_reserve_change(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+		delta_hub_reserve: &BalanceUpdate<Balance>,
+	) {
+		if !SlipFees::<T>::contains_key(asset_id) {
+			return;
+		}
+
+		let mut block_state = Self::hub_reserve_block_state(asset_id, asset_state);
+		match delta_hub_reserve {
+			BalanceUpdate::Increase(amount) => block_state.added = block_state.added.saturating_add(*amount),
+			BalanceUpdate::Decrease(amount) => block_state.removed = block_state.removed.saturating_add(*amount),
+		}
+		HubReserveBlockStates::<T>::insert(asset_id, block_state);
+	}
+
 	/// Process given protocol fee amount by calling a consume_protocol_fee hook.
 	/// If hook returns None, we need to burn the extra amount ourselves.
 	/// Returns information where the fee amounts were transferred/burned for fee reporting.
@@ -1757,9 +1989,13 @@ impl<T: Config> Pallet<T> {
 
 		let (asset_fee, _) = T::Fee::get_and_store((asset_out, asset_state.reserve));
 
-		let state_changes =
-			hydra_dx_math::omnipool::calculate_sell_hub_state_changes(&(&asset_state).into(), amount, asset_fee)
-				.ok_or(ArithmeticError::Overflow)?;
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes_with_slip_fee(
+			&(&asset_state).into(),
+			amount,
+			asset_fee,
+			&Self::hub_asset_trade_slip_fee(asset_out, &asset_state),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
 
 		ensure!(
 			*state_changes.asset.delta_reserve >= limit,
@@ -1783,12 +2019,8 @@ impl<T: Config> Pallet<T> {
 			.ok_or(ArithmeticError::Overflow)?;
 
 		// Token updates
-		T::Currency::transfer(
-			T::HubAssetId::get(),
-			who,
-			&Self::protocol_account(),
-			*state_changes.asset.delta_hub_reserve, // note: here we cannot use total_delta_hub_reserve as it included the extra minted amount!
-		)?;
+		// note: slip fee is transferred together with the hub amount added to the subpool and processed as extra protocol fee
+		T::Currency::transfer(T::HubAssetId::get(), who, &Self::protocol_account(), amount)?;
 		T::Currency::transfer(
 			asset_out,
 			&Self::protocol_account(),
@@ -1807,13 +2039,17 @@ impl<T: Config> Pallet<T> {
 			false,
 		);
 
+		Self::record_hub_reserve_change(asset_out, &asset_state, &state_changes.asset.delta_hub_reserve);
 		Self::set_asset_state(asset_out, new_asset_out_state);
 
+		let mut fees = trade_fees;
+		fees.extend(Self::process_extra_protocol_fee_amount(state_changes.fee.slip_fee)?);
+
 		Self::deposit_event(Event::SellExecuted {
 			who: who.clone(),
 			asset_in: T::HubAssetId::get(),
 			asset_out,
-			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_in: amount,
 			amount_out: *state_changes.asset.delta_reserve,
 			hub_amount_in: 0,
 			hub_amount_out: 0,
@@ -1827,12 +2063,9 @@ impl<T: Config> Pallet<T> {
 			Self::protocol_account(),
 			pallet_broadcast::types::Filler::Omnipool,
 			pallet_broadcast::types::TradeOperation::ExactIn,
-			vec![Asset::new(
-				T::HubAssetId::get().into(),
-				*state_changes.asset.delta_hub_reserve,
-			)],
+			vec![Asset::new(T::HubAssetId::get().into(), amount)],
 			vec![Asset::new(asset_out.into(), *state_changes.asset.delta_reserve)],
-			trade_fees,
+			fees,
 		);
 
 		T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
@@ -1869,20 +2102,22 @@ impl<T: Config> Pallet<T> {
 
 		let (asset_fee, _) = T::Fee::get_and_store((asset_out, asset_state.reserve));
 
-		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes_with_slip_fee(
 			&(&asset_state).into(),
 			amount,
 			asset_fee,
+			&Self::hub_asset_trade_slip_fee(asset_out, &asset_state),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
-		ensure!(
-			*state_changes.asset.delta_hub_reserve <= limit,
-			Error::<T>::SellLimitExceeded
-		);
+		let hub_amount_in = (*state_changes.asset.delta_hub_reserve)
+			.checked_add(state_changes.fee.slip_fee)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(hub_amount_in <= limit, Error::<T>::SellLimitExceeded);
 
 		ensure!(
-			*state_changes.asset.delta_hub_reserve
+			hub_amount_in
 				<= asset_state
 					.hub_reserve
 					.checked_div(T::MaxInRatio::get())
@@ -1897,12 +2132,8 @@ impl<T: Config> Pallet<T> {
 			.delta_update(&state_changes.asset)
 			.ok_or(ArithmeticError::Overflow)?;
 
-		T::Currency::transfer(
-			T::HubAssetId::get(),
-			who,
-			&Self::protocol_account(),
-			*state_changes.asset.delta_hub_reserve, //note: here we cannot use total_delta_hub_reserve as it included the extra minted amount!
-		)?;
+		// note: slip fee is transferred together with the hub amount added to the subpool and processed as extra protocol fee
+		T::Currency::transfer(T::HubAssetId::get(), who, &Self::protocol_account(), hub_amount_in)?;
 		T::Currency::transfer(
 			asset_out,
 			&Self::protocol_account(),
@@ -1921,14 +2152,18 @@ impl<T: Config> Pallet<T> {
 			false,
 		);
 
+		Self::record_hub_reserve_change(asset_out, &asset_state, &state_changes.asset.delta_hub_reserve);
 		Self::set_asset_state(asset_out, new_asset_out_state);
 
+		let mut fees = trade_fees;
+		fees.extend(Self::process_extra_protocol_fee_amount(state_changes.fee.slip_fee)?);
+
 		// TODO: Deprecated, remove when ready
 		Self::deposit_event(Event::BuyExecuted {
 			who: who.clone(),
 			asset_in: T::HubAssetId::get(),
 			asset_out,
-			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_in: hub_amount_in,
 			amount_out: *state_changes.asset.delta_reserve,
 			hub_amount_in: 0,
 			hub_amount_out: 0,
@@ -1942,12 +2177,9 @@ impl<T: Config> Pallet<T> {
 			Self::protocol_account(),
 			pallet_broadcast::types::Filler::Omnipool,
 			pallet_broadcast::types::TradeOperation::ExactOut,
-			vec![Asset::new(
-				T::HubAssetId::get().into(),
-				*state_changes.asset.delta_hub_reserve,
-			)],
+			vec![Asset::new(T::HubAssetId::get().into(), hub_amount_in)],
 			vec![Asset::new(asset_out.into(), *state_changes.asset.delta_reserve)],
-			trade_fees,
+			fees,
 		);
 
 		T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
@@ -2100,6 +2332,124 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[require_transactional]
+	pub fn do_split_position(
+		origin: OriginFor<T>,
+		position_id: T::PositionItemId,
+		shares: Balance,
+	) -> Result<T::PositionItemId, DispatchError> {
+		let who = ensure_signed(origin)?;
+
+		ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+		let position = Self::load_position(position_id, who.clone())?;
+
+		ensure!(position.shares > shares, Error::<T>::InsufficientShares);
+
+		let (remaining, new) = hydra_dx_math::omnipool::calculate_split_position(&(&position).into(), shares)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let updated_position = Position::<Balance, T::AssetId> {
+			asset_id: position.asset_id,
+			amount: remaining.amount,
+			shares: remaining.shares,
+			price: remaining.price,
+		};
+		let new_position = Position::<Balance, T::AssetId> {
+			asset_id: position.asset_id,
+			amount: new.amount,
+			shares: new.shares,
+			price: new.price,
+		};
+		let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+
+		let new_position_id = Self::create_and_mint_position_instance(&who)?;
+
+		<Positions<T>>::insert(position_id, updated_position.clone());
+		<Positions<T>>::insert(new_position_id, new_position.clone());
+
+		Self::deposit_event(Event::PositionUpdated {
+			position_id,
+			owner: who.clone(),
+			asset: position.asset_id,
+			amount: updated_position.amount,
+			shares: updated_position.shares,
+			price,
+		});
+		Self::deposit_event(Event::PositionCreated {
+			position_id: new_position_id,
+			owner: who,
+			asset: position.asset_id,
+			amount: new_position.amount,
+			shares: new_position.shares,
+			price,
+		});
+		Self::deposit_event(Event::PositionSplit {
+			position_id,
+			new_position_id,
+			shares,
+		});
+
+		Ok(new_position_id)
+	}
+
+	#[require_transactional]
+	pub fn do_merge_positions(
+		origin: OriginFor<T>,
+		position_id: T::PositionItemId,
+		other_position_id: T::PositionItemId,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+
+		ensure!(position_id != other_position_id, Error::<T>::SamePositionMerge);
+
+		let position = Self::load_position(position_id, who.clone())?;
+		let other_position = Self::load_position(other_position_id, who.clone())?;
+
+		ensure!(
+			position.asset_id == other_position.asset_id,
+			Error::<T>::PositionsAssetMismatch
+		);
+
+		let merged = hydra_dx_math::omnipool::calculate_merged_position(&(&position).into(), &(&other_position).into())
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let updated_position = Position::<Balance, T::AssetId> {
+			asset_id: position.asset_id,
+			amount: merged.amount,
+			shares: merged.shares,
+			price: merged.price,
+		};
+
+		<Positions<T>>::remove(other_position_id);
+		T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
+
+		Self::deposit_event(Event::PositionDestroyed {
+			position_id: other_position_id,
+			owner: who.clone(),
+		});
+
+		Self::deposit_event(Event::PositionUpdated {
+			position_id,
+			owner: who,
+			asset: updated_position.asset_id,
+			amount: updated_position.amount,
+			shares: updated_position.shares,
+			price: updated_position
+				.price_from_rational()
+				.ok_or(ArithmeticError::DivisionByZero)?,
+		});
+
+		<Positions<T>>::insert(position_id, updated_position);
+
+		Self::deposit_event(Event::PositionsMerged {
+			position_id,
+			merged_position_id: other_position_id,
+		});
+
+		Ok(())
+	}
+
 	#[require_transactional]
 	pub fn do_add_liquidity_with_limit(
 		origin: OriginFor<T>,
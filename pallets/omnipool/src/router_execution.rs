@@ -1,5 +1,6 @@
-use crate::types::Balance;
-use crate::{Config, Error, Pallet};
+use crate::types::{Balance, Tradability};
+use crate::{Assets, Config, Error, HubAssetTradability, Pallet};
+use frame_support::ensure;
 use frame_system::pallet_prelude::OriginFor;
 
 use hydradx_traits::fee::GetDynamicFee;
@@ -7,6 +8,7 @@ use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use sp_runtime::traits::Get;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::{vec, vec::Vec};
 
 // dev note: The code is calculate sell and buy is copied from the corresponding functions.
 // This is not ideal and should be refactored to avoid code duplication.
@@ -30,12 +32,19 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		let asset_out_state = Self::load_asset_state(asset_out).map_err(ExecutorError::Error)?;
 
 		if asset_in == T::HubAssetId::get() {
+			ensure!(
+				HubAssetTradability::<T>::get().contains(Tradability::SELL)
+					&& asset_out_state.tradable.contains(Tradability::BUY),
+				ExecutorError::Error(Error::<T>::NotAllowed.into())
+			);
+
 			let (asset_fee, _) = T::Fee::get((asset_out, asset_out_state.reserve));
 
-			let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
+			let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes_with_slip_fee(
 				&(&asset_out_state).into(),
 				amount_in,
 				asset_fee,
+				&Self::hub_asset_trade_slip_fee(asset_out, &asset_out_state),
 			)
 			.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -43,16 +52,22 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		}
 		let asset_in_state = Self::load_asset_state(asset_in).map_err(ExecutorError::Error)?;
 
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			ExecutorError::Error(Error::<T>::NotAllowed.into())
+		);
+
 		let (asset_fee, _) = T::Fee::get((asset_out, asset_out_state.reserve));
 		let (_, protocol_fee) = T::Fee::get((asset_in, asset_in_state.reserve));
 
-		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes_with_slip_fee(
 			&(&asset_in_state).into(),
 			&(&asset_out_state).into(),
 			amount_in,
 			asset_fee,
 			protocol_fee,
 			T::BurnProtocolFee::get(),
+			&Self::slip_fee(asset_in, &asset_in_state, asset_out, &asset_out_state),
 		)
 		.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -75,30 +90,45 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		let asset_out_state = Self::load_asset_state(asset_out).map_err(ExecutorError::Error)?;
 
 		if asset_in == T::HubAssetId::get() {
+			ensure!(
+				HubAssetTradability::<T>::get().contains(Tradability::SELL)
+					&& asset_out_state.tradable.contains(Tradability::BUY),
+				ExecutorError::Error(Error::<T>::NotAllowed.into())
+			);
+
 			let (asset_fee, _) = T::Fee::get((asset_out, asset_out_state.reserve));
 
-			let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
+			let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes_with_slip_fee(
 				&(&asset_out_state).into(),
 				amount_out,
 				asset_fee,
+				&Self::hub_asset_trade_slip_fee(asset_out, &asset_out_state),
 			)
 			.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
-			return Ok(*state_changes.asset.delta_hub_reserve);
+			return (*state_changes.asset.delta_hub_reserve)
+				.checked_add(state_changes.fee.slip_fee)
+				.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()));
 		}
 
 		let asset_in_state = Self::load_asset_state(asset_in).map_err(ExecutorError::Error)?;
 
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			ExecutorError::Error(Error::<T>::NotAllowed.into())
+		);
+
 		let (asset_fee, _) = T::Fee::get((asset_out, asset_out_state.reserve));
 		let (_, protocol_fee) = T::Fee::get((asset_in, asset_in_state.reserve));
 
-		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes_with_slip_fee(
 			&(&asset_in_state).into(),
 			&(&asset_out_state).into(),
 			amount_out,
 			asset_fee,
 			protocol_fee,
 			T::BurnProtocolFee::get(),
+			&Self::slip_fee(asset_in, &asset_in_state, asset_out, &asset_out_state),
 		)
 		.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -188,4 +218,13 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 
 		Ok(spot_price)
 	}
+
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		let assets = Assets::<T>::iter()
+			.filter(|(_, state)| state.tradable.intersects(Tradability::SELL | Tradability::BUY))
+			.map(|(asset_id, _)| asset_id)
+			.collect();
+
+		vec![(PoolType::Omnipool, assets)]
+	}
 }
@@ -0,0 +1,171 @@
+use super::*;
+use crate::types::AssetDetails;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedPointNumber;
+
+#[test]
+fn asset_details_should_return_state_and_cap_difference() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let state = Omnipool::load_asset_state(1_000).unwrap();
+			let total_hub_reserve = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+			let max_hub_reserve = FixedU128::from_inner(state.cap)
+				.checked_mul_int(total_hub_reserve)
+				.unwrap();
+			let expected_cap_difference = FixedU128::one()
+				.checked_sub(&FixedU128::checked_from_rational(state.hub_reserve, max_hub_reserve).unwrap())
+				.unwrap()
+				.checked_mul_int(state.reserve)
+				.unwrap();
+
+			assert_eq!(
+				Omnipool::asset_details(1_000),
+				Some(AssetDetails {
+					asset_id: 1_000,
+					state,
+					cap_difference: expected_cap_difference,
+					hub_asset_price: FixedU128::checked_from_rational(state.reserve, state.hub_reserve),
+				})
+			);
+		});
+}
+
+#[test]
+fn asset_details_should_return_none_when_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_eq!(Omnipool::asset_details(1_000), None);
+		});
+}
+
+#[test]
+fn assets_details_should_return_all_assets() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let mut assets: Vec<AssetId> = Omnipool::assets_details()
+				.into_iter()
+				.map(|details| details.asset_id)
+				.collect();
+			assets.sort();
+
+			assert_eq!(assets, vec![HDX, DAI, 1_000]);
+		});
+}
+
+#[test]
+fn tvl_should_be_denominated_in_stable_asset() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let hub_reserve = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+			let stable_asset = Omnipool::load_asset_state(DAI).unwrap();
+
+			assert_eq!(
+				Omnipool::tvl(DAI),
+				hydra_dx_math::omnipool::calculate_tvl(hub_reserve, (stable_asset.reserve, stable_asset.hub_reserve))
+			);
+		});
+}
+
+#[test]
+fn position_value_should_match_amounts_received_when_position_is_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, 1_000, 5000 * ONE),
+			(LP3, DAI, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			// move the price up so the LP receives some hub asset
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP3),
+				1_000,
+				DAI,
+				100 * ONE,
+				500 * ONE
+			));
+
+			let value = Omnipool::position_value(position_id).unwrap();
+			assert_eq!(value.withdrawal_fee, FixedU128::from_rational(1, 100));
+
+			let asset_balance = Tokens::free_balance(1_000, &LP1);
+			let hub_balance = Tokens::free_balance(LRNA, &LP1);
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				position.shares
+			));
+
+			assert_eq!(value.asset_amount, Tokens::free_balance(1_000, &LP1) - asset_balance);
+			assert_eq!(value.hub_amount, Tokens::free_balance(LRNA, &LP1) - hub_balance);
+		});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				Omnipool::position_value(1_000),
+				Err(Error::<Test>::PositionNotFound.into())
+			);
+		});
+}
+
+#[test]
+fn position_valuation_should_report_impermanent_loss_when_price_decreased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, 1_000, 5000 * ONE),
+			(LP3, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			let valuation = Omnipool::position_valuation(&position).unwrap();
+			assert_eq!(valuation.impermanent_loss, FixedU128::zero());
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), 1_000, DAI, 500 * ONE, 0));
+
+			let valuation = Omnipool::position_valuation(&position).unwrap();
+			assert_eq!(valuation.hub_amount, 0);
+			assert!(valuation.impermanent_loss > FixedU128::zero());
+			assert!(valuation.value < position.amount);
+			assert_eq!(valuation, Omnipool::position_value(position_id).unwrap());
+		});
+}
@@ -0,0 +1,231 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn merge_positions_should_combine_positions_and_destroy_merged_one() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 100 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			let merged = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged.asset_id, asset_id);
+			assert_eq!(merged.amount, position.amount + other_position.amount);
+			assert_eq!(merged.shares, position.shares + other_position.shares);
+			assert_eq!(merged.price_from_rational(), position.price_from_rational());
+
+			assert!(Positions::<Test>::get(other_position_id).is_none());
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&other_position_id).copied()), None);
+
+			expect_last_events(vec![
+				Event::PositionDestroyed {
+					position_id: other_position_id,
+					owner: LP1,
+				}
+				.into(),
+				Event::PositionUpdated {
+					position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 500 * ONE,
+					shares: 500 * ONE,
+					price: merged.price_from_rational().unwrap(),
+				}
+				.into(),
+				Event::PositionsMerged {
+					position_id,
+					merged_position_id: other_position_id,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_merging_position_with_itself() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+				Error::<Test>::SamePositionMerge
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP1, 2_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 2_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 2_000, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::PositionsAssetMismatch
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_does_not_own_both_positions() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_work_when_positions_have_different_prices() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP2), asset_id, DAI, 100 * ONE, 0));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 100 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP2), DAI, asset_id, 50 * ONE, 0));
+
+			let value = Omnipool::position_value(position_id).unwrap();
+			let other_value = Omnipool::position_value(other_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			let merged = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged.amount, position.amount + other_position.amount);
+			assert_eq!(merged.shares, position.shares + other_position.shares);
+
+			let price = position.price_from_rational().unwrap();
+			let other_price = other_position.price_from_rational().unwrap();
+			let merged_price = merged.price_from_rational().unwrap();
+			assert!(merged_price > price.min(other_price));
+			assert!(merged_price < price.max(other_price));
+
+			let merged_value = Omnipool::position_value(position_id).unwrap();
+			assert!(merged_value.value <= value.value + other_value.value);
+		});
+}
+
+#[test]
+fn merged_position_should_be_valued_same_as_both_positions_when_price_changed() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				150 * ONE
+			));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP2), asset_id, DAI, 100 * ONE, 0));
+
+			let value = Omnipool::position_value(position_id).unwrap();
+			let other_value = Omnipool::position_value(other_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			let merged_value = Omnipool::position_value(position_id).unwrap();
+			assert!(
+				merged_value
+					.asset_amount
+					.abs_diff(value.asset_amount + other_value.asset_amount)
+					<= 1
+			);
+			assert!(
+				merged_value
+					.hub_amount
+					.abs_diff(value.hub_amount + other_value.hub_amount)
+					<= 1
+			);
+		});
+}
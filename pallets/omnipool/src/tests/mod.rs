@@ -5,8 +5,10 @@ use sp_runtime::FixedU128;
 
 mod add_liquidity;
 mod add_token;
+mod api;
 mod buy;
 mod invariants;
+mod merge_positions;
 mod remove_liquidity;
 mod sell;
 
@@ -17,6 +19,8 @@ mod positions;
 mod refund;
 mod remove_liquidity_with_limit;
 mod remove_token;
+mod slip_fee;
+mod split_position;
 mod spot_price;
 mod tradability;
 mod types;
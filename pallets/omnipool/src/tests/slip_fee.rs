@@ -0,0 +1,283 @@
+use super::*;
+use crate::types::{HubReserveBlockState, SlipFeeParams};
+use frame_support::assert_noop;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
+
+fn slip_fee_params() -> SlipFeeParams {
+	SlipFeeParams {
+		factor: FixedU128::one(),
+		max_slip_fee: Permill::from_percent(5),
+	}
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+			(LP1, LRNA, 100 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_protocol_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+}
+
+#[test]
+fn set_asset_slip_fee_should_work_when_called_by_authority() {
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			100,
+			Some(slip_fee_params())
+		));
+
+		assert_eq!(Omnipool::slip_fee_params(100), Some(slip_fee_params()));
+		expect_last_events(vec![Event::AssetSlipFeeUpdated {
+			asset_id: 100,
+			params: Some(slip_fee_params()),
+		}
+		.into()]);
+
+		assert_ok!(Omnipool::set_asset_slip_fee(RuntimeOrigin::root(), 100, None));
+
+		assert_eq!(Omnipool::slip_fee_params(100), None);
+	});
+}
+
+#[test]
+fn set_asset_slip_fee_should_fail_when_called_by_non_authority() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_asset_slip_fee(RuntimeOrigin::signed(LP1), 100, Some(slip_fee_params())),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_slip_fee_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_asset_slip_fee(RuntimeOrigin::root(), 1_000, Some(slip_fee_params())),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_charge_slip_fee_when_set_for_asset() {
+	let sell_amount = 50 * ONE;
+
+	let (amount_out_without_slip_fee, protocol_fee_without_slip_fee) = ext().execute_with(|| {
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, sell_amount, 0));
+		(
+			Tokens::free_balance(200, &LP1),
+			Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR),
+		)
+	});
+
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			100,
+			Some(slip_fee_params())
+		));
+		let hub_reserve = Omnipool::load_asset_state(100).unwrap().hub_reserve;
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, sell_amount, 0));
+
+		let delta_hub_reserve = hub_reserve - Omnipool::load_asset_state(100).unwrap().hub_reserve;
+
+		assert!(Tokens::free_balance(200, &LP1) < amount_out_without_slip_fee);
+		assert_eq!(
+			HubReserveBlockStates::<Test>::get(100),
+			Some(HubReserveBlockState {
+				block: 1,
+				hub_reserve_at_block_start: hub_reserve,
+				added: 0,
+				removed: delta_hub_reserve,
+			})
+		);
+
+		// slip fee is consumed by the hook together with protocol fee
+		let slip_fee_rate = Permill::from_rational(delta_hub_reserve, hub_reserve);
+		assert_eq!(
+			Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR) - protocol_fee_without_slip_fee,
+			slip_fee_rate.mul_floor(delta_hub_reserve)
+		);
+	});
+}
+
+#[test]
+fn buy_should_charge_slip_fee_when_set_for_asset() {
+	let buy_amount = 50 * ONE;
+
+	let amount_in_without_slip_fee = ext().execute_with(|| {
+		let initial_balance = Tokens::free_balance(100, &LP1);
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			200,
+			100,
+			buy_amount,
+			u128::MAX
+		));
+		initial_balance - Tokens::free_balance(100, &LP1)
+	});
+
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			200,
+			Some(slip_fee_params())
+		));
+		let initial_balance = Tokens::free_balance(100, &LP1);
+
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			200,
+			100,
+			buy_amount,
+			u128::MAX
+		));
+
+		assert!(initial_balance - Tokens::free_balance(100, &LP1) > amount_in_without_slip_fee);
+		assert!(HubReserveBlockStates::<Test>::get(100).is_none());
+		assert!(HubReserveBlockStates::<Test>::get(200).unwrap().added > 0);
+	});
+}
+
+#[test]
+fn sell_hub_asset_should_charge_slip_fee_when_set_for_asset_out() {
+	let sell_amount = 50 * ONE;
+
+	let amount_out_without_slip_fee = ext().execute_with(|| {
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), LRNA, 200, sell_amount, 0));
+		Tokens::free_balance(200, &LP1)
+	});
+
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			200,
+			Some(slip_fee_params())
+		));
+		let hub_reserve = Omnipool::load_asset_state(200).unwrap().hub_reserve;
+		let protocol_fee_collector_balance = Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR);
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), LRNA, 200, sell_amount, 0));
+
+		let slip_fee = Permill::from_rational(sell_amount, hub_reserve).mul_floor(sell_amount);
+
+		assert!(Tokens::free_balance(200, &LP1) < amount_out_without_slip_fee);
+		assert_eq!(Tokens::free_balance(LRNA, &LP1), 50 * ONE);
+		assert_eq!(
+			Omnipool::load_asset_state(200).unwrap().hub_reserve - hub_reserve,
+			sell_amount - slip_fee
+		);
+		assert_eq!(
+			HubReserveBlockStates::<Test>::get(200),
+			Some(HubReserveBlockState {
+				block: 1,
+				hub_reserve_at_block_start: hub_reserve,
+				added: sell_amount - slip_fee,
+				removed: 0,
+			})
+		);
+		assert_eq!(
+			Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR) - protocol_fee_collector_balance,
+			slip_fee
+		);
+	});
+}
+
+#[test]
+fn buy_for_hub_asset_should_charge_slip_fee_when_set_for_asset_out() {
+	let buy_amount = 10 * ONE;
+
+	let amount_in_without_slip_fee = ext().execute_with(|| {
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			200,
+			LRNA,
+			buy_amount,
+			u128::MAX
+		));
+		100 * ONE - Tokens::free_balance(LRNA, &LP1)
+	});
+
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			200,
+			Some(slip_fee_params())
+		));
+		let protocol_fee_collector_balance = Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR);
+
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			200,
+			LRNA,
+			buy_amount,
+			u128::MAX
+		));
+
+		let amount_in = 100 * ONE - Tokens::free_balance(LRNA, &LP1);
+		let added = HubReserveBlockStates::<Test>::get(200).unwrap().added;
+
+		assert!(amount_in > amount_in_without_slip_fee);
+		assert!(added > 0);
+		assert_eq!(
+			Tokens::free_balance(LRNA, &PROTOCOL_FEE_COLLECTOR) - protocol_fee_collector_balance,
+			amount_in - added
+		);
+	});
+}
+
+#[test]
+fn buy_for_hub_asset_should_fail_when_slip_fee_exceeds_limit() {
+	ext().execute_with(|| {
+		let amount_in_quote = Omnipool::calculate_buy(PoolType::Omnipool, LRNA, 200, 10 * ONE).unwrap();
+
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			200,
+			Some(slip_fee_params())
+		));
+
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP1), 200, LRNA, 10 * ONE, amount_in_quote),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn slip_fee_should_increase_with_net_hub_reserve_change_in_block() {
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_slip_fee(
+			RuntimeOrigin::root(),
+			100,
+			Some(slip_fee_params())
+		));
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 10 * ONE, 0));
+
+		let first_quote = Omnipool::calculate_sell(PoolType::Omnipool, 100, 200, 10 * ONE).unwrap();
+
+		// hub reserve changes are reset in next block
+		System::set_block_number(2);
+		let second_quote = Omnipool::calculate_sell(PoolType::Omnipool, 100, 200, 10 * ONE).unwrap();
+
+		assert!(second_quote > first_quote);
+	});
+}
@@ -0,0 +1,127 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn split_position_should_create_new_position_with_split_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let asset_state = Omnipool::load_asset_state(asset_id).unwrap();
+
+			// Act
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&new_position_id).copied()), Some(LP1));
+
+			// pool state is not changed
+			assert_eq!(Omnipool::load_asset_state(asset_id).unwrap(), asset_state);
+
+			expect_last_events(vec![
+				Event::PositionUpdated {
+					position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: position.price_from_rational().unwrap(),
+				}
+				.into(),
+				Event::PositionCreated {
+					position_id: new_position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: position.price_from_rational().unwrap(),
+				}
+				.into(),
+				Event::PositionSplit {
+					position_id,
+					new_position_id,
+					shares: 100 * ONE,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_not_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -1,5 +1,6 @@
 use super::*;
 use frame_support::assert_noop;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 
 #[test]
 fn sell_asset_tradable_state_should_work_when_hub_asset_new_state_contains_sell_or_buy() {
@@ -66,3 +67,37 @@ fn tradability_should_allow_all_when_default() {
 	assert!(default_tradability.contains(Tradability::ADD_LIQUIDITY));
 	assert!(default_tradability.contains(Tradability::REMOVE_LIQUIDITY));
 }
+
+#[test]
+fn router_calculate_sell_should_fail_when_asset_is_not_tradable() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Omnipool as TradeExecution<_, _, _, _>>::calculate_sell(
+				PoolType::Omnipool,
+				100,
+				200,
+				10 * ONE
+			));
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				100,
+				Tradability::BUY
+			));
+
+			assert_eq!(
+				<Omnipool as TradeExecution<_, _, _, _>>::calculate_sell(PoolType::Omnipool, 100, 200, 10 * ONE),
+				Err(ExecutorError::Error(Error::<Test>::NotAllowed.into()))
+			);
+			assert_eq!(
+				<Omnipool as TradeExecution<_, _, _, _>>::calculate_buy(PoolType::Omnipool, 100, 200, 10 * ONE),
+				Err(ExecutorError::Error(Error::<Test>::NotAllowed.into()))
+			);
+		});
+}
@@ -137,7 +137,7 @@ where
 }
 
 /// Asset state representation including asset pool reserve.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct AssetReserveState<Balance> {
 	/// Quantity of asset in omnipool
 	pub reserve: Balance,
@@ -249,3 +249,48 @@ where
 		})
 	}
 }
+
+/// Asset state with additional details derived from the pool state.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct AssetDetails<AssetId, Balance> {
+	pub asset_id: AssetId,
+	/// Asset state including pool reserve and tradable state
+	pub state: AssetReserveState<Balance>,
+	/// Amount of asset which can still be added before the asset's weight cap is reached
+	pub cap_difference: Balance,
+	/// Price of hub asset denominated in the asset
+	pub hub_asset_price: Option<FixedU128>,
+}
+
+/// Slip fee parameters of an asset.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SlipFeeParams {
+	/// Slip fee rate per unit of relative hub reserve change within a block
+	pub factor: FixedU128,
+	/// Maximum slip fee rate
+	pub max_slip_fee: Permill,
+}
+
+/// Hub reserve changes of an asset caused by trades within a block.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct HubReserveBlockState<BlockNumber> {
+	/// Block in which the changes happened
+	pub block: BlockNumber,
+	/// Hub reserve of the asset before the first trade in the block
+	pub hub_reserve_at_block_start: Balance,
+	/// Hub asset amount added to the asset's subpool by trades
+	pub added: Balance,
+	/// Hub asset amount removed from the asset's subpool by trades
+	pub removed: Balance,
+}
+
+impl<BlockNumber> HubReserveBlockState<BlockNumber> {
+	/// Net change of hub reserve within the block.
+	pub fn delta_hub_reserve(&self) -> BalanceUpdate<Balance> {
+		if self.added >= self.removed {
+			BalanceUpdate::Increase(self.added.saturating_sub(self.removed))
+		} else {
+			BalanceUpdate::Decrease(self.removed.saturating_sub(self.added))
+		}
+	}
+}
@@ -25,6 +25,9 @@ pub trait WeightInfo {
 	fn withdraw_protocol_liquidity() -> Weight;
 	fn remove_token() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn split_position() -> Weight;
+	fn merge_positions() -> Weight;
+	fn set_asset_slip_fee() -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
@@ -218,14 +221,18 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::CounterForPendingConversions` (r:1 w:1)
 	/// Proof: `Referrals::CounterForPendingConversions` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::SlipFees` (r:2 w:0)
+	/// Proof: `Omnipool::SlipFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubReserveBlockStates` (r:2 w:2)
+	/// Proof: `Omnipool::HubReserveBlockStates` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7891`
 		//  Estimated: `13905`
 		// Minimum execution time: 396_351_000 picoseconds.
 		Weight::from_parts(400_745_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(42_u64))
-			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:3 w:3)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
@@ -277,14 +284,18 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::CounterForPendingConversions` (r:1 w:1)
 	/// Proof: `Referrals::CounterForPendingConversions` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::SlipFees` (r:2 w:0)
+	/// Proof: `Omnipool::SlipFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubReserveBlockStates` (r:2 w:2)
+	/// Proof: `Omnipool::HubReserveBlockStates` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7891`
 		//  Estimated: `13905`
 		// Minimum execution time: 398_251_000 picoseconds.
 		Weight::from_parts(401_486_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(42_u64))
-			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
@@ -556,4 +567,53 @@ impl WeightInfo for () {
 		Weight::from_parts(69_541_000, 6190)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:2 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Positions` (r:1 w:2)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::NextPositionId` (r:1 w:1)
+	/// Proof: `Omnipool::NextPositionId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::CollectionMaxSupply` (r:1 w:0)
+	/// Proof: `Uniques::CollectionMaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn split_position() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(89_530_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:2 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Positions` (r:2 w:2)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn merge_positions() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(86_102_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Omnipool::Assets` (r:1 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::SlipFees` (r:0 w:1)
+	/// Proof: `Omnipool::SlipFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubReserveBlockStates` (r:0 w:1)
+	/// Proof: `Omnipool::HubReserveBlockStates` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_asset_slip_fee() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(29_104_000, 3550)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -0,0 +1,66 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteQuote, Trade};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to quote trades along a route.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Quote a sell of `amount_in` of `asset_in` along the route.
+		/// If the route is empty, the on-chain route of the asset pair is used.
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Quote a buy of `amount_out` of `asset_out` along the route.
+		/// If the route is empty, the on-chain route of the asset pair is used.
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Find the route with the best amount out for a sell of `amount_in` of `asset_in` and quote it.
+		fn best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Find the route with the lowest amount in for a buy of `amount_out` of `asset_out` and quote it.
+		fn best_buy_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Get the stored routes which can not be executed anymore and can be invalidated.
+		fn broken_routes() -> Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)>;
+	}
+}
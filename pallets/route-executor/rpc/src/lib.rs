@@ -0,0 +1,210 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the route executor pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_route_executor_rpc_runtime_api::RouterApi as RouterRuntimeApi;
+use pallet_route_executor_rpc_runtime_api::{AssetPair, RouteQuote, Trade};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait RouterApi<BlockHash, AssetId, Balance> {
+	/// Quote a sell of `amount_in` of `asset_in` along the route, optionally at the given block.
+	/// If the route is empty, the on-chain route of the asset pair is used.
+	#[method(name = "router_quoteSell")]
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		route: Vec<Trade<AssetId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Quote a buy of `amount_out` of `asset_out` along the route, optionally at the given block.
+	/// If the route is empty, the on-chain route of the asset pair is used.
+	#[method(name = "router_quoteBuy")]
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		route: Vec<Trade<AssetId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Find and quote the route with the best amount out for a sell of `amount_in` of `asset_in`,
+	/// optionally at the given block.
+	#[method(name = "router_bestSellRoute")]
+	fn best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Find and quote the route with the lowest amount in for a buy of `amount_out` of `asset_out`,
+	/// optionally at the given block.
+	#[method(name = "router_bestBuyRoute")]
+	fn best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Get the stored routes which can not be executed anymore, optionally at the given block.
+	#[method(name = "router_brokenRoutes")]
+	fn broken_routes(&self, at: Option<BlockHash>) -> RpcResult<Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The route could not be quoted.
+	QuoteError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to quote trades along a route.
+pub struct Router<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Router<C, Block> {
+	/// Create new `Router` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance> RouterApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Router<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		route: Vec<Trade<AssetId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_sell(at, asset_in, asset_out, amount_in, route)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		route: Vec<Trade<AssetId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_buy(at, asset_in, asset_out, amount_out, route)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+
+	fn best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.best_sell_route(at, asset_in, asset_out, amount_in)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+
+	fn best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.best_buy_route(at, asset_in, asset_out, amount_out)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+
+	fn broken_routes(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.broken_routes(at).map_err(runtime_error_into_rpc_err)
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), "Runtime error", Some(format!("{:?}", err)))
+}
+
+fn quote_error_into_rpc_err(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::QuoteError.into(),
+		"Unable to quote the route",
+		Some(format!("{:?}", err)),
+	)
+}
@@ -0,0 +1,203 @@
+// This file is part of pallet-route-executor.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! On-chain route discovery.
+//!
+//! The pool graph is built from the pools exposed by the AMMs via [`TradeExecution::pools`].
+//! The search extends the candidate paths by one trade at a time, up to [`MAX_NUMBER_OF_TRADES`] trades,
+//! keeping only the best path found so far to each intermediate asset.
+//! A path never visits the same asset twice and never trades in the same pool twice.
+
+use crate::{Config, Error, Pallet, MAX_NUMBER_OF_TRADES};
+use frame_support::ensure;
+use hydradx_traits::router::{PoolType, Trade, TradeExecution};
+use sp_runtime::traits::Zero;
+use sp_runtime::DispatchError;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
+
+/// Path found by the search. Each hop is the index of the pool used and the asset reached by the trade.
+struct Path<AssetId, Balance> {
+	amount: Balance,
+	hops: Vec<(usize, AssetId)>,
+}
+
+impl<AssetId: PartialEq + Copy, Balance> Path<AssetId, Balance> {
+	fn contains_asset(&self, asset: AssetId) -> bool {
+		self.hops.iter().any(|(_, hop_asset)| *hop_asset == asset)
+	}
+
+	fn contains_pool(&self, pool_index: usize) -> bool {
+		self.hops.iter().any(|(index, _)| *index == pool_index)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Finds the route which gives the highest amount of `asset_out` when selling `amount_in` of `asset_in`.
+	///
+	/// Fails with `RouteNotFound` if there is no route with at most `MAX_NUMBER_OF_TRADES` trades.
+	pub fn discover_sell_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+	) -> Result<Vec<Trade<T::AssetId>>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let pools = T::AMM::pools();
+		let path = Self::discover_path(
+			&pools,
+			asset_in,
+			asset_out,
+			amount_in,
+			|pool, from, to, amount| T::AMM::calculate_sell(pool, from, to, amount).ok(),
+			|new_amount, amount| new_amount > amount,
+		)
+		.ok_or(Error::<T>::RouteNotFound)?;
+
+		let mut asset_in = asset_in;
+		let route = path
+			.hops
+			.into_iter()
+			.map(|(pool_index, asset_out)| {
+				let trade = Trade {
+					pool: pools[pool_index].0,
+					asset_in,
+					asset_out,
+				};
+				asset_in = asset_out;
+				trade
+			})
+			.collect();
+
+		Ok(route)
+	}
+
+	/// Finds the route which requires the lowest amount of `asset_in` when buying `amount_out` of `asset_out`.
+	///
+	/// The search is done backwards, starting from `asset_out`.
+	///
+	/// Fails with `RouteNotFound` if there is no route with at most `MAX_NUMBER_OF_TRADES` trades.
+	pub fn discover_buy_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+	) -> Result<Vec<Trade<T::AssetId>>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let pools = T::AMM::pools();
+		let path = Self::discover_path(
+			&pools,
+			asset_out,
+			asset_in,
+			amount_out,
+			|pool, from, to, amount| T::AMM::calculate_buy(pool, to, from, amount).ok(),
+			|new_amount, amount| new_amount < amount,
+		)
+		.ok_or(Error::<T>::RouteNotFound)?;
+
+		let mut asset_out = asset_out;
+		let mut route: Vec<Trade<T::AssetId>> = path
+			.hops
+			.into_iter()
+			.map(|(pool_index, asset_in)| {
+				let trade = Trade {
+					pool: pools[pool_index].0,
+					asset_in,
+					asset_out,
+				};
+				asset_out = asset_in;
+				trade
+			})
+			.collect();
+		route.reverse();
+
+		Ok(route)
+	}
+
+	/// Searches for the best path from `start` to `end`.
+	///
+	/// `calculate` returns the amount reached by trading `amount` from one asset to another in the given pool,
+	/// or `None` if the trade is not possible. `is_better` decides whether the first amount is better than the second.
+	fn discover_path(
+		pools: &[(PoolType<T::AssetId>, Vec<T::AssetId>)],
+		start: T::AssetId,
+		end: T::AssetId,
+		amount: T::Balance,
+		calculate: impl Fn(PoolType<T::AssetId>, T::AssetId, T::AssetId, T::Balance) -> Option<T::Balance>,
+		is_better: impl Fn(T::Balance, T::Balance) -> bool,
+	) -> Option<Path<T::AssetId, T::Balance>> {
+		let mut best: Option<Path<T::AssetId, T::Balance>> = None;
+		let mut frontier = BTreeMap::new();
+		frontier.insert(
+			start,
+			Path {
+				amount,
+				hops: Vec::new(),
+			},
+		);
+
+		for _ in 0..MAX_NUMBER_OF_TRADES {
+			let mut next_frontier = BTreeMap::<T::AssetId, Path<T::AssetId, T::Balance>>::new();
+
+			for (asset, path) in frontier.iter() {
+				for (pool_index, (pool, assets)) in pools.iter().enumerate() {
+					if path.contains_pool(pool_index) || !assets.contains(asset) {
+						continue;
+					}
+
+					for next_asset in assets.iter().copied() {
+						if next_asset == *asset || next_asset == start || path.contains_asset(next_asset) {
+							continue;
+						}
+
+						let Some(next_amount) = calculate(*pool, *asset, next_asset, path.amount) else {
+							continue;
+						};
+						if next_amount.is_zero() {
+							continue;
+						}
+
+						let mut hops = path.hops.clone();
+						hops.push((pool_index, next_asset));
+						let next_path = Path {
+							amount: next_amount,
+							hops,
+						};
+
+						if next_asset == end {
+							if best.as_ref().map_or(true, |b| is_better(next_amount, b.amount)) {
+								best = Some(next_path);
+							}
+						} else if next_frontier
+							.get(&next_asset)
+							.map_or(true, |p| is_better(next_amount, p.amount))
+						{
+							next_frontier.insert(next_asset, next_path);
+						}
+					}
+				}
+			}
+
+			if next_frontier.is_empty() {
+				break;
+			}
+			frontier = next_frontier;
+		}
+
+		best
+	}
+}
@@ -24,7 +24,11 @@ use frame_support::PalletId;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{fungibles::Inspect, Get},
+	storage::with_transaction,
+	traits::{
+		fungibles::{Inspect, Mutate},
+		Get,
+	},
 	transactional,
 };
 use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
@@ -33,21 +37,24 @@ use frame_system::ensure_signed;
 use hydradx_traits::registry::Inspect as RegistryInspect;
 use hydradx_traits::router::{inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouteQuote, RouterT, Trade, TradeExecution,
 };
 
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use pallet_broadcast::types::IncrementalIdType;
 pub use pallet_broadcast::types::{ExecutionType, Fee};
 use sp_core::U512;
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating};
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Zero};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Saturating, TransactionOutcome,
+};
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
 mod tests;
 pub mod weights;
 
+mod discovery;
 mod types;
 
 pub use weights::WeightInfo;
@@ -56,6 +63,7 @@ pub use weights::WeightInfo;
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 5;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -90,7 +98,8 @@ pub mod pallet {
 			+ CheckedAdd
 			+ CheckedDiv
 			+ Saturating
-			+ Zero;
+			+ Zero
+			+ AtLeast32BitUnsigned;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -144,6 +153,8 @@ pub mod pallet {
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///The stored route can not be executed anymore and has been removed
+		RouteInvalidated { asset_ids: Vec<T::AssetId> },
 	}
 
 	#[pallet::error]
@@ -168,6 +179,14 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		/// No route has been found between the assets
+		RouteNotFound,
+		/// The shares of the split routes are invalid or the number of routes is not supported
+		InvalidRouteSplit,
+		/// The balance of the asset to buy is already at or above the target balance
+		TargetBalanceReached,
+		/// The stored route is still valid so it can not be invalidated
+		RouteIsValid,
 	}
 
 	///Flag to indicate when to skip ED handling
@@ -238,72 +257,7 @@ pub mod pallet {
 			max_amount_in: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			let route_length = route.len();
-
-			let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
-
-			for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
-				Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			SkipEd::<T>::kill();
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
-
-			//TODO: we want to deprecate it once unified events are working fine
-			Self::deposit_event(Event::Executed {
-				asset_in,
-				asset_out,
-				amount_in: first_trade.amount_in,
-				amount_out,
-				event_id: next_event_id,
-			});
-
-			pallet_broadcast::Pallet::<T>::remove_from_context()?;
-
-			Ok(())
+			Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
 		}
 
 		/// Sets the on-chain route for a given asset pair.
@@ -454,6 +408,244 @@ pub mod pallet {
 
 			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
 		}
+
+		/// Executes a sell split between several routes of the same asset pair.
+		///
+		/// `amount_in` is distributed between the routes according to their shares, which have to add up to 100%.
+		/// The rounding remainder is sold on the last route.
+		/// The routes are executed one after another, so every route is calculated on the pool states left by the previous ones.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive from all routes.
+		/// - `routes`: Routes with their share of `amount_in`, at most `MAX_NUMBER_OF_SPLIT_ROUTES`.
+		/// 		   If a route is not specified, than the on-chain route is used.
+		/// 		   If no on-chain is present, then omnipool route is used as default
+		///
+		/// Emits one `Executed` event with the aggregated amounts when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::split_routes_weight(routes, T::WeightInfo::sell_weight))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let legs = Self::split_routes(asset_in, asset_out, amount_in, routes)?;
+
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+
+			let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+			let mut amount_out = T::Balance::zero();
+			for (route, leg_amount_in) in legs {
+				let trade_amounts = Self::calculate_sell_trade_amounts(&route, leg_amount_in)?;
+				let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+				amount_out = amount_out
+					.checked_add(&last_trade_amount.amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				Self::execute_sell_trades(origin.clone(), &who, &route, &trade_amounts)?;
+			}
+
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who,
+				asset_in,
+				asset_out,
+				user_balance_of_asset_out_before_trade,
+				amount_out,
+			)?;
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				event_id: next_event_id,
+			});
+
+			pallet_broadcast::Pallet::<T>::remove_from_context()?;
+
+			Ok(())
+		}
+
+		/// Executes a buy split between several routes of the same asset pair.
+		///
+		/// `amount_out` is distributed between the routes according to their shares, which have to add up to 100%.
+		/// The rounding remainder is bought on the last route.
+		/// The routes are executed one after another, so every route is calculated on the pool states left by the previous ones.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on all routes.
+		/// - `routes`: Routes with their share of `amount_out`, at most `MAX_NUMBER_OF_SPLIT_ROUTES`.
+		/// 		   If a route is not specified, than the on-chain route is used.
+		/// 		   If no on-chain is present, then omnipool route is used as default
+		///
+		/// Emits one `Executed` event with the aggregated amounts when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::split_routes_weight(routes, T::WeightInfo::buy_weight))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let legs = Self::split_routes(asset_in, asset_out, amount_out, routes)?;
+
+			let user_balance_of_asset_in_before_trade =
+				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+			let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+			let mut amount_in = T::Balance::zero();
+			for (route, leg_amount_out) in legs {
+				let trade_amounts = Self::calculate_buy_trade_amounts(&route, leg_amount_out)?;
+				let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+				amount_in = amount_in
+					.checked_add(&first_trade.amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				Self::execute_buy_trades(origin.clone(), &who, &route, &trade_amounts)?;
+			}
+
+			ensure!(amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::ensure_that_user_spent_asset_in_at_least(
+				who,
+				asset_in,
+				user_balance_of_asset_in_before_trade,
+				amount_in,
+			)?;
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				event_id: next_event_id,
+			});
+
+			pallet_broadcast::Pallet::<T>::remove_from_context()?;
+
+			Ok(())
+		}
+
+		/// Executes a sell with a series of trades specified in the route.
+		/// It sells the given percentage of the reducible user balance of `asset_in`
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `percentage`: The part of the reducible balance of `asset_in` to sell
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		/// 		   If no on-chain is present, then omnipool route is used as default
+		///
+		/// Emits `RouteExecuted` when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::sell_weight(route))]
+		#[transactional]
+		pub fn sell_percentage(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			percentage: Permill,
+			min_amount_out: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let balance = T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+			let amount_in = percentage.mul_floor(balance);
+
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+		}
+
+		/// Executes a buy with a series of trades specified in the route.
+		/// It buys the amount of `asset_out` missing to reach `target_balance` of the user.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `target_balance`: The balance of `asset_out` the user should have after the buy
+		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		/// 		   If no on-chain is present, then omnipool route is used as default
+		///
+		/// Emits `RouteExecuted` when successful.
+		///
+		/// Fails with `TargetBalanceReached` if the user already has at least `target_balance` of `asset_out`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::buy_weight(route))]
+		#[transactional]
+		pub fn buy_to_balance(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			target_balance: T::Balance,
+			max_amount_in: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let balance = T::Currency::balance(asset_out, &who);
+			ensure!(balance < target_balance, Error::<T>::TargetBalanceReached);
+			let amount_out = target_balance.saturating_sub(balance);
+
+			Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
+		}
+
+		/// Removes the stored route of an asset pair when it can not be executed anymore,
+		/// e.g. because an asset in the route is not tradable anymore or a pool of the route has been destroyed.
+		///
+		/// Can be called by any signed origin, the caller pays the transaction fee.
+		/// The route is validated the same way as in `set_route`, in both directions.
+		/// After the invalidation, the default route is used for the asset pair.
+		///
+		/// - `origin`: The origin of the call, must be signed
+		/// - `asset_pair`: The identifier of the asset-pair for which the route is invalidated
+		///
+		/// Emits `RouteInvalidated` when successful.
+		///
+		/// Fails with `RouteIsValid` error when the stored route can still be executed
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::invalidate_route_weight())]
+		#[transactional]
+		pub fn invalidate_route(origin: OriginFor<T>, asset_pair: AssetPair<T::AssetId>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let asset_pair = asset_pair.ordered_pair();
+			let route = Routes::<T>::get(asset_pair).ok_or(Error::<T>::RouteNotFound)?;
+			ensure!(!Self::is_route_valid(&route), Error::<T>::RouteIsValid);
+
+			Routes::<T>::remove(asset_pair);
+
+			Self::deposit_event(Event::RouteInvalidated {
+				asset_ids: asset_pair.to_ordered_vec(),
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -472,14 +664,7 @@ impl<T: Config> Pallet<T> {
 		route: Vec<Trade<T::AssetId>>,
 	) -> Result<(), DispatchError> {
 		let who = ensure_signed(origin.clone())?;
-
-		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-
-		Self::ensure_route_size(route.len())?;
-
-		let asset_pair = AssetPair::new(asset_in, asset_out);
-		let route = Self::get_route_or_default(route, asset_pair)?;
-		Self::ensure_route_arguments(&asset_pair, &route)?;
+		let route = Self::route_with_checks(asset_in, asset_out, route)?;
 
 		let user_balance_of_asset_out_before_trade =
 			T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
@@ -492,15 +677,89 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::TradingLimitReached
 		);
 
-		let route_length = route.len();
+		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+		Self::execute_sell_trades(origin, &who, &route, &trade_amounts)?;
+
+		Self::ensure_that_user_received_asset_out_at_most(
+			who,
+			asset_in,
+			asset_out,
+			user_balance_of_asset_out_before_trade,
+			last_trade_amount.amount_out,
+		)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out: last_trade_amount.amount_out,
+			event_id: next_event_id,
+		});
+
+		pallet_broadcast::Pallet::<T>::remove_from_context()?;
+
+		Ok(())
+	}
+
+	fn do_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<(), DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+		let route = Self::route_with_checks(asset_in, asset_out, route)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
 
 		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
 
-		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route.clone()).enumerate() {
+		Self::execute_buy_trades(origin, &who, &route, &trade_amounts)?;
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)?;
+
+		//TODO: we want to deprecate it once unified events are working fine
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in: first_trade.amount_in,
+			amount_out,
+			event_id: next_event_id,
+		});
+
+		pallet_broadcast::Pallet::<T>::remove_from_context()?;
+
+		Ok(())
+	}
+
+	/// Executes the trades of a sell route with the amounts calculated by `calculate_sell_trade_amounts`
+	fn execute_sell_trades(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		route: &[Trade<T::AssetId>],
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let route_length = route.len();
+
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route.iter().copied()).enumerate() {
 			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
 
 			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(trade.asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+				T::Currency::reducible_balance(trade.asset_in, who, Preservation::Expendable, Fortitude::Polite);
 
 			let execution_result = T::AMM::execute_sell(
 				origin.clone(),
@@ -523,27 +782,112 @@ impl<T: Config> Pallet<T> {
 
 		SkipEd::<T>::kill();
 
-		Self::ensure_that_user_received_asset_out_at_most(
-			who,
-			asset_in,
-			asset_out,
-			user_balance_of_asset_out_before_trade,
-			last_trade_amount.amount_out,
-		)?;
+		Ok(())
+	}
 
-		Self::deposit_event(Event::Executed {
-			asset_in,
-			asset_out,
-			amount_in,
-			amount_out: last_trade_amount.amount_out,
-			event_id: next_event_id,
-		});
+	/// Executes the trades of a buy route with the amounts calculated by `calculate_buy_trade_amounts`
+	fn execute_buy_trades(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		route: &[Trade<T::AssetId>],
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let route_length = route.len();
 
-		pallet_broadcast::Pallet::<T>::remove_from_context()?;
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route.iter().copied()).enumerate() {
+			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		SkipEd::<T>::kill();
 
 		Ok(())
 	}
 
+	/// Validates the split routes and distributes `amount` between them according to their shares.
+	/// The last route gets the rounding remainder.
+	fn split_routes(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: T::Balance,
+		routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+	) -> Result<Vec<(Vec<Trade<T::AssetId>>, T::Balance)>, DispatchError> {
+		ensure!(
+			!routes.is_empty() && (routes.len() as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::InvalidRouteSplit
+		);
+		ensure!(
+			routes.iter().all(|(share, _)| !share.is_zero()),
+			Error::<T>::InvalidRouteSplit
+		);
+		let total_share = routes
+			.iter()
+			.fold(0u32, |acc, (share, _)| acc.saturating_add(share.deconstruct()));
+		ensure!(
+			total_share == Permill::one().deconstruct(),
+			Error::<T>::InvalidRouteSplit
+		);
+
+		let last_index = routes.len().saturating_sub(1);
+		let mut remaining = amount;
+		let mut legs = Vec::with_capacity(routes.len());
+		for (index, (share, route)) in routes.into_iter().enumerate() {
+			let route = Self::route_with_checks(asset_in, asset_out, route)?;
+			let leg_amount = if index == last_index {
+				remaining
+			} else {
+				share.mul_floor(amount)
+			};
+			remaining = remaining.checked_sub(&leg_amount).ok_or(ArithmeticError::Underflow)?;
+			legs.push((route, leg_amount));
+		}
+
+		Ok(legs)
+	}
+
+	pub fn split_routes_weight(
+		routes: &[(Permill, Vec<Trade<T::AssetId>>)],
+		route_weight: impl Fn(&[Trade<T::AssetId>]) -> Weight,
+	) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, (_, route)| {
+			weight.saturating_add(route_weight(route))
+		})
+	}
+
+	fn route_with_checks(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<Vec<Trade<T::AssetId>>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		Ok(route)
+	}
+
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
 		ensure!(
 			(route_length as u32) <= MAX_NUMBER_OF_TRADES,
@@ -797,6 +1141,133 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Quotes a sell of `amount_in` of `asset_in` along the given route.
+	///
+	/// If the route is empty, the on-chain route (or the default one) is used.
+	///
+	/// The trades are executed by the router account in a storage transaction which is always rolled back,
+	/// so the quote contains the fees actually charged by the pools and the spot price of the route after the trades.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<RouteQuote<T::AssetId, T::Balance>, DispatchError>
+	where
+		<T as frame_system::Config>::RuntimeEvent: TryInto<pallet_broadcast::Event<T>>,
+	{
+		with_transaction(|| {
+			let quote = Self::route_with_checks(asset_in, asset_out, route).and_then(|route| {
+				let amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+				let who = Self::router_account();
+				T::Currency::mint_into(asset_in, &who, amount_in)?;
+
+				Self::simulate(route.clone(), amounts, || {
+					Self::do_sell(
+						frame_system::RawOrigin::Signed(who).into(),
+						asset_in,
+						asset_out,
+						amount_in,
+						T::Balance::zero(),
+						route,
+					)
+				})
+			});
+
+			TransactionOutcome::Rollback(quote)
+		})
+	}
+
+	/// Quotes a buy of `amount_out` of `asset_out` along the given route.
+	///
+	/// If the route is empty, the on-chain route (or the default one) is used.
+	///
+	/// The trades are executed the same way as in [`Pallet::quote_sell`], so no state change is persisted.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<RouteQuote<T::AssetId, T::Balance>, DispatchError>
+	where
+		<T as frame_system::Config>::RuntimeEvent: TryInto<pallet_broadcast::Event<T>>,
+	{
+		with_transaction(|| {
+			let quote = Self::route_with_checks(asset_in, asset_out, route).and_then(|route| {
+				let mut amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+				amounts.reverse();
+				let amount_in = amounts.first().ok_or(Error::<T>::RouteCalculationFailed)?.amount_in;
+				let who = Self::router_account();
+				T::Currency::mint_into(asset_in, &who, amount_in)?;
+
+				Self::simulate(route.clone(), amounts, || {
+					Pallet::<T>::buy(
+						frame_system::RawOrigin::Signed(who).into(),
+						asset_in,
+						asset_out,
+						amount_out,
+						amount_in,
+						route,
+					)
+				})
+			});
+
+			TransactionOutcome::Rollback(quote)
+		})
+	}
+
+	fn simulate(
+		route: Vec<Trade<T::AssetId>>,
+		amounts: Vec<AmountInAndOut<T::Balance>>,
+		execute: impl FnOnce() -> DispatchResult,
+	) -> Result<RouteQuote<T::AssetId, T::Balance>, DispatchError>
+	where
+		<T as frame_system::Config>::RuntimeEvent: TryInto<pallet_broadcast::Event<T>>,
+	{
+		let event_count = frame_system::Pallet::<T>::event_count() as usize;
+
+		execute()?;
+
+		let mut fees = Vec::<(T::AssetId, T::Balance)>::new();
+		let trade_fees = frame_system::Pallet::<T>::read_events_no_consensus()
+			.skip(event_count)
+			.filter_map(|record| match record.event.try_into() {
+				Ok(pallet_broadcast::Event::<T>::Swapped { fees, .. }) => Some(fees),
+				_ => None,
+			})
+			.flatten();
+		for fee in trade_fees {
+			let asset: T::AssetId = fee.asset.into();
+			let amount: T::Balance = fee.amount.into();
+			match fees.iter_mut().find(|(fee_asset, _)| *fee_asset == asset) {
+				Some((_, total)) => *total = total.saturating_add(amount),
+				None => fees.push((asset, amount)),
+			}
+		}
+
+		let spot_price = Self::spot_price_with_fee(&route);
+
+		Ok(RouteQuote {
+			route,
+			amounts,
+			fees,
+			spot_price,
+		})
+	}
+
+	/// Checks if the route can still be executed in both directions, the same way as `set_route` validates a new route.
+	pub fn is_route_valid(route: &[Trade<T::AssetId>]) -> bool {
+		Self::validate_route(route).is_ok()
+	}
+
+	/// Returns the stored routes which can not be executed anymore.
+	pub fn broken_routes() -> Vec<(AssetPair<T::AssetId>, Vec<Trade<T::AssetId>>)> {
+		Routes::<T>::iter()
+			.map(|(asset_pair, route)| (asset_pair, route.into_inner()))
+			.filter(|(_, route)| !Self::is_route_valid(route))
+			.collect()
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
@@ -846,6 +1317,50 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Pallet::<T>::buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
 	}
 
+	fn sell_percentage(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		percentage: Permill,
+		min_amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		Pallet::<T>::sell_percentage(origin, asset_in, asset_out, percentage, min_amount_out, route)
+	}
+
+	fn buy_to_balance(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		target_balance: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		Pallet::<T>::buy_to_balance(origin, asset_in, asset_out, target_balance, max_amount_in, route)
+	}
+
+	fn sell_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+	) -> DispatchResult {
+		Pallet::<T>::sell_split(origin, asset_in, asset_out, amount_in, min_amount_out, routes)
+	}
+
+	fn buy_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+	) -> DispatchResult {
+		Pallet::<T>::buy_split(origin, asset_in, asset_out, amount_out, max_amount_in, routes)
+	}
+
 	fn calculate_sell_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -913,6 +1428,50 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Ok(())
 	}
 
+	fn sell_percentage(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_percentage: Permill,
+		_min_amount_out: T::Balance,
+		_route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn buy_to_balance(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_target_balance: T::Balance,
+		_max_amount_in: T::Balance,
+		_route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sell_split(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_amount_in: T::Balance,
+		_min_amount_out: T::Balance,
+		_routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn buy_split(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_amount_out: T::Balance,
+		_max_amount_in: T::Balance,
+		_routes: Vec<(Permill, Vec<Trade<T::AssetId>>)>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
 	fn calculate_sell_trade_amounts(
 		_route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
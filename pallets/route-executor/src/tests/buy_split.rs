@@ -0,0 +1,116 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use sp_runtime::Permill;
+
+fn omnipool_and_xyk_routes() -> Vec<(Permill, Vec<Trade<AssetId>>)> {
+	vec![
+		(Permill::from_percent(50), default_omnipool_route()),
+		(Permill::from_percent(50), vec![HDX_AUSD_TRADE_IN_XYK]),
+	]
+}
+
+#[test]
+fn buy_split_should_execute_all_routes_and_emit_aggregated_event() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 100;
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			omnipool_and_xyk_routes()
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::Omnipool, 5, HDX, AUSD), (PoolType::XYK, 5, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: OMNIPOOL_BUY_CALCULATION_RESULT + XYK_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_buy_rounding_remainder_on_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let routes = vec![
+			(Permill::from_parts(333_333), default_omnipool_route()),
+			(Permill::from_parts(666_667), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			100,
+			routes
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::Omnipool, 3, HDX, AUSD), (PoolType::XYK, 7, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				OMNIPOOL_BUY_CALCULATION_RESULT + XYK_BUY_CALCULATION_RESULT - 1,
+				omnipool_and_xyk_routes()
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_shares_do_not_add_up_to_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		let routes = vec![
+			(Permill::from_percent(60), default_omnipool_route()),
+			(Permill::from_percent(60), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 100, routes),
+			Error::<Test>::InvalidRouteSplit
+		);
+	});
+}
@@ -0,0 +1,111 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiCurrency;
+
+#[test]
+fn buy_to_balance_should_buy_missing_amount_to_target_balance() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 1000), (ALICE, AUSD, 4)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let target_balance = 10;
+			let limit = 5;
+
+			//Act
+			assert_ok!(Router::buy_to_balance(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				target_balance,
+				limit,
+				vec![HDX_AUSD_TRADE_IN_XYK]
+			));
+
+			//Assert
+			assert_executed_buy_trades(vec![(PoolType::XYK, 6, HDX, AUSD)]);
+			assert_eq!(Currencies::free_balance(AUSD, &ALICE), target_balance);
+			expect_events(vec![Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: XYK_BUY_CALCULATION_RESULT,
+				amount_out: 6,
+				event_id: 0,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn buy_to_balance_should_work_with_omnipool_when_no_specified_or_onchain_route_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::buy_to_balance(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			10,
+			OMNIPOOL_BUY_CALCULATION_RESULT,
+			vec![]
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::Omnipool, 10, HDX, DOT)]);
+	});
+}
+
+#[test]
+fn buy_to_balance_should_fail_when_target_balance_is_already_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 1000), (ALICE, AUSD, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Router::buy_to_balance(
+					RuntimeOrigin::signed(ALICE),
+					HDX,
+					AUSD,
+					10,
+					5,
+					vec![HDX_AUSD_TRADE_IN_XYK]
+				),
+				Error::<Test>::TargetBalanceReached
+			);
+		});
+}
+
+#[test]
+fn buy_to_balance_should_fail_when_max_limit_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::buy_to_balance(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				OMNIPOOL_BUY_CALCULATION_RESULT - 1,
+				vec![]
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
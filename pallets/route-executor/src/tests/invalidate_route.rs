@@ -0,0 +1,165 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::RouteProvider;
+use hydradx_traits::router::{AssetPair, PoolType};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn xyk_route() -> Vec<Trade<AssetId>> {
+	vec![Trade {
+		pool: PoolType::XYK,
+		asset_in: HDX,
+		asset_out: AUSD,
+	}]
+}
+
+#[test]
+fn invalidate_route_should_remove_route_when_route_is_broken() {
+	ExtBuilder::default()
+		.with_broken_pools(vec![PoolType::XYK])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let asset_pair = AssetPair::new(HDX, AUSD);
+			assert_ok!(Router::force_insert_route(
+				RuntimeOrigin::root(),
+				asset_pair,
+				xyk_route()
+			));
+
+			//Act
+			assert_ok!(Router::invalidate_route(RuntimeOrigin::signed(ALICE), asset_pair));
+
+			//Assert
+			assert_eq!(Router::route(asset_pair), None);
+			assert_eq!(Router::get_route(asset_pair), default_omnipool_route());
+
+			expect_events(vec![
+				Event::RouteUpdated {
+					asset_ids: vec![HDX, AUSD],
+				}
+				.into(),
+				Event::RouteInvalidated {
+					asset_ids: vec![HDX, AUSD],
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn invalidate_route_should_work_when_asset_pair_is_not_ordered() {
+	ExtBuilder::default()
+		.with_broken_pools(vec![PoolType::XYK])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Router::force_insert_route(
+				RuntimeOrigin::root(),
+				AssetPair::new(HDX, AUSD),
+				xyk_route()
+			));
+
+			//Act
+			assert_ok!(Router::invalidate_route(
+				RuntimeOrigin::signed(ALICE),
+				AssetPair::new(AUSD, HDX)
+			));
+
+			//Assert
+			assert_eq!(Router::route(AssetPair::new(HDX, AUSD)), None);
+		});
+}
+
+#[test]
+fn invalidate_route_should_fail_when_route_is_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let asset_pair = AssetPair::new(HDX, AUSD);
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			asset_pair,
+			xyk_route()
+		));
+
+		//Act and assert
+		assert_noop!(
+			Router::invalidate_route(RuntimeOrigin::signed(ALICE), asset_pair),
+			Error::<Test>::RouteIsValid
+		);
+	});
+}
+
+#[test]
+fn invalidate_route_should_fail_when_no_route_is_stored() {
+	ExtBuilder::default()
+		.with_broken_pools(vec![PoolType::Omnipool])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				Router::invalidate_route(RuntimeOrigin::signed(ALICE), AssetPair::new(HDX, AUSD)),
+				Error::<Test>::RouteNotFound
+			);
+		});
+}
+
+#[test]
+fn invalidate_route_should_fail_when_called_with_unsigned_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::invalidate_route(RuntimeOrigin::none(), AssetPair::new(HDX, AUSD)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn broken_routes_should_return_only_routes_which_can_not_be_executed() {
+	ExtBuilder::default()
+		.with_broken_pools(vec![PoolType::XYK])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let lbp_route = vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: HDX,
+				asset_out: DOT,
+			}];
+			assert_ok!(Router::force_insert_route(
+				RuntimeOrigin::root(),
+				AssetPair::new(HDX, AUSD),
+				xyk_route()
+			));
+			assert_ok!(Router::force_insert_route(
+				RuntimeOrigin::root(),
+				AssetPair::new(HDX, DOT),
+				lbp_route
+			));
+
+			//Act
+			let broken_routes = Router::broken_routes();
+
+			//Assert
+			assert_eq!(broken_routes, vec![(AssetPair::new(HDX, AUSD), xyk_route())]);
+		});
+}
@@ -282,6 +282,8 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(PoolType<AssetId>, Vec<AssetId>)>,
+	broken_pools: Vec<PoolType<AssetId>>,
 }
 
 // Returns default values for genesis config
@@ -289,6 +291,8 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			pools: vec![],
+			broken_pools: vec![],
 		}
 	}
 }
@@ -299,6 +303,16 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<(PoolType<AssetId>, Vec<AssetId>)>) -> Self {
+		self.pools = pools;
+		self
+	}
+
+	pub fn with_broken_pools(mut self, pools: Vec<PoolType<AssetId>>) -> Self {
+		self.broken_pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -330,6 +344,9 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		POOLS.with(|v| *v.borrow_mut() = self.pools);
+		BROKEN_POOLS.with(|v| *v.borrow_mut() = self.broken_pools);
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -340,10 +357,16 @@ type ExecutedTradeInfo = (PoolType<AssetId>, Balance, AssetId, AssetId);
 thread_local! {
 	pub static EXECUTED_SELLS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
 	pub static EXECUTED_BUYS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
+	pub static POOLS: RefCell<Vec<(PoolType<AssetId>, Vec<AssetId>)>> = RefCell::new(Vec::default());
+	pub static BROKEN_POOLS: RefCell<Vec<PoolType<AssetId>>> = RefCell::new(Vec::default());
 }
 
 type OriginForRuntime = OriginFor<Test>;
 
+fn is_broken_pool(pool_type: PoolType<AssetId>) -> bool {
+	BROKEN_POOLS.with(|v| v.borrow().contains(&pool_type))
+}
+
 macro_rules! impl_fake_executor {
 	($pool_struct:ident, $pool_type: pat, $sell_calculation_result: expr, $buy_calculation_result: expr) => {
 		impl TradeExecution<OriginForRuntime, AccountId, AssetId, Balance> for $pool_struct {
@@ -359,7 +382,7 @@ macro_rules! impl_fake_executor {
 					return Err(ExecutorError::NotSupported);
 				}
 
-				if amount_in == INVALID_CALCULATION_AMOUNT {
+				if amount_in == INVALID_CALCULATION_AMOUNT || is_broken_pool(pool_type) {
 					return Err(ExecutorError::Error(DispatchError::Other("Some error happened")));
 				}
 
@@ -376,7 +399,7 @@ macro_rules! impl_fake_executor {
 					return Err(ExecutorError::NotSupported);
 				}
 
-				if amount_out == INVALID_CALCULATION_AMOUNT {
+				if amount_out == INVALID_CALCULATION_AMOUNT || is_broken_pool(pool_type) {
 					return Err(ExecutorError::Error(DispatchError::Other("Some error happened")));
 				}
 
@@ -474,6 +497,16 @@ macro_rules! impl_fake_executor {
 			) -> Result<FixedU128, ExecutorError<Self::Error>> {
 				Ok(FixedU128::from_rational(1, 10))
 			}
+
+			fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+				POOLS.with(|v| {
+					v.borrow()
+						.iter()
+						.filter(|(pool_type, _)| matches!(pool_type, $pool_type))
+						.cloned()
+						.collect()
+				})
+			}
 		}
 	};
 }
@@ -1,7 +1,14 @@
 pub mod buy;
+pub mod buy_split;
+pub mod buy_to_balance;
 pub mod force_insert_route;
+pub mod invalidate_route;
 pub mod mock;
+pub mod quote;
+pub mod route_discovery;
 pub mod sell;
 pub mod sell_all;
+pub mod sell_percentage;
+pub mod sell_split;
 pub mod set_route;
 pub mod spot_price;
@@ -0,0 +1,150 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{AmountInAndOut, PoolType, RouteQuote};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn quote_sell_should_return_amounts_and_spot_price_of_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let trades = vec![
+			HDX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: AUSD,
+				asset_out: MOVR,
+			},
+		];
+
+		//Act
+		let quote = Router::quote_sell(HDX, MOVR, amount_to_sell, trades.clone());
+
+		//Assert
+		assert_eq!(
+			quote,
+			Ok(RouteQuote {
+				route: trades,
+				amounts: vec![
+					AmountInAndOut {
+						amount_in: amount_to_sell,
+						amount_out: XYK_SELL_CALCULATION_RESULT,
+					},
+					AmountInAndOut {
+						amount_in: XYK_SELL_CALCULATION_RESULT,
+						amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+					},
+				],
+				fees: vec![],
+				spot_price: Some(FixedU128::from_rational(1, 100)),
+			})
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_use_default_route_when_no_route_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+
+		//Act
+		let quote = Router::quote_sell(HDX, AUSD, amount_to_sell, vec![]).unwrap();
+
+		//Assert
+		assert_eq!(quote.route, default_omnipool_route());
+		assert_eq!(
+			quote.amounts,
+			vec![AmountInAndOut {
+				amount_in: amount_to_sell,
+				amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			}]
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_not_persist_any_state_change() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let router_account = Router::router_account();
+		let pool_ausd_balance = Currencies::free_balance(AUSD, &ASSET_PAIR_ACCOUNT);
+		let event_count = System::event_count();
+
+		//Act
+		assert_ok!(Router::quote_sell(HDX, AUSD, 10, vec![HDX_AUSD_TRADE_IN_XYK]));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(HDX, &router_account), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &router_account), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &ASSET_PAIR_ACCOUNT), pool_ausd_balance);
+		assert_eq!(System::event_count(), event_count);
+	});
+}
+
+#[test]
+fn quote_buy_should_return_amounts_in_route_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let trades = vec![
+			HDX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: AUSD,
+				asset_out: MOVR,
+			},
+		];
+
+		//Act
+		let quote = Router::quote_buy(HDX, MOVR, amount_to_buy, trades.clone()).unwrap();
+
+		//Assert
+		assert_eq!(quote.route, trades);
+		assert_eq!(
+			quote.amounts,
+			vec![
+				AmountInAndOut {
+					amount_in: XYK_BUY_CALCULATION_RESULT,
+					amount_out: OMNIPOOL_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+					amount_out: amount_to_buy,
+				},
+			]
+		);
+		assert_eq!(Currencies::free_balance(MOVR, &Router::router_account()), 0);
+	});
+}
+
+#[test]
+fn quote_should_fail_when_route_does_not_match_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::quote_sell(HDX, MOVR, 10, vec![HDX_AUSD_TRADE_IN_XYK]),
+			Error::<Test>::InvalidRoute
+		);
+		assert_noop!(Router::quote_buy(HDX, HDX, 10, vec![]), Error::<Test>::NotAllowed);
+	});
+}
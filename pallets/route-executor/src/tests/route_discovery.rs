@@ -0,0 +1,229 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn discover_sell_route_should_find_direct_route_with_best_amount_out() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, AUSD, DOT]),
+			(PoolType::XYK, vec![HDX, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let route = Router::discover_sell_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(route, Ok(vec![HDX_AUSD_TRADE_IN_XYK]));
+		});
+}
+
+#[test]
+fn discover_sell_route_should_find_multi_hop_route_when_it_gives_more_amount_out() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, AUSD, KSM]),
+			(PoolType::XYK, vec![KSM, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let route = Router::discover_sell_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(
+				route,
+				Ok(vec![
+					Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: KSM,
+					},
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: KSM,
+						asset_out: AUSD,
+					},
+				])
+			);
+		});
+}
+
+#[test]
+fn discover_sell_route_should_not_trade_twice_in_same_pool() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::Omnipool, vec![HDX, KSM, AUSD])])
+		.build()
+		.execute_with(|| {
+			//Act
+			let route = Router::discover_sell_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(
+				route,
+				Ok(vec![Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: AUSD,
+				}])
+			);
+		});
+}
+
+#[test]
+fn discover_sell_route_should_find_route_with_max_number_of_trades() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::XYK, vec![HDX, AUSD]),
+			(PoolType::XYK, vec![AUSD, MOVR]),
+			(PoolType::XYK, vec![MOVR, KSM]),
+			(PoolType::XYK, vec![KSM, RMRK]),
+			(PoolType::XYK, vec![RMRK, SDN]),
+			(PoolType::XYK, vec![SDN, DOT]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let route = Router::discover_sell_route(HDX, SDN, 100);
+
+			//Assert
+			assert_ok!(&route);
+			assert_eq!(route.unwrap().len(), 5);
+		});
+}
+
+#[test]
+fn discover_sell_route_should_fail_when_route_exceeds_max_number_of_trades() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::XYK, vec![HDX, AUSD]),
+			(PoolType::XYK, vec![AUSD, MOVR]),
+			(PoolType::XYK, vec![MOVR, KSM]),
+			(PoolType::XYK, vec![KSM, RMRK]),
+			(PoolType::XYK, vec![RMRK, SDN]),
+			(PoolType::XYK, vec![SDN, DOT]),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(Router::discover_sell_route(HDX, DOT, 100), Error::<Test>::RouteNotFound);
+		});
+}
+
+#[test]
+fn discover_sell_route_should_fail_when_assets_are_not_connected() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, AUSD]),
+			(PoolType::XYK, vec![KSM, DOT]),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(Router::discover_sell_route(HDX, DOT, 100), Error::<Test>::RouteNotFound);
+		});
+}
+
+#[test]
+fn discover_sell_route_should_fail_when_assets_are_same() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::Omnipool, vec![HDX, AUSD])])
+		.build()
+		.execute_with(|| {
+			assert_noop!(Router::discover_sell_route(HDX, HDX, 100), Error::<Test>::NotAllowed);
+		});
+}
+
+#[test]
+fn discover_buy_route_should_find_route_with_least_amount_in() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, AUSD]),
+			(PoolType::XYK, vec![HDX, KSM]),
+			(
+				PoolType::Stableswap(STABLE_SHARE_ASSET),
+				vec![KSM, AUSD, STABLE_SHARE_ASSET],
+			),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let route = Router::discover_buy_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(
+				route,
+				Ok(vec![
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: HDX,
+						asset_out: KSM,
+					},
+					Trade {
+						pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+						asset_in: KSM,
+						asset_out: AUSD,
+					},
+				])
+			);
+		});
+}
+
+#[test]
+fn discover_buy_route_should_fail_when_assets_are_not_connected() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::Omnipool, vec![HDX, AUSD])])
+		.build()
+		.execute_with(|| {
+			assert_noop!(Router::discover_buy_route(HDX, DOT, 100), Error::<Test>::RouteNotFound);
+		});
+}
+
+#[test]
+fn discovered_route_should_be_executable() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, AUSD, KSM]),
+			(PoolType::XYK, vec![KSM, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let amount_to_sell = 10;
+			let route = Router::discover_sell_route(HDX, AUSD, amount_to_sell).unwrap();
+
+			//Act
+			assert_ok!(Router::sell(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				amount_to_sell,
+				XYK_SELL_CALCULATION_RESULT,
+				route
+			));
+
+			//Assert
+			assert_executed_sell_trades(vec![
+				(PoolType::Omnipool, amount_to_sell, HDX, KSM),
+				(PoolType::XYK, OMNIPOOL_SELL_CALCULATION_RESULT, KSM, AUSD),
+			]);
+		});
+}
@@ -0,0 +1,141 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
+
+#[test]
+fn sell_percentage_should_sell_part_of_user_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let limit = 5;
+		let trades = vec![HDX_AUSD_TRADE_IN_XYK];
+		let alice_balance = Currencies::free_balance(HDX, &ALICE);
+		let amount_to_sell = alice_balance / 4;
+
+		//Act
+		assert_ok!(Router::sell_percentage(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			Permill::from_percent(25),
+			limit,
+			trades
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, amount_to_sell, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_percentage_should_sell_all_user_balance_when_percentage_is_one() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let limit = 5;
+			let alice_nonnative_balance = Currencies::free_balance(KSM, &ALICE);
+			let trades = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: KSM,
+				asset_out: AUSD,
+			}];
+
+			//Act
+			assert_ok!(Router::sell_percentage(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				AUSD,
+				Permill::one(),
+				limit,
+				trades
+			));
+
+			//Assert
+			assert_executed_sell_trades(vec![(PoolType::XYK, alice_nonnative_balance, KSM, AUSD)]);
+		});
+}
+
+#[test]
+fn sell_percentage_should_work_with_omnipool_when_no_specified_or_onchain_route_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let alice_balance = Currencies::free_balance(HDX, &ALICE);
+
+		//Act
+		assert_ok!(Router::sell_percentage(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			Permill::from_percent(50),
+			1,
+			vec![]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::Omnipool, alice_balance / 2, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_percentage_should_fail_when_min_limit_to_receive_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_percentage(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				Permill::from_percent(25),
+				XYK_SELL_CALCULATION_RESULT + 1,
+				vec![HDX_AUSD_TRADE_IN_XYK]
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_percentage_should_fail_when_called_with_non_signed_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_percentage(
+				RuntimeOrigin::none(),
+				HDX,
+				AUSD,
+				Permill::from_percent(25),
+				1,
+				vec![HDX_AUSD_TRADE_IN_XYK]
+			),
+			BadOrigin
+		);
+	});
+}
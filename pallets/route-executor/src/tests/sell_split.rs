@@ -0,0 +1,200 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use sp_runtime::Permill;
+
+fn omnipool_and_xyk_routes() -> Vec<(Permill, Vec<Trade<AssetId>>)> {
+	vec![
+		(Permill::from_percent(50), default_omnipool_route()),
+		(Permill::from_percent(50), vec![HDX_AUSD_TRADE_IN_XYK]),
+	]
+}
+
+#[test]
+fn sell_split_should_execute_all_routes_and_emit_aggregated_event() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = 5;
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			omnipool_and_xyk_routes()
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::Omnipool, 5, HDX, AUSD), (PoolType::XYK, 5, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: OMNIPOOL_SELL_CALCULATION_RESULT + XYK_SELL_CALCULATION_RESULT,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_split_should_sell_rounding_remainder_on_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let routes = vec![
+			(Permill::from_parts(333_333), default_omnipool_route()),
+			(Permill::from_parts(666_667), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			1,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::Omnipool, 3, HDX, AUSD), (PoolType::XYK, 7, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_use_onchain_or_default_route_when_route_is_not_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let routes = vec![
+			(Permill::from_percent(40), vec![]),
+			(Permill::from_percent(60), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			1,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::Omnipool, 4, HDX, AUSD), (PoolType::XYK, 6, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				OMNIPOOL_SELL_CALCULATION_RESULT + XYK_SELL_CALCULATION_RESULT + 1,
+				omnipool_and_xyk_routes()
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_shares_do_not_add_up_to_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		let routes = vec![
+			(Permill::from_percent(50), default_omnipool_route()),
+			(Permill::from_percent(40), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidRouteSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_has_zero_share() {
+	ExtBuilder::default().build().execute_with(|| {
+		let routes = vec![
+			(Permill::one(), default_omnipool_route()),
+			(Permill::zero(), vec![HDX_AUSD_TRADE_IN_XYK]),
+		];
+
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidRouteSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_no_route_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, vec![]),
+			Error::<Test>::InvalidRouteSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_number_of_routes_exceeds_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let routes = vec![(Permill::from_percent(10), default_omnipool_route()); 10];
+
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidRouteSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		let routes = vec![
+			(Permill::from_percent(50), default_omnipool_route()),
+			(
+				Permill::from_percent(50),
+				vec![Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: DOT,
+				}],
+			),
+		];
+
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
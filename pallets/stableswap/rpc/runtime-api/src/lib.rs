@@ -0,0 +1,53 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the stableswap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::stableswap::AssetAmount;
+pub use pallet_stableswap::types::{AddLiquidityQuote, PoolDetails, TradeQuote};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query stableswap pools and trade quotes.
+	pub trait StableswapApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Get current state of all stableswap pools.
+		fn pools() -> Vec<PoolDetails<AssetId, Balance>>;
+
+		/// Get current state of a pool. Returns `None` if the pool does not exist.
+		fn pool(pool_id: AssetId) -> Option<PoolDetails<AssetId, Balance>>;
+
+		/// Get amount out and fee of selling `amount_in` of `asset_in` for `asset_out`.
+		fn quote_sell(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Get amount in and fee of buying `amount_out` of `asset_out` for `asset_in`.
+		fn quote_buy(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Get amount of shares received for adding given amounts of pool assets.
+		fn quote_add_liquidity(pool_id: AssetId, assets: Vec<AssetAmount<AssetId>>) -> Result<AddLiquidityQuote<Balance>, DispatchError>;
+
+		/// Get amount of asset received and fee for removing `share_amount` of shares in a single asset.
+		fn quote_remove_liquidity_one_asset(pool_id: AssetId, asset_id: AssetId, share_amount: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Get amounts of all pool assets received for removing `share_amount` of shares proportionally.
+		fn quote_remove_liquidity(pool_id: AssetId, share_amount: Balance) -> Result<Vec<AssetAmount<AssetId>>, DispatchError>;
+	}
+}
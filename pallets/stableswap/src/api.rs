@@ -0,0 +1,239 @@
+use crate::types::{AddLiquidityQuote, Balance, PoolDetails, Tradability, TradeQuote};
+use crate::{Config, Error, Pallet, PoolOffPegFeeMultiplier, Pools, D_ITERATIONS, Y_ITERATIONS};
+use frame_support::ensure;
+use frame_support::pallet_prelude::Get;
+use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::stableswap::AssetAmount;
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Returns details of all pools.
+	pub fn pools_details() -> Vec<PoolDetails<T::AssetId, Balance>> {
+		Pools::<T>::iter_keys()
+			.filter_map(|pool_id| Self::pool_details(pool_id))
+			.collect()
+	}
+
+	/// Returns current state of the pool or `None` if the pool does not exist.
+	pub fn pool_details(pool_id: T::AssetId) -> Option<PoolDetails<T::AssetId, Balance>> {
+		let pool = Pools::<T>::get(pool_id)?;
+		let reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id))?;
+		let amplification = Self::get_amplification(&pool);
+		let (fee, pegs) = Self::get_updated_pegs(pool_id, &pool).ok()?;
+		let share_issuance = T::Currency::total_issuance(pool_id);
+
+		let virtual_share_price = if share_issuance.is_zero() {
+			None
+		} else {
+			let d = hydra_dx_math::stableswap::calculate_d::<D_ITERATIONS>(&reserves, amplification, &pegs)?;
+			FixedU128::checked_from_rational(d, share_issuance)
+		};
+
+		Some(PoolDetails {
+			pool_id,
+			assets: pool.assets.into_inner(),
+			amplification,
+			fee,
+			offpeg_fee_multiplier: PoolOffPegFeeMultiplier::<T>::get(pool_id),
+			pegs,
+			reserves: hydra_dx_math::stableswap::normalize_reserves(&reserves),
+			share_issuance,
+			virtual_share_price,
+		})
+	}
+
+	/// Calculates amount out and fee of selling `amount_in` of `asset_in` for `asset_out`.
+	pub fn quote_sell(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		Self::ensure_trade_quote_allowed(pool_id, asset_in, asset_out, amount_in)?;
+
+		let (amount_out, fee) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, false)?;
+
+		Ok(TradeQuote {
+			amount_in,
+			amount_out,
+			fee,
+		})
+	}
+
+	/// Calculates amount in and fee of buying `amount_out` of `asset_out` for `asset_in`.
+	pub fn quote_buy(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		Self::ensure_trade_quote_allowed(pool_id, asset_in, asset_out, amount_out)?;
+
+		let (amount_in, fee) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, false)?;
+
+		Ok(TradeQuote {
+			amount_in,
+			amount_out,
+			fee,
+		})
+	}
+
+	/// Calculates amount of shares received for adding given amounts of pool assets.
+	pub fn quote_add_liquidity(
+		pool_id: T::AssetId,
+		assets: &[AssetAmount<T::AssetId>],
+	) -> Result<AddLiquidityQuote<Balance>, DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(assets.len() <= pool.assets.len(), Error::<T>::MaxAssetsExceeded);
+
+		let mut added_assets = BTreeMap::<T::AssetId, Balance>::new();
+		for asset in assets.iter() {
+			ensure!(
+				Self::is_asset_allowed(pool_id, asset.asset_id, Tradability::ADD_LIQUIDITY),
+				Error::<T>::NotAllowed
+			);
+			ensure!(
+				asset.amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				added_assets.insert(asset.asset_id, asset.amount).is_none(),
+				Error::<T>::IncorrectAssets
+			);
+		}
+
+		let pool_account = Self::pool_account(pool_id);
+		let initial_reserves = pool
+			.reserves_with_decimals::<T>(&pool_account)
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		let mut updated_reserves = Vec::with_capacity(initial_reserves.len());
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
+			let amount = if let Some(liq_added) = added_assets.remove(pool_asset) {
+				reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?
+			} else {
+				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
+				reserve.amount
+			};
+			updated_reserves.push(AssetReserve::new(amount, reserve.decimals));
+		}
+		ensure!(added_assets.is_empty(), Error::<T>::AssetNotInPool);
+
+		let amplification = Self::get_amplification(&pool);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		let (trade_fee, asset_pegs) = Self::get_updated_pegs(pool_id, &pool)?;
+		let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)?;
+		let (shares, fees) = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
+			&initial_reserves,
+			&updated_reserves,
+			amplification,
+			share_issuance,
+			trade_fee,
+			&asset_pegs,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(AddLiquidityQuote { shares, fees })
+	}
+
+	/// Calculates amount of `asset_id` received and fee for removing `share_amount` of shares.
+	pub fn quote_remove_liquidity_one_asset(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		share_amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(
+			Self::is_asset_allowed(pool_id, asset_id, Tradability::REMOVE_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+		ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+		let reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		ensure!(
+			share_issuance == share_amount || share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidityRemaining
+		);
+
+		let amplification = Self::get_amplification(&pool);
+		let (trade_fee, asset_pegs) = Self::get_updated_pegs(pool_id, &pool)?;
+		let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &reserves, &asset_pegs)?;
+		let (amount_out, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&reserves,
+			share_amount,
+			asset_idx,
+			share_issuance,
+			amplification,
+			trade_fee,
+			&asset_pegs,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(TradeQuote {
+			amount_in: share_amount,
+			amount_out,
+			fee,
+		})
+	}
+
+	/// Calculates amounts of all pool assets received for removing `share_amount` of shares proportionally.
+	pub fn quote_remove_liquidity(
+		pool_id: T::AssetId,
+		share_amount: Balance,
+	) -> Result<Vec<AssetAmount<T::AssetId>>, DispatchError> {
+		ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let pool_account = Self::pool_account(pool_id);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		ensure!(share_amount <= share_issuance, Error::<T>::InsufficientShares);
+
+		pool.assets
+			.iter()
+			.map(|asset_id| {
+				ensure!(
+					Self::is_asset_allowed(pool_id, *asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+				let reserve = T::Currency::free_balance(*asset_id, &pool_account);
+				let amount = if share_amount == share_issuance {
+					reserve
+				} else {
+					hydra_dx_math::stableswap::calculate_liquidity_out(reserve, share_amount, share_issuance)
+						.ok_or(ArithmeticError::Overflow)?
+				};
+				Ok(AssetAmount {
+					asset_id: *asset_id,
+					amount,
+				})
+			})
+			.collect()
+	}
+
+	fn ensure_trade_quote_allowed(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<(), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		ensure!(
+			Self::is_asset_allowed(pool_id, asset_in, Tradability::SELL)
+				&& Self::is_asset_allowed(pool_id, asset_out, Tradability::BUY),
+			Error::<T>::NotAllowed
+		);
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+
+		Ok(())
+	}
+}
@@ -37,6 +37,17 @@ const ASSET_ID_OFFSET: u32 = 2_000;
 fn setup_pool_with_initial_liquidity<T: Config>(
 	acc: &T::AccountId,
 ) -> (T::AssetId, PoolInfo<T::AssetId, BlockNumberFor<T>>)
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	setup_pool_with_assets::<T>(acc, MAX_ASSETS_IN_POOL)
+}
+
+fn setup_pool_with_assets<T: Config>(
+	acc: &T::AccountId,
+	asset_count: u32,
+) -> (T::AssetId, PoolInfo<T::AssetId, BlockNumberFor<T>>)
 where
 	T::AssetId: From<u32>,
 	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
@@ -47,7 +58,7 @@ where
 	let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
 	let mut added_liquidity: Vec<AssetAmount<T::AssetId>> = vec![];
 	let mut asset_ids: Vec<T::AssetId> = Vec::new();
-	for idx in 0..MAX_ASSETS_IN_POOL {
+	for idx in 0..asset_count {
 		let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
 		T::BenchmarkHelper::register_asset(asset_id, 12).expect("Failed to register asset");
 		asset_ids.push(asset_id);
@@ -58,14 +69,11 @@ where
 	}
 
 	// Worst case pool are those with pegs
-	let peg_source = vec![
-		PegSource::Value((1, 1)),
+	let mut peg_source = vec![PegSource::Value((1, 1))];
+	peg_source.resize(
+		asset_count as usize,
 		PegSource::Oracle((*b"benchmar", OraclePeriod::LastBlock)),
-		PegSource::Oracle((*b"benchmar", OraclePeriod::LastBlock)),
-		PegSource::Oracle((*b"benchmar", OraclePeriod::LastBlock)),
-		PegSource::Oracle((*b"benchmar", OraclePeriod::LastBlock)),
-	];
-	assert_eq!(peg_source.len() as u32, MAX_ASSETS_IN_POOL);
+	);
 	let first_asset_id = asset_ids[0];
 	for asset_id in asset_ids.iter().skip(1) {
 		T::BenchmarkHelper::register_asset_peg((first_asset_id, *asset_id), (1u128, 2u128), *b"benchmar")
@@ -504,5 +512,51 @@ benchmarks! {
 		assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Stableswap(pool_id), asset_in, asset_out).is_ok());
 	}
 
+	add_pool_asset {
+		let lp_provider: T::AccountId = T::PoolAssetLiquidityAccount::get();
+		let (pool_id, pool) = setup_pool_with_assets::<T>(&lp_provider, MAX_ASSETS_IN_POOL - 1);
+
+		let asset_id: T::AssetId = (MAX_ASSETS_IN_POOL - 1 + ASSET_ID_OFFSET).into();
+		T::BenchmarkHelper::register_asset(asset_id, 12)?;
+		T::BenchmarkHelper::register_asset_peg((pool.assets[0], asset_id), (1u128, 2u128), *b"benchmar")?;
+		T::Currency::update_balance(asset_id, &lp_provider, 1_000_000_000_000_000_000i128)?;
+
+		let shares_before = T::Currency::free_balance(pool_id, &lp_provider);
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let peg_source = Some(PegSource::Oracle((*b"benchmar", OraclePeriod::LastBlock)));
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id, 1_000_000_000_000_000u128, peg_source)
+	verify {
+		assert_eq!(crate::Pallet::<T>::pools(pool_id).unwrap().assets.last(), Some(&asset_id));
+		assert!(T::Currency::free_balance(pool_id, &lp_provider) > shares_before);
+	}
+
+	remove_pool_asset {
+		let lp_provider: T::AccountId = T::PoolAssetLiquidityAccount::get();
+		let (pool_id, pool) = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+
+		let asset_id: T::AssetId = *pool.assets.last().unwrap();
+		crate::Pallet::<T>::set_asset_tradable_state(RawOrigin::Root.into(),
+			pool_id,
+			asset_id,
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY,
+		)?;
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id)
+	verify {
+		assert!(crate::Pallet::<T>::pools(pool_id).unwrap().find_asset(asset_id).is_none());
+		assert_eq!(T::Currency::free_balance(asset_id, &crate::Pallet::<T>::pool_account(pool_id)), 0u128);
+	}
+
+	set_pool_dynamic_fee {
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let (pool_id, _) = setup_pool_with_initial_liquidity::<T>(&lp_provider);
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let multiplier = FixedU128::from(10);
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Some(multiplier))
+	verify {
+		assert_eq!(crate::Pallet::<T>::pool_offpeg_fee_multiplier(pool_id), Some(multiplier));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -26,6 +26,22 @@
 //! Source of target peg for each asset must be provided. Either constant value or external oracle.
 //! First asset in the pool is considered as a base asset and all other assets are pegged to it. Therefore peg of the first asset must be 1.
 //!
+//! Peg of a yield-bearing token (eg. ERC-4626 vault share or aToken) can be read from an EVM contract. The contract's view
+//! function must return exchange rate of the token to the first asset as single `uint256` with configured number of decimals.
+//! The call is gas-bounded by `EvmPegRateProvider` and the peg still moves towards the returned rate by at most `max_peg_update` per block.
+//! Weight of the call, based on its gas limit, is charged for each such peg whenever pegs of the pool are recalculated.
+//!
+//! ### Dynamic fee
+//! Optionally, trade fee of a pool can scale with imbalance of traded assets (Curve-style off-peg fee multiplier).
+//! Trades in an imbalanced pool pay up to `offpeg_multiplier` times the pool's trade fee.
+//! The same applies to fee of adding liquidity and removing liquidity of a single asset, so an imbalanced trade
+//! cannot be replicated by adding one asset and withdrawing another at the base fee.
+//!
+//! ### Metapools
+//! A pool whose asset is a share token of another (base) stableswap pool is a metapool.
+//! Router trades of a metapool asset against an underlying asset of the base pool are executed in a single hop,
+//! by adding or removing liquidity of the base pool and trading its share token in the metapool.
+//!
 //! ### Stableswap Hooks
 //!
 //! Stableswap pallet supports multiple hooks which are triggerred on certain operations:
@@ -54,29 +70,34 @@
 //!
 //! When LP decides to withdraw liquidity, it receives selected asset or all assets proportionality.
 //!
+//! Assets can be added to or removed from an existing pool by `AuthorityOrigin`. Share issuance is adjusted
+//! according to the change of pool's D, so the value of existing shares is preserved.
+//!
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
 
 extern crate core;
 
-use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
 use frame_support::{ensure, require_transactional, transactional, PalletId};
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use hydradx_traits::{oracle::RawOracle, registry::Inspect, stableswap::StableswapAddLiquidity, AccountIdFor};
 pub use pallet::*;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedU128, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
 
+pub mod api;
 mod trade_execution;
 pub mod types;
 pub mod weights;
 
 use crate::types::{
-	Balance, BoundedPegs, PegSource, PegType, PoolInfo, PoolPegInfo, PoolState, StableswapHooks, Tradability,
+	Balance, BoundedPegs, EvmPegRateProvider, PegSource, PegType, PoolInfo, PoolPegInfo, PoolState, StableswapHooks,
+	Tradability,
 };
 use hydra_dx_math::stableswap::types::AssetReserve;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
@@ -162,6 +183,10 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Account controlled by governance which provides liquidity of assets added to pools by `add_pool_asset`
+		/// and receives remaining liquidity of assets removed from pools by `remove_pool_asset`.
+		type PoolAssetLiquidityAccount: Get<Self::AccountId>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -179,6 +204,9 @@ pub mod pallet {
 		/// It is how the mechanism is designed.
 		type TargetPegOracle: RawOracle<Self::AssetId, Balance, BlockNumberFor<Self>>;
 
+		/// Provider of exchange rates of yield-bearing tokens read from EVM contracts (if configured for pool).
+		type EvmPegRateProvider: EvmPegRateProvider;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -202,6 +230,11 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Off-peg fee multiplier of pools with dynamic fee enabled.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_offpeg_fee_multiplier)]
+	pub type PoolOffPegFeeMultiplier<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -271,6 +304,27 @@ pub mod pallet {
 		},
 		/// A pool has been destroyed.
 		PoolDestroyed { pool_id: T::AssetId },
+		/// New asset has been added to a pool.
+		PoolAssetAdded {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			provider: T::AccountId,
+			amount: Balance,
+			shares: Balance,
+		},
+		/// Asset has been removed from a pool.
+		PoolAssetRemoved {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			beneficiary: T::AccountId,
+			amount: Balance,
+			shares: Balance,
+		},
+		/// Dynamic fee of a pool has been enabled, updated or disabled.
+		DynamicFeeUpdated {
+			pool_id: T::AssetId,
+			offpeg_multiplier: Option<FixedU128>,
+		},
 	}
 
 	#[pallet::error]
@@ -356,6 +410,18 @@ pub mod pallet {
 
 		/// Creating pool with pegs is not allowed for asset with different decimals.
 		IncorrectAssetDecimals,
+
+		/// Asset is already in the pool.
+		AssetAlreadyInPool,
+
+		/// Selling and adding liquidity of the asset must be disabled before it can be removed from the pool.
+		AssetNotFrozen,
+
+		/// Off-peg fee multiplier must be greater than one.
+		InvalidFeeMultiplier,
+
+		/// Failed to retrieve peg rate from EVM contract.
+		MissingEvmPegRate,
 	}
 
 	#[pallet::call]
@@ -515,7 +581,8 @@ pub mod pallet {
 		/// Emits `pallet_broadcast::Swapped` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		#[deprecated(note = "Use add_assets_liquidity instead")]
 		pub fn add_liquidity(
@@ -547,7 +614,8 @@ pub mod pallet {
 		/// Emits `pallet_broadcast::Swapped` event when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn add_liquidity_shares(
 			origin: OriginFor<T>,
@@ -587,7 +655,8 @@ pub mod pallet {
 		/// Emits `pallet_broadcast::Swapped` event when successful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -624,6 +693,7 @@ pub mod pallet {
 
 			let amplification = Self::get_amplification(&pool);
 			let (trade_fee, asset_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+			let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)?;
 
 			//Calculate how much asset user will receive. Note that the fee is already subtracted from the amount.
 			let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
@@ -689,7 +759,8 @@ pub mod pallet {
 		/// Emits `pallet_broadcast::Swapped` event when successful.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_asset_amount()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn withdraw_asset_amount(
 			origin: OriginFor<T>,
@@ -717,6 +788,7 @@ pub mod pallet {
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
 			let (trade_fee, asset_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+			let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)?;
 
 			// Calculate how much shares user needs to provide to receive `amount` of asset.
 			let (shares, fees) = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
@@ -785,7 +857,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -875,7 +948,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -1024,7 +1098,8 @@ pub mod pallet {
 		/// - Ensures that the pool's reserves are updated correctly after liquidity removal.
 		/// - Ensures that the pool's invariant is maintained.
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id)))]
 		#[transactional]
 		pub fn remove_liquidity(
 			origin: OriginFor<T>,
@@ -1101,6 +1176,7 @@ pub mod pallet {
 				// Remove the pool.
 				Pools::<T>::remove(pool_id);
 				PoolPegs::<T>::remove(pool_id);
+				PoolOffPegFeeMultiplier::<T>::remove(pool_id);
 				let _ = AssetTradability::<T>::clear_prefix(pool_id, MAX_ASSETS_IN_POOL, None);
 				T::DustAccountHandler::remove_account(&Self::pool_account(pool_id))?;
 				Self::deposit_event(Event::PoolDestroyed { pool_id });
@@ -1147,10 +1223,12 @@ pub mod pallet {
 		/// - `InvalidAmplification`: If the amplification parameter is invalid.
 		/// - `IncorrectInitialPegs`: If the initial pegs are incorrect.
 		/// - `MissingTargetPegOracle`: If the target peg oracle entry is missing.
+		/// - `MissingEvmPegRate`: If the rate of an EVM peg source cannot be retrieved.
 		/// - `IncorrectAssetDecimals`: If the assets have different decimals.
 		///
 		#[pallet::call_index(11)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_pegs())]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_pegs()
+							.saturating_add(Pallet::<T>::peg_sources_weight(peg_source)))]
 		#[transactional]
 		pub fn create_pool_with_pegs(
 			origin: OriginFor<T>,
@@ -1214,7 +1292,8 @@ pub mod pallet {
 		/// Emits `pallet_broadcast::Swapped` event when successful.
 		#[pallet::call_index(12)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_assets_liquidity()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
 		#[transactional]
 		pub fn add_assets_liquidity(
 			origin: OriginFor<T>,
@@ -1228,6 +1307,312 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add new asset to an existing pool.
+		///
+		/// The asset is appended to the list of pool assets. `T::PoolAssetLiquidityAccount` seeds the pool with `amount`
+		/// of the new asset and receives shares corresponding to the increase of pool's D, so existing LPs are not diluted.
+		///
+		/// If the pool has no liquidity yet, `amount` must be zero - initial liquidity of all assets,
+		/// including the new one, is then provided by the first `add_assets_liquidity`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `pool_id`: The ID of the pool.
+		/// - `asset_id`: The ID of the asset to add. Must be registered and not already in the pool.
+		/// - `amount`: Initial liquidity of the new asset.
+		/// - `peg_source`: Source of the peg of the new asset. Required if, and only if, the pool has pegs.
+		///
+		/// Emits `PoolAssetAdded` event if successful.
+		///
+		/// # Errors
+		/// - `PoolNotFound`: If the specified pool does not exist.
+		/// - `AssetAlreadyInPool`: If the asset is already in the pool.
+		/// - `ShareAssetInPoolAssets`: If the asset is the pool's share asset.
+		/// - `AssetNotRegistered`: If the asset is not registered in the AssetRegistry.
+		/// - `MaxAssetsExceeded`: If the pool already contains maximum number of assets.
+		/// - `IncorrectInitialPegs`: If `peg_source` is not provided for a pool with pegs or provided for a pool without pegs.
+		/// - `IncorrectAssetDecimals`: If the pool has pegs and the asset has different decimals than other pool assets.
+		/// - `InvalidInitialLiquidity`: If `amount` is zero for a pool with liquidity, or non-zero for an empty pool.
+		/// - `InsufficientTradingAmount`: If `amount` is less than the minimum trading amount.
+		/// - `InsufficientBalance`: If `T::PoolAssetLiquidityAccount` does not have enough balance of the asset.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_pool_asset()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(3))
+							.saturating_add(Pallet::<T>::peg_sources_weight(peg_source).saturating_mul(2)))]
+		#[transactional]
+		pub fn add_pool_asset(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			peg_source: Option<PegSource>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			let provider = T::PoolAssetLiquidityAccount::get();
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::AssetAlreadyInPool);
+			ensure!(asset_id != pool_id, Error::<T>::ShareAssetInPoolAssets);
+			ensure!(T::AssetInspection::exists(asset_id), Error::<T>::AssetNotRegistered);
+			let decimals = Self::retrieve_decimals(asset_id).ok_or(Error::<T>::UnknownDecimals)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			let amplification = Self::get_amplification(&pool);
+			let (_, initial_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+
+			pool.assets
+				.try_push(asset_id)
+				.map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+
+			let new_peg = match (PoolPegs::<T>::get(pool_id), peg_source) {
+				(Some(peg_info), Some(source)) => {
+					ensure!(
+						initial_reserves.iter().all(|reserve| reserve.decimals == decimals),
+						Error::<T>::IncorrectAssetDecimals
+					);
+					let mut sources = peg_info.source;
+					sources.try_push(source).map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+
+					let current_block: u128 = T::BlockNumberProvider::current_block_number().saturated_into();
+					let target_pegs = Self::get_target_pegs(current_block, &pool.assets, &sources)?;
+					let (new_peg, _) = target_pegs.last().copied().ok_or(Error::<T>::IncorrectInitialPegs)?;
+
+					let mut current = peg_info.current;
+					current.try_push(new_peg).map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+					PoolPegs::<T>::insert(
+						pool_id,
+						PoolPegInfo {
+							source: sources,
+							max_peg_update: peg_info.max_peg_update,
+							current,
+						},
+					);
+					new_peg
+				}
+				(None, None) => (1, 1),
+				_ => return Err(Error::<T>::IncorrectInitialPegs.into()),
+			};
+
+			let shares = if share_issuance.is_zero() {
+				ensure!(amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
+				Balance::zero()
+			} else {
+				ensure!(!amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
+				ensure!(
+					amount >= T::MinTradingLimit::get(),
+					Error::<T>::InsufficientTradingAmount
+				);
+				ensure!(
+					T::Currency::free_balance(asset_id, &provider) >= amount,
+					Error::<T>::InsufficientBalance
+				);
+
+				let shares = hydra_dx_math::stableswap::calculate_shares_for_added_asset::<D_ITERATIONS>(
+					&initial_reserves,
+					&initial_pegs,
+					AssetReserve::new(amount, decimals),
+					new_peg,
+					amplification,
+					share_issuance,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+				T::Currency::transfer(asset_id, &provider, &pool_account, amount)?;
+				T::Currency::deposit(pool_id, &provider, shares)?;
+				shares
+			};
+
+			Pools::<T>::insert(pool_id, pool);
+
+			if !shares.is_zero() {
+				// reserve of the new asset before the change is zero
+				let mut initial_reserves = initial_reserves;
+				initial_reserves.push(AssetReserve::new(Balance::zero(), decimals));
+				Self::call_on_liquidity_change_hook(pool_id, &initial_reserves, share_issuance)?;
+			}
+
+			Self::deposit_event(Event::PoolAssetAdded {
+				pool_id,
+				asset_id,
+				provider,
+				amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Remove an asset from an existing pool.
+		///
+		/// Selling and adding liquidity of the asset must be disabled first by `set_asset_tradable_state`,
+		/// so the asset can be drained from the pool by trades and liquidity removals.
+		///
+		/// Remaining reserve of the asset is transferred to `T::PoolAssetLiquidityAccount`, which must burn shares
+		/// corresponding to the decrease of pool's D, so remaining LPs are not diluted.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `pool_id`: The ID of the pool.
+		/// - `asset_id`: The ID of the asset to remove.
+		///
+		/// Emits `PoolAssetRemoved` event if successful.
+		///
+		/// # Errors
+		/// - `PoolNotFound`: If the specified pool does not exist.
+		/// - `AssetNotInPool`: If the asset is not part of the pool.
+		/// - `AssetNotFrozen`: If selling or adding liquidity of the asset is still allowed.
+		/// - `IncorrectAssets`: If the pool would be left with less than 2 assets.
+		/// - `NotAllowed`: If the asset is the base asset of a pool with pegs.
+		/// - `InsufficientShares`: If `T::PoolAssetLiquidityAccount` does not have enough shares to burn.
+		/// - `InsufficientLiquidityRemaining`: If the remaining liquidity in the pool is below the minimum required.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_pool_asset()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::evm_peg_calls_weight(*pool_id).saturating_mul(2)))]
+		#[transactional]
+		pub fn remove_pool_asset(origin: OriginFor<T>, pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			let beneficiary = T::PoolAssetLiquidityAccount::get();
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(
+				!AssetTradability::<T>::get(pool_id, asset_id)
+					.intersects(Tradability::SELL | Tradability::ADD_LIQUIDITY),
+				Error::<T>::AssetNotFrozen
+			);
+			ensure!(pool.assets.len() > 2, Error::<T>::IncorrectAssets);
+			// First asset is the base asset of pegs
+			ensure!(
+				asset_idx != 0 || !PoolPegs::<T>::contains_key(pool_id),
+				Error::<T>::NotAllowed
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			let amplification = Self::get_amplification(&pool);
+			let (_, asset_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+
+			let amount = initial_reserves[asset_idx].amount;
+			let shares = if amount.is_zero() || share_issuance.is_zero() {
+				Balance::zero()
+			} else {
+				hydra_dx_math::stableswap::calculate_shares_for_removed_asset::<D_ITERATIONS>(
+					&initial_reserves,
+					asset_idx,
+					amplification,
+					share_issuance,
+					&asset_pegs,
+				)
+				.ok_or(ArithmeticError::Overflow)?
+			};
+
+			if !shares.is_zero() {
+				ensure!(
+					T::Currency::free_balance(pool_id, &beneficiary) >= shares,
+					Error::<T>::InsufficientShares
+				);
+				ensure!(
+					share_issuance.saturating_sub(shares) >= T::MinPoolLiquidity::get(),
+					Error::<T>::InsufficientLiquidityRemaining
+				);
+				T::Currency::withdraw(pool_id, &beneficiary, shares)?;
+			}
+			if !amount.is_zero() {
+				T::Currency::transfer(asset_id, &pool_account, &beneficiary, amount)?;
+			}
+
+			pool.assets.remove(asset_idx);
+			Pools::<T>::insert(pool_id, pool);
+
+			if let Some(peg_info) = PoolPegs::<T>::get(pool_id) {
+				let mut source = peg_info.source;
+				source.remove(asset_idx);
+				let mut current = peg_info.current;
+				current.remove(asset_idx);
+				PoolPegs::<T>::insert(
+					pool_id,
+					PoolPegInfo {
+						source,
+						max_peg_update: peg_info.max_peg_update,
+						current,
+					},
+				);
+			}
+			AssetTradability::<T>::remove(pool_id, asset_id);
+
+			if !shares.is_zero() {
+				// reserves of the remaining assets are not changed, only share issuance
+				let mut initial_reserves = initial_reserves;
+				initial_reserves.remove(asset_idx);
+				Self::call_on_liquidity_change_hook(pool_id, &initial_reserves, share_issuance)?;
+			}
+
+			Self::deposit_event(Event::PoolAssetRemoved {
+				pool_id,
+				asset_id,
+				beneficiary,
+				amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Enable, update or disable dynamic fee of a pool.
+		///
+		/// With dynamic fee enabled, fee of trades between pool assets is scaled by how far the traded assets' reserves
+		/// deviate from peg-weighted balance. Fee equals pool's trade fee for balanced reserves and approaches
+		/// `offpeg_multiplier * fee` as the imbalance grows.
+		/// Fee of adding liquidity and removing liquidity of a single asset is scaled by imbalance of the most
+		/// imbalanced pool asset at current reserves.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `pool_id`: The ID of the pool.
+		/// - `offpeg_multiplier`: Off-peg fee multiplier. Must be greater than one. `None` disables dynamic fee.
+		///
+		/// Emits `DynamicFeeUpdated` event if successful.
+		///
+		/// # Errors
+		/// - `PoolNotFound`: If the specified pool does not exist.
+		/// - `InvalidFeeMultiplier`: If the multiplier is not greater than one.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_dynamic_fee())]
+		#[transactional]
+		pub fn set_pool_dynamic_fee(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			offpeg_multiplier: Option<FixedU128>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+			match offpeg_multiplier {
+				Some(multiplier) => {
+					ensure!(multiplier > FixedU128::one(), Error::<T>::InvalidFeeMultiplier);
+					PoolOffPegFeeMultiplier::<T>::insert(pool_id, multiplier);
+				}
+				None => PoolOffPegFeeMultiplier::<T>::remove(pool_id),
+			}
+
+			Self::deposit_event(Event::DynamicFeeUpdated {
+				pool_id,
+				offpeg_multiplier,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -1272,15 +1657,28 @@ impl<T: Config> Pallet<T> {
 			// Only recalculate, do not store
 			Self::get_updated_pegs(pool_id, &pool)?
 		};
-		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&initial_reserves,
-			index_in,
-			index_out,
-			amount_in,
-			amplification,
-			trade_fee,
-			&asset_pegs,
-		)
+		if let Some(offpeg_multiplier) = PoolOffPegFeeMultiplier::<T>::get(pool_id) {
+			hydra_dx_math::stableswap::calculate_out_given_in_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&initial_reserves,
+				index_in,
+				index_out,
+				amount_in,
+				amplification,
+				trade_fee,
+				offpeg_multiplier,
+				&asset_pegs,
+			)
+		} else {
+			hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&initial_reserves,
+				index_in,
+				index_out,
+				amount_in,
+				amplification,
+				trade_fee,
+				&asset_pegs,
+			)
+		}
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
@@ -1315,15 +1713,28 @@ impl<T: Config> Pallet<T> {
 		} else {
 			Self::get_updated_pegs(pool_id, &pool)?
 		};
-		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&initial_reserves,
-			index_in,
-			index_out,
-			amount_out,
-			amplification,
-			trade_fee,
-			&asset_pegs,
-		)
+		if let Some(offpeg_multiplier) = PoolOffPegFeeMultiplier::<T>::get(pool_id) {
+			hydra_dx_math::stableswap::calculate_in_given_out_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&initial_reserves,
+				index_in,
+				index_out,
+				amount_out,
+				amplification,
+				trade_fee,
+				offpeg_multiplier,
+				&asset_pegs,
+			)
+		} else {
+			hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&initial_reserves,
+				index_in,
+				index_out,
+				amount_out,
+				amplification,
+				trade_fee,
+				&asset_pegs,
+			)
+		}
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
@@ -1455,6 +1866,7 @@ impl<T: Config> Pallet<T> {
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
 		let (trade_fee, asset_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+		let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)?;
 		let (share_amount, fees) = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 			&initial_reserves,
 			&updated_reserves,
@@ -1545,6 +1957,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let (trade_fee, asset_pegs) = Self::update_and_return_pegs_and_trade_fee(pool_id, &pool)?;
+		let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)?;
 		let (amount_in, fee) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			shares,
@@ -1597,6 +2010,36 @@ impl<T: Config> Pallet<T> {
 		AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
 	}
 
+	/// Weight of EVM peg rate calls made each time pegs of the pool are recalculated.
+	///
+	/// Pegs are recalculated when an operation is executed and again when the pool state is passed to hooks.
+	pub fn evm_peg_calls_weight(pool_id: T::AssetId) -> Weight {
+		PoolPegs::<T>::get(pool_id).map_or(Weight::zero(), |peg_info| Self::peg_sources_weight(&peg_info.source))
+	}
+
+	fn peg_sources_weight<'a>(sources: impl IntoIterator<Item = &'a PegSource>) -> Weight {
+		let evm_calls = sources
+			.into_iter()
+			.filter(|source| matches!(source, PegSource::EvmCall(_)))
+			.count();
+		T::EvmPegRateProvider::rate_weight().saturating_mul(evm_calls as u64)
+	}
+
+	/// Returns base pool of metapool `pool_id` which contains `asset_id` as an underlying asset.
+	///
+	/// Pool is a metapool if one of its assets is a share token of another (base) stableswap pool.
+	/// Returns `None` if `asset_id` is an asset or share token of `pool_id` itself.
+	pub fn underlying_base_pool(pool_id: T::AssetId, asset_id: T::AssetId) -> Option<T::AssetId> {
+		let pool = Pools::<T>::get(pool_id)?;
+		if asset_id == pool_id || pool.find_asset(asset_id).is_some() {
+			return None;
+		}
+		pool.assets
+			.iter()
+			.copied()
+			.find(|base_pool_id| Pools::<T>::get(base_pool_id).is_some_and(|base| base.find_asset(asset_id).is_some()))
+	}
+
 	#[inline]
 	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
@@ -1794,6 +2237,22 @@ impl<T: Config> Pallet<T> {
 		.ok_or(ArithmeticError::Overflow.into())
 	}
 
+	// Fee of adding or removing liquidity - scaled by imbalance of the pool if dynamic fee is enabled
+	fn liquidity_fee(
+		pool_id: T::AssetId,
+		trade_fee: Permill,
+		reserves: &[AssetReserve],
+		pegs: &[PegType],
+	) -> Result<Permill, DispatchError> {
+		match PoolOffPegFeeMultiplier::<T>::get(pool_id) {
+			Some(offpeg_multiplier) => {
+				hydra_dx_math::stableswap::calculate_liquidity_dynamic_fee(reserves, trade_fee, offpeg_multiplier, pegs)
+					.ok_or(ArithmeticError::Overflow.into())
+			}
+			None => Ok(trade_fee),
+		}
+	}
+
 	// Same as get_current_pegs but it stores new pegs as well
 	#[require_transactional]
 	fn update_and_return_pegs_and_trade_fee(
@@ -1841,6 +2300,12 @@ impl<T: Config> Pallet<T> {
 						.map_err(|_| Error::<T>::MissingTargetPegOracle)?;
 					((entry.price.0, entry.price.1), entry.updated_at.saturated_into())
 				}
+				PegSource::EvmCall(call) => {
+					let peg = T::EvmPegRateProvider::rate(call)
+						.and_then(|rate| call.peg(rate))
+						.ok_or(Error::<T>::MissingEvmPegRate)?;
+					(peg, block_no)
+				}
 			};
 			r.push(p);
 		}
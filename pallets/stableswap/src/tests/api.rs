@@ -0,0 +1,239 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{assert_balance, Error};
+use hydradx_traits::stableswap::AssetAmount;
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{FixedU128, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+
+fn ext_with_pool(fee: Permill) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 200 * ONE),
+			(BOB, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B, ASSET_C].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee,
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+					AssetAmount::new(ASSET_C, 100 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn pool_details_should_return_current_pool_state() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			200,
+			10,
+			110
+		));
+		System::set_block_number(60);
+
+		let details = Stableswap::pool_details(pool_id).unwrap();
+
+		assert_eq!(details.pool_id, pool_id);
+		assert_eq!(details.assets, vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_eq!(details.amplification, 150);
+		assert_eq!(details.fee, Permill::from_percent(1));
+		assert_eq!(details.pegs, vec![(1, 1), (1, 1), (1, 1)]);
+		assert_eq!(details.reserves, vec![100 * ONE * 1_000_000; 3]);
+		assert_eq!(details.share_issuance, Tokens::total_issuance(pool_id));
+		assert!(details.virtual_share_price.is_some());
+	});
+}
+
+#[test]
+fn pool_details_should_return_none_when_pool_does_not_exist() {
+	ext_with_pool(Permill::zero()).execute_with(|| {
+		assert!(Stableswap::pool_details(1000).is_none());
+		assert_eq!(Stableswap::pools_details().len(), 1);
+	});
+}
+
+#[test]
+fn virtual_share_price_should_increase_when_fees_are_collected() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let initial_price = Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap();
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			30 * ONE,
+			0,
+		));
+
+		let price = Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap();
+		assert!(price > initial_price);
+		assert!(initial_price >= FixedU128::from(1));
+	});
+}
+
+#[test]
+fn quote_sell_should_match_executed_sell() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let quote = Stableswap::quote_sell(pool_id, ASSET_A, ASSET_B, 30 * ONE).unwrap();
+		assert!(quote.fee > 0);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			30 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, ASSET_A, 170 * ONE);
+		assert_balance!(BOB, ASSET_B, 200 * ONE + quote.amount_out);
+	});
+}
+
+#[test]
+fn quote_buy_should_match_executed_buy() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let quote = Stableswap::quote_buy(pool_id, ASSET_A, ASSET_B, 30 * ONE).unwrap();
+		assert!(quote.fee > 0);
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_B,
+			ASSET_A,
+			30 * ONE,
+			u128::MAX,
+		));
+
+		assert_balance!(BOB, ASSET_A, 200 * ONE - quote.amount_in);
+		assert_balance!(BOB, ASSET_B, 230 * ONE);
+	});
+}
+
+#[test]
+fn quote_sell_should_fail_when_assets_are_same() {
+	ext_with_pool(Permill::zero()).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::quote_sell(pool_id, ASSET_A, ASSET_A, 30 * ONE),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn quote_add_liquidity_should_match_received_shares() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let assets = vec![AssetAmount::new(ASSET_A, 10 * ONE), AssetAmount::new(ASSET_B, 20 * ONE)];
+
+		let quote = Stableswap::quote_add_liquidity(pool_id, &assets).unwrap();
+		assert_eq!(quote.fees.len(), 3);
+
+		assert_ok!(Stableswap::add_assets_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			BoundedVec::truncate_from(assets),
+			0,
+		));
+
+		assert_balance!(BOB, pool_id, quote.shares);
+	});
+}
+
+#[test]
+fn quote_add_liquidity_should_fail_when_asset_is_not_in_pool() {
+	ext_with_pool(Permill::zero()).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::quote_add_liquidity(pool_id, &[AssetAmount::new(4, 10 * ONE)]),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn quote_remove_liquidity_one_asset_should_match_received_amount() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 10;
+		let initial_balance = Tokens::free_balance(ASSET_A, &ALICE);
+
+		let quote = Stableswap::quote_remove_liquidity_one_asset(pool_id, ASSET_A, shares).unwrap();
+		assert_eq!(quote.amount_in, shares);
+
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ASSET_A,
+			shares,
+			0,
+		));
+
+		assert_balance!(ALICE, ASSET_A, initial_balance + quote.amount_out);
+	});
+}
+
+#[test]
+fn quote_remove_liquidity_should_match_received_amounts() {
+	ext_with_pool(Permill::from_percent(1)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 3;
+		let initial_balances = [ASSET_A, ASSET_B, ASSET_C].map(|asset| Tokens::free_balance(asset, &ALICE));
+
+		let quote = Stableswap::quote_remove_liquidity(pool_id, shares).unwrap();
+		assert_eq!(quote.len(), 3);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			BoundedVec::truncate_from(vec![
+				AssetAmount::new(ASSET_A, 0),
+				AssetAmount::new(ASSET_B, 0),
+				AssetAmount::new(ASSET_C, 0),
+			]),
+		));
+
+		for (asset_amount, initial_balance) in quote.iter().zip(initial_balances) {
+			assert_balance!(ALICE, asset_amount.asset_id, initial_balance + asset_amount.amount);
+		}
+	});
+}
@@ -0,0 +1,275 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{assert_balance, Error, Event, PoolOffPegFeeMultiplier};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::stableswap::AssetAmount;
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, Permill};
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+fn ext_with_imbalanced_pool() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 200 * ONE),
+			(BOB, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 400 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 300 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn set_pool_dynamic_fee_should_work_when_multiplier_is_valid() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let multiplier = FixedU128::from(5);
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(multiplier)
+		));
+
+		assert_eq!(PoolOffPegFeeMultiplier::<Test>::get(pool_id), Some(multiplier));
+		assert_eq!(
+			Stableswap::pool_details(pool_id).unwrap().offpeg_fee_multiplier,
+			Some(multiplier)
+		);
+		expect_events(vec![Event::DynamicFeeUpdated {
+			pool_id,
+			offpeg_multiplier: Some(multiplier),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fee_should_disable_dynamic_fee_when_multiplier_is_none() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+		assert_ok!(Stableswap::set_pool_dynamic_fee(RuntimeOrigin::root(), pool_id, None));
+
+		assert_eq!(PoolOffPegFeeMultiplier::<Test>::get(pool_id), None);
+		expect_events(vec![Event::DynamicFeeUpdated {
+			pool_id,
+			offpeg_multiplier: None,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fee_should_fail_when_multiplier_is_not_greater_than_one() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(FixedU128::from(1))),
+			Error::<Test>::InvalidFeeMultiplier
+		);
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(FixedU128::from_rational(1, 2))),
+			Error::<Test>::InvalidFeeMultiplier
+		);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fee_should_fail_when_pool_does_not_exist() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fee(RuntimeOrigin::root(), 1000, Some(FixedU128::from(5))),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fee_should_fail_when_origin_is_not_authority() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fee(RuntimeOrigin::signed(ALICE), pool_id, Some(FixedU128::from(5))),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_should_pay_higher_fee_when_dynamic_fee_is_enabled_and_pool_is_imbalanced() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let flat_quote = Stableswap::quote_sell(pool_id, ASSET_B, ASSET_A, 10 * ONE).unwrap();
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+
+		let dynamic_quote = Stableswap::quote_sell(pool_id, ASSET_B, ASSET_A, 10 * ONE).unwrap();
+		assert!(dynamic_quote.fee > flat_quote.fee);
+		assert_eq!(
+			dynamic_quote.amount_out + dynamic_quote.fee,
+			flat_quote.amount_out + flat_quote.fee
+		);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_B,
+			ASSET_A,
+			10 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, ASSET_A, 200 * ONE + dynamic_quote.amount_out);
+		assert_balance!(BOB, ASSET_B, 190 * ONE);
+	});
+}
+
+#[test]
+fn buy_should_pay_higher_fee_when_dynamic_fee_is_enabled_and_pool_is_imbalanced() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let flat_quote = Stableswap::quote_buy(pool_id, ASSET_B, ASSET_A, 10 * ONE).unwrap();
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+
+		let dynamic_quote = Stableswap::quote_buy(pool_id, ASSET_B, ASSET_A, 10 * ONE).unwrap();
+		assert!(dynamic_quote.fee > flat_quote.fee);
+		assert_eq!(
+			dynamic_quote.amount_in - dynamic_quote.fee,
+			flat_quote.amount_in - flat_quote.fee
+		);
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			200 * ONE,
+		));
+
+		assert_balance!(BOB, ASSET_A, 210 * ONE);
+		assert_balance!(BOB, ASSET_B, 200 * ONE - dynamic_quote.amount_in);
+	});
+}
+
+#[test]
+fn add_liquidity_should_pay_higher_fee_when_dynamic_fee_is_enabled_and_pool_is_imbalanced() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let assets = vec![AssetAmount::new(ASSET_B, 10 * ONE)];
+
+		let flat_quote = Stableswap::quote_add_liquidity(pool_id, &assets).unwrap();
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+
+		let dynamic_quote = Stableswap::quote_add_liquidity(pool_id, &assets).unwrap();
+		assert!(dynamic_quote.fees[1] > flat_quote.fees[1]);
+		assert!(dynamic_quote.shares < flat_quote.shares);
+
+		assert_ok!(Stableswap::add_assets_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			frame_support::BoundedVec::truncate_from(assets),
+			0,
+		));
+
+		assert_balance!(BOB, pool_id, dynamic_quote.shares);
+	});
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_pay_higher_fee_when_dynamic_fee_is_enabled_and_pool_is_imbalanced() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = 10 * ONE;
+
+		let flat_quote = Stableswap::quote_remove_liquidity_one_asset(pool_id, ASSET_A, shares).unwrap();
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+
+		let dynamic_quote = Stableswap::quote_remove_liquidity_one_asset(pool_id, ASSET_A, shares).unwrap();
+		assert!(dynamic_quote.fee > flat_quote.fee);
+		assert!(dynamic_quote.amount_out < flat_quote.amount_out);
+
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ASSET_A,
+			shares,
+			0,
+		));
+
+		assert_balance!(ALICE, ASSET_A, 100 * ONE + dynamic_quote.amount_out);
+	});
+}
+
+#[test]
+fn dynamic_fee_should_be_removed_when_pool_is_destroyed() {
+	ext_with_imbalanced_pool().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_pool_dynamic_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(FixedU128::from(5))
+		));
+
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			frame_support::BoundedVec::truncate_from(vec![AssetAmount::new(ASSET_A, 0), AssetAmount::new(ASSET_B, 0),]),
+		));
+
+		assert_eq!(PoolOffPegFeeMultiplier::<Test>::get(pool_id), None);
+	});
+}
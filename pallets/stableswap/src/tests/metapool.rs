@@ -0,0 +1,299 @@
+use crate::tests::mock::*;
+use crate::tests::to_bounded_asset_vec;
+use crate::{assert_balance, Error};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::stableswap::AssetAmount;
+use sp_runtime::{FixedPointNumber, Permill};
+use test_utils::assert_eq_approx;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const BASE_POOL: AssetId = 100;
+const META_POOL: AssetId = 200;
+
+fn metapool_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 1_000 * ONE),
+			(BOB, ASSET_C, 1_000 * ONE),
+			(ALICE, ASSET_A, 1_000_000 * ONE),
+			(ALICE, ASSET_B, 1_000_000 * ONE),
+			(ALICE, ASSET_C, 2_000_000 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("base".as_bytes().to_vec(), BASE_POOL, 18)
+		.with_registered_asset("meta".as_bytes().to_vec(), META_POOL, 18)
+		.build();
+
+	ext.execute_with(|| {
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			BASE_POOL,
+			to_bounded_asset_vec(vec![ASSET_A, ASSET_B]),
+			100,
+			Permill::from_rational(4u32, 10_000u32),
+		));
+		assert_ok!(Stableswap::add_assets_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			BASE_POOL,
+			BoundedVec::truncate_from(vec![
+				AssetAmount::new(ASSET_A, 1_000_000 * ONE),
+				AssetAmount::new(ASSET_B, 1_000_000 * ONE),
+			]),
+			0u128,
+		));
+
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			META_POOL,
+			to_bounded_asset_vec(vec![ASSET_C, BASE_POOL]),
+			100,
+			Permill::from_rational(4u32, 10_000u32),
+		));
+		let base_shares = Tokens::free_balance(BASE_POOL, &ALICE);
+		assert_ok!(Stableswap::add_assets_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			META_POOL,
+			BoundedVec::truncate_from(vec![
+				AssetAmount::new(ASSET_C, 2_000_000 * ONE),
+				AssetAmount::new(BASE_POOL, base_shares),
+			]),
+			0u128,
+		));
+	});
+
+	ext
+}
+
+#[test]
+fn underlying_base_pool_should_return_base_pool_only_for_underlying_assets() {
+	metapool_ext().execute_with(|| {
+		assert_eq!(Stableswap::underlying_base_pool(META_POOL, ASSET_A), Some(BASE_POOL));
+		assert_eq!(Stableswap::underlying_base_pool(META_POOL, ASSET_B), Some(BASE_POOL));
+		assert_eq!(Stableswap::underlying_base_pool(META_POOL, ASSET_C), None);
+		assert_eq!(Stableswap::underlying_base_pool(META_POOL, BASE_POOL), None);
+		assert_eq!(Stableswap::underlying_base_pool(META_POOL, META_POOL), None);
+		assert_eq!(Stableswap::underlying_base_pool(BASE_POOL, ASSET_C), None);
+	});
+}
+
+#[test]
+fn pools_should_contain_underlying_assets_of_metapool() {
+	metapool_ext().execute_with(|| {
+		let pools = <Stableswap as TradeExecution<_, _, _, _>>::pools();
+		let (_, meta_assets) = pools
+			.into_iter()
+			.find(|(pool_type, _)| *pool_type == PoolType::Stableswap(META_POOL))
+			.unwrap();
+
+		assert_eq!(meta_assets, vec![ASSET_C, BASE_POOL, ASSET_A, ASSET_B, META_POOL]);
+	});
+}
+
+#[test]
+fn calculate_sell_should_combine_base_pool_and_metapool_trades() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 100 * ONE;
+
+		let shares =
+			Stableswap::calculate_sell(PoolType::Stableswap(BASE_POOL), ASSET_A, BASE_POOL, amount_in).unwrap();
+		let expected = Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), BASE_POOL, ASSET_C, shares).unwrap();
+
+		assert_eq!(
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_in),
+			Ok(expected)
+		);
+	});
+}
+
+#[test]
+fn sell_should_work_when_asset_in_is_underlying_asset() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 100 * ONE;
+		let expected =
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_in).unwrap();
+
+		assert_ok!(Stableswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Stableswap(META_POOL),
+			ASSET_A,
+			ASSET_C,
+			amount_in,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_A, 900 * ONE);
+		assert_balance!(BOB, ASSET_C, 1_000 * ONE + expected);
+		assert_balance!(BOB, BASE_POOL, 0);
+	});
+}
+
+#[test]
+fn sell_should_work_when_asset_out_is_underlying_asset() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 100 * ONE;
+		let expected =
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_C, ASSET_B, amount_in).unwrap();
+
+		assert_ok!(Stableswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Stableswap(META_POOL),
+			ASSET_C,
+			ASSET_B,
+			amount_in,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_C, 900 * ONE);
+		assert_balance!(BOB, ASSET_B, expected);
+		assert_balance!(BOB, BASE_POOL, 0);
+	});
+}
+
+#[test]
+fn sell_should_be_executed_in_base_pool_when_both_assets_are_underlying() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 100 * ONE;
+		let expected =
+			Stableswap::calculate_sell(PoolType::Stableswap(BASE_POOL), ASSET_A, ASSET_B, amount_in).unwrap();
+		assert_eq!(
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_B, amount_in),
+			Ok(expected)
+		);
+
+		assert_ok!(Stableswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Stableswap(META_POOL),
+			ASSET_A,
+			ASSET_B,
+			amount_in,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_A, 900 * ONE);
+		assert_balance!(BOB, ASSET_B, expected);
+	});
+}
+
+#[test]
+fn sell_should_revert_both_trades_when_limit_is_not_reached() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 100 * ONE;
+		let expected =
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_in).unwrap();
+		let base_issuance = Tokens::total_issuance(BASE_POOL);
+
+		assert_noop!(
+			Stableswap::execute_sell(
+				RuntimeOrigin::signed(BOB),
+				PoolType::Stableswap(META_POOL),
+				ASSET_A,
+				ASSET_C,
+				amount_in,
+				expected + 1,
+			),
+			ExecutorError::Error(Error::<Test>::BuyLimitNotReached.into())
+		);
+
+		assert_balance!(BOB, ASSET_A, 1_000 * ONE);
+		assert_eq!(Tokens::total_issuance(BASE_POOL), base_issuance);
+	});
+}
+
+#[test]
+fn buy_should_work_when_asset_in_is_underlying_asset() {
+	metapool_ext().execute_with(|| {
+		let amount_out = 100 * ONE;
+		let expected_in =
+			Stableswap::calculate_buy(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_out).unwrap();
+
+		assert_ok!(Stableswap::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Stableswap(META_POOL),
+			ASSET_A,
+			ASSET_C,
+			amount_out,
+			expected_in,
+		));
+
+		assert_balance!(BOB, ASSET_A, 1_000 * ONE - expected_in);
+		assert_balance!(BOB, ASSET_C, 1_000 * ONE + amount_out);
+		assert_balance!(BOB, BASE_POOL, 0);
+	});
+}
+
+#[test]
+fn buy_should_work_when_asset_out_is_underlying_asset() {
+	metapool_ext().execute_with(|| {
+		let amount_out = 100 * ONE;
+		let expected_in =
+			Stableswap::calculate_buy(PoolType::Stableswap(META_POOL), ASSET_C, ASSET_B, amount_out).unwrap();
+
+		assert_ok!(Stableswap::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Stableswap(META_POOL),
+			ASSET_C,
+			ASSET_B,
+			amount_out,
+			expected_in,
+		));
+
+		assert_balance!(BOB, ASSET_C, 1_000 * ONE - expected_in);
+		assert_balance!(BOB, ASSET_B, amount_out);
+		assert_balance!(BOB, BASE_POOL, 0);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_max_limit_is_exceeded() {
+	metapool_ext().execute_with(|| {
+		let amount_out = 100 * ONE;
+		let expected_in =
+			Stableswap::calculate_buy(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_out).unwrap();
+
+		assert_noop!(
+			Stableswap::execute_buy(
+				RuntimeOrigin::signed(BOB),
+				PoolType::Stableswap(META_POOL),
+				ASSET_A,
+				ASSET_C,
+				amount_out,
+				expected_in - 1,
+			),
+			ExecutorError::Error(Error::<Test>::SlippageLimit.into())
+		);
+	});
+}
+
+#[test]
+fn trade_of_underlying_asset_for_metapool_shares_should_not_be_allowed() {
+	metapool_ext().execute_with(|| {
+		assert_eq!(
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, META_POOL, 100 * ONE),
+			Err(ExecutorError::Error(Error::<Test>::NotAllowed.into()))
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_combine_base_pool_and_metapool_prices() {
+	metapool_ext().execute_with(|| {
+		let amount_in = ONE;
+		let amount_out =
+			Stableswap::calculate_sell(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C, amount_in).unwrap();
+
+		let spot_price =
+			Stableswap::calculate_spot_price_with_fee(PoolType::Stableswap(META_POOL), ASSET_A, ASSET_C).unwrap();
+
+		assert_eq_approx!(
+			spot_price.reciprocal().unwrap().checked_mul_int(amount_in).unwrap(),
+			amount_out,
+			ONE / 1_000,
+			"spot price does not correspond to the trade"
+		);
+	});
+}
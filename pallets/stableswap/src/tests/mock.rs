@@ -58,6 +58,7 @@ pub const DAI: AssetId = 1;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 3;
 
 pub const ONE: Balance = 1_000_000_000_000;
 
@@ -76,6 +77,7 @@ thread_local! {
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
 	pub static PEG_ORACLE_VALUES: RefCell<HashMap<(AssetId,AssetId), (Balance,Balance,u64)>> = RefCell::new(HashMap::default());
+	pub static EVM_PEG_RATES: RefCell<HashMap<EvmAddress, Balance>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -145,6 +147,7 @@ parameter_types! {
 	pub const DAIAssetId: AssetId = DAI;
 	pub const MinimumLiquidity: Balance = 1_000_000;
 	pub const MinimumTradingLimit: Balance = 1000;
+	pub const TreasuryAccount: AccountId = TREASURY;
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
 }
 
@@ -188,6 +191,7 @@ impl Config for Test {
 	type AssetInspection = DummyRegistry;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type UpdateTradabilityOrigin = EnsureRoot<AccountId>;
+	type PoolAssetLiquidityAccount = TreasuryAccount;
 	type MinPoolLiquidity = MinimumLiquidity;
 	type AmplificationRange = AmplificationRange;
 	type MinTradingLimit = MinimumTradingLimit;
@@ -198,6 +202,7 @@ impl Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 	type TargetPegOracle = PegOracle;
+	type EvmPegRateProvider = EvmPegRates;
 }
 
 pub struct InitialLiquidity {
@@ -367,7 +372,8 @@ impl ExtBuilder {
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
-use crate::types::{PegSource, PoolInfo, PoolState, StableswapHooks};
+use crate::types::{EvmPegCall, EvmPegRateProvider, PegSource, PoolInfo, PoolState, StableswapHooks};
+use hydradx_traits::evm::EvmAddress;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::stableswap::AssetAmount;
 use hydradx_traits::{AccountIdFor, Inspect, Liquidity, OraclePeriod, RawEntry, RawOracle, Source, Volume};
@@ -561,3 +567,21 @@ pub(crate) fn set_peg_oracle_value(asset_a: AssetId, asset_b: AssetId, price: (B
 			.insert((asset_a, asset_b), (price.0, price.1, updated_at));
 	});
 }
+
+pub struct EvmPegRates;
+
+impl EvmPegRateProvider for EvmPegRates {
+	fn rate(call: &EvmPegCall) -> Option<Balance> {
+		EVM_PEG_RATES.with(|v| v.borrow().get(&call.contract).copied())
+	}
+
+	fn rate_weight() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+}
+
+pub(crate) fn set_evm_peg_rate(contract: EvmAddress, rate: Balance) {
+	EVM_PEG_RATES.with(|v| {
+		v.borrow_mut().insert(contract, rate);
+	});
+}
@@ -6,13 +6,18 @@ use sp_runtime::FixedU128;
 
 mod add_liquidity;
 mod amplification;
+mod api;
 mod calculate_spot_price;
 mod creation;
+mod dynamic_fee;
 mod hooks;
 mod invariants;
+mod metapool;
 pub(crate) mod mock;
 mod peg;
+mod peg_evm;
 mod peg_one;
+mod pool_assets;
 mod price;
 mod remove_liquidity;
 mod trades;
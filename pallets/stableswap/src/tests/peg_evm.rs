@@ -0,0 +1,185 @@
+use crate::tests::mock::*;
+use crate::types::{BoundedPegSources, EvmPegCall, EvmPegRateProvider, PegSource};
+use crate::{Error, PoolPegs};
+use hydradx_traits::evm::EvmAddress;
+use hydradx_traits::stableswap::AssetAmount;
+
+use crate::tests::to_bounded_asset_vec;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{FixedU128, Permill};
+use test_utils::assert_eq_approx;
+
+const RATE_ONE: Balance = 1_000_000_000_000_000_000;
+
+fn vault() -> EvmAddress {
+	EvmAddress::from_low_u64_be(4626)
+}
+
+fn evm_peg_source() -> PegSource {
+	PegSource::EvmCall(EvmPegCall {
+		contract: vault(),
+		// convertToAssets(uint256) selector, call data is not inspected by the mock
+		call_data: BoundedVec::truncate_from(vec![0x07, 0xa2, 0xd1, 0x3a]),
+		rate_decimals: 18,
+	})
+}
+
+fn create_evm_pegged_pool(pool_id: AssetId, max_peg_update: Permill) -> frame_support::dispatch::DispatchResult {
+	Stableswap::create_pool_with_pegs(
+		RuntimeOrigin::root(),
+		pool_id,
+		to_bounded_asset_vec(vec![1, 2]),
+		100,
+		Permill::from_percent(0),
+		BoundedPegSources::truncate_from(vec![PegSource::Value((1, 1)), evm_peg_source()]),
+		max_peg_update,
+	)
+}
+
+#[test]
+fn create_pool_with_evm_peg_should_set_initial_peg_from_rate() {
+	let pool_id = 100;
+	ExtBuilder::default()
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			set_evm_peg_rate(vault(), 1_050_000_000_000_000_000);
+
+			assert_ok!(create_evm_pegged_pool(pool_id, Permill::from_percent(100)));
+
+			let peg_info = PoolPegs::<Test>::get(pool_id).unwrap();
+			assert_eq!(
+				peg_info.source.to_vec(),
+				vec![PegSource::Value((1, 1)), evm_peg_source()]
+			);
+			assert_eq!(
+				peg_info.current.to_vec(),
+				vec![(1, 1), (1_050_000_000_000_000_000, RATE_ONE)]
+			);
+		});
+}
+
+#[test]
+fn create_pool_with_evm_peg_should_fail_when_rate_is_not_available() {
+	let pool_id = 100;
+	ExtBuilder::default()
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				create_evm_pegged_pool(pool_id, Permill::from_percent(100)),
+				Error::<Test>::MissingEvmPegRate
+			);
+
+			set_evm_peg_rate(vault(), 0);
+			assert_noop!(
+				create_evm_pegged_pool(pool_id, Permill::from_percent(100)),
+				Error::<Test>::MissingEvmPegRate
+			);
+		});
+}
+
+#[test]
+fn evm_peg_calls_weight_should_include_rate_call_of_each_evm_peg() {
+	let pool_id = 100;
+	ExtBuilder::default()
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			assert_eq!(Stableswap::evm_peg_calls_weight(pool_id), Weight::zero());
+
+			set_evm_peg_rate(vault(), RATE_ONE);
+			assert_ok!(create_evm_pegged_pool(pool_id, Permill::from_percent(100)));
+
+			assert_eq!(Stableswap::evm_peg_calls_weight(pool_id), EvmPegRates::rate_weight());
+		});
+}
+
+#[test]
+fn sell_should_fail_when_evm_peg_rate_becomes_unavailable() {
+	let pool_id = 100;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, 1, 100 * ONE),
+			(ALICE, 1, 1_000_000 * ONE),
+			(ALICE, 2, 1_000_000 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			set_evm_peg_rate(vault(), RATE_ONE);
+			assert_ok!(create_evm_pegged_pool(pool_id, Permill::from_percent(100)));
+			assert_ok!(Stableswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				BoundedVec::truncate_from(vec![
+					AssetAmount::new(1, 1_000_000 * ONE),
+					AssetAmount::new(2, 1_000_000 * ONE),
+				])
+			));
+
+			EVM_PEG_RATES.with(|v| v.borrow_mut().clear());
+
+			assert_noop!(
+				Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 100 * ONE, 0),
+				Error::<Test>::MissingEvmPegRate
+			);
+		});
+}
+
+#[test]
+fn sell_with_evm_peg_should_not_exceed_max_peg_update() {
+	let pool_id = 100;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, 1, 100 * ONE),
+			(ALICE, 1, 1_000_000 * ONE),
+			(ALICE, 2, 1_000_000 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			set_evm_peg_rate(vault(), RATE_ONE);
+			assert_ok!(create_evm_pegged_pool(pool_id, Permill::from_percent(1)));
+			assert_ok!(Stableswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				BoundedVec::truncate_from(vec![
+					AssetAmount::new(1, 1_000_000 * ONE),
+					AssetAmount::new(2, 1_000_000 * ONE),
+				])
+			));
+
+			set_evm_peg_rate(vault(), 1_100_000_000_000_000_000);
+			System::set_block_number(2);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				1,
+				2,
+				100 * ONE,
+				0
+			));
+
+			let peg = PoolPegs::<Test>::get(pool_id).unwrap().current[1];
+			assert_eq_approx!(
+				FixedU128::from_rational(peg.0, peg.1),
+				FixedU128::from_rational(101, 100),
+				FixedU128::from_rational(1, 1_000_000_000),
+				"peg moved by more than max peg update"
+			);
+		});
+}
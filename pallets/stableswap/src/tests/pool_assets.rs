@@ -0,0 +1,541 @@
+use crate::tests::mock::*;
+use crate::tests::to_bounded_asset_vec;
+use crate::types::{BoundedPegSources, PegSource, PoolInfo, Tradability};
+use crate::{assert_balance, AssetTradability, Error, Event, PoolPegs, Pools};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::stableswap::AssetAmount;
+use sp_runtime::Permill;
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const ASSET_D: AssetId = 4;
+
+fn ext_with_pool(assets: Vec<AssetId>) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+			(ALICE, ASSET_D, 200 * ONE),
+			(TREASURY, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("four".as_bytes().to_vec(), ASSET_D, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: assets.clone().try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: assets
+					.into_iter()
+					.map(|asset_id| AssetAmount::new(asset_id, 100 * ONE))
+					.collect(),
+			},
+		)
+		.build()
+}
+
+#[test]
+fn add_pool_asset_should_work_when_pool_has_liquidity() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let initial_shares = Tokens::free_balance(pool_id, &ALICE);
+		let initial_price = Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap();
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			100 * ONE,
+			None,
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(pool_id).unwrap().assets.to_vec(),
+			vec![ASSET_A, ASSET_B, ASSET_C]
+		);
+		assert_balance!(pool_account(pool_id), ASSET_C, 100 * ONE);
+		assert_balance!(TREASURY, ASSET_C, 100 * ONE);
+		assert_eq!(Tokens::free_balance(pool_id, &ALICE), initial_shares);
+
+		let shares = Tokens::free_balance(pool_id, &TREASURY);
+		assert!(shares > 0);
+		assert!(Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap() >= initial_price);
+
+		expect_events(vec![Event::PoolAssetAdded {
+			pool_id,
+			asset_id: ASSET_C,
+			provider: TREASURY,
+			amount: 100 * ONE,
+			shares,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn added_asset_should_be_tradable() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			100 * ONE,
+			None,
+		));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_C,
+			10 * ONE,
+			9 * ONE,
+		));
+	});
+}
+
+#[test]
+fn add_pool_asset_should_call_on_liquidity_changed_hook() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance_before = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			100 * ONE,
+			None,
+		));
+
+		let (p, state) = last_liquidity_changed_hook_state().unwrap();
+		assert_eq!(p, pool_id);
+		assert_eq!(state.assets, vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_eq!(state.before, vec![100 * ONE, 100 * ONE, 0]);
+		assert_eq!(state.after, vec![100 * ONE, 100 * ONE, 100 * ONE]);
+		assert_eq!(state.delta, vec![0, 0, 100 * ONE]);
+		assert_eq!(state.issuance_before, issuance_before);
+		assert_eq!(state.issuance_after, Tokens::total_issuance(pool_id));
+		assert_eq!(state.share_prices.len(), 3);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_work_when_pool_is_empty() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C, 100 * ONE, None),
+				Error::<Test>::InvalidInitialLiquidity
+			);
+
+			assert_ok!(Stableswap::add_pool_asset(
+				RuntimeOrigin::root(),
+				pool_id,
+				ASSET_C,
+				0,
+				None,
+			));
+
+			// Initial liquidity must include the new asset
+			assert_noop!(
+				Stableswap::add_assets_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					BoundedVec::truncate_from(vec![
+						AssetAmount::new(ASSET_A, 100 * ONE),
+						AssetAmount::new(ASSET_B, 100 * ONE),
+					]),
+					0,
+				),
+				Error::<Test>::InvalidInitialLiquidity
+			);
+			assert_ok!(Stableswap::add_assets_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				BoundedVec::truncate_from(vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+					AssetAmount::new(ASSET_C, 100 * ONE),
+				]),
+				0,
+			));
+		});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_asset_is_already_in_pool() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_B, 100 * ONE, None),
+			Error::<Test>::AssetAlreadyInPool
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_asset_is_not_registered() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, 10, 100 * ONE, None),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_origin_is_not_authority() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::signed(ALICE), pool_id, ASSET_C, 100 * ONE, None),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_peg_source_is_provided_for_pool_without_pegs() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(
+				RuntimeOrigin::root(),
+				pool_id,
+				ASSET_C,
+				100 * ONE,
+				Some(PegSource::Value((1, 1)))
+			),
+			Error::<Test>::IncorrectInitialPegs
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_initial_liquidity_is_missing() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C, 0, None),
+			Error::<Test>::InvalidInitialLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_liquidity_account_has_insufficient_balance() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_D, 100 * ONE, None),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_extend_pegs_when_pool_has_pegs() {
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+			(TREASURY, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool_with_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				to_bounded_asset_vec(vec![ASSET_A, ASSET_B]),
+				100,
+				Permill::from_percent(0),
+				BoundedPegSources::truncate_from(vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))]),
+				Permill::from_percent(100),
+			));
+			assert_ok!(Stableswap::add_assets_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				BoundedVec::truncate_from(vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+				]),
+				0,
+			));
+
+			assert_noop!(
+				Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C, 50 * ONE, None),
+				Error::<Test>::IncorrectInitialPegs
+			);
+
+			assert_ok!(Stableswap::add_pool_asset(
+				RuntimeOrigin::root(),
+				pool_id,
+				ASSET_C,
+				50 * ONE,
+				Some(PegSource::Value((1, 2))),
+			));
+
+			let peg_info = PoolPegs::<Test>::get(pool_id).unwrap();
+			assert_eq!(
+				peg_info.source.to_vec(),
+				vec![
+					PegSource::Value((1, 1)),
+					PegSource::Value((1, 1)),
+					PegSource::Value((1, 2))
+				]
+			);
+			assert_eq!(peg_info.current.to_vec(), vec![(1, 1), (1, 1), (1, 2)]);
+		});
+}
+
+#[test]
+fn remove_pool_asset_should_work_when_asset_is_frozen() {
+	ext_with_pool(vec![ASSET_A, ASSET_B, ASSET_C]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY,
+		));
+
+		// drain the asset
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_C,
+			ASSET_A,
+			80 * ONE,
+			1_000 * ONE,
+		));
+
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE),
+			TREASURY,
+			pool_id,
+			Tokens::free_balance(pool_id, &ALICE),
+		));
+
+		let initial_shares = Tokens::free_balance(pool_id, &TREASURY);
+		let initial_price = Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap();
+		let remaining_reserve = Tokens::free_balance(ASSET_C, &pool_account(pool_id));
+		let treasury_balance = Tokens::free_balance(ASSET_C, &TREASURY);
+
+		assert_ok!(Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C,));
+
+		assert_eq!(
+			Pools::<Test>::get(pool_id).unwrap().assets.to_vec(),
+			vec![ASSET_A, ASSET_B]
+		);
+		assert_balance!(pool_account(pool_id), ASSET_C, 0);
+		assert_balance!(TREASURY, ASSET_C, treasury_balance + remaining_reserve);
+		assert_eq!(AssetTradability::<Test>::get(pool_id, ASSET_C), Tradability::default());
+
+		let shares = initial_shares - Tokens::free_balance(pool_id, &TREASURY);
+		assert!(Stableswap::pool_details(pool_id).unwrap().virtual_share_price.unwrap() >= initial_price);
+
+		expect_events(vec![Event::PoolAssetRemoved {
+			pool_id,
+			asset_id: ASSET_C,
+			beneficiary: TREASURY,
+			amount: remaining_reserve,
+			shares,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_call_on_liquidity_changed_hook() {
+	ext_with_pool(vec![ASSET_A, ASSET_B, ASSET_C]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY,
+		));
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE),
+			TREASURY,
+			pool_id,
+			Tokens::free_balance(pool_id, &ALICE),
+		));
+		let issuance_before = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C,));
+
+		let (p, state) = last_liquidity_changed_hook_state().unwrap();
+		assert_eq!(p, pool_id);
+		assert_eq!(state.assets, vec![ASSET_A, ASSET_B]);
+		assert_eq!(state.before, vec![100 * ONE, 100 * ONE]);
+		assert_eq!(state.after, vec![100 * ONE, 100 * ONE]);
+		assert_eq!(state.issuance_before, issuance_before);
+		assert!(state.issuance_after < issuance_before);
+		assert_eq!(state.share_prices.len(), 2);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_asset_is_not_frozen() {
+	ext_with_pool(vec![ASSET_A, ASSET_B, ASSET_C]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C),
+			Error::<Test>::AssetNotFrozen
+		);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY | Tradability::ADD_LIQUIDITY,
+		));
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C),
+			Error::<Test>::AssetNotFrozen
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_pool_would_have_less_than_two_assets() {
+	ext_with_pool(vec![ASSET_A, ASSET_B]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_B,
+			Tradability::FROZEN,
+		));
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_B),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_liquidity_account_has_insufficient_shares() {
+	ext_with_pool(vec![ASSET_A, ASSET_B, ASSET_C]).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_C,
+			Tradability::FROZEN,
+		));
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_asset_is_base_asset_of_pegs() {
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+			(TREASURY, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool_with_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				to_bounded_asset_vec(vec![ASSET_A, ASSET_B, ASSET_C]),
+				100,
+				Permill::from_percent(0),
+				BoundedPegSources::truncate_from(vec![
+					PegSource::Value((1, 1)),
+					PegSource::Value((1, 1)),
+					PegSource::Value((1, 1))
+				]),
+				Permill::from_percent(100),
+			));
+			assert_ok!(Stableswap::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				pool_id,
+				ASSET_A,
+				Tradability::FROZEN,
+			));
+
+			assert_noop!(
+				Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_A),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
@@ -1,16 +1,73 @@
 use crate::types::Tradability;
-use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
+use crate::{Balance, Config, Error, Pallet, PoolOffPegFeeMultiplier, Pools, D_ITERATIONS, Y_ITERATIONS};
+use frame_support::storage::with_storage_layer;
 use frame_support::{ensure, BoundedVec};
+use frame_system::ensure_signed;
 use hydra_dx_math::stableswap::types::AssetReserve;
 use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use hydradx_traits::stableswap::AssetAmount;
 use orml_traits::MultiCurrency;
 use sp_core::Get;
-use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
+use sp_runtime::traits::{CheckedMul, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, DispatchResult, FixedU128};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+impl<T: Config> Pallet<T> {
+	/// Checks the tradability of the assets for the operation the router trade is executed as.
+	fn ensure_trade_allowed(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		let allowed = if asset_in == pool_id {
+			Self::is_asset_allowed(pool_id, asset_out, Tradability::REMOVE_LIQUIDITY)
+		} else if asset_out == pool_id {
+			Self::is_asset_allowed(pool_id, asset_in, Tradability::ADD_LIQUIDITY)
+		} else {
+			Self::is_asset_allowed(pool_id, asset_in, Tradability::SELL)
+				&& Self::is_asset_allowed(pool_id, asset_out, Tradability::BUY)
+		};
+		ensure!(allowed, ExecutorError::Error(Error::<T>::NotAllowed.into()));
+
+		Ok(())
+	}
+
+	/// Resolves how a trade of underlying assets of a metapool's base pool is executed.
+	/// Returns `None` if both assets are assets or share token of the pool itself.
+	fn metapool_route(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<Option<MetapoolRoute<T::AssetId>>, ExecutorError<DispatchError>> {
+		let route = match (
+			Self::underlying_base_pool(pool_id, asset_in),
+			Self::underlying_base_pool(pool_id, asset_out),
+		) {
+			(None, None) => return Ok(None),
+			(Some(base_in), Some(base_out)) if base_in == base_out => MetapoolRoute::BasePool(base_in),
+			(Some(base_pool_id), None) if asset_out == base_pool_id => MetapoolRoute::BasePool(base_pool_id),
+			(None, Some(base_pool_id)) if asset_in == base_pool_id => MetapoolRoute::BasePool(base_pool_id),
+			(Some(base_pool_id), None) if asset_out != pool_id => MetapoolRoute::UnderlyingIn(base_pool_id),
+			(None, Some(base_pool_id)) if asset_in != pool_id => MetapoolRoute::UnderlyingOut(base_pool_id),
+			_ => return Err(ExecutorError::Error(Error::<T>::NotAllowed.into())),
+		};
+
+		Ok(Some(route))
+	}
+}
+
+/// Trade of a metapool involving underlying assets of its base pool.
+enum MetapoolRoute<AssetId> {
+	/// Both assets are in the base pool, trade is executed in the base pool only.
+	BasePool(AssetId),
+	/// `asset_in` is sold to the base pool for its shares, which are then sold in the metapool.
+	UnderlyingIn(AssetId),
+	/// `asset_in` is sold in the metapool for shares of the base pool, which are then withdrawn as `asset_out`.
+	UnderlyingOut(AssetId),
+}
+
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
 where
 	u32: sp_std::convert::From<T::AssetId>,
@@ -26,6 +83,38 @@ where
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				match Self::metapool_route(pool_id, asset_in, asset_out)? {
+					Some(MetapoolRoute::BasePool(base_pool_id)) => {
+						return Self::calculate_sell(
+							PoolType::Stableswap(base_pool_id),
+							asset_in,
+							asset_out,
+							amount_in,
+						);
+					}
+					Some(MetapoolRoute::UnderlyingIn(base_pool_id)) => {
+						let shares = Self::calculate_sell(
+							PoolType::Stableswap(base_pool_id),
+							asset_in,
+							base_pool_id,
+							amount_in,
+						)?;
+						return Self::calculate_sell(pool_type, base_pool_id, asset_out, shares);
+					}
+					Some(MetapoolRoute::UnderlyingOut(base_pool_id)) => {
+						let shares = Self::calculate_sell(pool_type, asset_in, base_pool_id, amount_in)?;
+						return Self::calculate_sell(
+							PoolType::Stableswap(base_pool_id),
+							base_pool_id,
+							asset_out,
+							shares,
+						);
+					}
+					None => {}
+				}
+
+				Self::ensure_trade_allowed(pool_id, asset_in, asset_out)?;
+
 				if asset_in == pool_id {
 					let pool = Pools::<T>::get(pool_id)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
@@ -41,6 +130,8 @@ where
 					let amplification = Self::get_amplification(&pool);
 					let (trade_fee, asset_pegs) =
 						Self::get_updated_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &balances, &asset_pegs)
+						.map_err(ExecutorError::Error)?;
 					let (amount, _) =
 						hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 							&balances,
@@ -117,6 +208,8 @@ where
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let (trade_fee, asset_pegs) =
 						Self::get_updated_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &initial_reserves, &asset_pegs)
+						.map_err(ExecutorError::Error)?;
 					let (share_amount, _) = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 						&initial_reserves,
 						&updated_reserves[..],
@@ -146,6 +239,33 @@ where
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				match Self::metapool_route(pool_id, asset_in, asset_out)? {
+					Some(MetapoolRoute::BasePool(base_pool_id)) => {
+						return Self::calculate_buy(
+							PoolType::Stableswap(base_pool_id),
+							asset_in,
+							asset_out,
+							amount_out,
+						);
+					}
+					Some(MetapoolRoute::UnderlyingIn(base_pool_id)) => {
+						let shares = Self::calculate_buy(pool_type, base_pool_id, asset_out, amount_out)?;
+						return Self::calculate_buy(PoolType::Stableswap(base_pool_id), asset_in, base_pool_id, shares);
+					}
+					Some(MetapoolRoute::UnderlyingOut(base_pool_id)) => {
+						let shares = Self::calculate_buy(
+							PoolType::Stableswap(base_pool_id),
+							base_pool_id,
+							asset_out,
+							amount_out,
+						)?;
+						return Self::calculate_buy(pool_type, asset_in, base_pool_id, shares);
+					}
+					None => {}
+				}
+
+				Self::ensure_trade_allowed(pool_id, asset_in, asset_out)?;
+
 				if asset_out == pool_id {
 					//I wanna buy 500 shares, how much luqidity i need provide to get 500 shares
 					let pool = Pools::<T>::get(pool_id)
@@ -161,6 +281,8 @@ where
 					let amplification = Self::get_amplification(&pool);
 					let (trade_fee, asset_pegs) =
 						Self::get_updated_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &balances, &asset_pegs)
+						.map_err(ExecutorError::Error)?;
 
 					let liqudity = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 						&balances,
@@ -191,6 +313,8 @@ where
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
 					let (trade_fee, asset_pegs) =
 						Self::get_updated_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let trade_fee = Self::liquidity_fee(pool_id, trade_fee, &balances, &asset_pegs)
+						.map_err(ExecutorError::Error)?;
 
 					let (shares_amount, _fees) =
 						hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
@@ -226,6 +350,10 @@ where
 	) -> Result<(), ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				if let Some(route) = Self::metapool_route(pool_id, asset_in, asset_out)? {
+					return Self::execute_metapool_sell(who, pool_id, route, asset_in, asset_out, amount_in, min_limit);
+				}
+
 				if asset_in == pool_id {
 					Self::remove_liquidity_one_asset(who, pool_id, asset_out, amount_in, min_limit)
 						.map_err(ExecutorError::Error)
@@ -258,6 +386,10 @@ where
 	) -> Result<(), ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				if let Some(route) = Self::metapool_route(pool_id, asset_in, asset_out)? {
+					return Self::execute_metapool_buy(who, pool_id, route, asset_in, asset_out, amount_out, max_limit);
+				}
+
 				if asset_out == pool_id {
 					Self::add_liquidity_shares(who, pool_id, amount_out, asset_in, max_limit)
 						.map_err(ExecutorError::Error)
@@ -279,6 +411,7 @@ where
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				let pool_id = Self::underlying_base_pool(pool_id, asset_a).unwrap_or(pool_id);
 				let pool_account = Self::pool_account(pool_id);
 				Ok(T::Currency::free_balance(asset_a, &pool_account))
 			}
@@ -293,6 +426,39 @@ where
 	) -> Result<FixedU128, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
+				match Self::metapool_route(pool_id, asset_a, asset_b)? {
+					Some(MetapoolRoute::BasePool(base_pool_id)) => {
+						return Self::calculate_spot_price_with_fee(
+							PoolType::Stableswap(base_pool_id),
+							asset_a,
+							asset_b,
+						);
+					}
+					Some(MetapoolRoute::UnderlyingIn(base_pool_id)) => {
+						let base_price = Self::calculate_spot_price_with_fee(
+							PoolType::Stableswap(base_pool_id),
+							asset_a,
+							base_pool_id,
+						)?;
+						let meta_price = Self::calculate_spot_price_with_fee(pool_type, base_pool_id, asset_b)?;
+						return base_price
+							.checked_mul(&meta_price)
+							.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()));
+					}
+					Some(MetapoolRoute::UnderlyingOut(base_pool_id)) => {
+						let meta_price = Self::calculate_spot_price_with_fee(pool_type, asset_a, base_pool_id)?;
+						let base_price = Self::calculate_spot_price_with_fee(
+							PoolType::Stableswap(base_pool_id),
+							base_pool_id,
+							asset_b,
+						)?;
+						return meta_price
+							.checked_mul(&base_price)
+							.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()));
+					}
+					None => {}
+				}
+
 				let pool_account = Self::pool_account(pool_id);
 				let pool =
 					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
@@ -311,6 +477,32 @@ where
 				let min_trade_limit = T::MinTradingLimit::get();
 				let (trade_fee, asset_pegs) = Self::get_updated_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
 
+				// Dynamic fee of a marginal trade or liquidity change at current reserves
+				let trade_fee = match (
+					PoolOffPegFeeMultiplier::<T>::get(pool_id),
+					pool.find_asset(asset_a),
+					pool.find_asset(asset_b),
+				) {
+					(Some(offpeg_multiplier), Some(idx_a), Some(idx_b)) => {
+						hydra_dx_math::stableswap::calculate_trade_dynamic_fee(
+							&balances,
+							idx_b,
+							idx_a,
+							0,
+							0,
+							trade_fee,
+							offpeg_multiplier,
+							&asset_pegs,
+						)
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?
+					}
+					// Price of share asset - adding or removing liquidity
+					(Some(_), _, _) if asset_a == pool_id || asset_b == pool_id => {
+						Self::liquidity_fee(pool_id, trade_fee, &balances, &asset_pegs).map_err(ExecutorError::Error)?
+					}
+					_ => trade_fee,
+				};
+
 				let spot_price = hydra_dx_math::stableswap::calculate_spot_price(
 					pool_id.into(),
 					assets_with_reserves,
@@ -329,4 +521,115 @@ where
 			_ => Err(ExecutorError::NotSupported),
 		}
 	}
+
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter()
+			.map(|(pool_id, pool)| {
+				let mut assets = pool.assets.to_vec();
+				// underlying assets of base pools are tradable in a metapool too
+				for base_pool in pool.assets.iter().filter_map(Pools::<T>::get) {
+					for asset_id in base_pool.assets.into_iter() {
+						if !assets.contains(&asset_id) {
+							assets.push(asset_id);
+						}
+					}
+				}
+				assets.push(pool_id);
+				(PoolType::Stableswap(pool_id), assets)
+			})
+			.collect()
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	u32: sp_std::convert::From<T::AssetId>,
+	sp_std::vec::Vec<(u32, AssetReserve)>: FromIterator<(T::AssetId, AssetReserve)>,
+{
+	fn execute_metapool_sell(
+		who: T::RuntimeOrigin,
+		pool_id: T::AssetId,
+		route: MetapoolRoute<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		let account = ensure_signed(who.clone()).map_err(|e| ExecutorError::Error(e.into()))?;
+
+		match route {
+			MetapoolRoute::BasePool(base_pool_id) => Self::execute_sell(
+				who,
+				PoolType::Stableswap(base_pool_id),
+				asset_in,
+				asset_out,
+				amount_in,
+				min_limit,
+			),
+			MetapoolRoute::UnderlyingIn(base_pool_id) => with_storage_layer(|| -> DispatchResult {
+				let shares_before = T::Currency::free_balance(base_pool_id, &account);
+				Self::add_assets_liquidity(
+					who.clone(),
+					base_pool_id,
+					BoundedVec::truncate_from(vec![AssetAmount {
+						asset_id: asset_in,
+						amount: amount_in,
+					}]),
+					Balance::zero(),
+				)?;
+				let shares = T::Currency::free_balance(base_pool_id, &account).saturating_sub(shares_before);
+
+				Self::sell(who, pool_id, base_pool_id, asset_out, shares, min_limit)
+			})
+			.map_err(ExecutorError::Error),
+			MetapoolRoute::UnderlyingOut(base_pool_id) => with_storage_layer(|| -> DispatchResult {
+				let shares_before = T::Currency::free_balance(base_pool_id, &account);
+				Self::sell(who.clone(), pool_id, asset_in, base_pool_id, amount_in, Balance::zero())?;
+				let shares = T::Currency::free_balance(base_pool_id, &account).saturating_sub(shares_before);
+
+				Self::remove_liquidity_one_asset(who, base_pool_id, asset_out, shares, min_limit)
+			})
+			.map_err(ExecutorError::Error),
+		}
+	}
+
+	fn execute_metapool_buy(
+		who: T::RuntimeOrigin,
+		pool_id: T::AssetId,
+		route: MetapoolRoute<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		match route {
+			MetapoolRoute::BasePool(base_pool_id) => Self::execute_buy(
+				who,
+				PoolType::Stableswap(base_pool_id),
+				asset_in,
+				asset_out,
+				amount_out,
+				max_limit,
+			),
+			MetapoolRoute::UnderlyingIn(base_pool_id) => {
+				let shares = Self::calculate_buy(PoolType::Stableswap(pool_id), base_pool_id, asset_out, amount_out)?;
+
+				with_storage_layer(|| -> DispatchResult {
+					Self::add_liquidity_shares(who.clone(), base_pool_id, shares, asset_in, max_limit)?;
+					Self::buy(who, pool_id, asset_out, base_pool_id, amount_out, shares)
+				})
+				.map_err(ExecutorError::Error)
+			}
+			MetapoolRoute::UnderlyingOut(base_pool_id) => {
+				let shares =
+					Self::calculate_buy(PoolType::Stableswap(base_pool_id), base_pool_id, asset_out, amount_out)?;
+
+				with_storage_layer(|| -> DispatchResult {
+					Self::buy(who.clone(), pool_id, base_pool_id, asset_in, shares, max_limit)?;
+					Self::withdraw_asset_amount(who, base_pool_id, asset_out, amount_out, shares)
+				})
+				.map_err(ExecutorError::Error)
+			}
+		}
+	}
 }
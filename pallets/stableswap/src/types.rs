@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{Config, Pallet, MAX_ASSETS_IN_POOL};
-use sp_runtime::Permill;
+use sp_runtime::{FixedU128, Permill};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
@@ -14,6 +14,7 @@ use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::evm::EvmAddress;
 use hydradx_traits::{OraclePeriod, Source};
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
@@ -156,6 +157,52 @@ pub type BoundedPegs = BoundedVec<PegType, ConstU32<MAX_ASSETS_IN_POOL>>;
 pub enum PegSource {
 	Value(PegType),
 	Oracle((Source, OraclePeriod)),
+	EvmCall(EvmPegCall),
+}
+
+/// Maximum length of call data of an EVM peg source.
+pub const MAX_PEG_CALL_DATA_LEN: u32 = 68;
+
+/// View call to an EVM contract returning exchange rate of a yield-bearing token.
+/// `contract`: address of the contract (eg. ERC-4626 vault or Aave pool)
+/// `call_data`: ABI encoded selector and arguments of a view function returning single `uint256`
+/// `rate_decimals`: decimals of the returned rate, eg. 18 for `convertToAssets(1e18)` or 27 for Aave ray
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EvmPegCall {
+	pub contract: EvmAddress,
+	pub call_data: BoundedVec<u8, ConstU32<MAX_PEG_CALL_DATA_LEN>>,
+	pub rate_decimals: u8,
+}
+
+impl EvmPegCall {
+	/// Peg of the asset in units of the first pool asset for given `rate`.
+	pub fn peg(&self, rate: Balance) -> Option<PegType> {
+		if rate == 0 {
+			return None;
+		}
+		let denominator = 10u128.checked_pow(self.rate_decimals.into())?;
+		Some((rate, denominator))
+	}
+}
+
+/// Provider of exchange rates read from EVM contracts.
+pub trait EvmPegRateProvider {
+	/// Executes gas-bounded view call and returns the rate it returned.
+	/// Returns `None` if the call fails or the result does not fit into `Balance`.
+	fn rate(call: &EvmPegCall) -> Option<Balance>;
+
+	/// Weight of a single `rate` call.
+	fn rate_weight() -> Weight;
+}
+
+impl EvmPegRateProvider for () {
+	fn rate(_call: &EvmPegCall) -> Option<Balance> {
+		None
+	}
+
+	fn rate_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub type BoundedPegSources = BoundedVec<PegSource, ConstU32<MAX_ASSETS_IN_POOL>>;
@@ -177,3 +224,40 @@ impl PoolPegInfo {
 		}
 	}
 }
+
+/// Current state of a pool.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct PoolDetails<AssetId, Balance> {
+	pub pool_id: AssetId,
+	pub assets: Vec<AssetId>,
+	/// Current amplification, interpolated between initial and final amplification
+	pub amplification: u128,
+	/// Current trade fee, including the adjustment by pegs update
+	pub fee: Permill,
+	/// Off-peg fee multiplier if dynamic fee is enabled
+	pub offpeg_fee_multiplier: Option<FixedU128>,
+	/// Current pegs of pool assets
+	pub pegs: Vec<(Balance, Balance)>,
+	/// Pool reserves normalized to 18 decimals
+	pub reserves: Vec<Balance>,
+	pub share_issuance: Balance,
+	/// Value of one share in normalized pool assets (`D / share_issuance`). `None` if pool has no shares.
+	pub virtual_share_price: Option<FixedU128>,
+}
+
+/// Amounts of a trade calculated at current pool state.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct TradeQuote<Balance> {
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	/// Fee amount, already included in `amount_in` or `amount_out`
+	pub fee: Balance,
+}
+
+/// Amount of shares received for added liquidity calculated at current pool state.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct AddLiquidityQuote<Balance> {
+	pub shares: Balance,
+	/// Fee amounts for each pool asset
+	pub fees: Vec<Balance>,
+}
@@ -27,6 +27,9 @@ pub trait WeightInfo {
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn add_pool_asset() -> Weight;
+	fn remove_pool_asset() -> Weight;
+	fn set_pool_dynamic_fee() -> Weight;
 }
 
 /// Weights for pallet_stableswap using the hydraDX node and recommended hardware.
@@ -70,13 +73,15 @@ impl WeightInfo for () {
 	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3746`
 		//  Estimated: `29403`
 		// Minimum execution time: 1_421_812_000 picoseconds.
 		Weight::from_parts(1_425_672_000, 29403)
-			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().reads(37_u64))
 			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Stableswap::Pools` (r:1 w:0)
@@ -103,13 +108,15 @@ impl WeightInfo for () {
 	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn add_assets_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3746`
 		//  Estimated: `29403`
 		// Minimum execution time: 1_421_812_000 picoseconds.
 		Weight::from_parts(1_425_672_000, 29403)
-			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().reads(37_u64))
 			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Stableswap::AssetTradability` (r:1 w:0)
@@ -136,13 +143,15 @@ impl WeightInfo for () {
 	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn add_liquidity_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3615`
 		//  Estimated: `19071`
 		// Minimum execution time: 952_077_000 picoseconds.
 		Weight::from_parts(955_275_000, 19071)
-			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Stableswap::AssetTradability` (r:1 w:0)
@@ -169,13 +178,15 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:0 w:1)
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn remove_liquidity_one_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3638`
 		//  Estimated: `19071`
 		// Minimum execution time: 979_807_000 picoseconds.
 		Weight::from_parts(983_563_000, 19071)
-			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:6 w:0)
@@ -235,13 +246,15 @@ impl WeightInfo for () {
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn withdraw_asset_amount() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3638`
 		//  Estimated: `19071`
 		// Minimum execution time: 1_301_615_000 picoseconds.
 		Weight::from_parts(1_305_639_000, 19071)
-			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Stableswap::AssetTradability` (r:2 w:0)
@@ -268,13 +281,15 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:0 w:1)
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3701`
 		//  Estimated: `19071`
 		// Minimum execution time: 928_909_000 picoseconds.
 		Weight::from_parts(934_054_000, 19071)
-			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Stableswap::AssetTradability` (r:2 w:0)
@@ -301,13 +316,15 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3671`
 		//  Estimated: `19071`
 		// Minimum execution time: 917_977_000 picoseconds.
 		Weight::from_parts(921_374_000, 19071)
-			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().reads(28_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Stableswap::Pools` (r:1 w:0)
@@ -369,6 +386,8 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:0 w:1)
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32, ) -> Weight {
@@ -381,7 +400,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(1_245_529, 0).saturating_mul(c.into()))
 			// Standard Error: 689_000
 			.saturating_add(Weight::from_parts(887_743_321, 0).saturating_mul(e.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(e.into())))
 			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(e.into()))
@@ -410,6 +429,8 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32, ) -> Weight {
@@ -422,7 +443,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(3_957_770, 0).saturating_mul(c.into()))
 			// Standard Error: 2_458_249
 			.saturating_add(Weight::from_parts(530_090_295, 0).saturating_mul(e.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().reads((16_u64).saturating_mul(e.into())))
 			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(e.into()))
@@ -435,13 +456,15 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:1 w:0)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn calculate_spot_price_with_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1885`
 		//  Estimated: `16590`
 		// Minimum execution time: 336_682_000 picoseconds.
 		Weight::from_parts(337_962_000, 16590)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
 	}
 	/// Storage: `Stableswap::Pools` (r:1 w:1)
 	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
@@ -458,4 +481,65 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:6 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:7 w:3)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolPegs` (r:1 w:1)
+	/// Proof: `Stableswap::PoolPegs` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:7 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:1 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn add_pool_asset() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(1_149_863_000, 19071)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::AssetTradability` (r:1 w:1)
+	/// Proof: `Stableswap::AssetTradability` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolPegs` (r:1 w:1)
+	/// Proof: `Stableswap::PoolPegs` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:6 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:7 w:3)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:4 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:1 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_pool_asset() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(1_104_587_000, 19071)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Stableswap::Pools` (r:1 w:0)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolOffPegFeeMultiplier` (r:0 w:1)
+	/// Proof: `Stableswap::PoolOffPegFeeMultiplier` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_pool_dynamic_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3522`
+		// Minimum execution time: 20_874_000 picoseconds.
+		Weight::from_parts(21_193_000, 3522)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
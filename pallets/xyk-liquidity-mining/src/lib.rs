@@ -813,6 +813,48 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Add liquidity to XYK pool by providing only one of the pool assets and join multiple farms
+		/// with the received shares.
+		///
+		/// Part of `amount_a` is sold for `asset_b` and the rest is added to the pool together with
+		/// the received `asset_b`. The share is deposited to the first farm of the specified entries,
+		/// and then redeposit the shares to the remaining farms
+		///
+		/// Parameters:
+		/// - `origin`: account providing liquidity and depositing LP shares.
+		/// - `asset_a`: asset id of the provided asset
+		/// - `asset_b`: asset id of the second asset in the pair
+		/// - `amount_a`: amount of the provided asset
+		/// - `min_shares`: minimum amount of LP shares to receive
+		/// - `farm_entries`: list of global farm id and yield farm id pairs to join
+		///
+		/// Emits `SharesDeposited` event for the first farm entry
+		/// Emits `SharesRedeposited` event for each farm entry after the first one
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_single_asset_and_join_farms(farm_entries.len() as u32))]
+		pub fn add_liquidity_single_asset_and_join_farms(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			min_shares: Balance,
+			farm_entries: BoundedVec<(GlobalFarmId, YieldFarmId), T::MaxFarmEntriesPerDeposit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			ensure!(!farm_entries.is_empty(), Error::<T>::NoFarmsSpecified);
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			let shares_added = T::AMM::add_liquidity_single_asset(who, asset_a, asset_b, amount_a, min_shares)?;
+
+			Self::join_farms(origin, farm_entries, asset_pair, shares_added)?;
+
+			Ok(())
+		}
+
 		/// Redeposit already locked LP shares to another yield farm.
 		///
 		/// This function create yield farm entry for existing deposit. LP shares are not transferred
@@ -366,6 +366,16 @@ impl AMMAddLiquidity<AccountId, AssetId, Balance> for DummyAMM {
 	) -> Result<Balance, DispatchError> {
 		Ok(ADD_LIQUIDITY_XYK_SHARE_AMOUNT)
 	}
+
+	fn add_liquidity_single_asset(
+		_origin: AccountId,
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+		_amount_a: Balance,
+		_min_shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(ADD_LIQUIDITY_XYK_SHARE_AMOUNT)
+	}
 }
 
 use hydradx_traits::registry::{AssetKind, Inspect as InspectRegistry};
@@ -839,6 +849,16 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		DEPOSIT_ENTRIES.with(|v| v.borrow().get(&(deposit_id, yield_farm_id)).map(|d| d.global_farm_id))
 	}
 
+	fn get_farm_entries(deposit_id: u128) -> Vec<(u32, u32)> {
+		DEPOSIT_ENTRIES.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((id, _), _)| *id == deposit_id)
+				.map(|((_, yield_farm_id), d)| (d.global_farm_id, *yield_farm_id))
+				.collect()
+		})
+	}
+
 	fn create_global_farm_without_price_adjustment(
 		_total_rewards: Self::Balance,
 		_planned_yielding_periods: Self::Period,
@@ -45,6 +45,7 @@ pub fn expect_events(e: Vec<RuntimeEvent>) {
 }
 
 pub mod add_liquidity_and_join_farms;
+pub mod add_liquidity_single_asset_and_join_farms;
 pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
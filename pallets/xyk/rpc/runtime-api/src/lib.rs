@@ -0,0 +1,38 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the XYK pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_xyk::types::{AssetId, PoolDetails};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query XYK pools and fee tiers.
+	pub trait XYKApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Get current state of all XYK pools.
+		fn pools() -> Vec<PoolDetails<AccountId>>;
+
+		/// Get current state of the pool of given assets. Returns `None` if the pool does not exist.
+		fn pool(asset_a: AssetId, asset_b: AssetId) -> Option<PoolDetails<AccountId>>;
+
+		/// Get fee tiers which can be selected when a pool is created, including the default fee.
+		fn fee_tiers() -> Vec<(u32, u32)>;
+	}
+}
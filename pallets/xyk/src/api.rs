@@ -0,0 +1,57 @@
+use crate::types::{AssetId, PoolDetails};
+use crate::{is_fee_lower, reduce_fee, Config, FeeTiers, Pallet, PoolAssets};
+use frame_support::traits::Get;
+use orml_traits::MultiCurrency;
+use sp_std::cmp::Ordering;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Returns details of all pools.
+	pub fn pools_details() -> Vec<PoolDetails<T::AccountId>> {
+		PoolAssets::<T>::iter_keys()
+			.filter_map(|pool_account| Self::pool_details(&pool_account))
+			.collect()
+	}
+
+	/// Returns details of the pool of given assets or `None` if the pool does not exist.
+	pub fn pool_details_of_assets(asset_a: AssetId, asset_b: AssetId) -> Option<PoolDetails<T::AccountId>> {
+		Self::pool_details(&Self::pair_account_from_assets(asset_a, asset_b))
+	}
+
+	/// Returns current state of the pool or `None` if the pool does not exist.
+	pub fn pool_details(pool_account: &T::AccountId) -> Option<PoolDetails<T::AccountId>> {
+		let (asset_a, asset_b) = PoolAssets::<T>::get(pool_account)?;
+
+		Some(PoolDetails {
+			pool_account: pool_account.clone(),
+			assets: (asset_a, asset_b),
+			share_token: Self::share_token(pool_account),
+			reserves: (
+				T::Currency::free_balance(asset_a, pool_account),
+				T::Currency::free_balance(asset_b, pool_account),
+			),
+			total_shares: Self::total_liquidity(pool_account),
+			fee: Self::get_pool_fee(pool_account),
+		})
+	}
+
+	/// Returns all fee tiers which can be selected when a pool is created, including the default fee.
+	/// Fee tiers are sorted from the lowest fee.
+	pub fn fee_tiers() -> Vec<(u32, u32)> {
+		let default_fee = T::GetExchangeFee::get();
+		let mut tiers: Vec<(u32, u32)> = FeeTiers::<T>::iter_keys()
+			.filter(|fee| *fee != reduce_fee(default_fee))
+			.collect();
+		tiers.push(default_fee);
+		tiers.sort_by(|a, b| {
+			if is_fee_lower(*a, *b) {
+				Ordering::Less
+			} else if is_fee_lower(*b, *a) {
+				Ordering::Greater
+			} else {
+				Ordering::Equal
+			}
+		});
+		tiers
+	}
+}
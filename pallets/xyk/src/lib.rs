@@ -23,14 +23,25 @@
 //!
 //! This pallet implements AMM Api trait therefore it is possible to plug this pool implementation
 //! into the exchange pallet.
+//!
+//! ### Fee tiers
+//!
+//! Each pool has its own trade fee which is selected when the pool is created. The fee can be either
+//! the default `GetExchangeFee` or one of the fee tiers managed by `AuthorityOrigin`.
+//!
+//! ### Protocol fee
+//!
+//! `ProtocolFee` share of each trade fee is taken out of the pool and passed to `XykHooks::on_trade_fee`,
+//! which distributes it (e.g. to referrals, staking and treasury). The rest of the fee stays in the pool.
+//! Protocol fee is set by `AuthorityOrigin` and is zero by default.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 #![allow(clippy::manual_inspect)]
 
-use frame_support::sp_runtime::{traits::Zero, DispatchError};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_support::sp_runtime::{traits::Zero, DispatchError, Permill};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, weights::Weight};
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{
@@ -49,12 +60,16 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 #[cfg(test)]
 mod tests;
 
+pub mod api;
 mod impls;
+pub mod migration;
 mod trade_execution;
+pub mod traits;
 pub mod types;
 pub mod weights;
 
 pub use impls::XYKSpotPrice;
+pub use traits::XykHooks;
 
 pub use weights::WeightInfo;
 
@@ -72,7 +87,10 @@ pub mod pallet {
 		Source,
 	};
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -98,10 +116,13 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
-		/// Trading fee rate
+		/// Default trading fee rate. Used by pools created without fee tier.
 		#[pallet::constant]
 		type GetExchangeFee: Get<(u32, u32)>;
 
+		/// Origin which can add and remove fee tiers.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Minimum trading limit
 		#[pallet::constant]
 		type MinTradingLimit: Get<Balance>;
@@ -135,6 +156,9 @@ pub mod pallet {
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Hooks processing protocol part of the trade fee.
+		type XykHooks: XykHooks<Self::AccountId, AssetId, Balance, Error = DispatchError>;
 	}
 
 	#[pallet::error]
@@ -211,6 +235,21 @@ pub mod pallet {
 
 		/// Pool cannot be created due to outside factors.
 		CannotCreatePool,
+
+		/// Fee tier is not in the set of allowed fee tiers.
+		FeeTierNotAllowed,
+
+		/// Fee tier is invalid. Fee must be lower than 100%.
+		InvalidFeeTier,
+
+		/// Fee tier has been already added.
+		FeeTierAlreadyExists,
+
+		/// Fee tier does not exist.
+		FeeTierNotFound,
+
+		/// More than the protocol fee amount was taken out of the pool.
+		FeeOverdraft,
 	}
 
 	#[pallet::event]
@@ -277,6 +316,15 @@ pub mod pallet {
 			fee_amount: Balance,
 			pool: T::AccountId,
 		},
+
+		/// New fee tier was added.
+		FeeTierAdded { fee: (u32, u32) },
+
+		/// Fee tier was removed.
+		FeeTierRemoved { fee: (u32, u32) },
+
+		/// Protocol share of the trade fee was updated.
+		ProtocolFeeSet { fee: Permill },
 	}
 
 	/// Asset id storage for shared pool tokens
@@ -295,6 +343,19 @@ pub mod pallet {
 	pub(crate) type PoolAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
 
+	/// Fee tiers which can be selected when a pool is created, in addition to the default fee.
+	/// Fee tiers are stored reduced to lowest terms, so equal fees have the same key.
+	#[pallet::storage]
+	pub type FeeTiers<T: Config> = StorageMap<_, Blake2_128Concat, (u32, u32), (), OptionQuery>;
+
+	/// Trade fee of a pool.
+	#[pallet::storage]
+	pub(crate) type PoolFee<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), OptionQuery>;
+
+	/// Share of the trade fee taken out of the pool by the protocol.
+	#[pallet::storage]
+	pub type ProtocolFee<T: Config> = StorageValue<_, Permill, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create new pool for given asset pair.
@@ -305,6 +366,9 @@ pub mod pallet {
 		/// Pool is created with initial liquidity provided by `origin`.
 		/// Shares are issued with specified initial price and represents proportion of asset in the pool.
 		///
+		/// `fee_tier` - trade fee of the pool. Must be either the default fee or one of the allowed fee tiers.
+		/// Default fee is used if not provided.
+		///
 		/// Emits `PoolCreated` event when successful.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
@@ -314,6 +378,7 @@ pub mod pallet {
 			amount_a: Balance,
 			asset_b: AssetId,
 			amount_b: Balance,
+			fee_tier: Option<(u32, u32)>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -336,6 +401,8 @@ pub mod pallet {
 
 			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
 
+			let fee = Self::ensure_fee_tier_allowed(fee_tier)?;
+
 			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
 
 			ensure!(
@@ -368,6 +435,7 @@ pub mod pallet {
 
 			<ShareToken<T>>::insert(&pair_account, share_token);
 			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+			<PoolFee<T>>::insert(&pair_account, fee);
 
 			Self::deposit_event(Event::PoolCreated {
 				who: who.clone(),
@@ -431,113 +499,14 @@ pub mod pallet {
 			liquidity_amount: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!liquidity_amount.is_zero(), Error::<T>::ZeroLiquidity);
-
-			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let share_token = Self::share_token(&pair_account);
-
-			let total_shares = Self::total_liquidity(&pair_account);
-
-			let account_shares = T::Currency::free_balance(share_token, &who);
-
-			ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientLiquidity);
-
-			ensure!(account_shares >= liquidity_amount, Error::<T>::InsufficientAssetBalance);
-
-			// Account's liquidity left should be either 0 or at least MinPoolLiquidity
-			ensure!(
-				(account_shares.saturating_sub(liquidity_amount)) >= T::MinPoolLiquidity::get()
-					|| (account_shares == liquidity_amount),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
-			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
-
-			let liquidity_out = hydra_dx_math::xyk::calculate_liquidity_out(
-				asset_a_reserve,
-				asset_b_reserve,
-				liquidity_amount,
-				total_shares,
-			)
-			.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
-
-			let (remove_amount_a, remove_amount_b) = liquidity_out;
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &pair_account) >= remove_amount_a,
-				Error::<T>::InsufficientPoolAssetBalance
-			);
-			ensure!(
-				T::Currency::free_balance(asset_b, &pair_account) >= remove_amount_b,
-				Error::<T>::InsufficientPoolAssetBalance
-			);
-
-			let liquidity_left = total_shares
-				.checked_sub(liquidity_amount)
-				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
-
-			T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
-			T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
-
-			T::Currency::withdraw(share_token, &who, liquidity_amount)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
-
-			let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
-			let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
-			T::AMMHandler::on_liquidity_changed(
-				T::OracleSource::get(),
-				asset_a,
-				asset_b,
-				remove_amount_a,
-				remove_amount_b,
-				liquidity_a,
-				liquidity_b,
-				Ratio::new(liquidity_a, liquidity_b),
-			)
-			.map_err(|(_w, e)| e)?;
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: who.clone(),
+			Self::do_remove_liquidity(
+				who,
 				asset_a,
 				asset_b,
-				shares: liquidity_amount,
-			});
-
-			if liquidity_left == 0 {
-				<ShareToken<T>>::remove(&pair_account);
-				<PoolAssets<T>>::remove(&pair_account);
-				<TotalLiquidity<T>>::remove(&pair_account);
-
-				// Ignore the failure, this cant stop liquidity removal
-				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
-
-				if r.is_err() {
-					log::trace!(
-					target: "xyk::remova_liquidity", "XYK: Failed to remove account {:?} from dust-removal whitelist. Reason {:?}",
-						pair_account,
-					r
-					);
-				}
-
-				Self::deposit_event(Event::PoolDestroyed {
-					who,
-					asset_a,
-					asset_b,
-					share_token,
-					pool: pair_account,
-				});
-			}
+				liquidity_amount,
+				Balance::zero(),
+				Balance::zero(),
+			)?;
 
 			Ok(())
 		}
@@ -551,7 +520,7 @@ pub mod pallet {
 		/// Emits `SellExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight() + Pallet::<T>::trade_fee_weight())]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -575,7 +544,7 @@ pub mod pallet {
 		/// Emits `BuyExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight() + Pallet::<T>::trade_fee_weight())]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -590,6 +559,131 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add new fee tier which can be selected when a pool is created.
+		///
+		/// Fee tier is stored reduced to lowest terms, e.g. `(6, 2000)` is stored as `(3, 1000)`.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `FeeTierAdded` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_fee_tier())]
+		pub fn add_fee_tier(origin: OriginFor<T>, fee: (u32, u32)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(fee.1 > 0 && fee.0 < fee.1, Error::<T>::InvalidFeeTier);
+			let fee = reduce_fee(fee);
+			ensure!(!FeeTiers::<T>::contains_key(fee), Error::<T>::FeeTierAlreadyExists);
+
+			FeeTiers::<T>::insert(fee, ());
+
+			Self::deposit_event(Event::FeeTierAdded { fee });
+
+			Ok(())
+		}
+
+		/// Remove fee tier.
+		///
+		/// Removed fee tier cannot be selected for new pools. Existing pools keep their fee.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `FeeTierRemoved` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_tier())]
+		pub fn remove_fee_tier(origin: OriginFor<T>, fee: (u32, u32)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let fee = reduce_fee(fee);
+			ensure!(FeeTiers::<T>::contains_key(fee), Error::<T>::FeeTierNotFound);
+
+			FeeTiers::<T>::remove(fee);
+
+			Self::deposit_event(Event::FeeTierRemoved { fee });
+
+			Ok(())
+		}
+
+		/// Remove liquidity from specific liquidity pool in the form of burning shares.
+		///
+		/// Same as `remove_liquidity` but fails if amount of any asset received is lower than given minimum.
+		///
+		/// Parameters:
+		/// - `min_amount_a`: minimum amount of `asset_a` to receive
+		/// - `min_amount_b`: minimum amount of `asset_b` to receive
+		///
+		/// Emits 'LiquidityRemoved' when successful.
+		/// Emits 'PoolDestroyed' when pool is destroyed.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_liquidity()
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+		)]
+		#[transactional]
+		pub fn remove_liquidity_with_limits(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			liquidity_amount: Balance,
+			min_amount_a: Balance,
+			min_amount_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_liquidity(who, asset_a, asset_b, liquidity_amount, min_amount_a, min_amount_b)?;
+
+			Ok(())
+		}
+
+		/// Add liquidity to previously created asset pair pool providing only `asset_a`.
+		///
+		/// Optimal portion of `amount_a` is sold for `asset_b` first, so that the remaining amount of `asset_a`
+		/// and received amount of `asset_b` can be added to the pool in the pool ratio.
+		/// Negligible amount of assets left over due to rounding stays in the account.
+		///
+		/// Parameters:
+		/// - `min_shares`: minimum amount of shares to receive
+		///
+		/// Emits `pallet_broadcast::Swapped` and `LiquidityAdded` events when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_liquidity_single_asset()
+				.saturating_add(T::AMMHandler::on_trade_weight())
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(Pallet::<T>::trade_fee_weight())
+		)]
+		#[transactional]
+		pub fn add_liquidity_single_asset(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			min_shares: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_add_liquidity_single_asset(who, asset_a, asset_b, amount_a, min_shares)?;
+
+			Ok(())
+		}
+
+		/// Set share of the trade fee which is taken out of the pool by the protocol.
+		///
+		/// Protocol part of the fee is processed by `XykHooks`. The rest of the fee stays in the pool.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `ProtocolFeeSet` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, fee: Permill) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ProtocolFee::<T>::put(fee);
+
+			Self::deposit_event(Event::ProtocolFeeSet { fee });
+
+			Ok(())
+		}
 	}
 }
 
@@ -690,6 +784,186 @@ impl<T: Config> Pallet<T> {
 		Ok(shares_added)
 	}
 
+	#[transactional]
+	fn do_add_liquidity_single_asset(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		min_shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount_a,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+
+		let swap_amount = hydra_dx_math::xyk::calculate_single_asset_swap_amount(
+			asset_a_reserve,
+			amount_a,
+			Self::get_pool_fee(&pair_account),
+		)
+		.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
+
+		let initial_asset_b_balance = T::Currency::free_balance(asset_b, &who);
+
+		<Self as AMM<_, _, _, _>>::sell(&who, asset_pair, swap_amount, Balance::zero(), false)?;
+
+		let amount_b = T::Currency::free_balance(asset_b, &who)
+			.checked_sub(initial_asset_b_balance)
+			.ok_or(Error::<T>::AddAssetAmountInvalid)?;
+		let amount_a_left = amount_a
+			.checked_sub(swap_amount)
+			.ok_or(Error::<T>::AddAssetAmountInvalid)?;
+
+		// Amount of asset b is limited so that required amount of asset a, which is rounded up, does not exceed the amount left
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+		let max_amount_b =
+			hydra_dx_math::xyk::calculate_spot_price(asset_a_reserve, asset_b_reserve, amount_a_left.saturating_sub(1))
+				.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
+
+		let shares_added = Self::do_add_liquidity(who, asset_b, asset_a, amount_b.min(max_amount_b), amount_a_left)?;
+
+		ensure!(shares_added >= min_shares, Error::<T>::AssetAmountNotReachedLimit);
+
+		Ok(shares_added)
+	}
+
+	fn do_remove_liquidity(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		liquidity_amount: Balance,
+		min_amount_a: Balance,
+		min_amount_b: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!liquidity_amount.is_zero(), Error::<T>::ZeroLiquidity);
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let share_token = Self::share_token(&pair_account);
+
+		let total_shares = Self::total_liquidity(&pair_account);
+
+		let account_shares = T::Currency::free_balance(share_token, &who);
+
+		ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientLiquidity);
+
+		ensure!(account_shares >= liquidity_amount, Error::<T>::InsufficientAssetBalance);
+
+		// Account's liquidity left should be either 0 or at least MinPoolLiquidity
+		ensure!(
+			(account_shares.saturating_sub(liquidity_amount)) >= T::MinPoolLiquidity::get()
+				|| (account_shares == liquidity_amount),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+		let liquidity_out = hydra_dx_math::xyk::calculate_liquidity_out(
+			asset_a_reserve,
+			asset_b_reserve,
+			liquidity_amount,
+			total_shares,
+		)
+		.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
+
+		let (remove_amount_a, remove_amount_b) = liquidity_out;
+
+		ensure!(
+			remove_amount_a >= min_amount_a && remove_amount_b >= min_amount_b,
+			Error::<T>::AssetAmountNotReachedLimit
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &pair_account) >= remove_amount_a,
+			Error::<T>::InsufficientPoolAssetBalance
+		);
+		ensure!(
+			T::Currency::free_balance(asset_b, &pair_account) >= remove_amount_b,
+			Error::<T>::InsufficientPoolAssetBalance
+		);
+
+		let liquidity_left = total_shares
+			.checked_sub(liquidity_amount)
+			.ok_or(Error::<T>::InvalidLiquidityAmount)?;
+
+		T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
+		T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
+
+		T::Currency::withdraw(share_token, &who, liquidity_amount)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
+
+		let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
+		let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			asset_a,
+			asset_b,
+			remove_amount_a,
+			remove_amount_b,
+			liquidity_a,
+			liquidity_b,
+			Ratio::new(liquidity_a, liquidity_b),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: who.clone(),
+			asset_a,
+			asset_b,
+			shares: liquidity_amount,
+		});
+
+		if liquidity_left == 0 {
+			<ShareToken<T>>::remove(&pair_account);
+			<PoolAssets<T>>::remove(&pair_account);
+			<TotalLiquidity<T>>::remove(&pair_account);
+			<PoolFee<T>>::remove(&pair_account);
+
+			// Ignore the failure, this cant stop liquidity removal
+			let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
+
+			if r.is_err() {
+				log::trace!(
+				target: "xyk::remova_liquidity", "XYK: Failed to remove account {:?} from dust-removal whitelist. Reason {:?}",
+					pair_account,
+				r
+				);
+			}
+
+			Self::deposit_event(Event::PoolDestroyed {
+				who,
+				asset_a,
+				asset_b,
+				share_token,
+				pool: pair_account,
+			});
+		}
+
+		Ok((remove_amount_a, remove_amount_b))
+	}
+
 	/// Return balance of each asset in selected liquidity pool.
 	pub fn get_pool_balances(pool_address: T::AccountId) -> Option<Vec<(AssetId, Balance)>> {
 		let mut balances = Vec::new();
@@ -702,24 +976,133 @@ impl<T: Config> Pallet<T> {
 		}
 		Some(balances)
 	}
-	/// Calculate discounted trade fee
-	fn calculate_discounted_fee(amount: Balance) -> Result<Balance, DispatchError> {
+	/// Return trade fee of the pool. Default fee is returned if the pool has no fee set.
+	pub fn get_pool_fee(pair_account: &T::AccountId) -> (u32, u32) {
+		<PoolFee<T>>::get(pair_account).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
+	/// Return the fee to be used for a new pool or error if the fee tier is not allowed.
+	/// Fee equal to the default fee is returned as the default fee, fee equal to a fee tier as the fee tier.
+	fn ensure_fee_tier_allowed(fee_tier: Option<(u32, u32)>) -> Result<(u32, u32), DispatchError> {
+		let default_fee = T::GetExchangeFee::get();
+
+		match fee_tier.map(reduce_fee) {
+			None => Ok(default_fee),
+			Some(fee) if fee == reduce_fee(default_fee) => Ok(default_fee),
+			Some(fee) if FeeTiers::<T>::contains_key(fee) => Ok(fee),
+			Some(_) => Err(Error::<T>::FeeTierNotAllowed.into()),
+		}
+	}
+
+	/// Calculate discounted trade fee.
+	/// Discounted fee is never higher than the fee of the pool.
+	fn calculate_discounted_fee(pair_account: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let pool_fee = Self::get_pool_fee(pair_account);
+		let discounted_fee = T::DiscountedFee::get();
+
+		let fee = if is_fee_lower(pool_fee, discounted_fee) {
+			pool_fee
+		} else {
+			discounted_fee
+		};
+
 		Ok(
-			hydra_dx_math::fee::calculate_pool_trade_fee(amount, T::DiscountedFee::get())
+			hydra_dx_math::fee::calculate_pool_trade_fee(amount, fee)
 				.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?,
 		)
 	}
 
-	/// Calculate trade fee
-	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		let fee = T::GetExchangeFee::get();
-		Ok(hydra_dx_math::fee::calculate_pool_trade_fee(amount, (fee.0, fee.1))
-			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
+	/// Calculate trade fee of the pool
+	fn calculate_fee(pair_account: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let fee = Self::get_pool_fee(pair_account);
+		Ok(
+			hydra_dx_math::fee::calculate_pool_trade_fee(amount, fee)
+				.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?,
+		)
 	}
 
 	pub fn pair_account_from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
 		T::AssetPairAccountId::from_assets(asset_a, asset_b, "xyk")
 	}
+
+	/// Take protocol share of the trade fee out of the pool by calling `on_trade_fee` hook
+	/// and ensure that no more than the protocol fee amount is transferred.
+	///
+	/// Returns fee entries describing where all parts of the trade fee went.
+	fn process_trade_fee(
+		trader: &T::AccountId,
+		pair_account: &T::AccountId,
+		asset: AssetId,
+		amount: Balance,
+	) -> Result<Vec<Fee<T::AccountId>>, DispatchError> {
+		let protocol_fee_amount = ProtocolFee::<T>::get().mul_floor(amount);
+
+		let taken_fee_entries: Vec<Fee<T::AccountId>> = if protocol_fee_amount.is_zero() {
+			vec![]
+		} else {
+			let initial_reserve = T::Currency::free_balance(asset, pair_account);
+
+			let taken_fee =
+				T::XykHooks::on_trade_fee(pair_account.clone(), trader.clone(), asset, protocol_fee_amount)?;
+			let entries: Vec<Fee<T::AccountId>> = taken_fee
+				.into_iter()
+				.flatten()
+				.filter(|(balance, _)| *balance > 0)
+				.map(|(balance, recipient)| Fee::new(asset, balance, Destination::Account(recipient)))
+				.collect();
+
+			let taken_fee_total: Balance = entries.iter().map(|fee| fee.amount).sum();
+			let reserve = T::Currency::free_balance(asset, pair_account);
+			let diff = initial_reserve.saturating_sub(reserve);
+			ensure!(diff <= protocol_fee_amount, Error::<T>::FeeOverdraft);
+			ensure!(diff == taken_fee_total, Error::<T>::FeeOverdraft);
+
+			entries
+		};
+
+		let taken_fee_total: Balance = taken_fee_entries.iter().map(|fee| fee.amount).sum();
+
+		let mut all_trade_fees = vec![Fee::new(
+			asset,
+			amount.saturating_sub(taken_fee_total),
+			Destination::Account(pair_account.clone()),
+		)];
+		all_trade_fees.extend(taken_fee_entries);
+
+		Ok(all_trade_fees)
+	}
+
+	/// Weight of processing the protocol part of a trade fee - read of `ProtocolFee` and `XykHooks::on_trade_fee`.
+	pub fn trade_fee_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(T::XykHooks::on_trade_fee_weight())
+	}
+}
+
+/// Reduce fee to lowest terms. Fee with zero numerator or denominator is reduced to `(0, 1)`.
+fn reduce_fee(fee: (u32, u32)) -> (u32, u32) {
+	if fee.0 == 0 || fee.1 == 0 {
+		return (0, 1);
+	}
+	let (mut a, mut b) = fee;
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	(fee.0 / a, fee.1 / a)
+}
+
+/// Compare two fees. Fee with zero denominator is considered zero.
+fn is_fee_lower(fee: (u32, u32), other: (u32, u32)) -> bool {
+	let normalize = |f: (u32, u32)| {
+		if f.1 == 0 {
+			(0u64, 1u64)
+		} else {
+			(f.0 as u64, f.1 as u64)
+		}
+	};
+	let (fee, other) = (normalize(fee), normalize(other));
+	fee.0 * other.1 < other.0 * fee.1
 }
 
 // Implementation of AMM API which makes possible to plug the AMM pool into the exchange pallet.
@@ -814,9 +1197,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		);
 
 		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(amount_out)?
+			Self::calculate_discounted_fee(&pair_account, amount_out)?
 		} else {
-			Self::calculate_fee(amount_out)?
+			Self::calculate_fee(&pair_account, amount_out)?
 		};
 
 		let amount_out_without_fee = amount_out
@@ -893,6 +1276,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b,
 		)?;
 
+		let fees = Self::process_trade_fee(&transfer.origin, &pair_account, transfer.fee.0, transfer.fee.1)?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -926,11 +1311,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			pallet_broadcast::types::TradeOperation::ExactIn,
 			vec![Asset::new(transfer.assets.asset_in, transfer.amount)],
 			vec![Asset::new(transfer.assets.asset_out, transfer.amount_b)],
-			vec![Fee {
-				asset: transfer.fee.0,
-				amount: transfer.fee.1,
-				destination: Destination::Account(pair_account),
-			}],
+			fees,
 		);
 
 		Ok(())
@@ -992,9 +1373,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		);
 
 		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(buy_price)?
+			Self::calculate_discounted_fee(&pair_account, buy_price)?
 		} else {
-			Self::calculate_fee(buy_price)?
+			Self::calculate_fee(&pair_account, buy_price)?
 		};
 
 		let buy_price_with_fee = buy_price
@@ -1073,6 +1454,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b + transfer.fee.1,
 		)?;
 
+		let fees = Self::process_trade_fee(&transfer.origin, &pair_account, transfer.fee.0, transfer.fee.1)?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -1106,11 +1489,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			pallet_broadcast::types::TradeOperation::ExactOut,
 			vec![Asset::new(transfer.assets.asset_in, transfer.amount)],
 			vec![Asset::new(transfer.assets.asset_out, transfer.amount_b)],
-			vec![Fee {
-				asset: transfer.fee.0,
-				amount: transfer.fee.1,
-				destination: Destination::Account(pair_account),
-			}],
+			fees,
 		);
 
 		Ok(())
@@ -1132,8 +1511,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		T::MaxOutRatio::get()
 	}
 
-	fn get_fee(_pool_account_id: &T::AccountId) -> (u32, u32) {
-		T::GetExchangeFee::get()
+	fn get_fee(pool_account_id: &T::AccountId) -> (u32, u32) {
+		Self::get_pool_fee(pool_account_id)
 	}
 }
 
@@ -1180,4 +1559,14 @@ impl<T: Config> AMMAddLiquidity<T::AccountId, AssetId, Balance> for Pallet<T> {
 	) -> Result<Balance, DispatchError> {
 		Self::do_add_liquidity(who, asset_a, asset_b, amount_a, amount_b_max_limit)
 	}
+
+	fn add_liquidity_single_asset(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		min_shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		Self::do_add_liquidity_single_asset(who, asset_a, asset_b, amount_a, min_shares)
+	}
 }
@@ -0,0 +1,90 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// The log target.
+const TARGET: &str = "runtime::xyk::migration::v1";
+
+pub mod v1 {
+	use super::*;
+
+	/// Sets trade fee of all existing pools to the default `GetExchangeFee`.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use codec::Encode;
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let pools_count = PoolAssets::<T>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "XYK migration: PRE checks successful!");
+
+			Ok(pools_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(target: TARGET, "XYK migration to v1 already applied, skipping");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for XYK");
+
+			let default_fee = T::GetExchangeFee::get();
+			let mut weight = T::DbWeight::get().reads(1);
+
+			for pool_account in PoolAssets::<T>::iter_keys() {
+				PoolFee::<T>::insert(&pool_account, default_fee);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let pools_count = <u64 as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			let default_fee = T::GetExchangeFee::get();
+			for pool_account in PoolAssets::<T>::iter_keys() {
+				assert_eq!(
+					PoolFee::<T>::get(&pool_account),
+					Some(default_fee),
+					"Pool fee has not been migrated!"
+				);
+			}
+			assert_eq!(PoolFee::<T>::iter_keys().count() as u64, pools_count);
+
+			log::info!(target: TARGET, "XYK migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
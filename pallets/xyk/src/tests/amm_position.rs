@@ -18,7 +18,8 @@ fn get_liquidity_behind_shares_should_return_both_assets_value_when_pool_exists(
 				asset_a,
 				100 * ONE,
 				asset_b,
-				10 * ONE
+				10 * ONE,
+				None
 			));
 
 			let pair_account = XYK::get_pair_id(AssetPair {
@@ -0,0 +1,363 @@
+pub use super::mock::*;
+use crate::types::{AssetPair, PoolDetails};
+use crate::{Error, Event, FeeTiers, PoolFee};
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeExecution};
+use hydradx_traits::AMM as AmmPool;
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+
+const FEE_TIER: (u32, u32) = (1, 100);
+
+fn create_pool_with_fee_tier(fee_tier: Option<(u32, u32)>) -> AccountId {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		100_000_000_000_000,
+		DOT,
+		200_000_000_000_000,
+		fee_tier,
+	));
+
+	XYK::get_pair_id(AssetPair {
+		asset_in: HDX,
+		asset_out: DOT,
+	})
+}
+
+#[test]
+fn add_fee_tier_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert!(FeeTiers::<Test>::contains_key(FEE_TIER));
+		expect_events(vec![Event::FeeTierAdded { fee: FEE_TIER }.into()]);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(XYK::add_fee_tier(RuntimeOrigin::signed(ALICE), FEE_TIER), BadOrigin);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), (1, 0)),
+			Error::<Test>::InvalidFeeTier
+		);
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), (100, 100)),
+			Error::<Test>::InvalidFeeTier
+		);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_fee_tier_already_exists() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER),
+			Error::<Test>::FeeTierAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_store_fee_reduced_to_lowest_terms() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), (6, 2_000)));
+
+		assert!(FeeTiers::<Test>::contains_key((3, 1_000)));
+		assert!(!FeeTiers::<Test>::contains_key((6, 2_000)));
+		expect_events(vec![Event::FeeTierAdded { fee: (3, 1_000) }.into()]);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_equal_fee_tier_already_exists() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), (3, 1_000)));
+
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), (6, 2_000)),
+			Error::<Test>::FeeTierAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_remove_equal_fee_tier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), (3, 1_000)));
+
+		assert_ok!(XYK::remove_fee_tier(RuntimeOrigin::root(), (6, 2_000)));
+
+		assert!(!FeeTiers::<Test>::contains_key((3, 1_000)));
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert_ok!(XYK::remove_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert!(!FeeTiers::<Test>::contains_key(FEE_TIER));
+		expect_events(vec![Event::FeeTierRemoved { fee: FEE_TIER }.into()]);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert_noop!(XYK::remove_fee_tier(RuntimeOrigin::signed(ALICE), FEE_TIER), BadOrigin);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_fail_when_fee_tier_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::remove_fee_tier(RuntimeOrigin::root(), FEE_TIER),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_use_default_fee_when_fee_tier_is_not_provided() {
+	new_test_ext().execute_with(|| {
+		let pair_account = create_pool_with_fee_tier(None);
+
+		assert_eq!(PoolFee::<Test>::get(pair_account), Some((2, 1_000)));
+		assert_eq!(XYK::get_fee(&pair_account), (2, 1_000));
+	});
+}
+
+#[test]
+fn create_pool_should_store_fee_tier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+
+		assert_eq!(PoolFee::<Test>::get(pair_account), Some(FEE_TIER));
+		assert_eq!(XYK::get_fee(&pair_account), FEE_TIER);
+	});
+}
+
+#[test]
+fn create_pool_should_allow_default_fee_as_fee_tier() {
+	new_test_ext().execute_with(|| {
+		let pair_account = create_pool_with_fee_tier(Some((2, 1_000)));
+
+		assert_eq!(XYK::get_fee(&pair_account), (2, 1_000));
+	});
+}
+
+#[test]
+fn create_pool_should_use_fee_tier_when_equal_fee_is_provided() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		let pair_account = create_pool_with_fee_tier(Some((2, 200)));
+
+		assert_eq!(PoolFee::<Test>::get(pair_account), Some(FEE_TIER));
+	});
+}
+
+#[test]
+fn create_pool_should_use_default_fee_when_equal_fee_is_provided() {
+	new_test_ext().execute_with(|| {
+		let pair_account = create_pool_with_fee_tier(Some((1, 500)));
+
+		assert_eq!(PoolFee::<Test>::get(pair_account), Some((2, 1_000)));
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_tier_is_not_allowed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100_000_000_000_000,
+				DOT,
+				200_000_000_000_000,
+				Some(FEE_TIER),
+			),
+			Error::<Test>::FeeTierNotAllowed
+		);
+	});
+}
+
+#[test]
+fn removed_fee_tier_should_not_change_fee_of_existing_pool() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+
+		assert_ok!(XYK::remove_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+
+		assert_eq!(XYK::get_fee(&pair_account), FEE_TIER);
+	});
+}
+
+#[test]
+fn sell_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+
+		let amount_in = 1_000_000_000_000;
+		let amount_out =
+			hydra_dx_math::xyk::calculate_out_given_in(100_000_000_000_000, 200_000_000_000_000, amount_in).unwrap();
+		let fee = amount_out / 100;
+
+		let calculated_amount_out = XYK::calculate_sell(PoolType::XYK, HDX, DOT, amount_in).unwrap();
+		assert_eq!(calculated_amount_out, amount_out - fee);
+
+		let dot_balance = Currency::free_balance(DOT, &BOB);
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, amount_in, 0, false));
+
+		assert_eq!(Currency::free_balance(DOT, &BOB), dot_balance + amount_out - fee);
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DOT,
+			amount: amount_in,
+			sale_price: amount_out - fee,
+			fee_asset: DOT,
+			fee_amount: fee,
+			pool: pair_account,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+
+		let amount_out = 1_000_000_000_000;
+		let amount_in =
+			hydra_dx_math::xyk::calculate_in_given_out(200_000_000_000_000, 100_000_000_000_000, amount_out).unwrap();
+		let fee = amount_in / 100;
+
+		let calculated_amount_in = XYK::calculate_buy(PoolType::XYK, HDX, DOT, amount_out).unwrap();
+		assert_eq!(calculated_amount_in, amount_in + fee);
+
+		let hdx_balance = Currency::free_balance(HDX, &BOB);
+		assert_ok!(XYK::buy(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			amount_out,
+			u128::MAX,
+			false
+		));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), hdx_balance - amount_in - fee);
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_out: DOT,
+			asset_in: HDX,
+			amount: amount_out,
+			buy_price: amount_in,
+			fee_asset: HDX,
+			fee_amount: fee,
+			pool: pair_account,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn discounted_fee_should_not_exceed_pool_fee() {
+	new_test_ext().execute_with(|| {
+		let low_fee = (1, 10_000);
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), low_fee));
+		let pair_account = create_pool_with_fee_tier(Some(low_fee));
+
+		assert_eq!(XYK::calculate_discounted_fee(&pair_account, 1_000_000), Ok(100));
+		assert_eq!(XYK::calculate_fee(&pair_account, 1_000_000), Ok(100));
+	});
+}
+
+#[test]
+fn pool_fee_should_be_removed_when_pool_is_destroyed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+
+		assert_ok!(XYK::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000_000_000
+		));
+
+		assert!(!XYK::exists(AssetPair::new(HDX, DOT)));
+		assert_eq!(PoolFee::<Test>::get(pair_account), None);
+	});
+}
+
+#[test]
+fn pool_details_should_report_pool_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		let pair_account = create_pool_with_fee_tier(Some(FEE_TIER));
+		let share_token = XYK::share_token(pair_account);
+
+		let expected = PoolDetails {
+			pool_account: pair_account,
+			assets: (HDX, DOT),
+			share_token,
+			reserves: (100_000_000_000_000, 200_000_000_000_000),
+			total_shares: 100_000_000_000_000,
+			fee: FEE_TIER,
+		};
+
+		assert_eq!(XYK::pool_details_of_assets(DOT, HDX), Some(expected.clone()));
+		assert_eq!(XYK::pools_details(), vec![expected]);
+		assert_eq!(XYK::pool_details_of_assets(HDX, ACA), None);
+	});
+}
+
+#[test]
+fn fee_tiers_should_include_default_fee_sorted_by_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE_TIER));
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), (5, 10_000)));
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), (2, 1_000)));
+
+		assert_eq!(XYK::fee_tiers(), vec![(1, 2_000), (2, 1_000), FEE_TIER]);
+	});
+}
+
+#[test]
+fn migration_should_set_default_fee_of_existing_pools() {
+	new_test_ext().execute_with(|| {
+		let pair_account = create_pool_with_fee_tier(None);
+		PoolFee::<Test>::remove(pair_account);
+		StorageVersion::new(0).put::<XYK>();
+
+		crate::migration::v1::Migration::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoolFee::<Test>::get(pair_account), Some((2, 1_000)));
+		assert_eq!(StorageVersion::get::<XYK>(), 1);
+	});
+}
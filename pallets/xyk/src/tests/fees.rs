@@ -10,38 +10,41 @@ use crate::types::AssetPair;
 #[test]
 fn fee_calculation() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(XYK::calculate_fee(100_000), Ok(200));
-		assert_eq!(XYK::calculate_fee(10_000), Ok(20));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(200));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(20));
 
-		assert_eq!(XYK::calculate_discounted_fee(9_999), Ok(0));
-		assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(7));
-		assert_eq!(XYK::calculate_discounted_fee(100_000), Ok(70));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 9_999), Ok(0));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 10_000), Ok(7));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 100_000), Ok(70));
 	});
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1000))
 		.with_discounted_fee((10, 1000))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100_000), Ok(1_000));
-			assert_eq!(XYK::calculate_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(1_000));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
 
-			assert_eq!(XYK::calculate_discounted_fee(999), Ok(0));
-			assert_eq!(XYK::calculate_discounted_fee(1_000), Ok(10));
-			assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 999), Ok(0));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 1_000), Ok(10));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 0))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100000), Ok(0));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100000), Ok(0));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1))
 		.build()
 		.execute_with(|| {
-			assert_noop!(XYK::calculate_fee(u128::MAX), Error::<Test>::FeeAmountInvalid);
+			assert_noop!(
+				XYK::calculate_fee(&HDX_DOT_POOL_ID, u128::MAX),
+				Error::<Test>::FeeAmountInvalid
+			);
 		});
 }
 
@@ -54,6 +57,7 @@ fn get_fee_should_work() {
 			1_000_000_000,
 			DOT,
 			2_000_000_000,
+			None,
 		));
 
 		// existing pool
@@ -85,6 +89,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -92,6 +97,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair = AssetPair {
@@ -173,6 +179,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -180,6 +187,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair = AssetPair {
@@ -264,6 +272,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -271,6 +280,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair = AssetPair {
@@ -352,6 +362,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -360,6 +371,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let native_pair_account = XYK::get_pair_id(AssetPair {
@@ -444,6 +456,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -452,6 +465,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let native_pair_account = XYK::get_pair_id(AssetPair {
@@ -539,6 +553,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -547,6 +562,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair = AssetPair {
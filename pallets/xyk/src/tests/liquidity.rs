@@ -17,7 +17,8 @@ fn add_liquidity_should_work() {
 			asset_a,
 			100_000_000,
 			asset_b,
-			65_400_000
+			65_400_000,
+			None
 		));
 		let pair_account = XYK::get_pair_id(AssetPair {
 			asset_in: asset_a,
@@ -77,7 +78,8 @@ fn add_liquidity_mints_correct_shares() {
 			asset_a,
 			100_000_000,
 			asset_b,
-			65_400_000
+			65_400_000,
+			None
 		));
 
 		assert_ok!(XYK::add_liquidity(
@@ -109,7 +111,8 @@ fn add_liquidity_as_another_user_should_work() {
 			asset_b,
 			100_000_000,
 			asset_a,
-			1_000_000_000_000
+			1_000_000_000_000,
+			None
 		));
 		assert_ok!(XYK::add_liquidity(
 			RuntimeOrigin::signed(user),
@@ -197,6 +200,7 @@ fn add_liquidity_should_work_when_limit_is_set_above_account_balance() {
 			100_000_000,
 			asset_b,
 			100_000_000,
+			None,
 		));
 
 		assert!(Currency::free_balance(asset_b, &user) < amount_b_max_limit);
@@ -223,7 +227,8 @@ fn remove_liquidity_should_work() {
 			asset_a,
 			100_000_000,
 			asset_b,
-			1_000_000_000_000
+			1_000_000_000_000,
+			None
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -284,6 +289,7 @@ fn remove_liquidity_without_shares_should_not_work() {
 			100_000_000,
 			asset_b,
 			100_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -346,6 +352,7 @@ fn remove_liquidity_from_reduced_pool_should_not_work() {
 			100_000_000,
 			asset_b,
 			100_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -436,6 +443,7 @@ fn add_liquidity_more_than_owner_should_not_work() {
 			200_000_000,
 			ACA,
 			600_000_000_000_000,
+			None,
 		));
 
 		assert_eq!(Currency::free_balance(ACA, &ALICE), 400_000_000_000_000);
@@ -467,7 +475,14 @@ fn add_liquidity_more_than_owner_should_not_work() {
 #[test]
 fn add_insufficient_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 1000, ACA, 1500,));
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			1000,
+			ACA,
+			1500,
+			None,
+		));
 
 		assert_noop!(
 			XYK::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, ACA, 0, 0),
@@ -495,6 +510,7 @@ fn add_liquidity_exceeding_max_limit_should_not_work() {
 			100_000_000_000_000,
 			ACA,
 			100_000_000_000_000,
+			None,
 		));
 
 		assert_noop!(
@@ -506,7 +522,14 @@ fn add_liquidity_exceeding_max_limit_should_not_work() {
 #[test]
 fn remove_liquidity_should_respect_min_pool_limit() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 1000, ACA, 1500,));
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			1000,
+			ACA,
+			1500,
+			None,
+		));
 
 		assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, HDX, 2000, 2000));
 
@@ -567,7 +590,8 @@ fn add_liquidity_overflow_work() {
 				asset_a,
 				100_000,
 				asset_b,
-				10_u128.pow(38)
+				10_u128.pow(38),
+				None
 			));
 
 			assert_noop!(
@@ -598,6 +622,7 @@ fn share_ratio_calculations_are_correct() {
 				100 * ONE,
 				asset_b,
 				65_440_000_000_000,
+				None,
 			));
 
 			assert_eq!(Currency::free_balance(asset_a, &BOB), 1_000 * ONE);
@@ -687,3 +712,62 @@ fn share_ratio_calculations_are_correct() {
 			}
 		});
 }
+
+#[test]
+fn remove_liquidity_with_limits_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			DOT,
+			200 * ONE,
+			None,
+		));
+
+		assert_ok!(XYK::remove_liquidity_with_limits(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			10 * ONE,
+			10 * ONE,
+			20 * ONE,
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair::new(HDX, DOT));
+		assert_eq!(Currency::free_balance(HDX, &pair_account), 90 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), 180 * ONE);
+		assert_eq!(XYK::total_liquidity(pair_account), 90 * ONE);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			who: ALICE,
+			asset_a: HDX,
+			asset_b: DOT,
+			shares: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_with_limits_should_fail_when_min_amount_not_reached() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			DOT,
+			200 * ONE,
+			None,
+		));
+
+		assert_noop!(
+			XYK::remove_liquidity_with_limits(RuntimeOrigin::signed(ALICE), HDX, DOT, 10 * ONE, 10 * ONE + 1, 0),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+		assert_noop!(
+			XYK::remove_liquidity_with_limits(RuntimeOrigin::signed(ALICE), HDX, DOT, 10 * ONE, 0, 20 * ONE + 1),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+	});
+}
@@ -30,7 +30,7 @@ use crate::types::{AssetId, Balance};
 use frame_support::traits::{Everything, Get, Nothing};
 use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, Source};
 
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use std::cell::RefCell;
 
@@ -216,6 +216,7 @@ impl xyk::Config for Test {
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -225,6 +226,28 @@ impl xyk::Config for Test {
 	type DiscountedFee = DiscountedFeeRate;
 	type NonDustableWhitelistHandler = Whitelist;
 	type OracleSource = OracleSourceIdentifier;
+	type XykHooks = TreasuryFeeHook;
+}
+
+/// Sends whole protocol fee to treasury.
+pub struct TreasuryFeeHook;
+
+impl XykHooks<AccountId, AssetId, Balance> for TreasuryFeeHook {
+	type Error = DispatchError;
+
+	fn on_trade_fee(
+		fee_account: AccountId,
+		_trader: AccountId,
+		asset: AssetId,
+		amount: Balance,
+	) -> Result<Vec<Option<(Balance, AccountId)>>, Self::Error> {
+		Currency::transfer(asset, &fee_account, &TREASURY, amount)?;
+		Ok(vec![Some((amount, TREASURY))])
+	}
+
+	fn on_trade_fee_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub struct ExtBuilder {
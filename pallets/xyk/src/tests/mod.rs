@@ -1,8 +1,11 @@
 mod amm_position;
 mod creation;
+mod fee_tiers;
 mod fees;
 mod invariants;
 mod liquidity;
 pub(crate) mod mock;
+mod protocol_fee;
+mod single_asset_liquidity;
 mod spot_price;
 mod trades;
@@ -0,0 +1,158 @@
+pub use super::mock::*;
+use crate::types::AssetPair;
+use crate::{Event, ProtocolFee};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pallet_broadcast::types::{Asset, Destination, Fee};
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
+
+#[test]
+fn set_protocol_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(50)));
+
+		assert_eq!(ProtocolFee::<Test>::get(), Permill::from_percent(50));
+		expect_events(vec![Event::ProtocolFeeSet {
+			fee: Permill::from_percent(50),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_protocol_fee_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_protocol_fee(RuntimeOrigin::signed(ALICE), Permill::from_percent(50)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_should_not_take_protocol_fee_when_protocol_fee_is_zero() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			200_000_000_000,
+			DOT,
+			600_000_000_000_000,
+			None,
+		));
+
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			DOT,
+			456_444_678,
+			1_000_000_000_000,
+			false,
+		));
+
+		assert_eq!(Currency::free_balance(DOT, &TREASURY), 0);
+	});
+}
+
+#[test]
+fn sell_should_transfer_protocol_fee_when_protocol_fee_is_set() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			200_000_000_000,
+			DOT,
+			600_000_000_000_000,
+			None,
+		));
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(50)));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			DOT,
+			456_444_678,
+			1_000_000_000_000,
+			false,
+		));
+
+		// trader receives the same amount as without protocol fee
+		assert_eq!(Currency::free_balance(DOT, &ALICE), 401_363_483_591_788);
+		assert_eq!(Currency::free_balance(ACA, &pair_account), 200_456_444_678);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), 598_635_150_192_189);
+		assert_eq!(Currency::free_balance(DOT, &TREASURY), 1_366_216_023);
+
+		expect_events(vec![pallet_broadcast::Event::Swapped {
+			swapper: ALICE,
+			filler: pair_account,
+			filler_type: pallet_broadcast::types::Filler::XYK(share_token),
+			operation: pallet_broadcast::types::TradeOperation::ExactIn,
+			inputs: vec![Asset::new(ACA, 456_444_678)],
+			outputs: vec![Asset::new(DOT, 1_363_483_591_788)],
+			fees: vec![
+				Fee::new(DOT, 1_366_216_023, Destination::Account(pair_account)),
+				Fee::new(DOT, 1_366_216_023, Destination::Account(TREASURY)),
+			],
+			operation_stack: vec![],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_transfer_protocol_fee_when_protocol_fee_is_set() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			200_000_000,
+			DOT,
+			640_000_000_000,
+			None,
+		));
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(25)));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		assert_ok!(XYK::buy(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			DOT,
+			6_666_666,
+			1_000_000_000_000,
+			false,
+		));
+
+		// trader pays the same amount as without protocol fee
+		assert_eq!(Currency::free_balance(DOT, &ALICE), 999_337_886_898_839);
+		assert_eq!(Currency::free_balance(ACA, &pair_account), 193_333_334);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), 662_102_066_680);
+		assert_eq!(Currency::free_balance(DOT, &TREASURY), 11_034_481);
+
+		expect_events(vec![pallet_broadcast::Event::Swapped {
+			swapper: ALICE,
+			filler: pair_account,
+			filler_type: pallet_broadcast::types::Filler::XYK(share_token),
+			operation: pallet_broadcast::types::TradeOperation::ExactOut,
+			inputs: vec![Asset::new(DOT, 6_666_666)],
+			outputs: vec![Asset::new(ACA, 22_068_963_235)],
+			fees: vec![
+				Fee::new(DOT, 33_103_445, Destination::Account(pair_account)),
+				Fee::new(DOT, 11_034_481, Destination::Account(TREASURY)),
+			],
+			operation_stack: vec![],
+		}
+		.into()]);
+	});
+}
@@ -0,0 +1,126 @@
+pub use super::mock::*;
+use crate::types::{AssetId, AssetPair, Balance};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::AMM as AmmPool;
+use orml_traits::MultiCurrency;
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+fn create_hdx_dot_pool() -> (AccountId, AssetId) {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		100 * ONE,
+		DOT,
+		200 * ONE,
+		None,
+	));
+
+	let pair_account = XYK::get_pair_id(AssetPair::new(HDX, DOT));
+	(pair_account, XYK::share_token(pair_account))
+}
+
+#[test]
+fn add_liquidity_single_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		let (pair_account, share_token) = create_hdx_dot_pool();
+
+		assert_ok!(XYK::add_liquidity_single_asset(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			4_875_760_702_896,
+		));
+
+		// Only rounding dust is left
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE - 10 * ONE + 1);
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(share_token, &BOB), 4_875_760_702_896);
+
+		assert_eq!(Currency::free_balance(HDX, &pair_account), 110 * ONE - 1);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), 200 * ONE);
+		assert_eq!(XYK::total_liquidity(pair_account), 100 * ONE + 4_875_760_702_896);
+
+		expect_events(vec![
+			Event::SellExecuted {
+				who: BOB,
+				asset_in: HDX,
+				asset_out: DOT,
+				amount: 4_886_009_181_491,
+				sale_price: 9_298_165_124_559,
+				fee_asset: DOT,
+				fee_amount: 18_633_597_444,
+				pool: pair_account,
+			}
+			.into(),
+			Event::LiquidityAdded {
+				who: BOB,
+				asset_a: DOT,
+				asset_b: HDX,
+				amount_a: 9_298_165_124_559,
+				amount_b: 5_113_990_818_508,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_min_shares_not_reached() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 4_875_760_702_897),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_balance_is_insufficient() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(BOB), HDX, DOT, INITIAL_BALANCE + 1, 0),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_keep_pool_price_when_no_fee() {
+	ExtBuilder::default()
+		.with_exchange_fee((0, 0))
+		.build()
+		.execute_with(|| {
+			let (pair_account, _) = create_hdx_dot_pool();
+
+			assert_ok!(XYK::add_liquidity_single_asset(
+				RuntimeOrigin::signed(BOB),
+				DOT,
+				HDX,
+				20 * ONE,
+				0,
+			));
+
+			// Without fee, the whole amount is added to the pool, except of rounding dust
+			let dot_left = Currency::free_balance(DOT, &BOB) - (INITIAL_BALANCE - 20 * ONE);
+			assert!(dot_left <= 2);
+			assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE);
+			assert_eq!(Currency::free_balance(DOT, &pair_account), 220 * ONE - dot_left);
+		});
+}
@@ -29,7 +29,8 @@ fn spot_price_provider_should_return_correct_price_when_pool_exists() {
 				asset_a,
 				initial,
 				asset_b,
-				39_600_000_000_000
+				39_600_000_000_000,
+				None
 			));
 
 			let price = XYKSpotPrice::<Test>::spot_price(asset_a, asset_b);
@@ -66,7 +67,8 @@ fn spot_price_provider_should_return_none_when_asset_reserve_is_zero() {
 				asset_a,
 				initial,
 				asset_b,
-				39_600_000_000_000
+				39_600_000_000_000,
+				None
 			));
 
 			let pool_account = XYK::get_pair_id(AssetPair {
@@ -106,7 +108,8 @@ fn compare_sell_spot_price_with_and_without_fee() {
 				asset_a,
 				initial_a,
 				asset_b,
-				initial_b
+				initial_b,
+				None
 			));
 
 			let spot_price_without_fee = XYKSpotPrice::<Test>::spot_price(asset_a, asset_b).unwrap();
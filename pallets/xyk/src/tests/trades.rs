@@ -20,6 +20,7 @@ fn sell_test() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -99,6 +100,7 @@ fn execute_sell_should_use_event_id() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -175,6 +177,7 @@ fn work_flow_happy_path_should_work() {
 			350_000_000_000,
 			asset_b,
 			14_000_000_000_000,
+			None,
 		));
 
 		// User 1 really tries!
@@ -391,6 +394,7 @@ fn sell_with_correct_fees_should_work() {
 			10_000_000,
 			asset_b,
 			2_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -470,6 +474,7 @@ fn sell_without_sufficient_balance_should_not_work() {
 			1_000_000_000,
 			asset_b,
 			1_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -499,6 +504,7 @@ fn sell_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -507,6 +513,7 @@ fn sell_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			HDX,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -536,6 +543,7 @@ fn buy_without_sufficient_balance_should_not_work() {
 			1_000_000_000,
 			asset_b,
 			1_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -565,6 +573,7 @@ fn buy_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -573,6 +582,7 @@ fn buy_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			HDX,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -609,6 +619,7 @@ fn single_buy_should_work() {
 			200_000_000,
 			asset_b,
 			640_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -679,12 +690,12 @@ fn single_buy_should_work() {
 fn create_pool_with_insufficient_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 1_600_000),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 1_600_000, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 	});
@@ -734,7 +745,8 @@ fn discount_sell_with_no_native_pool_should_not_work() {
 			ACA,
 			1000,
 			DOT,
-			3_200_000
+			3_200_000,
+			None
 		));
 
 		assert_noop!(
@@ -769,7 +781,8 @@ fn discount_buy_with_no_native_pool_should_not_work() {
 			ACA,
 			10_000,
 			DOT,
-			32_000_000
+			32_000_000,
+			None
 		));
 
 		assert_noop!(
@@ -795,6 +808,7 @@ fn money_in_sell_money_out_should_leave_the_same_balance() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -859,6 +873,7 @@ fn money_in_money_out_should_leave_the_same_balance_for_both_accounts() {
 			100_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		let asset_pair = AssetPair {
@@ -925,6 +940,7 @@ fn sell_test_not_reaching_limit() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -973,6 +989,7 @@ fn buy_test_exceeding_max_limit() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -1021,6 +1038,7 @@ fn single_buy_more_than_ratio_out_should_not_work() {
 			200_000_000,
 			asset_b,
 			640_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -1062,7 +1080,8 @@ fn single_buy_more_than_ratio_in_should_not_work() {
 			asset_a,
 			100_000_000_000,
 			asset_b,
-			100_000_000_000
+			100_000_000_000,
+			None
 		));
 
 		assert_noop!(
@@ -1092,6 +1111,7 @@ fn single_sell_more_than_ratio_in_should_not_work() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -1133,7 +1153,8 @@ fn single_sell_more_than_ratio_out_should_not_work() {
 			asset_a,
 			100_000_000_000,
 			asset_b,
-			100_000_000_000
+			100_000_000_000,
+			None
 		));
 
 		assert_noop!(
@@ -1173,7 +1194,14 @@ fn buy_with_low_amount_should_not_work() {
 #[test]
 fn buy_with_excesive_amount_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 10_000, DOT, 10_000,));
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			10_000,
+			DOT,
+			10_000,
+			None,
+		));
 
 		assert_noop!(
 			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 20_000, 1_000_000, false),
@@ -1,5 +1,5 @@
 use crate::types::{AssetId, AssetPair, Balance};
-use crate::{Config, Error, Pallet};
+use crate::{Config, Error, Pallet, PoolAssets};
 use frame_support::ensure;
 use frame_support::traits::Get;
 use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
@@ -7,6 +7,7 @@ use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::{vec, vec::Vec};
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -40,7 +41,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 			ExecutorError::Error(Error::<T>::InsufficientPoolAssetBalance.into())
 		);
 
-		let transfer_fee = Self::calculate_fee(amount_out).map_err(ExecutorError::Error)?;
+		let transfer_fee = Self::calculate_fee(&pair_account, amount_out).map_err(ExecutorError::Error)?;
 
 		let amount_out_without_fee = amount_out
 			.checked_sub(transfer_fee)
@@ -84,7 +85,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		let amount_in = hydra_dx_math::xyk::calculate_in_given_out(asset_out_reserve, asset_in_reserve, amount_out)
 			.map_err(|_| ExecutorError::Error(Error::<T>::BuyAssetAmountInvalid.into()))?;
 
-		let transfer_fee = Self::calculate_fee(amount_in).map_err(ExecutorError::Error)?;
+		let transfer_fee = Self::calculate_fee(&pair_account, amount_in).map_err(ExecutorError::Error)?;
 
 		let amount_in_with_fee = amount_in
 			.checked_add(transfer_fee)
@@ -162,7 +163,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		let spot_price_with_fee = hydra_dx_math::xyk::calculate_spot_price_with_fee(
 			asset_a_reserve,
 			asset_b_reserve,
-			Some(T::GetExchangeFee::get()),
+			Some(Self::get_pool_fee(&pair_account)),
 		)
 		.map_err(|_| ExecutorError::Error(ArithmeticError::Overflow.into()))?
 		.reciprocal()
@@ -170,4 +171,10 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 
 		Ok(spot_price_with_fee)
 	}
+
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		PoolAssets::<T>::iter_values()
+			.map(|(asset_a, asset_b)| (PoolType::XYK, vec![asset_a, asset_b]))
+			.collect()
+	}
 }
@@ -0,0 +1,58 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Hooks executed by the XYK pallet.
+pub trait XykHooks<AccountId, AssetId, Balance> {
+	type Error;
+
+	/// Called after successful trade with the protocol part of the trade fee.
+	/// The fee amount is held by `fee_account` and can be transferred out of it.
+	///
+	/// Returns used amounts and their recipients.
+	fn on_trade_fee(
+		fee_account: AccountId,
+		trader: AccountId,
+		asset: AssetId,
+		amount: Balance,
+	) -> Result<Vec<Option<(Balance, AccountId)>>, Self::Error>;
+
+	/// Worst case weight of `on_trade_fee`.
+	fn on_trade_fee_weight() -> Weight;
+}
+
+// Default implementation for no-op hooks.
+impl<AccountId, AssetId, Balance> XykHooks<AccountId, AssetId, Balance> for () {
+	type Error = DispatchError;
+
+	fn on_trade_fee(
+		_fee_account: AccountId,
+		_trader: AccountId,
+		_asset: AssetId,
+		_amount: Balance,
+	) -> Result<Vec<Option<(Balance, AccountId)>>, Self::Error> {
+		Ok(vec![])
+	}
+
+	fn on_trade_fee_weight() -> Weight {
+		Weight::zero()
+	}
+}
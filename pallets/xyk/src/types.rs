@@ -22,7 +22,7 @@ pub type Price = FixedU128;
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, RuntimeDebug};
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -67,3 +67,16 @@ impl AssetPair {
 		buf
 	}
 }
+
+/// Current state of a pool.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct PoolDetails<AccountId> {
+	pub pool_account: AccountId,
+	pub assets: (AssetId, AssetId),
+	pub share_token: AssetId,
+	/// Reserves of pool assets, in the same order as `assets`
+	pub reserves: (Balance, Balance),
+	pub total_shares: Balance,
+	/// Trade fee of the pool
+	pub fee: (u32, u32),
+}
@@ -19,6 +19,10 @@ pub trait WeightInfo {
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn add_fee_tier() -> Weight;
+	fn remove_fee_tier() -> Weight;
+	fn add_liquidity_single_asset() -> Weight;
+	fn set_protocol_fee() -> Weight;
 }
 
 /// Weights for amm using the hydraDX node and recommended hardware.
@@ -322,4 +326,71 @@ impl WeightInfo for () {
 		Weight::from_parts(40_698_000, 6190)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 	}
+	/// Storage: `XYK::FeeTiers` (r:1 w:1)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn add_fee_tier() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(11_483_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XYK::FeeTiers` (r:1 w:1)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn remove_fee_tier() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(13_317_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XYK::PoolFee` (r:1 w:0)
+	/// Proof: `XYK::PoolFee` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:0)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::TotalLiquidity` (r:1 w:1)
+	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:4 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExistentialDepositCounter` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExistentialDepositCounter` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencyPrice` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencyPrice` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
+	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn add_liquidity_single_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4566`
+		//  Estimated: `21654`
+		// Minimum execution time: 612_384_000 picoseconds.
+		Weight::from_parts(615_142_000, 21654)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: `XYK::ProtocolFee` (r:0 w:1)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_protocol_fee() -> Weight {
+		// ESTIMATED - not measured by the benchmark CLI. Based on the storage accesses of the call,
+		// has to be replaced by benchmark results.
+		Weight::from_parts(8_392_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -42,13 +42,14 @@ use hydradx_traits::{
 use orml_traits::{GetByKey, MultiCurrency};
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
 use pallet_circuit_breaker::WeightInfo;
+use pallet_cryptoswap::types::PoolStateChangeLimits;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
 use pallet_stableswap::types::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::v4::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{CONCENTRATED_LIQUIDITY_SOURCE, CRYPTOSWAP_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::vec;
@@ -611,6 +612,25 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::Cryptoswap(_) => {
+					let price_result = AggregatedPriceGetter::get_price(asset_a, asset_b, period, CRYPTOSWAP_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
+				PoolType::ConcentratedLiquidity => {
+					let price_result =
+						AggregatedPriceGetter::get_price(asset_a, asset_b, period, CONCENTRATED_LIQUIDITY_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				PoolType::Aave => EmaPrice::from(1),
 				_ => return None,
 			};
@@ -1057,6 +1077,77 @@ where
 	}
 }
 
+/// Enforces circuit breaker limits on cryptoswap trades and liquidity changes.
+pub struct CryptoswapCircuitBreaker<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> PoolStateChangeLimits<AccountId, AssetId> for CryptoswapCircuitBreaker<Runtime>
+where
+	Runtime: pallet_circuit_breaker::Config + frame_system::Config<AccountId = AccountId>,
+{
+	fn ensure_trade_limit(
+		asset_in: AssetId,
+		asset_in_reserve: Balance,
+		amount_in: Balance,
+		asset_out: AssetId,
+		asset_out_reserve: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+			asset_in.into(),
+			asset_in_reserve.into(),
+			amount_in.into(),
+			asset_out.into(),
+			asset_out_reserve.into(),
+			amount_out.into(),
+		)?;
+		Ok(())
+	}
+
+	fn ensure_add_liquidity_limit(
+		who: &AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		added_liquidity: Balance,
+	) -> DispatchResult {
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_add_liquidity_limit(
+			frame_system::RawOrigin::Signed(who.clone()).into(),
+			asset_id.into(),
+			initial_liquidity.into(),
+			added_liquidity.into(),
+		)?;
+		Ok(())
+	}
+
+	fn ensure_remove_liquidity_limit(
+		who: &AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		removed_liquidity: Balance,
+	) -> DispatchResult {
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_remove_liquidity_limit(
+			frame_system::RawOrigin::Signed(who.clone()).into(),
+			asset_id.into(),
+			initial_liquidity.into(),
+			removed_liquidity.into(),
+		)?;
+		Ok(())
+	}
+
+	fn trade_limit_weight() -> Weight {
+		<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit().saturating_add(
+			<Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry(),
+		)
+	}
+
+	fn liquidity_limit_weight() -> Weight {
+		<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_add_liquidity_limit()
+			.max(<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_remove_liquidity_limit())
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_liquidity_limit_entry(),
+			)
+	}
+}
+
 /// Price provider that returns a price of an asset that can be used to pay tx fee.
 /// If an asset cannot be used as fee payment asset, None is returned.
 pub struct AssetFeeOraclePriceProvider<NativeAsset, FeePaymentAsset, Router, Oracle, FallbackPrice, Period>(
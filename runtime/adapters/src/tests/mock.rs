@@ -264,6 +264,7 @@ impl pallet_xyk::Config for Test {
 	type NativeAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = XYKExchangeFee;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -273,6 +274,7 @@ impl pallet_xyk::Config for Test {
 	type AMMHandler = ();
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = DummyDuster;
+	type XykHooks = ();
 }
 
 impl pallet_broadcast::Config for Test {
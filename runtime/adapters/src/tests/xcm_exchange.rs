@@ -291,5 +291,6 @@ fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		amount,
 		asset_b,
 		amount,
+		None,
 	));
 }
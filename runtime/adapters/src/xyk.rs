@@ -1,6 +1,15 @@
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use hydradx_traits::CanCreatePool;
 use hydradx_traits::Inspect;
-use primitives::AssetId;
+use orml_traits::MultiCurrency;
+use pallet_xyk::XykHooks;
+use primitives::{AccountId, AssetId, Balance};
+use sp_runtime::traits::Zero;
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+use sp_std::vec;
+use sp_std::vec::Vec;
 
 pub struct AllowPoolCreation<LBP, R>(sp_std::marker::PhantomData<(LBP, R)>);
 
@@ -25,3 +34,63 @@ where
 		pallet_lbp::DisallowWhenLBPPoolRunning::<LBP>::can_create(asset_a, asset_b)
 	}
 }
+
+/// Distributes protocol part of XYK trade fee to referrals, staking and treasury.
+///
+/// Referrals are processed first (except for native asset), staking takes the remaining amount if fee asset
+/// is native asset. Whatever is left is transferred to `ProtocolFeeRecipient`.
+pub struct XykHookAdapter<NativeAsset, Runtime, MC, ProtocolFeeRecipient>(
+	PhantomData<(NativeAsset, Runtime, MC, ProtocolFeeRecipient)>,
+);
+
+impl<NativeAsset, Runtime, MC, ProtocolFeeRecipient> XykHooks<AccountId, AssetId, Balance>
+	for XykHookAdapter<NativeAsset, Runtime, MC, ProtocolFeeRecipient>
+where
+	NativeAsset: Get<AssetId>,
+	ProtocolFeeRecipient: Get<AccountId>,
+	Runtime: frame_system::Config<AccountId = AccountId> + pallet_staking::Config + pallet_referrals::Config,
+	<Runtime as pallet_staking::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_referrals::Config>::AssetId: From<AssetId>,
+	MC: MultiCurrency<AccountId, CurrencyId = AssetId, Balance = Balance>,
+{
+	type Error = DispatchError;
+
+	fn on_trade_fee(
+		fee_account: AccountId,
+		trader: AccountId,
+		asset: AssetId,
+		amount: Balance,
+	) -> Result<Vec<Option<(Balance, AccountId)>>, Self::Error> {
+		let referrals_used = if asset == NativeAsset::get() {
+			None
+		} else {
+			pallet_referrals::Pallet::<Runtime>::process_trade_fee(fee_account.clone(), trader, asset.into(), amount)?
+		};
+
+		let referral_amount = referrals_used.clone().map(|(balance, _)| balance).unwrap_or_default();
+		let staking_used = pallet_staking::Pallet::<Runtime>::process_trade_fee(
+			fee_account.clone(),
+			asset.into(),
+			amount.saturating_sub(referral_amount),
+		)?;
+
+		let staking_amount = staking_used.clone().map(|(balance, _)| balance).unwrap_or_default();
+		let remaining = amount.saturating_sub(referral_amount).saturating_sub(staking_amount);
+		let treasury_used = if remaining.is_zero() {
+			None
+		} else {
+			MC::transfer(asset, &fee_account, &ProtocolFeeRecipient::get(), remaining)?;
+			Some((remaining, ProtocolFeeRecipient::get()))
+		};
+
+		Ok(vec![staking_used, referrals_used, treasury_used])
+	}
+
+	fn on_trade_fee_weight() -> Weight {
+		// Estimated - not benchmarked. The worst case is a fee in non-native asset which is processed by referrals
+		// (oracle price, linked account, referrer, asset rewards, shares and the transfer to the pot), checked by
+		// staking and the rest of it transferred to `ProtocolFeeRecipient`.
+		Weight::from_parts(65_000_000, 30_000)
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(17, 9))
+	}
+}
@@ -17,6 +17,7 @@
 
 use super::*;
 use crate::evm::precompiles::erc20_mapping::SetCodeForErc20Precompile;
+use crate::evm::stableswap_peg::EvmPegRateAdapter;
 use crate::evm::Erc20Currency;
 use crate::origins::{GeneralAdmin, OmnipoolAdmin};
 use crate::system::NativeAssetId;
@@ -24,7 +25,7 @@ use crate::Stableswap;
 use core::ops::RangeInclusive;
 use frame_support::{
 	parameter_types,
-	sp_runtime::traits::{One, PhantomData},
+	sp_runtime::traits::{CheckedDiv, CheckedMul, One, PhantomData},
 	sp_runtime::{
 		app_crypto::sp_core::crypto::UncheckedFrom, traits::Zero, ArithmeticError, DispatchError, DispatchResult,
 		FixedPointNumber, Percent,
@@ -38,9 +39,10 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
 use hydradx_adapters::{
-	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OmnipoolRawOracleAssetVolumeProvider, OraclePriceProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider,
-	RelayChainBlockNumberProvider, StableswapHooksAdapter, VestingInfo,
+	AssetFeeOraclePriceProvider, CryptoswapCircuitBreaker, EmaOraclePriceAdapter, FreezableNFT,
+	MultiCurrencyLockedBalance, OmnipoolHookAdapter, OmnipoolRawOracleAssetVolumeProvider, OraclePriceProvider,
+	PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider, StableswapHooksAdapter,
+	VestingInfo,
 };
 pub use hydradx_traits::{
 	evm::CallContext,
@@ -54,6 +56,7 @@ use orml_traits::{
 	currency::{MultiCurrency, MultiLockableCurrency, MutationHooks, OnDeposit, OnTransfer},
 	GetByKey, Happened,
 };
+use pallet_cryptoswap::weights::WeightInfo as CryptoswapWeights;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_dynamic_fees::types::FeeParams;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
@@ -63,6 +66,7 @@ use pallet_omnipool::{
 };
 use pallet_otc::NamedReserveIdentifier;
 use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeights, MAX_NUMBER_OF_TRADES};
+use pallet_stableswap::types::EvmPegRateProvider;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_staking::{
 	types::{Action, Point},
@@ -71,7 +75,7 @@ use pallet_staking::{
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
 use pallet_xyk::weights::WeightInfo as XykWeights;
 use primitives::constants::{
-	chain::{CORE_ASSET_ID, OMNIPOOL_SOURCE, XYK_SOURCE},
+	chain::{CONCENTRATED_LIQUIDITY_SOURCE, CORE_ASSET_ID, CRYPTOSWAP_SOURCE, OMNIPOOL_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
 	time::DAYS,
 };
@@ -969,6 +973,37 @@ impl RouterWeightInfo {
 	pub fn skip_ed_handling_overweight() -> Weight {
 		weights::pallet_route_executor::HydraWeight::<Runtime>::skip_ed_handling_for_trade_with_insufficient_assets()
 	}
+
+	/// Number of stableswap pools a trade is executed in. Trades of underlying assets of a metapool go through its base pool too.
+	pub fn stableswap_pools_in_trade(trade: &Trade<AssetId>) -> u64 {
+		let PoolType::Stableswap(pool_id) = trade.pool else {
+			return 0;
+		};
+		if Stableswap::underlying_base_pool(pool_id, trade.asset_in).is_some()
+			|| Stableswap::underlying_base_pool(pool_id, trade.asset_out).is_some()
+		{
+			2
+		} else {
+			1
+		}
+	}
+
+	/// Weight of EVM peg rate calls of a single recalculation of pegs of stableswap pools a trade is executed in.
+	/// Pegs are recalculated once by `calculate_*` and twice by `execute_*` - for the trade and for the hook.
+	pub fn stableswap_evm_peg_calls_weight(trade: &Trade<AssetId>) -> Weight {
+		let PoolType::Stableswap(pool_id) = trade.pool else {
+			return Weight::zero();
+		};
+		let base_pool = Stableswap::underlying_base_pool(pool_id, trade.asset_in)
+			.or_else(|| Stableswap::underlying_base_pool(pool_id, trade.asset_out));
+
+		[Some(pool_id), base_pool]
+			.into_iter()
+			.flatten()
+			.fold(Weight::zero(), |weight, pool_id| {
+				weight.saturating_add(Stableswap::evm_peg_calls_weight(pool_id))
+			})
+	}
 }
 
 impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
@@ -988,9 +1023,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade).saturating_mul((c + 2 * e) as u64))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(XYK::trade_fee_weight()),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(
+						c,
+						e,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight())
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1023,9 +1072,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade).saturating_mul((c + 2 * e) as u64))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(XYK::trade_fee_weight()),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(
+						c,
+						e,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight())
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1056,9 +1119,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade).saturating_mul((c + 2 * e) as u64))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(XYK::trade_fee_weight()),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(
+						c,
+						e,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight())
+				}
 				PoolType::Aave => Weight::zero(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1081,9 +1158,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade).saturating_mul((c + 2 * e) as u64))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(XYK::trade_fee_weight()),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(
+						c,
+						e,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight())
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1106,9 +1197,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade).saturating_mul((c + 2 * e) as u64))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(XYK::trade_fee_weight()),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(
+						c,
+						e,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight())
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1140,8 +1245,20 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(
+						1,
+						0,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1154,8 +1271,20 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_mul(Self::stableswap_pools_in_trade(&trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(&trade))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(
+						1,
+						0,
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+				}
 				PoolType::Aave => Aave::trade_weight(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1177,6 +1306,37 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		weights::pallet_route_executor::HydraWeight::<Runtime>::force_insert_route()
 	}
 
+	fn invalidate_route_weight() -> Weight {
+		//The stored route is not known upfront, so we expect a worst case with max number of trades in the most expensive pool
+		let worst_trade_weight = [
+			weights::pallet_omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				.saturating_mul(2)
+				.saturating_add(
+					<Runtime as pallet_stableswap::Config>::EvmPegRateProvider::rate_weight()
+						.saturating_mul(2 * pallet_stableswap::MAX_ASSETS_IN_POOL as u64),
+				),
+			weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_cryptoswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(
+				1,
+				0,
+				ConcentratedLiquidityMaxSwapSteps::get(),
+			),
+			Aave::trade_weight(),
+		]
+		.into_iter()
+		.fold(Weight::zero(), |worst, weight| worst.max(weight));
+
+		//The route is validated by selling in both directions, one of them can be repeated with a different amount
+		let number_of_trades = 3 * MAX_NUMBER_OF_TRADES as u64;
+
+		Self::set_route_overweight()
+			.saturating_add(worst_trade_weight.saturating_mul(number_of_trades))
+			.saturating_add(weights::pallet_route_executor::HydraWeight::<Runtime>::force_insert_route())
+	}
+
 	// Used in OtcSettlements::settle_otc_order extrinsic
 	fn calculate_spot_price_with_fee_weight(route: &[Trade<AssetId>]) -> Weight {
 		let mut weight = Self::calculate_spot_price_overweight();
@@ -1187,8 +1347,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+						.saturating_mul(Self::stableswap_pools_in_trade(trade))
+						.saturating_add(Self::stableswap_evm_peg_calls_weight(trade))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
+				PoolType::Cryptoswap(_) => {
+					weights::pallet_cryptoswap::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+				}
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+				}
 				PoolType::Aave => Weight::zero(),
 			};
 			weight.saturating_accrue(amm_weight);
@@ -1214,7 +1382,7 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP, Aave);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, Aave, Cryptoswap, ConcentratedLiquidity);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type NativeAssetId = NativeAssetId;
 	type InspectRegistry = AssetRegistry;
@@ -1259,6 +1427,37 @@ impl pallet_otc_settlements::Config for Runtime {
 	type RouterWeightInfo = RouterWeightInfo;
 }
 
+parameter_types! {
+	pub const LimitOrdersNamedReserveId: NamedReserveIdentifier = *b"limitord";
+	pub const LimitOrderDeposit: Balance = 10 * UNITS;
+	pub const MaxLimitOrderLifetime: BlockNumber = 30 * DAYS;
+}
+
+impl pallet_limit_orders::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type SpotPriceProvider = Omnipool;
+	#[cfg(feature = "runtime-benchmarks")]
+	type SpotPriceProvider = DummySpotPriceProvider;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Router = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type MinimumTradingLimit = MinTradingLimit;
+	type MaxOrdersPerPair = ConstU32<100>;
+	type MaxOrdersPerAccountPerPair = ConstU32<5>;
+	type MaxOrderLifetime = MaxLimitOrderLifetime;
+	type OrderDeposit = LimitOrderDeposit;
+	type NativeAssetId = NativeAssetId;
+	type MaxIterations = ConstU32<40>;
+	type ExecutionRetryDelay = ConstU32<10>;
+	type NamedReserveId = LimitOrdersNamedReserveId;
+	type RouterWeightInfo = RouterWeightInfo;
+	type WeightInfo = weights::pallet_limit_orders::HydraWeight<Runtime>;
+}
+
 // Dynamic fees
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Permill> = FeeParams{
@@ -1429,15 +1628,106 @@ impl pallet_stableswap::Config for Runtime {
 	type UpdateTradabilityOrigin = EitherOf<EnsureRoot<Self::AccountId>, TechCommitteeSuperMajority>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type PoolAssetLiquidityAccount = TreasuryAccount;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
 	type TargetPegOracle = EmaOracle;
+	type EvmPegRateProvider = EvmPegRateAdapter<Runtime>;
 	type WeightInfo = weights::pallet_stableswap::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+// Cryptoswap
+parameter_types! {
+	pub const CryptoswapOracleSource: Source = CRYPTOSWAP_SOURCE;
+	pub const CryptoswapPriceOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config + pallet_ema_oracle::Config> pallet_cryptoswap::BenchmarkHelper<AssetId>
+	for RegisterAsset<T>
+{
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
+
+	fn register_oracle_price(
+		asset_pair: (AssetId, AssetId),
+		price: (Balance, Balance),
+		source: Source,
+	) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset_peg(asset_pair, price, source)
+	}
+}
+
+impl pallet_cryptoswap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type ShareAccountId = StableswapAccountIdConstructor<Runtime>;
+	type AssetInspection = AssetRegistry;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
+	type DustAccountHandler = Duster;
+	type OracleSource = CryptoswapOracleSource;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type PriceOracle = EmaOracle;
+	type PriceOraclePeriod = CryptoswapPriceOraclePeriod;
+	type OracleWhitelist = EmaOracle;
+	type CircuitBreaker = CryptoswapCircuitBreaker<Runtime>;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
+	type WeightInfo = weights::pallet_cryptoswap::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
+// Concentrated liquidity
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"clxykpid");
+	pub const ConcentratedLiquidityOracleSource: Source = CONCENTRATED_LIQUIDITY_SOURCE;
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 4682_u128;
+	pub ConcentratedLiquidityTradeFee: Permill = Permill::from_rational(3u32, 1000u32);
+	pub const ConcentratedLiquidityTickSpacing: i32 = 60;
+	pub const ConcentratedLiquidityMaxSwapSteps: u32 = 128;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config + pallet_ema_oracle::Config>
+	pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T>
+{
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type AssetRegistry = AssetRegistry;
+	type AssetPairAccountId = AssetPairAccountId<Self>;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
+	type NonDustableWhitelistHandler = Duster;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type TradeFee = ConcentratedLiquidityTradeFee;
+	type TickSpacing = ConcentratedLiquidityTickSpacing;
+	type MaxSwapSteps = ConcentratedLiquidityMaxSwapSteps;
+	type MinTradingLimit = MinTradingLimit;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type OracleWhitelist = EmaOracle;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type PositionItemId = ItemId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = Uniques;
+	type WeightInfo = weights::pallet_concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
@@ -1556,6 +1846,85 @@ where
 	}
 }
 
+/// Moves liquidity of graduated LBP pools into a new XYK pool or into Omnipool.
+pub struct LbpGraduation;
+
+impl pallet_lbp::GraduatePool<AccountId, AssetId, Balance> for LbpGraduation {
+	fn graduate(
+		target: pallet_lbp::GraduationTarget,
+		owner: &AccountId,
+		asset_a: (AssetId, Balance),
+		asset_b: (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult {
+		match target {
+			pallet_lbp::GraduationTarget::XYK => XYK::create_pool(
+				RawOrigin::Signed(owner.clone()).into(),
+				asset_a.0,
+				asset_a.1,
+				asset_b.0,
+				asset_b.1,
+				None,
+			),
+			pallet_lbp::GraduationTarget::Omnipool { weight_cap } => {
+				// Omnipool prices are denominated in the hub asset
+				let price_a = Omnipool::load_asset_state(asset_a.0)?
+					.price()
+					.ok_or(ArithmeticError::DivisionByZero)?;
+				let initial_price = price_a.checked_mul(&price).ok_or(ArithmeticError::Overflow)?;
+
+				<Currencies as MultiCurrency<AccountId>>::transfer(
+					asset_b.0,
+					owner,
+					&Omnipool::protocol_account(),
+					asset_b.1,
+				)?;
+				Omnipool::add_token(
+					RawOrigin::Root.into(),
+					asset_b.0,
+					initial_price,
+					weight_cap,
+					owner.clone(),
+				)?;
+				Omnipool::add_liquidity(RawOrigin::Signed(owner.clone()).into(), asset_a.0, asset_a.1)
+			}
+		}
+	}
+
+	fn spot_price(target: pallet_lbp::GraduationTarget, asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128> {
+		match target {
+			pallet_lbp::GraduationTarget::XYK => {
+				let pool_account = XYK::pair_account_from_assets(asset_a, asset_b);
+				FixedU128::checked_from_rational(
+					Currencies::free_balance(asset_a, &pool_account),
+					Currencies::free_balance(asset_b, &pool_account),
+				)
+			}
+			pallet_lbp::GraduationTarget::Omnipool { .. } => {
+				let price_a = Omnipool::load_asset_state(asset_a).ok()?.price()?;
+				let price_b = Omnipool::load_asset_state(asset_b).ok()?.price()?;
+				price_b.checked_div(&price_a)
+			}
+		}
+	}
+
+	fn graduate_weight(target: pallet_lbp::GraduationTarget) -> Weight {
+		match target {
+			// Creation of the XYK pool is included in the benchmark of the pool graduation
+			pallet_lbp::GraduationTarget::XYK => Weight::zero(),
+			pallet_lbp::GraduationTarget::Omnipool { .. } => {
+				weights::pallet_omnipool::HydraWeight::<Runtime>::add_token()
+					.saturating_add(weights::pallet_omnipool::HydraWeight::<Runtime>::add_liquidity())
+					.saturating_add(<Runtime as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			}
+		}
+	}
+}
+
+parameter_types! {
+	pub const LBPMaxGraduationPriceDifference: Permill = Permill::from_percent(1);
+}
+
 impl pallet_lbp::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currencies;
@@ -1569,6 +1938,9 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type Graduation = LbpGraduation;
+	type GraduationOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
+	type MaxGraduationPriceDifference = LBPMaxGraduationPriceDifference;
 }
 
 parameter_types! {
@@ -1585,6 +1957,7 @@ impl pallet_xyk::Config for Runtime {
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = weights::pallet_xyk::HydraWeight<Runtime>;
 	type GetExchangeFee = XYKExchangeFee;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -1594,6 +1967,7 @@ impl pallet_xyk::Config for Runtime {
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = Duster;
 	type OracleSource = XYKOracleSourceIdentifier;
+	type XykHooks = hydradx_adapters::xyk::XykHookAdapter<NativeAssetId, Runtime, Currencies, TreasuryAccount>;
 }
 
 parameter_types! {
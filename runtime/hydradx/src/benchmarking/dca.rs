@@ -31,16 +31,17 @@ use frame_support::{
 };
 use frame_system::RawOrigin;
 use hydradx_traits::router::PoolType;
+use hydradx_traits::OraclePeriod;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
-use pallet_dca::types::{Order, Schedule, ScheduleId};
+use pallet_dca::types::{Order, PriceCondition, Schedule, ScheduleId};
 use pallet_dca::{ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use scale_info::prelude::vec::Vec;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::DispatchResult;
-use sp_runtime::{DispatchError, Permill};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, Permill};
 use sp_std::vec;
 
 pub const HDX: AssetId = 0;
@@ -67,6 +68,7 @@ fn schedule_fake(
 		total_amount: 1100 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		price_condition: None,
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Buy {
 			asset_in,
@@ -99,6 +101,10 @@ fn schedule_buy_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		price_condition: Some(PriceCondition {
+			limit_price: FixedU128::from_inner(u128::MAX),
+			oracle_period: OraclePeriod::Short,
+		}),
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Buy {
 			asset_in,
@@ -127,6 +133,10 @@ fn schedule_sell_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		price_condition: Some(PriceCondition {
+			limit_price: FixedU128::from_inner(1),
+			oracle_period: OraclePeriod::Short,
+		}),
 		slippage: Some(Permill::from_percent(100)),
 		order: Order::Sell {
 			asset_in,
@@ -445,6 +455,7 @@ runtime_benchmarks! {
 			total_amount: 1100 * ONE,
 			max_retries: None,
 			stability_threshold: None,
+			price_condition: None,
 			slippage: Some(Permill::from_percent(15)),
 			order: Order::Buy {
 				asset_in: asset_1,
@@ -529,6 +540,7 @@ pub fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		amount,
 		asset_b,
 		amount,
+		None,
 	));
 
 	assert_ok!(XYK::sell(
@@ -234,6 +234,7 @@ where
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
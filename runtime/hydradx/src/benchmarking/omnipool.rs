@@ -17,7 +17,7 @@ use frame_system::RawOrigin;
 use hydradx_traits::router::{PoolType, TradeExecution};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use pallet_omnipool::types::Tradability;
+use pallet_omnipool::types::{SlipFeeParams, Tradability};
 use pallet_referrals::ReferralCode;
 
 pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
@@ -336,6 +336,73 @@ runtime_benchmarks! {
 		assert!(Omnipool::positions(current_position_id).is_none());
 	}
 
+	split_position {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let new_position_id = Omnipool::next_position_id();
+	}: {Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), current_position_id, liquidity_added / 2)? }
+	verify {
+		assert!(Omnipool::positions(new_position_id).is_some());
+	}
+
+	merge_positions {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let other_position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+	}: {Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), current_position_id, other_position_id)? }
+	verify {
+		assert!(Omnipool::positions(other_position_id).is_none());
+	}
+
 	set_asset_weight_cap {
 		init()?;
 	}: { Omnipool::set_asset_weight_cap(RawOrigin::Root.into(), DAI, Permill::from_percent(10))? }
@@ -344,6 +411,17 @@ runtime_benchmarks! {
 		assert!(asset_state.cap == 100_000_000_000_000_000u128);
 	}
 
+	set_asset_slip_fee {
+		init()?;
+		let params = SlipFeeParams {
+			factor: FixedU128::one(),
+			max_slip_fee: Permill::from_percent(5),
+		};
+	}: { Omnipool::set_asset_slip_fee(RawOrigin::Root.into(), DAI, Some(params))? }
+	verify {
+		assert_eq!(Omnipool::slip_fee_params(DAI), Some(params));
+	}
+
 	withdraw_protocol_liquidity {
 		init()?;
 		let acc = Omnipool::protocol_account();
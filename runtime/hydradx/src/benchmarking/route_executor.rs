@@ -101,6 +101,7 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		None,
 		None,
 		None,
+		None,
 	)?;
 
 	System::set_block_number(2u32);
@@ -138,6 +139,7 @@ fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		amount,
 		asset_b,
 		amount,
+		None,
 	));
 
 	let seller: AccountId = funded_account("caller", 0, &[asset_a]);
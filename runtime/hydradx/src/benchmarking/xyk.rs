@@ -6,6 +6,7 @@ use frame_benchmarking::{account, BenchmarkError};
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use sp_runtime::Permill;
 use sp_std::prelude::*;
 
 use hydradx_traits::router::{PoolType, TradeExecution};
@@ -52,8 +53,11 @@ runtime_benchmarks! {
 		let amount_a : Balance = INITIAL_BALANCE;
 		let amount_b : Balance = INITIAL_BALANCE;
 
+		let fee_tier = (1, 1_000);
+		XYK::add_fee_tier(RawOrigin::Root.into(), fee_tier)?;
+
 		assert_eq!(frame_system::Pallet::<Runtime>::account(caller.clone()).sufficients, 2);
-	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b)
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b, Some(fee_tier))
 	verify {
 		assert_eq!(Currencies::free_balance(asset_a, &caller), 0);
 		assert_eq!(Currencies::free_balance(asset_b, &caller), 0);
@@ -85,7 +89,7 @@ runtime_benchmarks! {
 		let max_limit : Balance = INITIAL_BALANCE;
 
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE - 10, asset_b, INITIAL_BALANCE - 10)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE - 10, asset_b, INITIAL_BALANCE - 10, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, INITIAL_BALANCE - amount)?;
 
@@ -99,6 +103,33 @@ runtime_benchmarks! {
 		assert_eq!(frame_system::Pallet::<Runtime>::account(caller).sufficients, 2);
 	}
 
+	add_liquidity_single_asset {
+		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let fee_asset = register_asset(b"FEE".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let caller = funded_account::<Runtime>("caller", 0, &[asset_a, asset_b, fee_asset]);
+		let maker = funded_account::<Runtime>("maker", 1, &[asset_a, asset_b, fee_asset]);
+
+		init_fee_asset(fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(maker.clone()).into(), fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(caller.clone()).into(), fee_asset)?;
+
+		let amount : Balance = 100_000_000_000_000;
+
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
+
+		// Caller provides only asset a
+		<Currencies as MultiCurrency<AccountId>>::transfer(asset_b, &caller, &maker, INITIAL_BALANCE)?;
+		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, INITIAL_BALANCE - amount)?;
+
+		let share_token = XYK::share_token(XYK::pair_account_from_assets(asset_a, asset_b));
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, 1)
+	verify {
+		assert!(Currencies::free_balance(share_token, &caller) > 0);
+		assert!(Currencies::free_balance(asset_a, &caller) < amount / 1_000_000);
+	}
+
 	remove_liquidity {
 		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
 		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
@@ -110,7 +141,7 @@ runtime_benchmarks! {
 		init_fee_asset(fee_asset)?;
 		MultiTransactionPayment::set_currency(RawOrigin::Signed(maker.clone()).into(), fee_asset)?;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		//Only for XYK shares
 		assert_eq!(frame_system::Pallet::<Runtime>::account(maker.clone()).sufficients, 1);
@@ -139,7 +170,7 @@ runtime_benchmarks! {
 		let amount: Balance = 250_000_000_000_000;
 		let min_bought: Balance = 1;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, INITIAL_BALANCE - amount)?;
 
@@ -170,7 +201,7 @@ runtime_benchmarks! {
 		let amount: Balance = 200_000_000_000_000;
 		let max_sold: Balance = INITIAL_BALANCE;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, 749_249_999_999_999_u128)?;
 
@@ -203,7 +234,7 @@ runtime_benchmarks! {
 		let amount: Balance = 250_000_000_000_000;
 		let min_bought: Balance = 1;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, INITIAL_BALANCE - amount)?;
 		assert_eq!(frame_system::Pallet::<Runtime>::account(caller.clone()).sufficients, 1);
@@ -244,7 +275,7 @@ runtime_benchmarks! {
 		let amount: Balance = 200_000_000_000_000;
 		let max_sold: Balance = INITIAL_BALANCE;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, 749_249_999_999_999_u128)?;
 
@@ -283,7 +314,7 @@ runtime_benchmarks! {
 		let amount: Balance = 200_000_000_000_000;
 		let max_sold: Balance = INITIAL_BALANCE;
 
-		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE, None)?;
 
 		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, 749_249_999_999_999_u128)?;
 
@@ -291,6 +322,28 @@ runtime_benchmarks! {
 	}: {
 		assert!(<XYK as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::XYK, asset_a, asset_b).is_ok());
 	}
+
+	add_fee_tier {
+		let fee_tier = (1, 1_000);
+	}: _(RawOrigin::Root, fee_tier)
+	verify {
+		assert!(pallet_xyk::FeeTiers::<Runtime>::contains_key(fee_tier));
+	}
+
+	remove_fee_tier {
+		let fee_tier = (1, 1_000);
+		XYK::add_fee_tier(RawOrigin::Root.into(), fee_tier)?;
+	}: _(RawOrigin::Root, fee_tier)
+	verify {
+		assert!(!pallet_xyk::FeeTiers::<Runtime>::contains_key(fee_tier));
+	}
+
+	set_protocol_fee {
+		let fee = Permill::from_percent(20);
+	}: _(RawOrigin::Root, fee)
+	verify {
+		assert_eq!(pallet_xyk::ProtocolFee::<Runtime>::get(), fee);
+	}
 }
 
 #[cfg(test)]
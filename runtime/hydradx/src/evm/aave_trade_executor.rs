@@ -433,6 +433,23 @@ where
 		// Price is always 1:1
 		Ok(FixedU128::from(1))
 	}
+
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		let pool = <BorrowingContract<T>>::get();
+		let Ok(reserves) = Self::get_reserves_list(pool) else {
+			return vec![];
+		};
+
+		reserves
+			.into_iter()
+			.filter_map(|reserve| {
+				let data = Self::get_reserve_data(pool, reserve).ok()?;
+				let reserve_asset = HydraErc20Mapping::address_to_asset(reserve)?;
+				let atoken_asset = HydraErc20Mapping::address_to_asset(data.atoken_address)?;
+				Some((PoolType::Aave, vec![reserve_asset, atoken_asset]))
+			})
+			.collect()
+	}
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -0,0 +1,41 @@
+use crate::evm::executor::{BalanceOf, CallResult, NonceIdOf};
+use crate::evm::Executor;
+use evm::ExitReason::Succeed;
+use frame_support::weights::Weight;
+use hydradx_traits::evm::{CallContext, EVM};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use pallet_stableswap::types::{EvmPegCall, EvmPegRateProvider};
+use primitive_types::U256;
+use primitives::Balance;
+use sp_runtime::traits::CheckedConversion;
+use sp_std::marker::PhantomData;
+
+const PEG_RATE_GAS_LIMIT: u64 = 100_000;
+
+/// Reads exchange rates of yield-bearing tokens (eg. ERC-4626 vaults, aTokens) for stableswap pegs.
+pub struct EvmPegRateAdapter<T>(PhantomData<T>);
+
+impl<T> EvmPegRateProvider for EvmPegRateAdapter<T>
+where
+	T: pallet_evm::Config + frame_system::Config,
+	BalanceOf<T>: TryFrom<U256> + Into<U256>,
+	T::AddressMapping: AddressMapping<T::AccountId>,
+	pallet_evm::AccountIdOf<T>: From<T::AccountId>,
+	NonceIdOf<T>: Into<T::Nonce>,
+{
+	fn rate(call: &EvmPegCall) -> Option<Balance> {
+		let context = CallContext::new_view(call.contract);
+		let (res, value): CallResult = Executor::<T>::view(context, call.call_data.to_vec(), PEG_RATE_GAS_LIMIT);
+
+		if !matches!(res, Succeed(_)) {
+			log::debug!(target: "evm", "stableswap peg rate call to {:?} failed: {:?}", call.contract, res);
+			return None;
+		}
+
+		U256::checked_from(value.as_slice())?.try_into().ok()
+	}
+
+	fn rate_weight() -> Weight {
+		<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(PEG_RATE_GAS_LIMIT, true)
+	}
+}
@@ -136,10 +136,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 297,
+	spec_version: 322,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 6,
 	state_version: 1,
 };
 
@@ -205,7 +205,7 @@ construct_runtime!(
 		OmnipoolLiquidityMining: pallet_omnipool_liquidity_mining = 63,
 		OTC: pallet_otc = 64,
 		CircuitBreaker: pallet_circuit_breaker = 65,
-
+		LimitOrders: pallet_limit_orders = 66,
 		Router: pallet_route_executor = 67,
 		DynamicFees: pallet_dynamic_fees = 68,
 		Staking: pallet_staking = 69,
@@ -231,6 +231,8 @@ construct_runtime!(
 
 		XYKLiquidityMining: pallet_xyk_liquidity_mining = 95,
 		XYKWarehouseLM: warehouse_liquidity_mining::<Instance2> = 96,
+		Cryptoswap: pallet_cryptoswap = 97,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 98,
 
 		RelayChainInfo: pallet_relaychain_info = 201,
 		//NOTE: DCA pallet should be declared before ParachainSystem pallet,
@@ -326,12 +328,15 @@ mod benches {
 		[pallet_circuit_breaker, CircuitBreaker]
 		[pallet_bonds, Bonds]
 		[pallet_stableswap, Stableswap]
+		[pallet_cryptoswap, Cryptoswap]
+		[pallet_concentrated_liquidity, ConcentratedLiquidity]
 		[pallet_claims, Claims]
 		[pallet_staking, Staking]
 		[pallet_referrals, Referrals]
 		[pallet_evm_accounts, EVMAccounts]
 		[pallet_otc, OTC]
 		[pallet_otc_settlements, OtcSettlements]
+		[pallet_limit_orders, LimitOrders]
 		[pallet_liquidation, Liquidation]
 		[pallet_state_trie_migration, StateTrieMigration]
 		[frame_system, SystemBench::<Runtime>]
@@ -478,8 +483,9 @@ use frame_support::{
 	weights::WeightToFee as _,
 };
 use hydradx_traits::evm::Erc20Mapping;
+use hydradx_traits::router::AssetPair;
 use pallet_liquidation::BorrowingContract;
-use pallet_route_executor::TradeExecution;
+use pallet_route_executor::{RouteQuote, TradeExecution};
 use polkadot_xcm::latest::Location;
 use polkadot_xcm::{IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
 use primitives::constants::chain::CORE_ASSET_ID;
@@ -1123,6 +1129,151 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			Router::quote_sell(asset_in, asset_out, amount_in, route)
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			Router::quote_buy(asset_in, asset_out, amount_out, route)
+		}
+
+		fn best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			let route = Router::discover_sell_route(asset_in, asset_out, amount_in)?;
+			Router::quote_sell(asset_in, asset_out, amount_in, route)
+		}
+
+		fn best_buy_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			let route = Router::discover_buy_route(asset_in, asset_out, amount_out)?;
+			Router::quote_buy(asset_in, asset_out, amount_out, route)
+		}
+
+		fn broken_routes() -> Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)> {
+			Router::broken_routes()
+		}
+	}
+
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, Balance, ItemId> for Runtime {
+		fn assets() -> Vec<pallet_omnipool_rpc_runtime_api::AssetDetails<AssetId, Balance>> {
+			Omnipool::assets_details()
+		}
+
+		fn asset(asset_id: AssetId) -> Option<pallet_omnipool_rpc_runtime_api::AssetDetails<AssetId, Balance>> {
+			Omnipool::asset_details(asset_id)
+		}
+
+		fn tvl(stable_asset_id: AssetId) -> Option<Balance> {
+			Omnipool::tvl(stable_asset_id)
+		}
+
+		fn position_value(position_id: ItemId) -> Result<pallet_omnipool_rpc_runtime_api::PositionValuation<Balance>, DispatchError> {
+			Omnipool::position_value(position_id)
+		}
+
+		fn position_valuation(
+			position: pallet_omnipool_rpc_runtime_api::Position<Balance, AssetId>,
+		) -> Result<pallet_omnipool_rpc_runtime_api::PositionValuation<Balance>, DispatchError> {
+			Omnipool::position_valuation(&position)
+		}
+	}
+
+	impl pallet_lbp_rpc_runtime_api::LbpApi<Block, AccountId, BlockNumber> for Runtime {
+		fn pool_state(pool_id: AccountId) -> Option<pallet_lbp_rpc_runtime_api::PoolState<BlockNumber>> {
+			LBP::pool_state(pool_id)
+		}
+
+		fn projected_prices(
+			pool_id: AccountId,
+			from: BlockNumber,
+			step: BlockNumber,
+			count: u32,
+		) -> Result<Vec<pallet_lbp_rpc_runtime_api::PricePoint<BlockNumber>>, DispatchError> {
+			LBP::projected_prices(pool_id, from, step, count)
+		}
+	}
+
+	impl pallet_stableswap_rpc_runtime_api::StableswapApi<Block, AssetId, Balance> for Runtime {
+		fn pools() -> Vec<pallet_stableswap_rpc_runtime_api::PoolDetails<AssetId, Balance>> {
+			Stableswap::pools_details()
+		}
+
+		fn pool(pool_id: AssetId) -> Option<pallet_stableswap_rpc_runtime_api::PoolDetails<AssetId, Balance>> {
+			Stableswap::pool_details(pool_id)
+		}
+
+		fn quote_sell(
+			pool_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<pallet_stableswap_rpc_runtime_api::TradeQuote<Balance>, DispatchError> {
+			Stableswap::quote_sell(pool_id, asset_in, asset_out, amount_in)
+		}
+
+		fn quote_buy(
+			pool_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<pallet_stableswap_rpc_runtime_api::TradeQuote<Balance>, DispatchError> {
+			Stableswap::quote_buy(pool_id, asset_in, asset_out, amount_out)
+		}
+
+		fn quote_add_liquidity(
+			pool_id: AssetId,
+			assets: Vec<pallet_stableswap_rpc_runtime_api::AssetAmount<AssetId>>,
+		) -> Result<pallet_stableswap_rpc_runtime_api::AddLiquidityQuote<Balance>, DispatchError> {
+			Stableswap::quote_add_liquidity(pool_id, &assets)
+		}
+
+		fn quote_remove_liquidity_one_asset(
+			pool_id: AssetId,
+			asset_id: AssetId,
+			share_amount: Balance,
+		) -> Result<pallet_stableswap_rpc_runtime_api::TradeQuote<Balance>, DispatchError> {
+			Stableswap::quote_remove_liquidity_one_asset(pool_id, asset_id, share_amount)
+		}
+
+		fn quote_remove_liquidity(
+			pool_id: AssetId,
+			share_amount: Balance,
+		) -> Result<Vec<pallet_stableswap_rpc_runtime_api::AssetAmount<AssetId>>, DispatchError> {
+			Stableswap::quote_remove_liquidity(pool_id, share_amount)
+		}
+	}
+
+	impl pallet_xyk_rpc_runtime_api::XYKApi<Block, AccountId> for Runtime {
+		fn pools() -> Vec<pallet_xyk_rpc_runtime_api::PoolDetails<AccountId>> {
+			XYK::pools_details()
+		}
+
+		fn pool(asset_a: AssetId, asset_b: AssetId) -> Option<pallet_xyk_rpc_runtime_api::PoolDetails<AccountId>> {
+			XYK::pool_details_of_assets(asset_a, asset_b)
+		}
+
+		fn fee_tiers() -> Vec<(u32, u32)> {
+			XYK::fee_tiers()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 
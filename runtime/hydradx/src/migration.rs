@@ -22,4 +22,6 @@ impl cumulus_pallet_xcmp_queue::migration::v5::V5Config for Runtime {
 pub type Migrations = (
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
 	evm::precompiles::erc20_mapping::SetCodeMetadataForErc20Precompile,
+	pallet_xyk::migration::v1::Migration<Runtime>,
+	pallet_dca::migration::v1::Migration<Runtime>,
 );
@@ -11,7 +11,9 @@ pub mod pallet_claims;
 pub mod pallet_collator_selection;
 pub mod pallet_collective_council;
 pub mod pallet_collective_technical_committee;
+pub mod pallet_concentrated_liquidity;
 pub mod pallet_conviction_voting;
+pub mod pallet_cryptoswap;
 pub mod pallet_currencies;
 pub mod pallet_dca;
 pub mod pallet_democracy;
@@ -23,6 +25,7 @@ pub mod pallet_ema_oracle;
 pub mod pallet_evm_accounts;
 pub mod pallet_identity;
 pub mod pallet_lbp;
+pub mod pallet_limit_orders;
 pub mod pallet_liquidation;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
@@ -0,0 +1,139 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for `pallet_limit_orders`
+//!
+//! ESTIMATED WEIGHTS - these values were NOT produced by the benchmark CLI. They are hand-written estimates
+//! based on the storage accesses of each call and have to be replaced by benchmark results
+//! (see the command below) before the pallet is enabled on a live network.
+
+// Command to generate benchmarked weights:
+// ./target/release/hydradx
+// benchmark
+// pallet
+// --wasm-execution=compiled
+// --pallet
+// pallet-limit-orders
+// --extrinsic
+// *
+// --heap-pages
+// 4096
+// --steps
+// 50
+// --repeat
+// 20
+// --template=scripts/pallet-weight-template.hbs
+// --output
+// runtime/hydradx/src/weights/pallet_limit_orders.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_limit_orders`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_limit_orders` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_limit_orders::WeightInfo for HydraWeight<T> {
+	/// Storage: `LimitOrders::NextOrderId` (r:1 w:1)
+	/// Proof: `LimitOrders::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:0 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		Weight::from_parts(78_412_000, 5492)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:0 w:1)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		Weight::from_parts(72_986_000, 5492)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:2 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::AccountOrderCount` (r:1 w:1)
+	/// Proof: `LimitOrders::AccountOrderCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:0 w:1)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn execute_order() -> Weight {
+		Weight::from_parts(91_515_000, 6156)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LimitOrders::OrderBook` (r:1 w:0)
+	/// Proof: `LimitOrders::OrderBook` (`max_values`: None, `max_size`: Some(2027), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:100 w:0)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:2 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::ExecutionRetryAt` (r:100 w:0)
+	/// Proof: `LimitOrders::ExecutionRetryAt` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn process_order_book(n: u32, ) -> Weight {
+		Weight::from_parts(12_871_442, 6110)
+			.saturating_add(Weight::from_parts(9_842_315, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2585).saturating_mul(n.into()))
+	}
+}
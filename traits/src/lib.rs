@@ -288,6 +288,16 @@ pub trait AMMAddLiquidity<AccountId, AssetId, Balance> {
 		amount_a: Balance,
 		amount_b_max_limit: Balance,
 	) -> Result<Balance, DispatchError>;
+
+	/// Add liquidity providing only `asset_a`. Part of `amount_a` is traded for `asset_b` first.
+	/// Returns amount of shares received.
+	fn add_liquidity_single_asset(
+		origin: AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		min_shares: Balance,
+	) -> Result<Balance, DispatchError>;
 }
 
 /// Provides account's fee payment asset
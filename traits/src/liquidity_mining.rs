@@ -171,6 +171,10 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 
 	/// Returns `Some(global_farm_id)` for given `deposit_id` and `yield_farm_id` or `None`.
 	fn get_global_farm_id(deposit_id: DepositId, yield_farm_id: YieldFarmId) -> Option<u32>;
+
+	/// Returns `(global_farm_id, yield_farm_id)` of all farm entries of the deposit. Returns empty vec if
+	/// deposit doesn't exist.
+	fn get_farm_entries(deposit_id: DepositId) -> Vec<(GlobalFarmId, YieldFarmId)>;
 }
 
 /// Implementers of this trait provide `price_adjustment` for given `GlobalFarm`.
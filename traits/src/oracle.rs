@@ -339,6 +339,24 @@ where
 	}
 }
 
+/// Handler of the whitelist of asset pairs whose oracles are tracked.
+pub trait OracleWhitelistHandler<AssetId> {
+	/// Start tracking oracle of given asset pair and source.
+	fn add_to_whitelist(source: Source, asset_a: AssetId, asset_b: AssetId) -> dispatch::DispatchResult;
+
+	fn add_to_whitelist_weight() -> Weight;
+}
+
+impl<AssetId> OracleWhitelistHandler<AssetId> for () {
+	fn add_to_whitelist(_source: Source, _asset_a: AssetId, _asset_b: AssetId) -> dispatch::DispatchResult {
+		Ok(())
+	}
+
+	fn add_to_whitelist_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct RawEntry<Balance, BlockNumber> {
 	pub price: (Balance, Balance),
@@ -3,7 +3,9 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -11,6 +13,7 @@ pub trait RouteSpotPriceProvider<AssetId> {
 	fn spot_price_with_fee(route: &[Trade<AssetId>]) -> Option<FixedU128>;
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialOrd, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
 pub struct AssetPair<AssetId> {
 	pub asset_in: AssetId,
@@ -60,6 +63,7 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
@@ -67,6 +71,8 @@ pub enum PoolType<AssetId> {
 	Stableswap(AssetId),
 	Omnipool,
 	Aave,
+	Cryptoswap(AssetId),
+	ConcentratedLiquidity,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -76,6 +82,7 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
@@ -83,12 +90,27 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 }
 
+/// Result of a route simulation, used to quote trades without executing them
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct RouteQuote<AssetId, Balance> {
+	/// The route the quote was calculated for
+	pub route: Vec<Trade<AssetId>>,
+	/// Amounts in and out of every trade, in the order of the route
+	pub amounts: Vec<AmountInAndOut<Balance>>,
+	/// Fees charged by the pools during the trades, aggregated per fee asset
+	pub fees: Vec<(AssetId, Balance)>,
+	/// Spot price (including fees) of the route after the trades are executed
+	pub spot_price: Option<FixedU128>,
+}
+
 pub fn inverse_route<AssetId>(trades: Vec<Trade<AssetId>>) -> Vec<Trade<AssetId>> {
 	trades
 		.into_iter()
@@ -130,6 +152,42 @@ pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 		route: Vec<Trade>,
 	) -> DispatchResult;
 
+	fn sell_percentage(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		percentage: Permill,
+		min_amount_out: Balance,
+		route: Vec<Trade>,
+	) -> DispatchResult;
+
+	fn buy_to_balance(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		target_balance: Balance,
+		max_amount_in: Balance,
+		route: Vec<Trade>,
+	) -> DispatchResult;
+
+	fn sell_split(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		routes: Vec<(Permill, Vec<Trade>)>,
+	) -> DispatchResult;
+
+	fn buy_split(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		routes: Vec<(Permill, Vec<Trade>)>,
+	) -> DispatchResult;
+
 	fn calculate_sell_trade_amounts(route: &[Trade], amount_in: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
 
 	fn calculate_buy_trade_amounts(route: &[Trade], amount_out: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
@@ -190,10 +248,16 @@ pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 		asset_a: AssetId,
 		asset_b: AssetId,
 	) -> Result<FixedU128, ExecutorError<Self::Error>>;
+
+	/// Pools provided by the AMM, each with the list of assets which can be traded in it.
+	/// Used by the router to discover routes on-chain.
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		Vec::new()
+	}
 }
 
 #[allow(clippy::redundant_clone)] //Needed as it complains about redundant clone, but clone is needed as Origin is moved and it is not copy type.
-#[impl_trait_for_tuples::impl_for_tuples(1, 5)]
+#[impl_trait_for_tuples::impl_for_tuples(1, 7)]
 impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	TradeExecution<Origin, AccountId, AssetId, Balance> for Tuple
 {
@@ -309,6 +373,12 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 		);
 		Err(value)
 	}
+
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		let mut pools = Vec::new();
+		for_tuples!( #( pools.extend(Tuple::pools()); )* );
+		pools
+	}
 }
 
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
@@ -320,6 +390,7 @@ pub trait AmmTradeWeights<Trade> {
 	fn buy_and_calculate_buy_trade_amounts_weight(route: &[Trade]) -> Weight;
 	fn set_route_weight(route: &[Trade]) -> Weight;
 	fn force_insert_route_weight() -> Weight;
+	fn invalidate_route_weight() -> Weight;
 	fn get_route_weight() -> Weight;
 	fn calculate_spot_price_with_fee_weight(route: &[Trade]) -> Weight;
 }
@@ -346,6 +417,9 @@ impl<Trade> AmmTradeWeights<Trade> for () {
 	fn force_insert_route_weight() -> Weight {
 		Weight::zero()
 	}
+	fn invalidate_route_weight() -> Weight {
+		Weight::zero()
+	}
 	fn get_route_weight() -> Weight {
 		Weight::zero()
 	}